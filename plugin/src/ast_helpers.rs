@@ -28,7 +28,8 @@ use crate::{
 mod number;
 
 pub use number::{
-    get_number_literal_string_value, get_number_literal_value, Number, NumberOrBigInt,
+    get_number_literal_string_value, get_number_literal_value, is_bigint_literal,
+    is_binary_literal, is_hex_literal, is_octal_literal, Number, NumberOrBigInt,
 };
 use squalid::EverythingExt;
 use tree_sitter_lint::{
@@ -514,7 +515,6 @@ pub fn maybe_get_directive<'a>(
     })
 }
 
-#[allow(dead_code)]
 pub fn is_default_import(node: Node) -> bool {
     node.kind() == Identifier && node.parent().unwrap().kind() == ImportClause
 }
@@ -851,6 +851,24 @@ pub fn is_async_function(node: Node) -> bool {
     }
 }
 
+pub fn is_promise_executor<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let parent = node.next_non_parentheses_ancestor(context);
+    if parent.kind() != Arguments {
+        return false;
+    }
+    let new_expression = parent.parent().unwrap();
+    if new_expression.kind() != NewExpression {
+        return false;
+    }
+    let callee = new_expression.field("constructor");
+    callee.kind() == Identifier
+        && callee.text(context) == "Promise"
+        && parent
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .next()
+            .matches(|first_argument| first_argument.skip_parentheses() == node)
+}
+
 #[cfg(test)]
 mod tests {
     use speculoos::prelude::*;
@@ -185,19 +185,19 @@ impl hash::Hash for Number {
     }
 }
 
-fn is_bigint_literal(number_node_text: &str) -> bool {
+pub fn is_bigint_literal(number_node_text: &str) -> bool {
     number_node_text.ends_with('n')
 }
 
-fn is_hex_literal(number_node_text: &str) -> bool {
+pub fn is_hex_literal(number_node_text: &str) -> bool {
     number_node_text.starts_with("0x") || number_node_text.starts_with("0X")
 }
 
-fn is_binary_literal(number_node_text: &str) -> bool {
+pub fn is_binary_literal(number_node_text: &str) -> bool {
     number_node_text.starts_with("0b") || number_node_text.starts_with("0B")
 }
 
-fn is_octal_literal(number_node_text: &str) -> bool {
+pub fn is_octal_literal(number_node_text: &str) -> bool {
     number_node_text.starts_with("0o") || number_node_text.starts_with("0O")
 }
 
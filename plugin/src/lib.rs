@@ -26,33 +26,51 @@ pub use code_path_analysis::{
 };
 use rules::{
     accessor_pairs_rule, array_bracket_newline_rule, array_callback_return_rule,
+    arrow_body_style_rule, block_scoped_var_rule, capitalized_comments_rule,
     class_methods_use_this_rule, complexity_rule, consistent_return_rule, constructor_super_rule,
     default_case_last_rule, default_case_rule, default_param_last_rule, dot_location_rule,
-    for_direction_rule, getter_return_rule, guard_for_in_rule, line_comment_position_rule,
-    max_nested_callbacks_rule, max_params_rule, max_statements_rule, no_array_constructor_rule,
-    no_async_promise_executor_rule, no_await_in_loop_rule, no_class_assign_rule,
-    no_compare_neg_zero_rule, no_cond_assign_rule, no_const_assign_rule,
-    no_constant_binary_expression_rule, no_constant_condition_rule, no_constructor_return_rule,
-    no_control_regex_rule, no_debugger_rule, no_dupe_args_rule, no_dupe_class_members_rule,
-    no_dupe_else_if_rule, no_dupe_keys_rule, no_duplicate_case_rule, no_duplicate_imports_rule,
-    no_empty_character_class_rule, no_empty_pattern_rule, no_eq_null_rule, no_ex_assign_rule,
-    no_extra_bind_rule, no_extra_label_rule, no_fallthrough_rule, no_func_assign_rule,
-    no_import_assign_rule, no_inner_declarations_rule, no_invalid_regexp_rule, no_labels_rule,
-    no_lonely_if_rule, no_mixed_operators_rule, no_multi_assign_rule, no_multi_str_rule,
-    no_negated_condition_rule, no_nested_ternary_rule, no_new_native_nonconstructor_rule,
-    no_new_object_rule, no_new_rule, no_new_symbol_rule, no_new_wrappers_rule,
-    no_octal_escape_rule, no_octal_rule, no_param_reassign_rule, no_plusplus_rule, no_proto_rule,
-    no_regex_spaces_rule, no_restricted_properties_rule, no_return_assign_rule, no_script_url_rule,
-    no_self_assign_rule, no_sequences_rule, no_ternary_rule, no_this_before_super_rule,
-    no_throw_literal_rule, no_undef_rule, no_unneeded_ternary_rule, no_unreachable_loop_rule,
+    for_direction_rule, func_name_matching_rule, func_names_rule, func_style_rule,
+    getter_return_rule, guard_for_in_rule, line_comment_position_rule,
+    logical_assignment_operators_rule, max_classes_per_file_rule, max_depth_rule,
+    max_lines_per_function_rule, max_lines_rule, max_nested_callbacks_rule, max_params_rule,
+    max_statements_per_line_rule, max_statements_rule, multiline_comment_style_rule, new_cap_rule,
+    no_array_constructor_rule, no_async_promise_executor_rule, no_await_in_loop_rule,
+    no_case_declarations_rule, no_class_assign_rule, no_compare_neg_zero_rule, no_cond_assign_rule,
+    no_const_assign_rule, no_constant_binary_expression_rule, no_constant_condition_rule,
+    no_constructor_return_rule, no_control_regex_rule, no_debugger_rule, no_div_regex_rule,
+    no_dupe_args_rule, no_dupe_class_members_rule, no_dupe_else_if_rule, no_dupe_keys_rule,
+    no_duplicate_case_rule, no_duplicate_imports_rule, no_empty_character_class_rule,
+    no_empty_pattern_rule, no_eq_null_rule, no_ex_assign_rule, no_extend_native_rule,
+    no_extra_bind_rule, no_extra_boolean_cast_rule, no_extra_label_rule, no_fallthrough_rule,
+    no_func_assign_rule, no_import_assign_rule, no_inner_declarations_rule, no_invalid_regexp_rule,
+    no_irregular_whitespace_rule, no_labels_rule, no_lone_blocks_rule, no_lonely_if_rule,
+    no_loss_of_precision_rule, no_misleading_character_class_rule, no_mixed_operators_rule,
+    no_multi_assign_rule, no_multi_str_rule, no_negated_condition_rule, no_nested_ternary_rule,
+    no_new_native_nonconstructor_rule, no_new_object_rule, no_new_rule, no_new_symbol_rule,
+    no_new_wrappers_rule, no_nonoctal_decimal_escape_rule, no_obj_calls_rule, no_octal_escape_rule,
+    no_octal_rule, no_param_reassign_rule, no_plusplus_rule, no_promise_executor_return_rule,
+    no_proto_rule, no_prototype_builtins_rule, no_regex_spaces_rule, no_restricted_exports_rule,
+    no_restricted_imports_rule, no_restricted_properties_rule, no_restricted_syntax_rule,
+    no_return_assign_rule, no_script_url_rule, no_self_assign_rule, no_self_compare_rule,
+    no_sequences_rule, no_setter_return_rule, no_sparse_arrays_rule,
+    no_template_curly_in_string_rule, no_ternary_rule, no_this_before_super_rule,
+    no_throw_literal_rule, no_undef_rule, no_unexpected_multiline_rule,
+    no_unmodified_loop_condition_rule, no_unneeded_ternary_rule, no_unreachable_loop_rule,
     no_unreachable_rule, no_unsafe_finally_rule, no_unsafe_negation_rule,
-    no_unsafe_optional_chaining_rule, no_unused_labels_rule, no_unused_vars_rule,
-    no_useless_call_rule, no_useless_catch_rule, no_useless_escape_rule, no_useless_return_rule,
-    prefer_destructuring_rule, prefer_numeric_literals_rule, prefer_object_has_own_rule,
-    prefer_promise_reject_errors_rule, prefer_rest_params_rule, prefer_spread_rule,
-    prefer_template_rule, radix_rule, require_await_rule, require_yield_rule, sort_imports_rule,
-    sort_keys_rule, sort_vars_rule, space_unary_ops_rule, symbol_description_rule,
-    vars_on_top_rule, wrap_regex_rule, yield_star_spacing_rule, yoda_rule,
+    no_unsafe_optional_chaining_rule, no_unused_expressions_rule, no_unused_labels_rule,
+    no_unused_private_class_members_rule, no_unused_vars_rule, no_useless_backreference_rule,
+    no_useless_call_rule, no_useless_catch_rule, no_useless_computed_key_rule,
+    no_useless_concat_rule, no_useless_constructor_rule, no_useless_escape_rule,
+    no_useless_rename_rule, no_useless_return_rule, no_warning_comments_rule,
+    object_shorthand_rule, one_var_rule, operator_assignment_rule, prefer_arrow_callback_rule,
+    prefer_destructuring_rule, prefer_exponentiation_operator_rule,
+    prefer_named_capture_group_rule, prefer_numeric_literals_rule, prefer_object_has_own_rule,
+    prefer_promise_reject_errors_rule, prefer_regex_literals_rule, prefer_rest_params_rule,
+    prefer_spread_rule, prefer_template_rule, radix_rule, require_await_rule,
+    require_unicode_regexp_rule, require_yield_rule, sort_imports_rule, sort_keys_rule,
+    sort_vars_rule, space_unary_ops_rule, symbol_description_rule, unicode_bom_rule,
+    use_isnan_rule, valid_typeof_rule, vars_on_top_rule, wrap_regex_rule, yield_star_spacing_rule,
+    yoda_rule,
 };
 use scope::ScopeManager;
 pub use visit::Visit;
@@ -175,6 +193,60 @@ pub fn instantiate() -> Plugin {
             prefer_promise_reject_errors_rule(),
             prefer_numeric_literals_rule(),
             prefer_destructuring_rule(),
+            no_restricted_imports_rule(),
+            no_restricted_exports_rule(),
+            no_restricted_syntax_rule(),
+            object_shorthand_rule(),
+            prefer_arrow_callback_rule(),
+            arrow_body_style_rule(),
+            func_style_rule(),
+            func_names_rule(),
+            func_name_matching_rule(),
+            new_cap_rule(),
+            max_depth_rule(),
+            max_lines_rule(),
+            max_lines_per_function_rule(),
+            max_classes_per_file_rule(),
+            max_statements_per_line_rule(),
+            no_loss_of_precision_rule(),
+            no_unused_expressions_rule(),
+            no_unused_private_class_members_rule(),
+            no_useless_constructor_rule(),
+            no_useless_computed_key_rule(),
+            no_useless_rename_rule(),
+            no_useless_concat_rule(),
+            no_useless_backreference_rule(),
+            no_misleading_character_class_rule(),
+            prefer_regex_literals_rule(),
+            prefer_named_capture_group_rule(),
+            require_unicode_regexp_rule(),
+            no_div_regex_rule(),
+            logical_assignment_operators_rule(),
+            operator_assignment_rule(),
+            prefer_exponentiation_operator_rule(),
+            one_var_rule(),
+            block_scoped_var_rule(),
+            no_case_declarations_rule(),
+            no_lone_blocks_rule(),
+            no_extra_boolean_cast_rule(),
+            no_setter_return_rule(),
+            no_promise_executor_return_rule(),
+            valid_typeof_rule(),
+            use_isnan_rule(),
+            no_self_compare_rule(),
+            no_template_curly_in_string_rule(),
+            no_sparse_arrays_rule(),
+            no_unexpected_multiline_rule(),
+            no_unmodified_loop_condition_rule(),
+            no_irregular_whitespace_rule(),
+            no_nonoctal_decimal_escape_rule(),
+            unicode_bom_rule(),
+            no_obj_calls_rule(),
+            no_prototype_builtins_rule(),
+            no_extend_native_rule(),
+            no_warning_comments_rule(),
+            capitalized_comments_rule(),
+            multiline_comment_style_rule(),
         ])
         .configs([("all".to_owned(), configs::all())])
         .build()
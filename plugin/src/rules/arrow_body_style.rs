@@ -0,0 +1,806 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::{regex, OptionExt};
+use tree_sitter_lint::{
+    range_between_ends, range_between_starts, rule, tree_sitter::Node, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    all_comments::AllComments,
+    ast_helpers::NodeExtJs,
+    kind::{
+        Comment, ForStatement, Object, ParenthesizedExpression, ReturnStatement,
+        SequenceExpression, StatementBlock,
+    },
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+    Always,
+    #[default]
+    AsNeeded,
+    Never,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustMode([Mode; 1]),
+    ModeAndOptionsObject(Mode, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    require_return_for_object_literal: bool,
+}
+
+struct Options {
+    mode: Mode,
+    require_return_for_object_literal: bool,
+}
+
+impl Options {
+    pub fn from_mode_and_options_object(mode: Mode, options_object: OptionsObject) -> Self {
+        Self {
+            mode,
+            require_return_for_object_literal: options_object.require_return_for_object_literal,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_mode_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustMode(mode) => {
+                Self::from_mode_and_options_object(mode[0], Default::default())
+            }
+            OptionsVariants::ModeAndOptionsObject(mode, options_object) => {
+                Self::from_mode_and_options_object(mode, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+fn is_not_comment(node: Node) -> bool {
+    node.kind() != Comment
+}
+
+fn has_asi_problem(token: Option<Node>, context: &QueryMatchContext) -> bool {
+    token.matches(|token| regex!(r#"^[(\[/`+-]"#).is_match(&token.text(context)))
+}
+
+fn comments_exist_in_range(all_comments: &AllComments, start: usize, end: usize) -> bool {
+    all_comments
+        .iter()
+        .any(|comment| comment.start_byte() >= start && comment.end_byte() <= end)
+}
+
+fn is_inside_for_loop_initializer(node: Node) -> bool {
+    let mut current_node = node;
+    while let Some(parent) = current_node.parent() {
+        if parent.kind() == ForStatement
+            && parent
+                .child_by_field_name("initializer")
+                .matches(|initializer| initializer == current_node)
+        {
+            return true;
+        }
+        current_node = parent;
+    }
+    false
+}
+
+pub fn arrow_body_style_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "arrow-body-style",
+        languages => [Javascript],
+        messages => [
+            unexpected_other_block => "Unexpected block statement surrounding arrow body.",
+            unexpected_empty_block => "Unexpected block statement surrounding arrow body; put a value of `undefined` immediately after the `=>`.",
+            unexpected_object_block => "Unexpected block statement surrounding arrow body; parenthesize the returned value and move it immediately after the `=>`.",
+            unexpected_single_block => "Unexpected block statement surrounding arrow body; move the returned value immediately after the `=>`.",
+            expected_block => "Expected block statement surrounding arrow body.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            always: bool = options.mode == Mode::Always,
+            as_needed: bool = options.mode == Mode::AsNeeded,
+            never: bool = options.mode == Mode::Never,
+            require_return_for_object_literal: bool = options.require_return_for_object_literal,
+            [per-file-run]
+            func_info_has_in_operator: Vec<bool>,
+        },
+        methods => {
+            fn validate_block_body(&self, node: Node<'a>, arrow_body: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let block_body = arrow_body.non_comment_named_children(context).collect::<Vec<_>>();
+
+                if block_body.len() != 1 && !self.never {
+                    return;
+                }
+
+                let return_argument = block_body
+                    .first()
+                    .filter(|statement| statement.kind() == ReturnStatement)
+                    .and_then(|statement| statement.maybe_first_non_comment_named_child(context));
+
+                if self.as_needed
+                    && self.require_return_for_object_literal
+                    && return_argument.matches(|return_argument| {
+                        return_argument.skip_parentheses().kind() == Object
+                    })
+                {
+                    return;
+                }
+
+                if !(self.never || self.as_needed && block_body[0].kind() == ReturnStatement) {
+                    return;
+                }
+
+                let message_id = if block_body.is_empty() {
+                    "unexpected_empty_block"
+                } else if block_body.len() > 1 {
+                    "unexpected_other_block"
+                } else if return_argument.matches(|return_argument| {
+                    context.get_first_token(return_argument, Option::<fn(Node) -> bool>::None).kind() == "{"
+                }) {
+                    "unexpected_object_block"
+                } else {
+                    "unexpected_single_block"
+                };
+
+                context.report(violation! {
+                    node => node,
+                    range => arrow_body.range(),
+                    message_id => message_id,
+                    fix => |fixer| {
+                        let Some(return_argument) = return_argument.filter(|_| block_body.len() == 1) else {
+                            return;
+                        };
+                        let token_after_arrow_body =
+                            context.maybe_get_token_after(arrow_body, Some(is_not_comment));
+                        if has_asi_problem(token_after_arrow_body, context) {
+                            return;
+                        }
+
+                        let all_comments = context.retrieve::<AllComments<'a>>();
+                        let opening_brace = context.get_first_token(arrow_body, Option::<fn(Node) -> bool>::None);
+                        let closing_brace = context.get_last_token(arrow_body, Option::<fn(Node) -> bool>::None);
+                        let first_value_token = context.get_first_token(return_argument, Option::<fn(Node) -> bool>::None);
+                        let last_value_token = context.get_last_token(block_body[0], Option::<fn(Node) -> bool>::None);
+                        let comments_exist = comments_exist_in_range(
+                            &all_comments,
+                            opening_brace.end_byte(),
+                            first_value_token.start_byte(),
+                        ) || comments_exist_in_range(
+                            &all_comments,
+                            last_value_token.end_byte(),
+                            closing_brace.start_byte(),
+                        );
+
+                        // Remove tokens around the return value.
+                        // If comments don't exist, remove extra spaces as well.
+                        if comments_exist {
+                            fixer.remove(opening_brace);
+                            fixer.remove(closing_brace);
+                            fixer.remove(context.get_first_token(block_body[0], Option::<fn(Node) -> bool>::None));
+                        } else {
+                            fixer.remove_range(range_between_starts(
+                                opening_brace.range(),
+                                first_value_token.range(),
+                            ));
+                            // Keep the value separated from whatever directly
+                            // follows the closing brace, eg `() => {return a}in b`.
+                            let value_end_token = if last_value_token.kind() == ";" {
+                                context.get_token_before(last_value_token, Option::<fn(Node) -> bool>::None)
+                            } else {
+                                last_value_token
+                            };
+                            if token_after_arrow_body.matches(|token_after_arrow_body| {
+                                token_after_arrow_body.start_byte() == closing_brace.end_byte()
+                                    && !ast_utils::can_tokens_be_adjacent(
+                                        value_end_token,
+                                        token_after_arrow_body,
+                                        context,
+                                    )
+                            }) {
+                                fixer.replace_text_range(
+                                    range_between_ends(last_value_token.range(), closing_brace.range()),
+                                    " ",
+                                );
+                            } else {
+                                fixer.remove_range(range_between_ends(
+                                    last_value_token.range(),
+                                    closing_brace.range(),
+                                ));
+                            }
+                        }
+
+                        // If the first token of the return value is `{` or the return value is a sequence expression,
+                        // enclose the return value by parentheses to avoid syntax error.
+                        if (first_value_token.kind() == "{"
+                            || return_argument.kind() == SequenceExpression
+                            || *self.func_info_has_in_operator.last().unwrap()
+                                && is_inside_for_loop_initializer(node))
+                            && !ast_utils::is_parenthesised(return_argument)
+                        {
+                            fixer.insert_text_before(first_value_token, "(");
+                            fixer.insert_text_after(last_value_token, ")");
+                        }
+
+                        // If the last token of the return statement is semicolon, remove it.
+                        // Non-block arrow body is an expression, not a statement.
+                        if last_value_token.kind() == ";" {
+                            fixer.remove(last_value_token);
+                        }
+                    }
+                });
+            }
+
+            fn validate_expression_body(&self, node: Node<'a>, arrow_body: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                if !(self.always
+                    || self.as_needed
+                        && self.require_return_for_object_literal
+                        && arrow_body.skip_parentheses().kind() == Object)
+                {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    range => arrow_body.skip_parentheses().range(),
+                    message_id => "expected_block",
+                    fix => |fixer| {
+                        let arrow_token = node.maybe_first_child_of_kind("=>").unwrap();
+                        let first_token_after_arrow = context.get_token_after(arrow_token, Some(is_not_comment));
+                        let second_token_after_arrow = context.get_token_after(first_token_after_arrow, Some(is_not_comment));
+                        let last_token = context.get_last_token(node, Option::<fn(Node) -> bool>::None);
+
+                        let parenthesised_object_literal = (first_token_after_arrow.kind() == "("
+                            && second_token_after_arrow.kind() == "{")
+                            .then(|| second_token_after_arrow.parent().unwrap())
+                            .filter(|brace_node| {
+                                brace_node.kind() == Object
+                                    && brace_node.parent().unwrap().kind() == ParenthesizedExpression
+                            });
+
+                        match parenthesised_object_literal {
+                            // If the value is object literal, remove parentheses which were forced by syntax.
+                            Some(parenthesised_object_literal) => {
+                                let opening_paren_token = first_token_after_arrow;
+                                let opening_brace_token = second_token_after_arrow;
+
+                                if ast_utils::is_token_on_same_line(opening_paren_token, opening_brace_token) {
+                                    fixer.replace_text(opening_paren_token, "{return ");
+                                } else {
+                                    // Avoid ASI
+                                    fixer.replace_text(opening_paren_token, "{");
+                                    fixer.insert_text_before(opening_brace_token, "return ");
+                                }
+
+                                // Closing paren for the object doesn't have to be lastToken, e.g.: () => ({}).foo()
+                                fixer.remove(context.get_last_token(
+                                    parenthesised_object_literal.parent().unwrap(),
+                                    Option::<fn(Node) -> bool>::None,
+                                ));
+                                fixer.insert_text_after(last_token, "}");
+                            }
+                            None => {
+                                fixer.insert_text_before(first_token_after_arrow, "{return ");
+                                fixer.insert_text_after(last_token, "}");
+                            }
+                        }
+                    }
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (binary_expression
+                operator: "in"
+              ) @c
+            "# => |node, context| {
+                self.func_info_has_in_operator
+                    .iter_mut()
+                    .for_each(|has_in_operator| {
+                        *has_in_operator = true;
+                    });
+            },
+            r#"
+              (arrow_function) @c
+            "# => |node, context| {
+                self.func_info_has_in_operator.push(false);
+            },
+            r#"
+              arrow_function:exit
+            "# => |node, context| {
+                let arrow_body = node.field("body");
+                if arrow_body.kind() == StatementBlock {
+                    self.validate_block_body(node, arrow_body, context);
+                } else {
+                    self.validate_expression_body(node, arrow_body, context);
+                }
+                self.func_info_has_in_operator.pop().unwrap();
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::ArrowFunction};
+
+    #[test]
+    fn test_arrow_body_style_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            arrow_body_style_rule(),
+            rule_tests! {
+                valid => [
+                    "var foo = () => {};",
+                    "var foo = () => 0;",
+                    "var addToB = (a) => { b =  b + a };",
+                    "var foo = () => { /* do nothing */ };",
+                    "var foo = () => {\n /* do nothing */ \n};",
+                    "var foo = (retv, name) => {\nretv[name] = true;\nreturn retv;\n};",
+                    "var foo = () => ({});",
+                    "var foo = () => bar();",
+                    "var foo = () => { bar(); };",
+                    "var foo = () => { b = a };",
+                    "var foo = () => { bar: 1 };",
+                    { code => "var foo = () => { return 0; };", options => ["always"] },
+                    { code => "var foo = () => { return bar(); };", options => ["always"] },
+                    { code => "var foo = () => 0;", options => ["never"] },
+                    { code => "var foo = () => ({ foo: 0 });", options => ["never"] },
+                    { code => "var foo = () => {};", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var foo = () => 0;", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var addToB = (a) => { b =  b + a };", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var foo = () => { /* do nothing */ };", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var foo = () => {\n /* do nothing */ \n};", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var foo = (retv, name) => {\nretv[name] = true;\nreturn retv;\n};", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var foo = () => bar();", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var foo = () => { bar(); };", options => ["as-needed", { require_return_for_object_literal => true }] },
+                    { code => "var foo = () => { return { bar: 0 }; };", options => ["as-needed", { require_return_for_object_literal => true }] }
+                ],
+                invalid => [
+                    {
+                        code => "for (var foo = () => { return a in b ? bar : () => {} } ;;);",
+                        output => "for (var foo = () => (a in b ? bar : () => {}) ;;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 22, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "a in b; for (var f = () => { return c };;);",
+                        output => "a in b; for (var f = () => c;;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 28, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (a = b => { return c in d ? e : f } ;;);",
+                        output => "for (a = b => (c in d ? e : f) ;;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 15, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (var f = () => { return a };;);",
+                        output => "for (var f = () => a;;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 20, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (var f;f = () => { return a };);",
+                        output => "for (var f;f = () => a;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 22, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (var f = () => { return a in c };;);",
+                        output => "for (var f = () => (a in c);;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 20, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (var f;f = () => { return a in c };);",
+                        output => "for (var f;f = () => a in c;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 22, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (;;){var f = () => { return a in c }}",
+                        output => "for (;;){var f = () => a in c}",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 24, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (a = b => { return c = d in e } ;;);",
+                        output => "for (a = b => (c = d in e) ;;);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 15, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (var a;;a = b => { return c = d in e } );",
+                        output => "for (var a;;a = b => c = d in e );",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 22, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (let a = (b, c, d) => { return vb && c in d; }; ;);",
+                        output => "for (let a = (b, c, d) => (vb && c in d); ;);",
+                        errors => [{ line => 1, column => 27, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (let a = (b, c, d) => { return v in b && c in d; }; ;);",
+                        output => "for (let a = (b, c, d) => (v in b && c in d); ;);",
+                        errors => [{ line => 1, column => 27, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "function foo(){ for (let a = (b, c, d) => { return v in b && c in d; }; ;); }",
+                        output => "function foo(){ for (let a = (b, c, d) => (v in b && c in d); ;); }",
+                        errors => [{ line => 1, column => 43, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for ( a = (b, c, d) => { return v in b && c in d; }; ;);",
+                        output => "for ( a = (b, c, d) => (v in b && c in d); ;);",
+                        errors => [{ line => 1, column => 24, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for ( a = (b) => { return (c in d) }; ;);",
+                        output => "for ( a = (b) => (c in d); ;);",
+                        errors => [{ line => 1, column => 18, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (let a = (b, c, d) => { return vb in dd ; }; ;);",
+                        output => "for (let a = (b, c, d) => (vb in dd ); ;);",
+                        errors => [{ line => 1, column => 27, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "for (let a = (b, c, d) => { return vb in c in dd ; }; ;);",
+                        output => "for (let a = (b, c, d) => (vb in c in dd ); ;);",
+                        errors => [{ line => 1, column => 27, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "do{let a = () => {return f in ff}}while(true){}",
+                        output => "do{let a = () => f in ff}while(true){}",
+                        errors => [{ line => 1, column => 18, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "do{for (let a = (b, c, d) => { return vb in c in dd ; }; ;);}while(true){}",
+                        output => "do{for (let a = (b, c, d) => (vb in c in dd ); ;);}while(true){}",
+                        errors => [{ line => 1, column => 30, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "scores.map(score => { return x in +(score / maxScore).toFixed(2)});",
+                        output => "scores.map(score => x in +(score / maxScore).toFixed(2));",
+                        errors => [{ line => 1, column => 21, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "const fn = (a) => { return a == b}",
+                        output => "const fn = (a) => a == b",
+                        errors => [{ line => 1, column => 19, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => 0",
+                        output => "var foo = () => {return 0}",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 17, end_line => 1, end_column => 18, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "var foo = () => 0;",
+                        output => "var foo = () => {return 0};",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "var foo = () => ({});",
+                        output => "var foo = () => {return {}};",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 18, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "var foo = () => (  {});",
+                        output => "var foo = () => {return   {}};",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 20, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "(() => ({}))",
+                        output => "(() => {return {}})",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 9, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "(() => ( {}))",
+                        output => "(() => {return  {}})",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 10, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return 0; };",
+                        output => "var foo = () => 0;",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return 0 };",
+                        output => "var foo = () => 0;",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return bar(); };",
+                        output => "var foo = () => bar();",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => {\n};",
+                        output => None,
+                        options => ["never"],
+                        errors => [{ line => 1, column => 17, end_line => 2, end_column => 2, type => ArrowFunction, message_id => "unexpected_empty_block" }]
+                    },
+                    {
+                        code => "var foo = () => {\nreturn 0;\n};",
+                        output => "var foo = () => 0;",
+                        options => ["never"],
+                        errors => [{ line => 1, column => 17, end_line => 3, end_column => 2, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return { bar: 0 }; };",
+                        output => "var foo = () => ({ bar: 0 });",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_object_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return ({ bar: 0 }); };",
+                        output => "var foo = () => ({ bar: 0 });",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return a, b }",
+                        output => "var foo = () => (a, b)",
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return };",
+                        output => None, // not fixed
+                        options => ["as-needed", { require_return_for_object_literal => true }],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return; };",
+                        output => None, // not fixed
+                        options => ["as-needed", { require_return_for_object_literal => true }],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return ( /* a */ {ok: true} /* b */ ) };",
+                        output => "var foo = () => ( /* a */ {ok: true} /* b */ );",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return '{' };",
+                        output => "var foo = () => '{';",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return { bar: 0 }.bar; };",
+                        output => "var foo = () => ({ bar: 0 }.bar);",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_object_block" }]
+                    },
+                    {
+                        code => "var foo = (retv, name) => {\nretv[name] = true;\nreturn retv;\n};",
+                        output => None, // not fixed
+                        options => ["never"],
+                        errors => [{ line => 1, column => 27, type => ArrowFunction, message_id => "unexpected_other_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return 0; };",
+                        output => "var foo = () => 0;",
+                        options => ["as-needed", { require_return_for_object_literal => true }],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => { return bar(); };",
+                        output => "var foo = () => bar();",
+                        options => ["as-needed", { require_return_for_object_literal => true }],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => ({});",
+                        output => "var foo = () => {return {}};",
+                        options => ["as-needed", { require_return_for_object_literal => true }],
+                        errors => [{ line => 1, column => 18, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "var foo = () => ({ bar: 0 });",
+                        output => "var foo = () => {return { bar: 0 }};",
+                        options => ["as-needed", { require_return_for_object_literal => true }],
+                        errors => [{ line => 1, column => 18, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "var foo = () => (((((((5)))))));",
+                        output => "var foo = () => {return (((((((5)))))))};",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 24, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        // Not fixed; fixing would cause ASI issues.
+                        code => "var foo = () => { return bar }\n[1, 2, 3].map(foo)",
+                        output => None,
+                        options => ["never"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        // Not fixed; fixing would cause ASI issues.
+                        code => "var foo = () => { return bar }\n(1).toString();",
+                        output => None,
+                        options => ["never"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        // Fixing here is ok because the arrow function has a semicolon afterwards.
+                        code => "var foo = () => { return bar };\n[1, 2, 3].map(foo)",
+                        output => "var foo = () => bar;\n[1, 2, 3].map(foo)",
+                        options => ["never"],
+                        errors => [{ line => 1, column => 17, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = /* a */ ( /* b */ ) /* c */ => /* d */ { /* e */ return /* f */ 5 /* g */ ; /* h */ } /* i */ ;",
+                        output => "var foo = /* a */ ( /* b */ ) /* c */ => /* d */  /* e */  /* f */ 5 /* g */  /* h */  /* i */ ;",
+                        options => ["as-needed"],
+                        errors => [{ line => 1, column => 50, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = /* a */ ( /* b */ ) /* c */ => /* d */ ( /* e */ 5 /* f */ ) /* g */ ;",
+                        output => "var foo = /* a */ ( /* b */ ) /* c */ => /* d */ {return ( /* e */ 5 /* f */ )} /* g */ ;",
+                        options => ["always"],
+                        errors => [{ line => 1, column => 60, type => ArrowFunction, message_id => "expected_block" }]
+                    },
+                    {
+                        code => "var foo = () => {\nreturn bar;\n};",
+                        output => "var foo = () => bar;",
+                        errors => [{ line => 1, column => 17, end_line => 3, end_column => 2, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => {\nreturn bar;};",
+                        output => "var foo = () => bar;",
+                        errors => [{ line => 1, column => 17, end_line => 2, end_column => 13, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "var foo = () => {return bar;\n};",
+                        output => "var foo = () => bar;",
+                        errors => [{ line => 1, column => 17, end_line => 2, end_column => 2, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "
+                          var foo = () => {
+                            return foo
+                              .bar;
+                          };
+                        ",
+                        output => "
+                          var foo = () => foo
+                              .bar;
+                        ",
+                        errors => [{ line => 2, column => 43, type => ArrowFunction, message_id => "unexpected_single_block" }]
+                    },
+                    {
+                        code => "
+                          var foo = () => {
+                            return {
+                              bar: 1,
+                              baz: 2
+                            };
+                          };
+                        ",
+                        output => "
+                          var foo = () => ({
+                              bar: 1,
+                              baz: 2
+                            });
+                        ",
+                        errors => [{ line => 2, column => 43, end_line => 7, end_column => 28, type => ArrowFunction, message_id => "unexpected_object_block" }]
+                    },
+                    {
+                        code => "var foo = () => ({foo: 1}).foo();",
+                        output => "var foo = () => {return {foo: 1}.foo()};",
+                        options => ["always"],
+                        errors => [{ message_id => "expected_block", type => ArrowFunction }]
+                    },
+                    {
+                        code => "var foo = () => ({foo: 1}.foo());",
+                        output => "var foo = () => {return ({foo: 1}.foo())};",
+                        options => ["always"],
+                        errors => [{ message_id => "expected_block", type => ArrowFunction }]
+                    },
+                    {
+                        code => "var foo = () => ( {foo: 1} ).foo();",
+                        output => "var foo = () => {return  {foo: 1} .foo()};",
+                        options => ["always"],
+                        errors => [{ message_id => "expected_block", type => ArrowFunction }]
+                    },
+                    {
+                        code => "
+                          var foo = () => ({
+                              bar: 1,
+                              baz: 2
+                            });
+                        ",
+                        output => "
+                          var foo = () => {return {
+                              bar: 1,
+                              baz: 2
+                            }};
+                        ",
+                        options => ["always"],
+                        errors => [{ message_id => "expected_block", type => ArrowFunction }]
+                    },
+                    {
+                        code => "
+                          parsedYears = _map(years, (year) => (
+                              {
+                                  index : year,
+                                  title : splitYear(year)
+                              }
+                          ));
+                        ",
+                        output => "
+                          parsedYears = _map(years, (year) => {
+                              return {
+                                  index : year,
+                                  title : splitYear(year)
+                              }
+                          });
+                        ",
+                        options => ["always"],
+                        errors => [{ message_id => "expected_block", type => ArrowFunction }]
+                    },
+                    {
+                        code => "const createMarker = (color) => ({ latitude, longitude }, index) => {};",
+                        output => "const createMarker = (color) => {return ({ latitude, longitude }, index) => {}};",
+                        options => ["always"],
+                        errors => [{ message_id => "expected_block", type => ArrowFunction }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
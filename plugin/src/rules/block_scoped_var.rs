@@ -0,0 +1,411 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Range},
+    violation, NodeExt, QueryMatchContext, Rule,
+};
+
+use crate::scope::ScopeManager;
+
+fn is_outside_of_scope(identifier: Node, scope_range: Range) -> bool {
+    identifier.start_byte() < scope_range.start_byte || identifier.end_byte() > scope_range.end_byte
+}
+
+fn check_declared_variables<'a>(
+    declaration: Node<'a>,
+    scope_range: Range,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let scope_manager = context.retrieve::<ScopeManager<'a>>();
+
+    for variable in scope_manager.get_declared_variables(declaration) {
+        let Some(definition) = variable
+            .defs()
+            .find(|def| def.parent() == Some(declaration))
+        else {
+            continue;
+        };
+        let definition_position = definition.name().start_position();
+
+        for reference in variable.references() {
+            let identifier = reference.identifier();
+            if !is_outside_of_scope(identifier, scope_range) {
+                continue;
+            }
+
+            context.report(violation! {
+                node => identifier,
+                message_id => "out_of_scope",
+                data => {
+                    name => identifier.text(context),
+                    definition_line => definition_position.row + 1,
+                    definition_column => definition_position.column + 1,
+                }
+            });
+        }
+    }
+}
+
+pub fn block_scoped_var_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "block-scoped-var",
+        languages => [Javascript],
+        messages => [
+            out_of_scope => "'{{name}}' declared on line {{definition_line}} column {{definition_column}} is used outside of binding context.",
+        ],
+        state => {
+            [per-file-run]
+            stack: Vec<Range>,
+        },
+        listeners => [
+            r#"
+              (program) @c
+              (statement_block) @c
+              (for_statement) @c
+              (for_in_statement) @c
+              (switch_statement) @c
+              (catch_clause) @c
+              (class_static_block) @c
+            "# => |node, context| {
+                self.stack.push(node.range());
+            },
+            r#"
+              program:exit,
+              statement_block:exit,
+              for_statement:exit,
+              for_in_statement:exit,
+              switch_statement:exit,
+              catch_clause:exit,
+              class_static_block:exit
+            "# => |node, context| {
+                self.stack.pop().unwrap();
+            },
+            r#"
+              (variable_declaration) @c
+            "# => |node, context| {
+                check_declared_variables(node, *self.stack.last().unwrap(), context);
+            },
+            r#"
+              (for_in_statement
+                kind: "var"
+              ) @c
+            "# => |node, context| {
+                // `for (var x in y)` doesn't have a separate declaration node,
+                // its binding context is the loop itself
+                check_declared_variables(node.field("left"), node.range(), context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::Identifier};
+
+    #[test]
+    fn test_block_scoped_var_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            block_scoped_var_rule(),
+            rule_tests! {
+                valid => [
+                    // See issue https://github.com/eslint/eslint/issues/2242
+                    "function f() { } f(); var exports = { f: f };",
+                    "var f = () => {}; f(); var exports = { f: f };",
+                    "!function f(){ f; }",
+                    "function f() { } f(); var exports = { f: f };",
+                    "function f() { var a, b; { a = 1; b = 2; } }; f();",
+                    "var a; function f() { var b = a; }",
+                    "function f(a) { }",
+                    "!function(a) { };",
+                    "!function f(a) { };",
+                    "function f(a) { var b = a; }",
+                    "!function f(a) { var b = a; };",
+                    "function f() { var g = f; }",
+                    "function f() { } function g() { var f = g; }",
+                    "function f() { var hasOwnProperty; { hasOwnProperty; } }",
+                    "function f(){ a; b; var a, b; }",
+                    "function f(){ g(); function g(){} }",
+                    "if (true) { var a = 1; a; }",
+                    "var a; if (true) { a; }",
+                    "for (var i = 0; i < 10; i++) { i; }",
+                    "var i; for(i; i; i) { i; }",
+                    "function myFunc(foo) {  \"use strict\";  var { bar } = foo;  bar.hello();}",
+                    "function myFunc(foo) {  \"use strict\";  var [ bar ]  = foo;  bar.hello();}",
+                    "function myFunc(...foo) {  return foo;}",
+                    "var f = () => { var g = f; }",
+                    "class Foo {}\nexport default Foo;",
+                    "foo; class C { static { var foo; } }",
+                    "let foo; class C { static { var foo; } }",
+                    "var foo; { let foo; }",
+                    "function f(){ { var a = 0; a; } }",
+                    "function a() { for (var b in {}) { var c = b; } }",
+                    "function a() { for (var b of {}) { var c = b; } }",
+                    "class C { static { var foo; foo; } }",
+                    "class C { static { foo; var foo; } }",
+                    "class C { static { if (bar) { foo; } var foo; } }",
+                    "var foo; class C { static { foo; } } ",
+                    "class C { static { foo; } } var foo;",
+                    "class C { static { var foo; } } ",
+                    "class C { static {} } "
+                ],
+                invalid => [
+                    {
+                        code => "function f(){ x; { var x; } }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "x",
+                                definition_line => 1,
+                                definition_column => 24
+                            },
+                            line => 1,
+                            column => 15,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "function f(){ { var x; } x; }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "x",
+                                definition_line => 1,
+                                definition_column => 21
+                            },
+                            line => 1,
+                            column => 26,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "function f() { var a; { var b = 0; } a = b; }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "b",
+                                definition_line => 1,
+                                definition_column => 29
+                            },
+                            line => 1,
+                            column => 42,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "function f() { try { var a = 0; } catch (e) { var b = a; } }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "a",
+                                definition_line => 1,
+                                definition_column => 26
+                            },
+                            line => 1,
+                            column => 55,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "function a() { for (var b in {}) { var c = b; } c; }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "c",
+                                definition_line => 1,
+                                definition_column => 40
+                            },
+                            line => 1,
+                            column => 49,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "function a() { for (var b of {}) { var c = b; } c; }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "c",
+                                definition_line => 1,
+                                definition_column => 40
+                            },
+                            line => 1,
+                            column => 49,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "function f(){ switch(2) { case 1: var b = 2; b; break; default: b; break;} b; }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "b",
+                                definition_line => 1,
+                                definition_column => 39
+                            },
+                            line => 1,
+                            column => 76,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "for (var a = 0;;) {} a;",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "a",
+                                definition_line => 1,
+                                definition_column => 10
+                            },
+                            line => 1,
+                            column => 22,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "for (var a in []) {} a;",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "a",
+                                definition_line => 1,
+                                definition_column => 10
+                            },
+                            line => 1,
+                            column => 22,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "for (var a of []) {} a;",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "a",
+                                definition_line => 1,
+                                definition_column => 10
+                            },
+                            line => 1,
+                            column => 22,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "{ var a = 0; } a;",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "a",
+                                definition_line => 1,
+                                definition_column => 7
+                            },
+                            line => 1,
+                            column => 16,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "if (true) { var a; } a;",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "a",
+                                definition_line => 1,
+                                definition_column => 17
+                            },
+                            line => 1,
+                            column => 22,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "if (true) { var a = 1; } else { var a = 2; }",
+                        errors => [
+                            {
+                                message_id => "out_of_scope",
+                                data => {
+                                    name => "a",
+                                    definition_line => 1,
+                                    definition_column => 37
+                                },
+                                line => 1,
+                                column => 17,
+                                type => Identifier
+                            },
+                            {
+                                message_id => "out_of_scope",
+                                data => {
+                                    name => "a",
+                                    definition_line => 1,
+                                    definition_column => 17
+                                },
+                                line => 1,
+                                column => 37,
+                                type => Identifier
+                            }
+                        ]
+                    },
+                    {
+                        code => "for (var i = 0;;) {} for(var i = 0;;) {}",
+                        errors => [
+                            {
+                                message_id => "out_of_scope",
+                                data => {
+                                    name => "i",
+                                    definition_line => 1,
+                                    definition_column => 30
+                                },
+                                line => 1,
+                                column => 10,
+                                type => Identifier
+                            },
+                            {
+                                message_id => "out_of_scope",
+                                data => {
+                                    name => "i",
+                                    definition_line => 1,
+                                    definition_column => 10
+                                },
+                                line => 1,
+                                column => 30,
+                                type => Identifier
+                            }
+                        ]
+                    },
+                    {
+                        code => "class C { static { if (bar) { var foo; } foo; } }",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "foo",
+                                definition_line => 1,
+                                definition_column => 35
+                            },
+                            line => 1,
+                            column => 42,
+                            type => Identifier
+                        }]
+                    },
+                    {
+                        code => "{ var foo,\n  bar; } bar;",
+                        errors => [{
+                            message_id => "out_of_scope",
+                            data => {
+                                name => "bar",
+                                definition_line => 2,
+                                definition_column => 3
+                            },
+                            line => 2,
+                            column => 9,
+                            type => Identifier
+                        }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,608 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::Deserialize;
+use squalid::{regex, OptionExt};
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Point, Range},
+    violation, NodeExt, QueryMatchContext, Rule, SkipOptionsBuilder,
+};
+
+use crate::{
+    all_comments::AllComments,
+    ast_helpers::{get_comment_contents, get_comment_type, CommentType},
+    directives::directives_pattern,
+    kind::Comment,
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Capitalize {
+    #[default]
+    Always,
+    Never,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+struct CommentOptions {
+    ignore_pattern: Option<String>,
+    ignore_inline_comments: bool,
+    ignore_consecutive_comments: bool,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    #[serde(flatten)]
+    common: CommentOptions,
+    line: Option<CommentOptions>,
+    block: Option<CommentOptions>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustCapitalize([Capitalize; 1]),
+    CapitalizeAndOptionsObject(Capitalize, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+#[derive(Clone)]
+struct NormalizedOptions {
+    ignore_pattern_reg_exp: Option<Regex>,
+    ignore_inline_comments: bool,
+    ignore_consecutive_comments: bool,
+}
+
+impl From<CommentOptions> for NormalizedOptions {
+    fn from(value: CommentOptions) -> Self {
+        Self {
+            ignore_pattern_reg_exp: value
+                .ignore_pattern
+                .filter(|ignore_pattern| !ignore_pattern.is_empty())
+                .map(|ignore_pattern| Regex::new(&format!(r#"^\s*(?:{ignore_pattern})"#)).unwrap()),
+            ignore_inline_comments: value.ignore_inline_comments,
+            ignore_consecutive_comments: value.ignore_consecutive_comments,
+        }
+    }
+}
+
+struct Options {
+    capitalize: Capitalize,
+    line: NormalizedOptions,
+    block: NormalizedOptions,
+}
+
+impl Options {
+    pub fn from_capitalize_and_options_object(
+        capitalize: Capitalize,
+        options_object: OptionsObject,
+    ) -> Self {
+        Self {
+            capitalize,
+            line: options_object
+                .line
+                .unwrap_or_else(|| options_object.common.clone())
+                .into(),
+            block: options_object
+                .block
+                .unwrap_or_else(|| options_object.common.clone())
+                .into(),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_capitalize_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustCapitalize(capitalize) => {
+                Self::from_capitalize_and_options_object(capitalize[0], Default::default())
+            }
+            OptionsVariants::CapitalizeAndOptionsObject(capitalize, options_object) => {
+                Self::from_capitalize_and_options_object(capitalize, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+fn get_token_before_including_comments<'a>(
+    comment: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    context.maybe_get_token_before(
+        comment,
+        Some(
+            SkipOptionsBuilder::<fn(Node) -> bool>::default()
+                .include_comments(true)
+                .build()
+                .unwrap(),
+        ),
+    )
+}
+
+fn is_inline_comment<'a>(comment: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let previous_token = get_token_before_including_comments(comment, context);
+    let next_token = context.maybe_get_token_after(
+        comment,
+        Some(
+            SkipOptionsBuilder::<fn(Node) -> bool>::default()
+                .include_comments(true)
+                .build()
+                .unwrap(),
+        ),
+    );
+
+    previous_token
+        .matches(|previous_token| comment.start_position().row == previous_token.end_position().row)
+        && next_token
+            .matches(|next_token| comment.end_position().row == next_token.start_position().row)
+}
+
+fn is_consecutive_comment<'a>(comment: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    get_token_before_including_comments(comment, context)
+        .matches(|previous_token_or_comment| previous_token_or_comment.kind() == Comment)
+}
+
+fn get_comment_char_range(comment: Node, comment_text: &str, offset: usize, len: usize) -> Range {
+    let get_point = |offset: usize| {
+        let preceding_text = &comment_text[..offset];
+        match preceding_text.rfind('\n') {
+            Some(last_newline_index) => Point {
+                row: comment.start_position().row + preceding_text.matches('\n').count(),
+                column: offset - last_newline_index - 1,
+            },
+            None => Point {
+                row: comment.start_position().row,
+                column: comment.start_position().column + offset,
+            },
+        }
+    };
+
+    Range {
+        start_byte: comment.start_byte() + offset,
+        end_byte: comment.start_byte() + offset + len,
+        start_point: get_point(offset),
+        end_point: get_point(offset + len),
+    }
+}
+
+pub fn capitalized_comments_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "capitalized-comments",
+        languages => [Javascript],
+        messages => [
+            unexpected_lowercase_comment => "Comments should not begin with a lowercase character.",
+            unexpected_uppercase_comment => "Comments should not begin with an uppercase character.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            capitalize: Capitalize = options.capitalize,
+            line_options: NormalizedOptions = options.line.clone(),
+            block_options: NormalizedOptions = options.block.clone(),
+        },
+        methods => {
+            fn is_comment_valid(
+                &self,
+                comment: Node<'a>,
+                options: &NormalizedOptions,
+                context: &QueryMatchContext<'a, '_>,
+            ) -> bool {
+                let comment_value = get_comment_contents(comment, context);
+
+                if ast_utils::COMMENTS_IGNORE_PATTERN.is_match(&comment_value)
+                    || directives_pattern.is_match(comment_value.trim())
+                {
+                    return true;
+                }
+
+                let comment_without_asterisks = comment_value.replace('*', "");
+
+                if options
+                    .ignore_pattern_reg_exp
+                    .as_ref()
+                    .matches(|ignore_pattern_reg_exp| {
+                        ignore_pattern_reg_exp.is_match(&comment_without_asterisks)
+                    })
+                {
+                    return true;
+                }
+
+                if options.ignore_inline_comments && is_inline_comment(comment, context) {
+                    return true;
+                }
+
+                if options.ignore_consecutive_comments && is_consecutive_comment(comment, context) {
+                    return true;
+                }
+
+                if regex!(r#"^\s*[^:/?#\s]+://[^?#]"#).is_match(&comment_without_asterisks) {
+                    return true;
+                }
+
+                let Some(first_word_char) = comment_without_asterisks
+                    .chars()
+                    .find(|ch| !ch.is_whitespace())
+                else {
+                    return true;
+                };
+
+                if !first_word_char.is_alphabetic() {
+                    return true;
+                }
+
+                let is_uppercase = first_word_char.to_lowercase().ne([first_word_char]);
+                let is_lowercase = first_word_char.to_uppercase().ne([first_word_char]);
+
+                match self.capitalize {
+                    Capitalize::Always => !is_lowercase,
+                    Capitalize::Never => !is_uppercase,
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                for &comment in context.retrieve::<AllComments<'a>>().iter() {
+                    let options = match get_comment_type(comment, context) {
+                        CommentType::Line => &self.line_options,
+                        CommentType::Block => &self.block_options,
+                    };
+
+                    if self.is_comment_valid(comment, options, context) {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => comment,
+                        message_id => match self.capitalize {
+                            Capitalize::Always => "unexpected_lowercase_comment",
+                            Capitalize::Never => "unexpected_uppercase_comment",
+                        },
+                        fix => |fixer| {
+                            let comment_text = comment.text(context);
+                            let Some((index, letter)) = comment_text[2..]
+                                .char_indices()
+                                .find(|(_, ch)| ch.is_alphabetic())
+                            else {
+                                return;
+                            };
+
+                            fixer.replace_text_range(
+                                get_comment_char_range(
+                                    comment,
+                                    &comment_text,
+                                    index + 2,
+                                    letter.len_utf8(),
+                                ),
+                                match self.capitalize {
+                                    Capitalize::Always => letter.to_uppercase().collect::<String>(),
+                                    Capitalize::Never => letter.to_lowercase().collect::<String>(),
+                                },
+                            );
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_capitalized_comments_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            capitalized_comments_rule(),
+            rule_tests! {
+                valid => [
+                    // No options: capitalization required
+                    "//Uppercase",
+                    "// Uppercase",
+                    "/*Uppercase */",
+                    "/* Uppercase */",
+                    "/*\nUppercase */",
+                    "/** Uppercase */",
+                    "/**\nUppercase */",
+                    "//\u{dc}ber",
+
+                    // No options: Skips comments that only contain whitespace
+                    "// ",
+                    "//",
+                    "/**/",
+                    "/* */",
+                    "/*\n*/",
+                    "/**\n*/",
+
+                    // No options: non-alphabetical is okay
+                    "//123",
+                    "// 123",
+                    "/*123*/",
+                    "/* 123 */",
+                    "/**123 */",
+                    "/** 123 */",
+                    "/**\n123 */",
+                    "/*\n123 */",
+                    "/*123\n*/",
+                    "/*\n123\n*/",
+                    "// !foo",
+
+                    // No options: eslint/istanbul/jshint/jscs/globals?/exported are okay
+                    "// jscs: enable",
+                    "// jscs:disable",
+                    "// eslint-disable-line",
+                    "// eslint-disable-next-line",
+                    "/* eslint semi:off */",
+                    "/* eslint-env node */",
+                    "/* istanbul ignore next */",
+                    "/* jshint asi:true */",
+                    "/* jscs: enable */",
+                    "/* global var1, var2 */",
+                    "/* global var1:true, var2 */",
+                    "/* globals var1, var2 */",
+                    "/* globals var1:true, var2 */",
+                    "/* exported myVar */",
+
+                    // Ignores shebangs
+                    "#!foo",
+                    { code => "#!foo", options => ["always"] },
+                    { code => "#!Foo", options => ["never"] },
+                    "#!/usr/bin/env node",
+                    { code => "#!/usr/bin/env node", options => ["always"] },
+                    { code => "#!/usr/bin/env node", options => ["never"] },
+
+                    // Using "always" string option
+                    { code => "//Uppercase", options => ["always"] },
+                    { code => "// Uppercase", options => ["always"] },
+                    { code => "/*Uppercase */", options => ["always"] },
+                    { code => "/* Uppercase */", options => ["always"] },
+
+                    // Using "never" string option
+                    { code => "//lowercase", options => ["never"] },
+                    { code => "// lowercase", options => ["never"] },
+                    { code => "/*lowercase */", options => ["never"] },
+                    { code => "/* lowercase */", options => ["never"] },
+                    { code => "/*\nlowercase */", options => ["never"] },
+                    { code => "//\u{fc}ber", options => ["never"] },
+                    { code => "// eslint-disable-line", options => ["never"] },
+                    { code => "/* eslint-env node */", options => ["never"] },
+                    { code => "/* global var1, var2 */", options => ["never"] },
+
+                    // Directive comments
+                    "/* eslint-disable */",
+                    "/* eslint-enable */",
+                    "// eslint-disable-next-line no-console -- reason",
+                    "/* exported */",
+
+                    // Comments which start with URLs should always be valid
+                    { code => "// https://github.com", options => ["always"] },
+                    { code => "// HTTPS://GITHUB.COM", options => ["never"] },
+
+                    // Using ignorePattern
+                    { code => "//v8 foo", options => ["always", { ignore_pattern => "v\\d" }] },
+                    { code => "// lowercase ignored", options => ["always", { ignore_pattern => "lowercase" }] },
+                    { code => "/* lowercase ignored */", options => ["always", { ignore_pattern => "lowercase" }] },
+                    { code => "/*\n * lowercase ignored */", options => ["always", { ignore_pattern => "lowercase" }] },
+                    { code => "// Uppercase ignored", options => ["never", { ignore_pattern => "Upper" }] },
+
+                    // Inline comments are ignored when configured
+                    { code => "foo(/* ignored */ a);", options => ["always", { ignore_inline_comments => true }] },
+                    { code => "foo(/* Ignored */ a);", options => ["never", { ignore_inline_comments => true }] },
+
+                    // Consecutive comments are ignored when configured
+                    {
+                        code => "// This comment is valid since it is capitalized,\n// and this one is valid since it follows a valid one,\n// and same with this one.",
+                        options => ["always", { ignore_consecutive_comments => true }]
+                    },
+                    {
+                        code => "/* This comment is valid since it is capitalized, */\n/* and this one is valid since it follows a valid one, */\n/* and same with this one. */",
+                        options => ["always", { ignore_consecutive_comments => true }]
+                    },
+                    {
+                        code => "// This comment is valid since it is capitalized,\n/* and this one is valid since it follows a valid one, */\n// and same with this one.",
+                        options => ["always", { ignore_consecutive_comments => true }]
+                    },
+
+                    // Separate line/block options
+                    { code => "// ignored", options => ["always", { line => { ignore_pattern => "ignored" } }] },
+                    { code => "/* ignored */", options => ["always", { block => { ignore_pattern => "ignored" } }] },
+                    {
+                        code => "// lowercase\n/* Uppercase */",
+                        options => ["always", { line => { ignore_pattern => "lowercase" }, block => { ignore_inline_comments => true } }]
+                    }
+                ],
+                invalid => [
+                    // No options: capitalization required
+                    {
+                        code => "//lowercase",
+                        output => "//Lowercase",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1, type => Comment }]
+                    },
+                    {
+                        code => "// lowercase",
+                        output => "// Lowercase",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "/*lowercase */",
+                        output => "/*Lowercase */",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "/* lowercase */",
+                        output => "/* Lowercase */",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "/** lowercase */",
+                        output => "/** Lowercase */",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "/*\nlowercase */",
+                        output => "/*\nLowercase */",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "/**\nlowercase */",
+                        output => "/**\nLowercase */",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "//\u{fc}ber",
+                        output => "//\u{dc}ber",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "//\u{3c0}",
+                        output => "//\u{3a0}",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "foo(/* invalid */a);",
+                        output => "foo(/* Invalid */a);",
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 5 }]
+                    },
+
+                    // Using "never" string option
+                    {
+                        code => "//Uppercase",
+                        output => "//uppercase",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected_uppercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "// Uppercase",
+                        output => "// uppercase",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected_uppercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "/*Uppercase */",
+                        output => "/*uppercase */",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected_uppercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "/*\nUppercase */",
+                        output => "/*\nuppercase */",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected_uppercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "//\u{dc}ber",
+                        output => "//\u{fc}ber",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected_uppercase_comment", line => 1, column => 1 }]
+                    },
+
+                    // Not a URL
+                    {
+                        code => "// http",
+                        output => "// Http",
+                        options => ["always"],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+
+                    // Not matching ignorePattern
+                    {
+                        code => "// not matching",
+                        output => "// Not matching",
+                        options => ["always", { ignore_pattern => "ignored?" }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "// not matching",
+                        output => "// Not matching",
+                        options => ["always", { line => { ignore_pattern => "ignored?" } }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "// line\n/* block */",
+                        output => "// Line\n/* block */",
+                        options => ["always", { block => { ignore_pattern => "block" } }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    },
+
+                    // Not an inline comment
+                    {
+                        code => "foo(a, // not an inline comment\nb);",
+                        output => "foo(a, // Not an inline comment\nb);",
+                        options => ["always", { ignore_inline_comments => true }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 8 }]
+                    },
+                    {
+                        code => "foo(a, /* not an inline comment */\nb);",
+                        output => "foo(a, /* Not an inline comment */\nb);",
+                        options => ["always", { ignore_inline_comments => true }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 8 }]
+                    },
+                    {
+                        code => "foo(a,\n/* not an inline comment */b);",
+                        output => "foo(a,\n/* Not an inline comment */b);",
+                        options => ["always", { ignore_inline_comments => true }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 2, column => 1 }]
+                    },
+                    {
+                        code => "foo(a,\n/* not an inline comment */\nb);",
+                        output => "foo(a,\n/* Not an inline comment */\nb);",
+                        options => ["always", { ignore_inline_comments => true }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 2, column => 1 }]
+                    },
+
+                    // Consecutive comments
+                    {
+                        code => "// This comment is valid since it is capitalized,\n// and this one is valid since it follows a valid one,\n// and same with this one.",
+                        output => "// This comment is valid since it is capitalized,\n// And this one is valid since it follows a valid one,\n// And same with this one.",
+                        options => ["always"],
+                        errors => [
+                            { message_id => "unexpected_lowercase_comment", line => 2, column => 1 },
+                            { message_id => "unexpected_lowercase_comment", line => 3, column => 1 }
+                        ]
+                    },
+                    {
+                        code => "// this comment is invalid since it is not capitalized,\n// but this one is ignored since it is consecutive.",
+                        output => "// This comment is invalid since it is not capitalized,\n// but this one is ignored since it is consecutive.",
+                        options => ["always", { ignore_consecutive_comments => true }],
+                        errors => [{ message_id => "unexpected_lowercase_comment", line => 1, column => 1 }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,677 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+use squalid::{regex, OptionExt};
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::{get_call_expression_arguments, skip_nodes_of_type, NodeExtJs},
+    kind::{
+        self, CallExpression, ComputedPropertyName, Function, GeneratorFunction, Identifier,
+        MemberExpression, Object, Pair, PropertyIdentifier, SubscriptExpression,
+    },
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum AlwaysNever {
+    #[default]
+    Always,
+    Never,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    consider_property_descriptor: bool,
+    #[serde(alias = "includeCommonJSModuleExports")]
+    include_common_js_module_exports: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustAlwaysNever([AlwaysNever; 1]),
+    JustOptionsObject([OptionsObject; 1]),
+    AlwaysNeverAndOptionsObject(AlwaysNever, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+struct Options {
+    always_never: AlwaysNever,
+    consider_property_descriptor: bool,
+    include_common_js_module_exports: bool,
+}
+
+impl Options {
+    pub fn from_always_never_and_options_object(
+        always_never: AlwaysNever,
+        options_object: OptionsObject,
+    ) -> Self {
+        Self {
+            always_never,
+            consider_property_descriptor: options_object.consider_property_descriptor,
+            include_common_js_module_exports: options_object.include_common_js_module_exports,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_always_never_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustAlwaysNever(always_never) => {
+                Self::from_always_never_and_options_object(always_never[0], Default::default())
+            }
+            OptionsVariants::JustOptionsObject([options_object]) => {
+                Self::from_always_never_and_options_object(Default::default(), options_object)
+            }
+            OptionsVariants::AlwaysNeverAndOptionsObject(always_never, options_object) => {
+                Self::from_always_never_and_options_object(always_never, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+const RESERVED_WORDS: [&str; 37] = [
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+fn is_identifier(name: &str) -> bool {
+    regex!(r#"^[\p{XID_Start}$_][\p{XID_Continue}$\u{200C}\u{200D}]*$"#).is_match(name)
+        && !RESERVED_WORDS.contains(&name)
+}
+
+fn is_module_exports(pattern: Node, context: &QueryMatchContext) -> bool {
+    match pattern.kind() {
+        MemberExpression => {
+            let object = pattern.field("object");
+            object.kind() == Identifier
+                && object.text(context) == "module"
+                && pattern.field("property").text(context) == "exports"
+        }
+        SubscriptExpression => {
+            let object = pattern.field("object");
+            object.kind() == Identifier
+                && object.text(context) == "module"
+                && ast_utils::get_static_string_value(pattern.field("index"), context)
+                    .matches(|index| index == "exports")
+        }
+        _ => false,
+    }
+}
+
+fn is_property_call(
+    object_name: &str,
+    func_name: &str,
+    node: Option<Node>,
+    context: &QueryMatchContext,
+) -> bool {
+    node.matches(|node| {
+        node.kind() == CallExpression
+            && ast_utils::is_specific_member_access(
+                node.field("function"),
+                Some(object_name),
+                Some(func_name),
+                context,
+            )
+    })
+}
+
+fn get_string_literal_value<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    let node = skip_nodes_of_type(node, ComputedPropertyName);
+    (node.kind() == kind::String)
+        .then(|| ast_utils::get_static_string_value(node, context))
+        .flatten()
+}
+
+fn get_named_function_expression_name<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    let node = node.skip_parentheses();
+    if !matches!(node.kind(), Function | GeneratorFunction) {
+        return None;
+    }
+    node.child_by_field_name("name")
+        .map(|name| name.text(context))
+}
+
+fn parent_of_kind<'a>(node: Option<Node<'a>>, kind: &str) -> Option<Node<'a>> {
+    node.and_then(|node| node.parent())
+        .filter(|parent| parent.kind() == kind)
+}
+
+pub fn func_name_matching_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "func-name-matching",
+        languages => [Javascript],
+        messages => [
+            match_property => "Function name `{{func_name}}` should match property name `{{name}}`.",
+            match_variable => "Function name `{{func_name}}` should match variable name `{{name}}`.",
+            not_match_property => "Function name `{{func_name}}` should not match property name `{{name}}`.",
+            not_match_variable => "Function name `{{func_name}}` should not match variable name `{{name}}`.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            name_matches: AlwaysNever = options.always_never,
+            consider_property_descriptor: bool = options.consider_property_descriptor,
+            include_module_exports: bool = options.include_common_js_module_exports,
+        },
+        methods => {
+            fn should_warn(&self, func_name: &str, name: &str) -> bool {
+                match self.name_matches {
+                    AlwaysNever::Always => func_name != name,
+                    AlwaysNever::Never => func_name == name,
+                }
+            }
+
+            fn report(&self, node: Node<'a>, name: &str, func_name: &str, is_prop: bool, context: &QueryMatchContext<'a, '_>) {
+                context.report(violation! {
+                    node => node,
+                    message_id => match (self.name_matches, is_prop) {
+                        (AlwaysNever::Always, true) => "match_property",
+                        (AlwaysNever::Always, false) => "match_variable",
+                        (AlwaysNever::Never, true) => "not_match_property",
+                        (AlwaysNever::Never, false) => "not_match_variable",
+                    },
+                    data => {
+                        func_name => func_name,
+                        name => name,
+                    }
+                });
+            }
+
+            fn check_property(&self, node: Node<'a>, key: Node<'a>, function_name: &str, context: &QueryMatchContext<'a, '_>) {
+                if key.kind() == PropertyIdentifier {
+                    let property_name = key.text(context);
+
+                    if self.consider_property_descriptor
+                        && property_name == "value"
+                        && node.parent().unwrap().kind() == Object
+                    {
+                        let object = node.parent().unwrap();
+                        let call_of_object = parent_of_kind(Some(object), kind::Arguments)
+                            .and_then(|arguments| arguments.parent());
+                        let outer_pair = parent_of_kind(Some(object), Pair);
+                        let call_of_outer_object =
+                            parent_of_kind(parent_of_kind(outer_pair, Object), kind::Arguments)
+                                .and_then(|arguments| arguments.parent());
+
+                        if is_property_call("Object", "defineProperty", call_of_object, context)
+                            || is_property_call("Reflect", "defineProperty", call_of_object, context)
+                        {
+                            let property = get_call_expression_arguments(call_of_object.unwrap())
+                                .and_then(|mut arguments| arguments.nth(1));
+                            if let Some(property_value) = property
+                                .and_then(|property| get_string_literal_value(property, context))
+                            {
+                                if self.should_warn(function_name, &property_value) {
+                                    self.report(node, &property_value, function_name, true, context);
+                                }
+                            }
+                        } else if is_property_call("Object", "defineProperties", call_of_outer_object, context)
+                            || is_property_call("Object", "create", call_of_outer_object, context)
+                        {
+                            let outer_key = outer_pair.unwrap().field("key");
+                            if outer_key.kind() == PropertyIdentifier {
+                                let property_name = outer_key.text(context);
+                                if self.should_warn(function_name, &property_name) {
+                                    self.report(node, &property_name, function_name, true, context);
+                                }
+                            }
+                        } else if self.should_warn(function_name, &property_name) {
+                            self.report(node, &property_name, function_name, true, context);
+                        }
+                    } else if self.should_warn(function_name, &property_name) {
+                        self.report(node, &property_name, function_name, true, context);
+                    }
+                    return;
+                }
+
+                if let Some(key_value) = get_string_literal_value(key, context) {
+                    if is_identifier(&key_value) && self.should_warn(function_name, &key_value) {
+                        self.report(node, &key_value, function_name, true, context);
+                    }
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (variable_declarator
+                name: (identifier) @name
+                value: (_) @value
+              ) @c
+            "# => |captures, context| {
+                let node = captures["c"];
+                let Some(function_name) = get_named_function_expression_name(captures["value"], context) else {
+                    return;
+                };
+                let name = captures["name"].text(context);
+                if self.should_warn(&function_name, &name) {
+                    self.report(node, &name, &function_name, false, context);
+                }
+            },
+            r#"
+              (assignment_expression) @c
+            "# => |node, context| {
+                let Some(function_name) = get_named_function_expression_name(node.field("right"), context) else {
+                    return;
+                };
+                let left = node.field("left");
+                if left.kind() == SubscriptExpression
+                    && get_string_literal_value(left.field("index"), context).is_none()
+                    || !self.include_module_exports && is_module_exports(left, context)
+                    || !matches!(left.kind(), Identifier | MemberExpression | SubscriptExpression)
+                {
+                    return;
+                }
+
+                let is_prop = left.kind() != Identifier;
+                let name = if is_prop {
+                    ast_utils::get_static_property_name(left, context)
+                } else {
+                    Some(left.text(context))
+                };
+
+                if let Some(name) = name {
+                    if is_identifier(&name) && self.should_warn(&function_name, &name) {
+                        self.report(node, &name, &function_name, is_prop, context);
+                    }
+                }
+            },
+            r#"
+              (pair) @c
+              (field_definition
+                value: (_)
+              ) @c
+            "# => |node, context| {
+                let Some(function_name) = get_named_function_expression_name(node.field("value"), context) else {
+                    return;
+                };
+                let key = if node.kind() == Pair {
+                    node.field("key")
+                } else {
+                    node.field("property")
+                };
+                self.check_property(node, key, &function_name, context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::{AssignmentExpression, FieldDefinition, VariableDeclarator};
+
+    #[test]
+    fn test_func_name_matching_rule() {
+        RuleTester::run(
+            func_name_matching_rule(),
+            rule_tests! {
+                valid => [
+                    "var foo;",
+                    "var foo = function foo() {};",
+                    { code => "var foo = function foo() {};", options => ["always"] },
+                    { code => "var foo = function bar() {};", options => ["never"] },
+                    "var foo = function() {}",
+                    { code => "var foo = () => {}", environment => { ecma_version => 6 } },
+                    "foo = function foo() {};",
+                    { code => "foo = function foo() {};", options => ["always"] },
+                    { code => "foo = function bar() {};", options => ["never"] },
+                    { code => "foo &&= function foo() {};", environment => { ecma_version => 2021 } },
+                    { code => "obj.foo ||= function foo() {};", environment => { ecma_version => 2021 } },
+                    { code => "obj['foo'] ??= function foo() {};", environment => { ecma_version => 2021 } },
+                    "obj.foo = function foo() {};",
+                    { code => "obj.foo = function foo() {};", options => ["always"] },
+                    { code => "obj.foo = function bar() {};", options => ["never"] },
+                    "obj.foo = function() {};",
+                    { code => "obj.foo = function() {};", options => ["always"] },
+                    { code => "obj.foo = function() {};", options => ["never"] },
+                    "obj.bar.foo = function foo() {};",
+                    { code => "obj.bar.foo = function foo() {};", options => ["always"] },
+                    { code => "obj.bar.foo = function baz() {};", options => ["never"] },
+                    "obj['foo'] = function foo() {};",
+                    { code => "obj['foo'] = function foo() {};", options => ["always"] },
+                    { code => "obj['foo'] = function bar() {};", options => ["never"] },
+                    "obj['foo//bar'] = function foo() {};",
+                    { code => "obj['foo//bar'] = function foo() {};", options => ["always"] },
+                    { code => "obj['foo//bar'] = function foo() {};", options => ["never"] },
+                    "obj[foo] = function bar() {};",
+                    { code => "obj[foo] = function bar() {};", options => ["always"] },
+                    { code => "obj[foo] = function bar() {};", options => ["never"] },
+                    "var obj = {foo: function foo() {}};",
+                    { code => "var obj = {foo: function foo() {}};", options => ["always"] },
+                    { code => "var obj = {foo: function bar() {}};", options => ["never"] },
+                    "var obj = {'foo': function foo() {}};",
+                    { code => "var obj = {'foo': function foo() {}};", options => ["always"] },
+                    { code => "var obj = {'foo': function bar() {}};", options => ["never"] },
+                    "var obj = {'foo//bar': function foo() {}};",
+                    { code => "var obj = {'foo//bar': function foo() {}};", options => ["always"] },
+                    { code => "var obj = {'foo//bar': function foo() {}};", options => ["never"] },
+                    "var obj = {foo: function() {}};",
+                    { code => "var obj = {foo: function() {}};", options => ["always"] },
+                    { code => "var obj = {foo: function() {}};", options => ["never"] },
+                    { code => "var obj = {[foo]: function bar() {}} ", environment => { ecma_version => 6 } },
+                    { code => "var obj = {['x' + 2]: function bar(){}};", environment => { ecma_version => 6 } },
+                    "obj['x' + 2] = function bar(){};",
+                    { code => "var [ bar ] = [ function bar(){} ];", environment => { ecma_version => 6 } },
+                    { code => "function a(foo = function bar() {}) {}", environment => { ecma_version => 6 } },
+                    "module.exports = function foo(name) {};",
+                    "module['exports'] = function foo(name) {};",
+                    { code => "module.exports = function foo(name) {};", options => [{ include_common_js_module_exports => false }] },
+                    { code => "module.exports = function foo(name) {};", options => ["always", { include_common_js_module_exports => false }] },
+                    { code => "module.exports = function foo(name) {};", options => ["never", { include_common_js_module_exports => false }] },
+                    { code => "module['exports'] = function foo(name) {};", options => [{ include_common_js_module_exports => false }] },
+                    { code => "module['exports'] = function foo(name) {};", options => ["always", { include_common_js_module_exports => false }] },
+                    { code => "module['exports'] = function foo(name) {};", options => ["never", { include_common_js_module_exports => false }] },
+                    "({['foo']: function foo() {}})",
+                    { code => "({['foo']: function foo() {}})", options => ["always"] },
+                    { code => "({['foo']: function bar() {}})", options => ["never"] },
+                    { code => "({['❤']: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[foo]: function bar() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[null]: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[1]: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[true]: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[`x`]: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[/abc/]: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[[1, 2, 3]]: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "({[{x: y}]: function foo() {}})", environment => { ecma_version => 6 } },
+                    { code => "a.b.c = function foo() {};", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Object.defineProperty(foo, 'bar', { value: function bar() {} })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Object.defineProperties(foo, { bar: { value: function bar() {} } })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Object.create(proto, { bar: { value: function bar() {} } })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Object.defineProperty(foo, 'b' + 'ar', { value: function bar() {} })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Object.defineProperties(foo, { ['bar']: { value: function bar() {} } })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Object.create(proto, { ['bar']: { value: function bar() {} } })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Object.defineProperty(foo, 'bar', { value() {} })", options => ["never", { consider_property_descriptor => true }] },
+                    { code => "Object.defineProperties(foo, { bar: { value() {} } })", options => ["never", { consider_property_descriptor => true }] },
+                    { code => "Object.create(proto, { bar: { value() {} } })", options => ["never", { consider_property_descriptor => true }] },
+                    { code => "Reflect.defineProperty(foo, 'bar', { value: function bar() {} })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Reflect.defineProperty(foo, 'b' + 'ar', { value: function baz() {} })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "Reflect.defineProperty(foo, 'bar', { value() {} })", options => ["never", { consider_property_descriptor => true }] },
+                    { code => "foo({ value: function value() {} })", options => ["always", { consider_property_descriptor => true }] },
+                    { code => "class C { x = function () {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { x = function () {}; }", options => ["always"], environment => { ecma_version => 2022 } },
+                    { code => "class C { 'x' = function () {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { ['x'] = function () {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { #x = function () {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { x = function x() {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { x = function y() {}; }", options => ["never"], environment => { ecma_version => 2022 } },
+                    { code => "class C { 'x' = function x() {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { 'x' = function y() {}; }", options => ["never"], environment => { ecma_version => 2022 } },
+                    { code => "class C { [x] = function y() {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { 'xy ' = function foo() {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { 1 = function x0() {}; }", environment => { ecma_version => 2022 } },
+                    { code => "class C { [1] = function x1() {}; }", environment => { ecma_version => 2022 } }
+                ],
+                invalid => [
+                    {
+                        code => "let foo = function bar() {};",
+                        options => ["always"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_variable", data => { func_name => "bar", name => "foo" }, type => VariableDeclarator }]
+                    },
+                    {
+                        code => "let foo = function bar() {};",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_variable", data => { func_name => "bar", name => "foo" }, type => VariableDeclarator }]
+                    },
+                    {
+                        code => "foo = function bar() {};",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_variable", data => { func_name => "bar", name => "foo" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "obj.foo = function bar() {};",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "bar", name => "foo" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "obj.bar.foo = function bar() {};",
+                        options => ["always"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "bar", name => "foo" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "obj['foo'] = function bar() {};",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "bar", name => "foo" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "let obj = {foo: function bar() {}};",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "bar", name => "foo" }, type => Pair }]
+                    },
+                    {
+                        code => "let obj = {'foo': function bar() {}};",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "bar", name => "foo" }, type => Pair }]
+                    },
+                    {
+                        code => "({['foo']: function bar() {}})",
+                        options => ["always"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "bar", name => "foo" }, type => Pair }]
+                    },
+                    {
+                        code => "module.exports = function foo(name) {};",
+                        options => [{ include_common_js_module_exports => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "foo", name => "exports" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "module.exports = function foo(name) {};",
+                        options => ["always", { include_common_js_module_exports => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "foo", name => "exports" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "module.exports = function exports(name) {};",
+                        options => ["never", { include_common_js_module_exports => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "not_match_property", data => { func_name => "exports", name => "exports" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "module['exports'] = function foo(name) {};",
+                        options => [{ include_common_js_module_exports => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "foo", name => "exports" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "module['exports'] = function exports(name) {};",
+                        options => ["never", { include_common_js_module_exports => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "not_match_property", data => { func_name => "exports", name => "exports" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "var foo = function foo(name) {};",
+                        options => ["never"],
+                        errors => [{ message_id => "not_match_variable", data => { func_name => "foo", name => "foo" }, type => VariableDeclarator }]
+                    },
+                    {
+                        code => "obj.foo = function foo(name) {};",
+                        options => ["never"],
+                        errors => [{ message_id => "not_match_property", data => { func_name => "foo", name => "foo" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { value: function baz() {} })",
+                        options => ["always", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "match_property", data => { func_name => "baz", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "Object.defineProperties(foo, { bar: { value: function baz() {} } })",
+                        options => ["always", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "match_property", data => { func_name => "baz", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "Object.create(proto, { bar: { value: function baz() {} } })",
+                        options => ["always", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "match_property", data => { func_name => "baz", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "var obj = { value: function foo(name) {} }",
+                        options => ["always", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "match_property", data => { func_name => "foo", name => "value" }, type => Pair }]
+                    },
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { value: function bar() {} })",
+                        options => ["never", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "not_match_property", data => { func_name => "bar", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "Object.defineProperties(foo, { bar: { value: function bar() {} } })",
+                        options => ["never", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "not_match_property", data => { func_name => "bar", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "Object.create(proto, { bar: { value: function bar() {} } })",
+                        options => ["never", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "not_match_property", data => { func_name => "bar", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "Reflect.defineProperty(foo, 'bar', { value: function baz() {} })",
+                        options => ["always", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "match_property", data => { func_name => "baz", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "Reflect.defineProperty(foo, 'bar', { value: function bar() {} })",
+                        options => ["never", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "not_match_property", data => { func_name => "bar", name => "bar" }, type => Pair }]
+                    },
+                    {
+                        code => "foo({ value: function bar() {} })",
+                        options => ["always", { consider_property_descriptor => true }],
+                        errors => [{ message_id => "match_property", data => { func_name => "bar", name => "value" }, type => Pair }]
+                    },
+                    {
+                        code => "module.exports = function foo(name) {};",
+                        options => ["always", { include_common_js_module_exports => true }],
+                        errors => [{ message_id => "match_property", data => { func_name => "foo", name => "exports" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "class C { x = function y() {}; }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "match_property", data => { func_name => "y", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class C { x = function x() {}; }",
+                        options => ["never"],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "not_match_property", data => { func_name => "x", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class C { 'x' = function y() {}; }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "match_property", data => { func_name => "y", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class C { 'x' = function x() {}; }",
+                        options => ["never"],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "not_match_property", data => { func_name => "x", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class C { ['x'] = function y() {}; }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "match_property", data => { func_name => "y", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class C { ['x'] = function x() {}; }",
+                        options => ["never"],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "not_match_property", data => { func_name => "x", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class C { static x = function y() {}; }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "match_property", data => { func_name => "y", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class C { static x = function x() {}; }",
+                        options => ["never"],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "not_match_property", data => { func_name => "x", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "(class { x = function y() {}; })",
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "match_property", data => { func_name => "y", name => "x" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "var obj = { '\\u1885': function foo() {} };", // Not a valid identifier in es5
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "match_property", data => { func_name => "foo", name => "\u{1885}" }, type => Pair }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,462 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::NodeExtJs,
+    kind::{
+        AssignmentExpression, AssignmentPattern, FieldDefinition, GeneratorFunction, Identifier,
+        ObjectAssignmentPattern, Pair, ShorthandPropertyIdentifierPattern, VariableDeclarator,
+    },
+    scope::{ScopeManager, VariableType},
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Config {
+    #[default]
+    Always,
+    AsNeeded,
+    Never,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    generators: Option<Config>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustConfig([Config; 1]),
+    ConfigAndOptionsObject(Config, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+struct Options {
+    config: Config,
+    generators: Config,
+}
+
+impl Options {
+    pub fn from_config_and_options_object(config: Config, options_object: OptionsObject) -> Self {
+        Self {
+            config,
+            generators: options_object.generators.unwrap_or(config),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_config_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustConfig(config) => {
+                Self::from_config_and_options_object(config[0], Default::default())
+            }
+            OptionsVariants::ConfigAndOptionsObject(config, options_object) => {
+                Self::from_config_and_options_object(config, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+fn has_inferred_name<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let parent = node.next_non_parentheses_ancestor(context);
+    let is_value = |field_name: &str| {
+        parent
+            .child_by_field_name(field_name)
+            .matches(|value| value.skip_parentheses() == node)
+    };
+
+    match parent.kind() {
+        VariableDeclarator => parent.field("name").kind() == Identifier && is_value("value"),
+        Pair | FieldDefinition => is_value("value"),
+        AssignmentExpression | AssignmentPattern => {
+            parent.field("left").kind() == Identifier && is_value("right")
+        }
+        ObjectAssignmentPattern => {
+            parent.field("left").kind() == ShorthandPropertyIdentifierPattern && is_value("right")
+        }
+        _ => false,
+    }
+}
+
+fn is_recursive<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let scope_manager = context.retrieve::<ScopeManager<'a>>();
+    scope_manager
+        .get_declared_variables(node)
+        .find(|variable| {
+            variable
+                .defs()
+                .next()
+                .matches(|def| def.type_() == VariableType::FunctionName)
+        })
+        .matches(|variable| variable.references().next().is_some())
+}
+
+pub fn func_names_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "func-names",
+        languages => [Javascript],
+        messages => [
+            unnamed => "Unexpected unnamed {{name}}.",
+            named => "Unexpected named {{name}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            config: Config = options.config,
+            generators_config: Config = options.generators,
+        },
+        methods => {
+            fn get_config_for_node(&self, node: Node<'a>) -> Config {
+                if node.kind() == GeneratorFunction {
+                    self.generators_config
+                } else {
+                    self.config
+                }
+            }
+
+            fn report(&self, node: Node<'a>, message_id: &'static str, context: &QueryMatchContext<'a, '_>) {
+                context.report(violation! {
+                    node => node,
+                    message_id => message_id,
+                    range => ast_utils::get_function_head_range(node),
+                    data => {
+                        name => ast_utils::get_function_name_with_kind(node, context),
+                    }
+                });
+            }
+        },
+        listeners => [
+            r#"
+              function:exit,
+              generator_function:exit
+            "# => |node, context| {
+                // Skip recursive functions.
+                if is_recursive(node, context) {
+                    return;
+                }
+
+                let has_name = node.child_by_field_name("name").is_some();
+
+                match self.get_config_for_node(node) {
+                    Config::Never => {
+                        if has_name {
+                            self.report(node, "named", context);
+                        }
+                    }
+                    Config::AsNeeded => {
+                        if !has_name && !has_inferred_name(node, context) {
+                            self.report(node, "unnamed", context);
+                        }
+                    }
+                    Config::Always => {
+                        if !has_name {
+                            self.report(node, "unnamed", context);
+                        }
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{Function, GeneratorFunction},
+    };
+
+    #[test]
+    fn test_func_names_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            func_names_rule(),
+            rule_tests! {
+                valid => [
+                    "Foo.prototype.bar = function bar(){};",
+                    { code => "Foo.prototype.bar = () => {}", environment => { ecma_version => 6 } },
+                    "function foo(){}",
+                    "function test(d, e, f) {}",
+                    "new function bar(){}",
+                    "exports = { get foo() { return 1; }, set bar(val) { return val; } };",
+                    { code => "({ foo() { return 1; } });", environment => { ecma_version => 6 } },
+                    { code => "class A { constructor(){} foo(){} get bar(){} set baz(value){} static qux(){}}", environment => { ecma_version => 6 } },
+                    "function foo() {}",
+                    "var a = function foo() {};",
+                    { code => "class A { constructor(){} foo(){} get bar(){} set baz(value){} static qux(){}}", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "({ foo() {} });", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "var foo = function(){};", options => ["as-needed"] },
+                    { code => "({foo: function(){}});", options => ["as-needed"] },
+                    { code => "(foo = function(){});", options => ["as-needed"] },
+                    { code => "({foo = function(){}} = {});", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "({key: foo = function(){}} = {});", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "[foo = function(){}] = [];", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "function fn(foo = function(){}) {}", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "function foo() {}", options => ["never"] },
+                    { code => "var a = function() {};", options => ["never"] },
+                    { code => "var a = function foo() { foo(); };", options => ["never"] },
+                    { code => "var foo = {bar: function() {}};", options => ["never"] },
+                    { code => "$('#foo').click(function() {});", options => ["never"] },
+                    { code => "Foo.prototype.bar = function() {};", options => ["never"] },
+                    { code => "class A { constructor(){} foo(){} get bar(){} set baz(value){} static qux(){}}", options => ["never"], environment => { ecma_version => 6 } },
+                    { code => "({ foo() {} });", options => ["never"], environment => { ecma_version => 6 } },
+
+                    // export default
+                    { code => "export default function foo() {}", options => ["always"], environment => { source_type => "module", ecma_version => 6 } },
+                    { code => "export default function foo() {}", options => ["as-needed"], environment => { source_type => "module", ecma_version => 6 } },
+                    { code => "export default function foo() {}", options => ["never"], environment => { source_type => "module", ecma_version => 6 } },
+                    { code => "export default function() {}", options => ["never"], environment => { source_type => "module", ecma_version => 6 } },
+
+                    // generators
+                    { code => "var foo = bar(function *baz() {});", environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["always"], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["always", { generators => "always" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["always", { generators => "as-needed" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["always", { generators => "as-needed" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["as-needed"], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["as-needed", { generators => "always" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["as-needed", { generators => "as-needed" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["as-needed", { generators => "as-needed" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["never", { generators => "always" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *baz() {});", options => ["never", { generators => "as-needed" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["never", { generators => "as-needed" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *() {});", options => ["never"], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["never"], environment => { ecma_version => 6 } },
+                    { code => "(function*() {}())", options => ["never"], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *() {});", options => ["never", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["never", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "(function*() {}())", options => ["never", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *() {});", options => ["always", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["always", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "(function*() {}())", options => ["always", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = bar(function *() {});", options => ["as-needed", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "var foo = function*() {};", options => ["as-needed", { generators => "never" }], environment => { ecma_version => 6 } },
+                    { code => "(function*() {}())", options => ["as-needed", { generators => "never" }], environment => { ecma_version => 6 } },
+
+                    // class fields
+                    { code => "class C { foo = function() {}; }", options => ["as-needed"], environment => { ecma_version => 2022 } },
+                    { code => "class C { [foo] = function() {}; }", options => ["as-needed"], environment => { ecma_version => 2022 } },
+                    { code => "class C { #foo = function() {}; }", options => ["as-needed"], environment => { ecma_version => 2022 } }
+                ],
+                invalid => [
+                    {
+                        code => "Foo.prototype.bar = function() {};",
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 21, end_column => 29 }]
+                    },
+                    {
+                        code => "(function(){}())",
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 2, end_column => 10 }]
+                    },
+                    {
+                        code => "f(function(){})",
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 3, end_column => 11 }]
+                    },
+                    {
+                        code => "var a = new Date(function() {});",
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 18, end_column => 26 }]
+                    },
+                    {
+                        code => "var test = function(d, e, f) {};",
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 12, end_column => 20 }]
+                    },
+                    {
+                        code => "new function() {}",
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 5, end_column => 13 }]
+                    },
+                    {
+                        code => "Foo.prototype.bar = function() {};",
+                        options => ["as-needed"],
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 21, end_column => 29 }]
+                    },
+                    {
+                        code => "(function(){}())",
+                        options => ["as-needed"],
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 2, end_column => 10 }]
+                    },
+                    {
+                        code => "f(function(){})",
+                        options => ["as-needed"],
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 3, end_column => 11 }]
+                    },
+                    {
+                        code => "var a = new Date(function() {});",
+                        options => ["as-needed"],
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 18, end_column => 26 }]
+                    },
+                    {
+                        code => "new function() {}",
+                        options => ["as-needed"],
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 5, end_column => 13 }]
+                    },
+                    {
+                        code => "var {foo} = function(){};",
+                        options => ["as-needed"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 13, end_column => 21 }]
+                    },
+                    {
+                        code => "({ a: obj.prop = function(){} } = foo);",
+                        options => ["as-needed"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 18, end_column => 26 }]
+                    },
+                    {
+                        code => "[obj.prop = function(){}] = foo;",
+                        options => ["as-needed"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 13, end_column => 21 }]
+                    },
+                    {
+                        code => "var { a: [b] = function(){} } = foo;",
+                        options => ["as-needed"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 16, end_column => 24 }]
+                    },
+                    {
+                        code => "function foo({ a } = function(){}) {};",
+                        options => ["as-needed"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, line => 1, column => 22, end_column => 30 }]
+                    },
+                    {
+                        code => "var x = function foo() {};",
+                        options => ["never"],
+                        errors => [{ message_id => "named", data => { name => "function 'foo'" }, type => Function, line => 1, column => 9, end_column => 21 }]
+                    },
+                    {
+                        code => "Foo.prototype.bar = function foo() {};",
+                        options => ["never"],
+                        errors => [{ message_id => "named", data => { name => "function 'foo'" }, type => Function, line => 1, column => 21, end_column => 33 }]
+                    },
+                    {
+                        code => "({foo: function foo() {}})",
+                        options => ["never"],
+                        errors => [{ message_id => "named", data => { name => "method 'foo'" }, type => Function, line => 1, column => 3, end_column => 20 }]
+                    },
+
+                    // export default
+                    {
+                        code => "export default function() {}",
+                        options => ["always"],
+                        environment => { source_type => "module", ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, column => 16, end_column => 24 }]
+                    },
+                    {
+                        code => "export default function() {}",
+                        options => ["as-needed"],
+                        environment => { source_type => "module", ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, column => 16, end_column => 24 }]
+                    },
+                    {
+                        code => "export default (function(){});",
+                        options => ["as-needed"],
+                        environment => { source_type => "module", ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "function" }, type => Function, column => 17, end_column => 25 }]
+                    },
+
+                    // generators
+                    {
+                        code => "var foo = bar(function *() {});",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "generator function" }, type => GeneratorFunction, line => 1, column => 15, end_column => 25 }]
+                    },
+                    {
+                        code => "var foo = function*() {};",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "generator function" }, type => GeneratorFunction, line => 1, column => 11, end_column => 20 }]
+                    },
+                    {
+                        code => "(function*() {}())",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "generator function" }, type => GeneratorFunction, line => 1, column => 2, end_column => 11 }]
+                    },
+                    {
+                        code => "var foo = bar(function *() {});",
+                        options => ["always", { generators => "as-needed" }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "generator function" }, type => GeneratorFunction, line => 1, column => 15, end_column => 25 }]
+                    },
+                    {
+                        code => "var foo = bar(function *() {});",
+                        options => ["as-needed"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "generator function" }, type => GeneratorFunction, line => 1, column => 15, end_column => 25 }]
+                    },
+                    {
+                        code => "var foo = function*() {};",
+                        options => ["never", { generators => "always" }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "generator function" }, type => GeneratorFunction, line => 1, column => 11, end_column => 20 }]
+                    },
+                    {
+                        code => "var foo = bar(function *() {});",
+                        options => ["never", { generators => "as-needed" }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unnamed", data => { name => "generator function" }, type => GeneratorFunction, line => 1, column => 15, end_column => 25 }]
+                    },
+                    {
+                        code => "var foo = bar(function *baz() {});",
+                        options => ["never"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "named", data => { name => "generator function 'baz'" }, type => GeneratorFunction, line => 1, column => 15, end_column => 28 }]
+                    },
+                    {
+                        code => "var foo = bar(function *baz() {});",
+                        options => ["always", { generators => "never" }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "named", data => { name => "generator function 'baz'" }, type => GeneratorFunction, line => 1, column => 15, end_column => 28 }]
+                    },
+
+                    // class fields
+                    {
+                        code => "class C { foo = function() {} }",
+                        options => ["always"],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "unnamed", data => { name => "method 'foo'" }, column => 11, end_column => 25 }]
+                    },
+                    {
+                        code => "class C { #foo = function foo() {} }",
+                        options => ["never"],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "named", data => { name => "private method #foo" }, column => 11, end_column => 29 }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,388 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::{is_export_default, NodeExtJs},
+    kind::{ExportStatement, VariableDeclarator},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Style {
+    Declaration,
+    #[default]
+    Expression,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum NamedExportsStyle {
+    Declaration,
+    Expression,
+    Ignore,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Overrides {
+    named_exports: Option<NamedExportsStyle>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustStyle([Style; 1]),
+    StyleAndOptionsObject(Style, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    allow_arrow_functions: bool,
+    overrides: Overrides,
+}
+
+struct Options {
+    style: Style,
+    allow_arrow_functions: bool,
+    named_exports: Option<NamedExportsStyle>,
+}
+
+impl Options {
+    pub fn from_style_and_options_object(style: Style, options_object: OptionsObject) -> Self {
+        Self {
+            style,
+            allow_arrow_functions: options_object.allow_arrow_functions,
+            named_exports: options_object.overrides.named_exports,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_style_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustStyle(style) => {
+                Self::from_style_and_options_object(style[0], Default::default())
+            }
+            OptionsVariants::StyleAndOptionsObject(style, options_object) => {
+                Self::from_style_and_options_object(style, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+fn is_named_export(node: Node) -> bool {
+    node.kind() == ExportStatement && !is_export_default(node)
+}
+
+fn get_variable_declarator_parent<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    Some(node.next_non_parentheses_ancestor(context))
+        .filter(|parent| parent.kind() == VariableDeclarator)
+}
+
+fn is_in_named_export(variable_declarator: Node) -> bool {
+    variable_declarator
+        .parent()
+        .and_then(|declaration| declaration.parent())
+        .matches(is_named_export)
+}
+
+pub fn func_style_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "func-style",
+        languages => [Javascript],
+        messages => [
+            expression => "Expected a function expression.",
+            declaration => "Expected a function declaration.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            enforce_declarations: bool = options.style == Style::Declaration,
+            allow_arrow_functions: bool = options.allow_arrow_functions,
+            export_function_style: Option<NamedExportsStyle> = options.named_exports,
+            [per-file-run]
+            stack: Vec<bool>,
+        },
+        methods => {
+            fn check_variable_declarator(&self, variable_declarator: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let is_in_named_export = is_in_named_export(variable_declarator);
+                if self.enforce_declarations
+                    && (self.export_function_style.is_none() || !is_in_named_export)
+                    || is_in_named_export
+                        && self.export_function_style == Some(NamedExportsStyle::Declaration)
+                {
+                    context.report(violation! {
+                        node => variable_declarator,
+                        message_id => "declaration",
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (function_declaration) @c
+              (generator_function_declaration) @c
+            "# => |node, context| {
+                self.stack.push(false);
+
+                let parent = node.parent().unwrap();
+                let is_in_named_export = is_named_export(parent);
+                if !self.enforce_declarations
+                    && !is_export_default(parent)
+                    && (self.export_function_style.is_none() || !is_in_named_export)
+                    || is_in_named_export
+                        && self.export_function_style == Some(NamedExportsStyle::Expression)
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "expression",
+                    });
+                }
+            },
+            r#"
+              (function) @c
+              (generator_function) @c
+            "# => |node, context| {
+                self.stack.push(false);
+
+                if let Some(variable_declarator) = get_variable_declarator_parent(node, context) {
+                    self.check_variable_declarator(variable_declarator, context);
+                }
+            },
+            r#"
+              (method_definition) @c
+            "# => |node, context| {
+                self.stack.push(false);
+            },
+            r#"
+              function_declaration:exit,
+              generator_function_declaration:exit,
+              function:exit,
+              generator_function:exit,
+              method_definition:exit
+            "# => |node, context| {
+                self.stack.pop().unwrap();
+            },
+            r#"
+              (this) @c
+              (super) @c
+            "# => |node, context| {
+                if let Some(has_this_or_super_expr) = self.stack.last_mut() {
+                    *has_this_or_super_expr = true;
+                }
+            },
+            r#"
+              (arrow_function) @c
+            "# => |node, context| {
+                if self.allow_arrow_functions {
+                    return;
+                }
+                self.stack.push(false);
+            },
+            r#"
+              arrow_function:exit
+            "# => |node, context| {
+                if self.allow_arrow_functions {
+                    return;
+                }
+
+                let has_this_or_super_expr = self.stack.pop().unwrap();
+                if has_this_or_super_expr {
+                    return;
+                }
+                if let Some(variable_declarator) = get_variable_declarator_parent(node, context) {
+                    self.check_variable_declarator(variable_declarator, context);
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::FunctionDeclaration;
+
+    #[test]
+    fn test_func_style_rule() {
+        RuleTester::run(
+            func_style_rule(),
+            rule_tests! {
+                valid => [
+                    { code => "function foo(){}\n function bar(){}", options => ["declaration"] },
+                    { code => "foo.bar = function(){};", options => ["declaration"] },
+                    { code => "(function() { /* code */ }());", options => ["declaration"] },
+                    { code => "var module = (function() { return {}; }());", options => ["declaration"] },
+                    { code => "var object = { foo: function(){} };", options => ["declaration"] },
+                    { code => "Array.prototype.foo = function(){};", options => ["declaration"] },
+                    { code => "foo.bar = function(){};", options => ["expression"] },
+                    { code => "var foo = function(){};\n var bar = function(){};", options => ["expression"] },
+                    { code => "var foo = () => {};\n var bar = () => {}", options => ["expression"], environment => { ecma_version => 6 } },
+
+                    // https://github.com/eslint/eslint/issues/3819
+                    { code => "var foo = function() { this; }.bind(this);", options => ["declaration"] },
+                    { code => "var foo = () => { this; };", options => ["declaration"], environment => { ecma_version => 6 } },
+                    { code => "class C extends D { foo() { var bar = () => { super.baz(); }; } }", options => ["declaration"], environment => { ecma_version => 6 } },
+                    { code => "var obj = { foo() { var bar = () => super.baz; } }", options => ["declaration"], environment => { ecma_version => 6 } },
+                    { code => "export default function () {};", environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "var foo = () => {};", options => ["declaration", { allow_arrow_functions => true }], environment => { ecma_version => 6 } },
+                    { code => "var foo = () => { function foo() { this; } };", options => ["declaration", { allow_arrow_functions => true }], environment => { ecma_version => 6 } },
+                    { code => "var foo = () => ({ bar() { super.baz(); } });", options => ["declaration", { allow_arrow_functions => true }], environment => { ecma_version => 6 } },
+                    { code => "export function foo() {};", options => ["declaration"], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export function foo() {};", options => ["expression", { overrides => { named_exports => "declaration" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export function foo() {};", options => ["declaration", { overrides => { named_exports => "declaration" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export function foo() {};", options => ["expression", { overrides => { named_exports => "ignore" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export function foo() {};", options => ["declaration", { overrides => { named_exports => "ignore" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = function(){};", options => ["expression"], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = function(){};", options => ["declaration", { overrides => { named_exports => "expression" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = function(){};", options => ["expression", { overrides => { named_exports => "expression" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = function(){};", options => ["declaration", { overrides => { named_exports => "ignore" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = function(){};", options => ["expression", { overrides => { named_exports => "ignore" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = () => {};", options => ["expression", { overrides => { named_exports => "expression" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = () => {};", options => ["declaration", { overrides => { named_exports => "expression" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = () => {};", options => ["declaration", { overrides => { named_exports => "ignore" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = () => {};", options => ["expression", { overrides => { named_exports => "ignore" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = () => {};", options => ["declaration", { allow_arrow_functions => true, overrides => { named_exports => "expression" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = () => {};", options => ["expression", { allow_arrow_functions => true, overrides => { named_exports => "expression" } }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export var foo = () => {};", options => ["declaration", { allow_arrow_functions => true, overrides => { named_exports => "ignore" } }], environment => { ecma_version => 6, source_type => "module" } }
+                ],
+                invalid => [
+                    {
+                        code => "var foo = function(){};",
+                        options => ["declaration"],
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "var foo = () => {};",
+                        options => ["declaration"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "var foo = () => { function foo() { this; } };",
+                        options => ["declaration"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "var foo = () => ({ bar() { super.baz(); } });",
+                        options => ["declaration"],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "function foo(){}",
+                        options => ["expression"],
+                        errors => [{ message_id => "expression", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "export function foo(){}",
+                        options => ["expression"],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "expression", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "export function foo() {};",
+                        options => ["declaration", { overrides => { named_exports => "expression" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "expression", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "export function foo() {};",
+                        options => ["expression", { overrides => { named_exports => "expression" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "expression", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "export var foo = function(){};",
+                        options => ["declaration"],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "export var foo = function(){};",
+                        options => ["expression", { overrides => { named_exports => "declaration" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "export var foo = function(){};",
+                        options => ["declaration", { overrides => { named_exports => "declaration" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "export var foo = () => {};",
+                        options => ["declaration"],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "export var b = () => {};",
+                        options => ["expression", { overrides => { named_exports => "declaration" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "export var c = () => {};",
+                        options => ["declaration", { overrides => { named_exports => "declaration" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "function foo() {};",
+                        options => ["expression", { overrides => { named_exports => "declaration" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "expression", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "var foo = function() {};",
+                        options => ["declaration", { overrides => { named_exports => "expression" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    },
+                    {
+                        code => "var foo = () => {};",
+                        options => ["declaration", { overrides => { named_exports => "expression" } }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "declaration", type => VariableDeclarator }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,1376 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{
+        get_call_expression_arguments, get_number_literal_value, is_logical_expression, NodeExtJs,
+        Number, NumberOrBigInt,
+    },
+    kind::{
+        self, AssignmentExpression, BinaryExpression, CallExpression, ExpressionStatement,
+        Identifier, MemberExpression, StatementBlock, SubscriptExpression, Super, This,
+        UnaryExpression, Undefined, WithStatement,
+    },
+    scope::{Scope, ScopeManager},
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+    #[default]
+    Always,
+    Never,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustMode([Mode; 1]),
+    ModeAndOptionsObject(Mode, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    enforce_for_if_statements: bool,
+}
+
+struct Options {
+    mode: Mode,
+    enforce_for_if_statements: bool,
+}
+
+impl Options {
+    pub fn from_mode_and_options_object(mode: Mode, options_object: OptionsObject) -> Self {
+        Self {
+            mode,
+            enforce_for_if_statements: options_object.enforce_for_if_statements,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_mode_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustMode(mode) => {
+                Self::from_mode_and_options_object(mode[0], Default::default())
+            }
+            OptionsVariants::ModeAndOptionsObject(mode, options_object) => {
+                Self::from_mode_and_options_object(mode, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+fn is_undefined(expression: Node, scope: &Scope, context: &QueryMatchContext) -> bool {
+    match expression.kind() {
+        Undefined => ast_utils::is_reference_to_global_variable(scope, expression),
+        UnaryExpression => {
+            expression.field("operator").kind() == "void" && {
+                let argument = expression.field("argument").skip_parentheses();
+                argument.kind() == kind::Number
+                    && get_number_literal_value(argument, context)
+                        == NumberOrBigInt::Number(Number::Integer(0))
+            }
+        }
+        _ => false,
+    }
+}
+
+fn is_reference(expression: Node) -> bool {
+    matches!(
+        expression.kind(),
+        Identifier | MemberExpression | SubscriptExpression
+    )
+}
+
+fn is_binary_expression_with_operator(expression: Node, operator: &str) -> bool {
+    expression.kind() == BinaryExpression && expression.field("operator").kind() == operator
+}
+
+fn get_reference_and_nullish(expression: Node) -> (Node, Node) {
+    let left = expression.field("left").skip_parentheses();
+    let right = expression.field("right").skip_parentheses();
+    if is_reference(left) {
+        (left, right)
+    } else {
+        (right, left)
+    }
+}
+
+fn is_implicit_nullish_comparison(
+    expression: Node,
+    scope: &Scope,
+    context: &QueryMatchContext,
+) -> bool {
+    if !is_binary_expression_with_operator(expression, "==") {
+        return false;
+    }
+
+    let (reference, nullish) = get_reference_and_nullish(expression);
+    is_reference(reference)
+        && (ast_utils::is_null_literal(nullish) || is_undefined(nullish, scope, context))
+}
+
+fn is_explicit_nullish_comparison(
+    expression: Node,
+    scope: &Scope,
+    context: &QueryMatchContext,
+) -> bool {
+    if !is_binary_expression_with_operator(expression, "||") {
+        return false;
+    }
+
+    let left = expression.field("left").skip_parentheses();
+    let right = expression.field("right").skip_parentheses();
+    if !is_binary_expression_with_operator(left, "===")
+        || !is_binary_expression_with_operator(right, "===")
+    {
+        return false;
+    }
+
+    let (left_reference, left_nullish) = get_reference_and_nullish(left);
+    let (right_reference, right_nullish) = get_reference_and_nullish(right);
+    ast_utils::is_same_reference(left_reference, right_reference, None, context)
+        && (ast_utils::is_null_literal(left_nullish) && is_undefined(right_nullish, scope, context)
+            || is_undefined(left_nullish, scope, context)
+                && ast_utils::is_null_literal(right_nullish))
+}
+
+fn get_boolean_cast_argument<'a>(
+    expression: Node<'a>,
+    scope: &Scope,
+    context: &QueryMatchContext,
+) -> Option<Node<'a>> {
+    if expression.kind() != CallExpression {
+        return None;
+    }
+    let callee = expression.field("function");
+    if !(callee.kind() == Identifier
+        && callee.text(context) == "Boolean"
+        && ast_utils::is_reference_to_global_variable(scope, callee))
+    {
+        return None;
+    }
+    let mut arguments = get_call_expression_arguments(expression)?;
+    let argument = arguments.next()?;
+    arguments.next().is_none().then_some(argument)
+}
+
+struct Existence<'a> {
+    reference: Node<'a>,
+    operator: &'static str,
+}
+
+fn get_existence<'a>(
+    expression: Node<'a>,
+    scope: &Scope,
+    context: &QueryMatchContext,
+) -> Option<Existence<'a>> {
+    let is_negated =
+        expression.kind() == UnaryExpression && expression.field("operator").kind() == "!";
+    let base = if is_negated {
+        expression.field("argument").skip_parentheses()
+    } else {
+        expression
+    };
+
+    if is_reference(base) {
+        return Some(Existence {
+            reference: base,
+            operator: if is_negated { "||" } else { "&&" },
+        });
+    }
+    if base.kind() == UnaryExpression && base.field("operator").kind() == "!" {
+        let argument = base.field("argument").skip_parentheses();
+        if is_reference(argument) {
+            return Some(Existence {
+                reference: argument,
+                operator: "&&",
+            });
+        }
+    }
+    if let Some(argument) = get_boolean_cast_argument(base, scope, context)
+        .map(|argument| argument.skip_parentheses())
+        .filter(|&argument| is_reference(argument))
+    {
+        return Some(Existence {
+            reference: argument,
+            operator: if is_negated { "||" } else { "&&" },
+        });
+    }
+    if is_implicit_nullish_comparison(expression, scope, context) {
+        return Some(Existence {
+            reference: get_reference_and_nullish(expression).0,
+            operator: "??",
+        });
+    }
+    if is_explicit_nullish_comparison(expression, scope, context) {
+        return Some(Existence {
+            reference: get_reference_and_nullish(expression.field("left").skip_parentheses()).0,
+            operator: "??",
+        });
+    }
+    None
+}
+
+fn is_inside_with_block(node: Node) -> bool {
+    let mut node = node;
+    while let Some(parent) = node.parent() {
+        if parent.kind() == WithStatement && parent.field("body") == node {
+            return true;
+        }
+        node = parent;
+    }
+    false
+}
+
+fn get_leftmost_operand(node: Node) -> Node {
+    let operator = node.field("operator").kind();
+    let mut left = node.field("left");
+    // a parenthesized logical expression won't be unwrapped, it either
+    // needs parentheses or it doesn't
+    while is_binary_expression_with_operator(left, operator) {
+        left = left.field("left");
+    }
+    left
+}
+
+fn cannot_be_getter(expression: Node) -> bool {
+    let expression = expression.skip_parentheses();
+    expression.kind() == Identifier && !is_inside_with_block(expression)
+}
+
+fn accesses_single_property(expression: Node) -> bool {
+    let expression = expression.skip_parentheses();
+    if is_inside_with_block(expression) {
+        return expression.kind() == Identifier;
+    }
+    matches!(expression.kind(), MemberExpression | SubscriptExpression)
+        && matches!(
+            expression.field("object").skip_parentheses().kind(),
+            Identifier | Super | This
+        )
+}
+
+pub fn logical_assignment_operators_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "logical-assignment-operators",
+        languages => [Javascript],
+        messages => [
+            assignment => "Assignment (=) can be replaced with operator assignment ({{operator}}).",
+            use_logical_operator => "Convert this assignment to use the operator {{ operator }}.",
+            logical => "Logical expression can be replaced with an assignment ({{ operator }}).",
+            convert_logical => "Replace this logical expression with an assignment with the operator {{ operator }}.",
+            if_ => "'if' statement can be replaced with a logical operator assignment with operator {{ operator }}.",
+            convert_if => "Replace this 'if' statement with a logical assignment with operator {{ operator }}.",
+            unexpected => "Unexpected logical operator assignment ({{operator}}) shorthand.",
+            separate => "Separate the logical assignment into an assignment with a logical operator.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            mode: Mode = options.mode,
+            check_if: bool = options.mode == Mode::Always && options.enforce_for_if_statements,
+        },
+        listeners => [
+            // foo ||= bar
+            r#"
+              (augmented_assignment_expression) @c
+            "# => |node, context| {
+                if self.mode != Mode::Never {
+                    return;
+                }
+
+                let operator_token = node.field("operator");
+                let operator = operator_token.kind();
+                if !ast_utils::is_logical_assignment_operator(operator) {
+                    return;
+                }
+
+                let left = node.field("left");
+                context.report(violation! {
+                    node => node,
+                    message_id => "unexpected",
+                    data => {
+                        operator => operator,
+                    },
+                    fix => |fixer| {
+                        // TODO: suggestions?
+                        if !cannot_be_getter(left) || context.get_comments_inside(node).next().is_some() {
+                            return;
+                        }
+
+                        let operator = &operator[..operator.len() - 1];
+                        // -> foo = foo || bar
+                        fixer.replace_text(operator_token, format!("= {} {}", left.text(context), operator));
+
+                        let right = node.field("right");
+                        let precedence = ast_utils::get_precedence(right)
+                            <= ast_utils::get_binary_expression_operator_precedence(operator);
+                        // ?? and || / && cannot be mixed but have same precedence
+                        let mixed = operator == "??" && is_logical_expression(right);
+                        if !ast_utils::is_parenthesised(right) && (precedence || mixed) {
+                            // -> foo = foo || (bar)
+                            fixer.insert_text_before(right, "(");
+                            fixer.insert_text_after(right, ")");
+                        }
+                    }
+                });
+            },
+            // foo = foo || bar
+            r#"
+              (assignment_expression) @c
+            "# => |node, context| {
+                if self.mode != Mode::Always {
+                    return;
+                }
+
+                let right = node.field("right").skip_parentheses();
+                if !is_logical_expression(right) {
+                    return;
+                }
+
+                let left = node.field("left");
+                let left_operand = get_leftmost_operand(right);
+                if !ast_utils::is_same_reference(left, left_operand, None, context) {
+                    return;
+                }
+
+                let logical_expression = left_operand.parent().unwrap();
+                let logical_operator_token = logical_expression.field("operator");
+                let operator = format!("{}=", logical_operator_token.kind());
+                context.report(violation! {
+                    node => node,
+                    message_id => "assignment",
+                    data => {
+                        operator => operator,
+                    },
+                    fix => |fixer| {
+                        // TODO: suggestions?
+                        if !cannot_be_getter(left) || context.get_comments_inside(node).next().is_some() {
+                            return;
+                        }
+
+                        // No need for parenthesis around the assignment based on precedence as the precedence stays the same even with changed operator
+                        let assignment_operator_token = context.get_token_after(left, Option::<fn(Node) -> bool>::None);
+                        let first_right_operand_token = context.get_token_after(logical_operator_token, Option::<fn(Node) -> bool>::None);
+
+                        // -> foo ||= bar
+                        fixer.replace_text(
+                            node,
+                            format!(
+                                "{}{}{}{}",
+                                context.get_text_slice(node.start_byte()..assignment_operator_token.start_byte()),
+                                logical_operator_token.kind(),
+                                context.get_text_slice(assignment_operator_token.start_byte()..logical_expression.start_byte()),
+                                context.get_text_slice(first_right_operand_token.start_byte()..node.end_byte()),
+                            ),
+                        );
+                    }
+                });
+            },
+            // foo || (foo = bar)
+            r#"
+              (binary_expression) @c
+            "# => |node, context| {
+                if self.mode != Mode::Always || !is_logical_expression(node) {
+                    return;
+                }
+
+                // Right side has to be parenthesized, otherwise would be parsed as (foo || foo) = bar which is illegal
+                let left = node.field("left").skip_parentheses();
+                let assignment = node.field("right").skip_parentheses();
+                if assignment.kind() != AssignmentExpression
+                    || !is_reference(left)
+                    || !ast_utils::is_same_reference(left, assignment.field("left"), None, context)
+                {
+                    return;
+                }
+
+                let operator = node.field("operator").kind();
+                context.report(violation! {
+                    node => node,
+                    message_id => "logical",
+                    data => {
+                        operator => format!("{operator}="),
+                    },
+                    fix => |fixer| {
+                        // TODO: suggestions?
+                        if !(cannot_be_getter(left) || accesses_single_property(left))
+                            || context.get_comments_inside(node).next().is_some()
+                        {
+                            return;
+                        }
+
+                        let parent = node.parent().unwrap();
+                        let requires_outer_parenthesis = parent.kind() != ExpressionStatement
+                            && ast_utils::get_kind_precedence(AssignmentExpression) < ast_utils::get_precedence(parent);
+
+                        let assignment_operator_token = context.get_token_after(
+                            assignment.field("left"),
+                            Option::<fn(Node) -> bool>::None,
+                        );
+                        // -> foo ||= bar
+                        let replacement = format!(
+                            "{}{}{}",
+                            context.get_text_slice(assignment.start_byte()..assignment_operator_token.start_byte()),
+                            operator,
+                            context.get_text_slice(assignment_operator_token.start_byte()..assignment.end_byte()),
+                        );
+
+                        fixer.replace_text(
+                            node,
+                            if !ast_utils::is_parenthesised(node) && requires_outer_parenthesis {
+                                format!("({replacement})")
+                            } else {
+                                replacement
+                            },
+                        );
+                    }
+                });
+            },
+            // if (foo) foo = bar
+            r#"
+              (if_statement
+                !alternative
+              ) @c
+            "# => |node, context| {
+                if !self.check_if {
+                    return;
+                }
+
+                let consequent = node.field("consequence");
+                let has_body = consequent.kind() == StatementBlock;
+                let body = if has_body {
+                    let mut statements = consequent.non_comment_named_children(SupportedLanguage::Javascript);
+                    let (Some(body), None) = (statements.next(), statements.next()) else {
+                        return;
+                    };
+                    body
+                } else {
+                    consequent
+                };
+                if body.kind() != ExpressionStatement {
+                    return;
+                }
+                let expression = body.first_non_comment_named_child(SupportedLanguage::Javascript);
+                if expression.kind() != AssignmentExpression {
+                    return;
+                }
+
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let scope = scope_manager.get_scope(node);
+                let test = node.field("condition").skip_parentheses();
+                let Some(existence) = get_existence(test, &scope, context).filter(|existence| {
+                    ast_utils::is_same_reference(existence.reference, expression.field("left"), None, context)
+                }) else {
+                    return;
+                };
+
+                let should_be_fixed = cannot_be_getter(existence.reference)
+                    || !is_logical_expression(test) && accesses_single_property(existence.reference);
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "if_",
+                    data => {
+                        operator => format!("{}=", existence.operator),
+                    },
+                    fix => |fixer| {
+                        // TODO: suggestions?
+                        if !should_be_fixed || context.get_comments_inside(node).next().is_some() {
+                            return;
+                        }
+
+                        let first_body_token = context.get_first_token(body, Option::<fn(Node) -> bool>::None);
+                        if context
+                            .maybe_get_token_before(node, Option::<fn(Node) -> bool>::None)
+                            .matches(|prev_token| {
+                                !matches!(prev_token.kind(), ";" | "{")
+                                    && !matches!(first_body_token.kind(), Identifier | This | Super)
+                            })
+                        {
+                            // Do not fix if the fixed statement could be part of the previous statement (eg. fn() if (a == null) (a) = b --> fn()(a) ??= b)
+                            return;
+                        }
+
+                        let operator_token = context.get_token_after(
+                            expression.field("left"),
+                            Option::<fn(Node) -> bool>::None,
+                        );
+                        let next_token = context.maybe_get_token_after(expression, Option::<fn(Node) -> bool>::None);
+                        // -> foo ||= bar
+                        fixer.replace_text(
+                            node,
+                            format!(
+                                "{}{}{}{}",
+                                context.get_text_slice(body.start_byte()..operator_token.start_byte()),
+                                existence.operator,
+                                context.get_text_slice(operator_token.start_byte()..body.end_byte()),
+                                if has_body && next_token.matches(|next_token| next_token.kind() != ";") {
+                                    ";"
+                                } else {
+                                    ""
+                                },
+                            ),
+                        );
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{AugmentedAssignmentExpression, IfStatement},
+    };
+
+    #[test]
+    fn test_logical_assignment_operators_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            logical_assignment_operators_rule(),
+            rule_tests! {
+                valid => [
+                    // Unrelated
+                    "a || b",
+                    "a && b",
+                    "a ?? b",
+                    "a || a || b",
+                    "var a = a || b",
+                    "a === undefined ? a : b",
+                    "while (a) a = b",
+
+                    // Preferred
+                    "a ||= b",
+                    "a &&= b",
+                    "a ??= b",
+
+                    // > Operator
+                    "a += a || b",
+                    "a *= a || b",
+                    "a ||= a || b",
+                    "a &&= a || b",
+
+                    // > Right
+                    "a = a",
+                    "a = b",
+                    "a = a === b",
+                    "a = a + b",
+                    "a = a / b",
+                    "a = fn(a) || b",
+
+                    // > Reference
+                    "a = false || c",
+                    "a = f() || g()",
+                    "a = b || c",
+                    "a = b || a",
+                    "object.a = object.b || c",
+                    "[a] = a || b",
+                    "({ a } = a || b)",
+
+                    // Logical
+                    "(a = b) || a",
+                    "a + (a = b)",
+                    "a || (b ||= c)",
+                    "a || (b &&= c)",
+                    "a || b === 0",
+                    "a || fn()",
+                    "a = a && b || c",
+                    "!a || (a = b)",
+                    "a || (b && c)",
+                    "a || (b ?? c)",
+
+                    // > Reference
+                    "a || (b = c)",
+                    "a || (a ||= b)",
+                    "fn() || (a = b)",
+                    "a.b || (a = b)",
+                    {
+                        code => "class Class { #prop; constructor() { this.#prop || (this.prop = value) } }",
+                    },
+                    {
+                        code => "class Class { #prop; constructor() { this.prop || (this.#prop = value) } }",
+                    },
+
+                    // If
+                    {
+                        code => "if (a) a = b",
+                        options => ["always", { enforce_for_if_statements => false }]
+                    },
+                    "if (a == undefined) a = b",
+                    {
+                        code => "if (a) { a = b } else {}",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a) { a = b } else if (a) {}",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (unrelated) {} else if (a) a = b; else {}",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a) { a = b; other() }",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a) { a = b } other()",
+                        options => ["always", { enforce_for_if_statements => false }]
+                    },
+                    {
+                        code => "if (a) {}",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a) { b = c }",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a) a += b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a === null) a = b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a != undefined) a = b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a === null || a == undefined) a = b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a === null || b === undefined) a = b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a === null && a === undefined) a = b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a === void 1) a = b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "var undefined; if (a == undefined) a = b",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "function fn(undefined) { if (a == undefined) a = b }",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a) b = a",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a.b) a.c = d",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (!a) a = a",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (a) { a = a }",
+                        options => ["always", { enforce_for_if_statements => false }]
+                    },
+                    {
+                        code => "if (Boolean(a)) b = a",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "function fn(Boolean) { if (Boolean(a)) a = b }",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+                    {
+                        code => "if (Boolean(a, b)) a = c",
+                        options => ["always", { enforce_for_if_statements => true }]
+                    },
+
+                    // Never
+                    { code => "a = a || b", options => ["never"] },
+                    { code => "a = a && b", options => ["never"] },
+                    { code => "a = a ?? b", options => ["never"] },
+                    { code => "a = b", options => ["never"] },
+                    { code => "a += b", options => ["never"] },
+                    { code => "a -= b", options => ["never"] },
+                    { code => "a.b = a.b || c", options => ["never"] },
+                    { code => "if (a) a = b", options => ["never"] }
+                ],
+                invalid => [
+                    // Assignment
+                    {
+                        code => "a = a || b",
+                        output => "a ||= b",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a && b",
+                        output => "a &&= b",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "a = a ?? b",
+                        output => "a ??= b",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "foo = foo || bar",
+                        output => "foo ||= bar",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Right
+                    {
+                        code => "a = a || fn()",
+                        output => "a ||= fn()",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a || b && c",
+                        output => "a ||= b && c",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a || (b || c)",
+                        output => "a ||= (b || c)",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a || (b ? c : d)",
+                        output => "a ||= (b ? c : d)",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Reference
+                    {
+                        code => "a.b = a.b ?? c",
+                        output => None,
+                        // suggestions: [{ message_id => "use_logical_operator", output => "a.b ??= c" }]
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "a[b] = a[b] ?? c",
+                        output => None,
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "a['b'] = a['b'] ?? c",
+                        output => None,
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "this.prop = this.prop ?? {}",
+                        output => None,
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "with (object) a = a || b",
+                        output => None,
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "with (object) { a = a || b }",
+                        output => None,
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "with (a = a || b) {}",
+                        output => "with (a ||= b) {}",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "with (object) {} a = a || b",
+                        output => "with (object) {} a ||= b",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Parenthesis
+                    {
+                        code => "(a) = a || b",
+                        output => "(a) ||= b",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = (a) || b",
+                        output => "a ||= b",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a || (b)",
+                        output => "a ||= (b)",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "(a = a || b)",
+                        output => "(a ||= b)",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Comments
+                    {
+                        code => "/* before */ a = a || b",
+                        output => "/* before */ a ||= b",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a || b // after",
+                        output => "a ||= b // after",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a /* between */ = a || b",
+                        output => None,
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a /* between */ || b",
+                        output => None,
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Mixed
+                    {
+                        code => "a = a || b || c",
+                        output => "a ||= b || c",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a = a && b && c",
+                        output => "a &&= b && c",
+                        errors => [{ message_id => "assignment", type => AssignmentExpression, data => { operator => "&&=" } }]
+                    },
+
+                    // Logical
+                    {
+                        code => "a || (a = b)",
+                        output => "a ||= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a && (a = b)",
+                        output => "a &&= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "a ?? (a = b)",
+                        output => "a ??= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "foo ?? (foo = bar)",
+                        output => "foo ??= bar",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "??=" } }]
+                    },
+
+                    // > Right
+                    {
+                        code => "a || (a = 0)",
+                        output => "a ||= 0",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a || (a = fn())",
+                        output => "a ||= fn()",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a || (a = (b || c))",
+                        output => "a ||= (b || c)",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Reference
+                    {
+                        code => "a.b || (a.b = c)",
+                        output => "a.b ||= c",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "class Class { #prop; constructor() { this.#prop || (this.#prop = value) } }",
+                        output => "class Class { #prop; constructor() { this.#prop ||= value } }",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a['b'] || (a['b'] = c)",
+                        output => "a['b'] ||= c",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a[0] || (a[0] = b)",
+                        output => "a[0] ||= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a[this] || (a[this] = b)",
+                        output => "a[this] ||= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "foo.bar || (foo.bar = baz)",
+                        output => "foo.bar ||= baz",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a.b.c || (a.b.c = d)",
+                        output => None,
+                        // suggestions: [{ message_id => "convert_logical", output => "a.b.c ||= d" }]
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a[b.c] || (a[b.c] = d)",
+                        output => None,
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a[b?.c] || (a[b?.c] = d)",
+                        output => None,
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "with (object) a.b || (a.b = c)",
+                        output => None,
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Parenthesis
+                    {
+                        code => "a || ((a) = b)",
+                        output => "(a) ||= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a || (a = (b))",
+                        output => "a ||= (b)",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "(a) || (a = b)",
+                        output => "a ||= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "(a || (a = b))",
+                        output => "(a ||= b)",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Comments
+                    {
+                        code => "/* before */ a || (a = b)",
+                        output => "/* before */ a ||= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a || (a = b) // after",
+                        output => "a ||= b // after",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a /* between */ || (a = b)",
+                        output => None,
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a || (a /* between */ = b)",
+                        output => None,
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Fix Condition
+                    {
+                        code => "a.b || (a.b = c)",
+                        output => "a.b ||= c",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "this.prop || (this.prop = value)",
+                        output => "this.prop ||= value",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "with (object) a || (a = b)",
+                        output => "with (object) a ||= b",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Outer parenthesis
+                    {
+                        code => "a || (a = b) || c",
+                        output => "(a ||= b) || c",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "fn(a || (a = b))",
+                        output => "fn((a ||= b))",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a + (b || (b = c))",
+                        output => "a + (b ||= c)",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a ? b || (b = c) : d",
+                        output => "a ? (b ||= c) : d",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "!(a || (a = b))",
+                        output => "!(a ||= b)",
+                        errors => [{ message_id => "logical", type => BinaryExpression, data => { operator => "||=" } }]
+                    },
+
+                    // If
+                    {
+                        code => "if (a) a = b",
+                        output => "a &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (Boolean(a)) a = b",
+                        output => "a &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (!!a) a = b",
+                        output => "a &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (!a) a = b",
+                        output => "a ||= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "if (!Boolean(a)) a = b",
+                        output => "a ||= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "if (a == undefined) a = b",
+                        output => "a ??= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "if (a == null) a = b",
+                        output => "a ??= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "if (a === null || a === undefined) a = b",
+                        output => "a ??= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "if (a === undefined || a === null) a = b",
+                        output => "a ??= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "if (a === null || a === void 0) a = b",
+                        output => "a ??= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "if (a === void 0 || a === null) a = b",
+                        output => "a ??= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "if (a) { a = b; }",
+                        output => "a &&= b;",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (a) { a = b }",
+                        output => "a &&= b;",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+
+                    // > Reference
+                    {
+                        code => "if (a.b) a.b = c",
+                        output => "a.b &&= c",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (a[b]) a[b] = c",
+                        output => "a[b] &&= c",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (a.b.c) a.b.c = d",
+                        output => None,
+                        // suggestions: [{ message_id => "convert_if", output => "a.b.c &&= d" }]
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (a.b === undefined || a.b === null) a.b = c",
+                        output => None,
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "with (object) if (a) a = b",
+                        output => "with (object) a &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+
+                    // > Parenthesis
+                    {
+                        code => "if ((a)) a = b",
+                        output => "a &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (a) (a) = b",
+                        output => "(a) &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (a) a = (b)",
+                        output => "a &&= (b)",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+
+                    // > Previous statement
+                    {
+                        code => "fn();\nif (a) a = b",
+                        output => "fn();\na &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "fn()\nif (a) a = b",
+                        output => "fn()\na &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "fn()\nif (a) (a) = b",
+                        output => None,
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "{ if (a) (a) = b }",
+                        output => "{ (a) &&= b }",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+
+                    // > Comments
+                    {
+                        code => "/* before */ if (a) a = b",
+                        output => "/* before */ a &&= b",
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "if (a) /* between */ a = b",
+                        output => None,
+                        options => ["always", { enforce_for_if_statements => true }],
+                        errors => [{ message_id => "if_", type => IfStatement, data => { operator => "&&=" } }]
+                    },
+
+                    // Never
+                    {
+                        code => "a ||= b",
+                        output => "a = a || b",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a &&= b",
+                        output => "a = a && b",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "a ??= b",
+                        output => "a = a ?? b",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "foo ||= bar",
+                        output => "foo = foo || bar",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Suggestion
+                    {
+                        code => "a.b ||= c",
+                        output => None,
+                        // suggestions: [{ message_id => "separate", output => "a.b = a.b || c" }]
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a[b] ||= c",
+                        output => None,
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "with (object) a ||= b",
+                        output => None,
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Parenthesis
+                    {
+                        code => "(a) ||= b",
+                        output => "(a) = a || b",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a ||= (b)",
+                        output => "a = a || (b)",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "(a ||= b)",
+                        output => "(a = a || b)",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Comments
+                    {
+                        code => "/* before */ a ||= b",
+                        output => "/* before */ a = a || b",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a ||= b // after",
+                        output => "a = a || b // after",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a /* before */ ||= b",
+                        output => None,
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a ||= /* after */ b",
+                        output => None,
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+
+                    // > Precedence
+                    {
+                        code => "a ||= b && c",
+                        output => "a = a || b && c",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a &&= b || c",
+                        output => "a = a && (b || c)",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "a ||= b || c",
+                        output => "a = a || (b || c)",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "||=" } }]
+                    },
+                    {
+                        code => "a &&= b && c",
+                        output => "a = a && (b && c)",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "&&=" } }]
+                    },
+                    {
+                        code => "a ??= b || c",
+                        output => "a = a ?? (b || c)",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "??=" } }]
+                    },
+                    {
+                        code => "a ??= b ? c : d",
+                        output => "a = a ?? (b ? c : d)",
+                        options => ["never"],
+                        errors => [{ message_id => "unexpected", type => AugmentedAssignmentExpression, data => { operator => "??=" } }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, violation, Rule};
+
+use crate::kind::Class;
+
+const DEFAULT_MAX: usize = 1;
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    ignore_expressions: bool,
+    max: usize,
+}
+
+impl Default for OptionsObject {
+    fn default() -> Self {
+        Self {
+            ignore_expressions: Default::default(),
+            max: DEFAULT_MAX,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Options {
+    Usize(usize),
+    Object(OptionsObject),
+}
+
+impl Options {
+    pub fn max(&self) -> usize {
+        match self {
+            Self::Usize(value) => *value,
+            Self::Object(OptionsObject { max, .. }) => *max,
+        }
+    }
+
+    pub fn ignore_expressions(&self) -> bool {
+        match self {
+            Self::Object(OptionsObject {
+                ignore_expressions, ..
+            }) => *ignore_expressions,
+            _ => false,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::Usize(DEFAULT_MAX)
+    }
+}
+
+pub fn max_classes_per_file_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "max-classes-per-file",
+        languages => [Javascript],
+        messages => [
+            maximum_exceeded => "File has too many classes ({{class_count}}). Maximum allowed is {{max}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            max: usize = options.max(),
+            ignore_expressions: bool = options.ignore_expressions(),
+
+            [per-file-run]
+            class_count: usize,
+        },
+        listeners => [
+            r#"
+              (class_declaration) @c
+              (class) @c
+            "# => |node, context| {
+                if self.ignore_expressions && node.kind() == Class {
+                    return;
+                }
+
+                self.class_count += 1;
+            },
+            r#"
+              program:exit
+            "# => |node, context| {
+                if self.class_count > self.max {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "maximum_exceeded",
+                        data => {
+                            class_count => self.class_count,
+                            max => self.max,
+                        }
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::Program;
+
+    #[test]
+    fn test_max_classes_per_file_rule() {
+        RuleTester::run(
+            max_classes_per_file_rule(),
+            rule_tests! {
+                valid => [
+                    "class Foo {}",
+                    "var x = class {};",
+                    "var x = 5;",
+                    {
+                        code => "class Foo {}",
+                        options => [1]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}",
+                        options => [2]
+                    },
+                    {
+                        code => "class Foo {}",
+                        options => [{ max => 1 }]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}",
+                        options => [{ max => 2 }]
+                    },
+                    {
+                        code => "class Foo {}\nconst myExpression = class {}\n",
+                        options => [{ ignore_expressions => true, max => 1 }]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}\nconst myExpression = class {}\n",
+                        options => [{ ignore_expressions => true, max => 2 }]
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "class Foo {}\nclass Bar {}",
+                        errors => [{ message_id => "maximum_exceeded", type => Program }]
+                    },
+                    {
+                        code => "class Foo {}\nconst myExpression = class {}\n",
+                        errors => [{ message_id => "maximum_exceeded", type => Program }]
+                    },
+                    {
+                        code => "var x = class {};\nvar y = class {};",
+                        errors => [{ message_id => "maximum_exceeded", type => Program }]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}",
+                        options => [1],
+                        errors => [{ message_id => "maximum_exceeded", type => Program }]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}\nclass Baz {}",
+                        options => [2],
+                        errors => [{ message_id => "maximum_exceeded", data => { class_count => 3, max => 2 }, type => Program }]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "maximum_exceeded", type => Program }]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}\nclass Baz {}",
+                        options => [{ max => 2 }],
+                        errors => [{ message_id => "maximum_exceeded", type => Program }]
+                    },
+                    {
+                        code => "class Foo {}\nclass Bar {}\nconst myExpression = class {}\n",
+                        options => [{ ignore_expressions => true, max => 1 }],
+                        errors => [{ message_id => "maximum_exceeded", data => { class_count => 2, max => 1 }, type => Program }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, QueryMatchContext, Rule};
+
+use crate::kind::{ElseClause, IfStatement};
+
+const DEFAULT_MAX: usize = 4;
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    #[serde(alias = "maximum")]
+    max: usize,
+}
+
+impl Default for OptionsObject {
+    fn default() -> Self {
+        Self { max: DEFAULT_MAX }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Options {
+    Usize(usize),
+    Object(OptionsObject),
+}
+
+impl Options {
+    pub fn max(&self) -> usize {
+        match self {
+            Self::Usize(value) => *value,
+            Self::Object(OptionsObject { max }) => *max,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::Usize(DEFAULT_MAX)
+    }
+}
+
+fn is_else_if(node: Node) -> bool {
+    node.kind() == IfStatement && node.parent().unwrap().kind() == ElseClause
+}
+
+pub fn max_depth_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "max-depth",
+        languages => [Javascript],
+        messages => [
+            too_deeply => "Blocks are nested too deeply ({{depth}}). Maximum allowed is {{max_depth}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            max_depth: usize = options.max(),
+
+            [per-file-run]
+            function_stack: Vec<usize>,
+        },
+        methods => {
+            fn push_block(&mut self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let len = self.function_stack.last_mut().unwrap();
+                *len += 1;
+
+                if *len > self.max_depth {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "too_deeply",
+                        data => {
+                            depth => *len,
+                            max_depth => self.max_depth,
+                        }
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (program) @c
+              (function_declaration) @c
+              (function) @c
+              (arrow_function) @c
+              (generator_function_declaration) @c
+              (generator_function) @c
+              (method_definition) @c
+              (class_static_block) @c
+            "# => |node, context| {
+                self.function_stack.push(0);
+            },
+            r#"
+              program:exit,
+              function_declaration:exit,
+              function:exit,
+              arrow_function:exit,
+              generator_function_declaration:exit,
+              generator_function:exit,
+              method_definition:exit,
+              class_static_block:exit
+            "# => |node, context| {
+                self.function_stack.pop().unwrap();
+            },
+            r#"
+              (if_statement) @c
+              (switch_statement) @c
+              (try_statement) @c
+              (do_statement) @c
+              (while_statement) @c
+              (with_statement) @c
+              (for_statement) @c
+              (for_in_statement) @c
+            "# => |node, context| {
+                if is_else_if(node) {
+                    return;
+                }
+
+                self.push_block(node, context);
+            },
+            r#"
+              if_statement:exit,
+              switch_statement:exit,
+              try_statement:exit,
+              do_statement:exit,
+              while_statement:exit,
+              with_statement:exit,
+              for_statement:exit,
+              for_in_statement:exit
+            "# => |node, context| {
+                if is_else_if(node) {
+                    return;
+                }
+
+                *self.function_stack.last_mut().unwrap() -= 1;
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::{ForInStatement, ForStatement, WhileStatement};
+
+    #[test]
+    fn test_max_depth_rule() {
+        RuleTester::run(
+            max_depth_rule(),
+            rule_tests! {
+                valid => [
+                    { code => "function foo() { if (true) { if (false) { if (true) { } } } }", options => [3] },
+                    { code => "function foo() { if (true) { } else if (false) { } else if (true) { } else if (false) {} }", options => [3] },
+                    { code => "var foo = () => { if (true) { if (false) { if (true) { } } } }", options => [3], environment => { ecma_version => 6 } },
+                    "function foo() { if (true) { if (false) { if (true) { } } } }",
+
+                    // object property options
+                    { code => "function foo() { if (true) { if (false) { if (true) { } } } }", options => [{ max => 3 }] },
+
+                    { code => "class C { static { if (1) { if (2) {} } } }", options => [2], environment => { ecma_version => 2022 } },
+                    { code => "class C { static { if (1) { if (2) {} } if (1) { if (2) {} } } }", options => [2], environment => { ecma_version => 2022 } },
+                    { code => "class C { static { if (1) { if (2) {} } } static { if (1) { if (2) {} } } }", options => [2], environment => { ecma_version => 2022 } },
+                    { code => "if (1) { class C { static { if (1) { if (2) {} } } } }", options => [2], environment => { ecma_version => 2022 } },
+                    { code => "function foo() { if (1) { class C { static { if (1) { if (2) {} } } } } }", options => [2], environment => { ecma_version => 2022 } },
+                    {
+                        code => "function foo() { if (1) { if (2) { class C { static { if (1) { if (2) {} } if (1) { if (2) {} } } } } } if (1) { if (2) {} } }",
+                        options => [2],
+                        environment => { ecma_version => 2022 }
+                    },
+                    { code => "class C { foo() { if (1) { if (2) {} } } }", options => [2], environment => { ecma_version => 6 } }
+                ],
+                invalid => [
+                    {
+                        code => "function foo() { if (true) { if (false) { if (true) { } } } }",
+                        options => [2],
+                        errors => [{ message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, type => IfStatement }]
+                    },
+                    {
+                        code => "var foo = () => { if (true) { if (false) { if (true) { } } } }",
+                        options => [2],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, type => IfStatement }]
+                    },
+                    {
+                        code => "function foo() { if (true) {} else { for(;;) {} } }",
+                        options => [1],
+                        errors => [{ message_id => "too_deeply", data => { depth => 2, max_depth => 1 }, type => ForStatement }]
+                    },
+                    {
+                        code => "function foo() { while (true) { if (true) {} } }",
+                        options => [1],
+                        errors => [{ message_id => "too_deeply", data => { depth => 2, max_depth => 1 }, type => IfStatement }]
+                    },
+                    {
+                        code => "function foo() { for (let x of foo) { if (true) {} } }",
+                        options => [1],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "too_deeply", data => { depth => 2, max_depth => 1 }, type => IfStatement }]
+                    },
+                    {
+                        code => "function foo() { while (true) { if (true) { if (false) { } } } }",
+                        options => [1],
+                        errors => [
+                            { message_id => "too_deeply", data => { depth => 2, max_depth => 1 }, type => IfStatement },
+                            { message_id => "too_deeply", data => { depth => 3, max_depth => 1 }, type => IfStatement }
+                        ]
+                    },
+                    {
+                        code => "function foo() { if (true) { if (false) { if (true) { if (false) { if (true) { } } } } } }",
+                        errors => [{ message_id => "too_deeply", data => { depth => 5, max_depth => 4 }, type => IfStatement }]
+                    },
+                    {
+                        code => "function foo() { if (true) { switch (1) { case 1: for (var x in y) { while (true) {} } } } }",
+                        options => [2],
+                        errors => [
+                            { message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, type => ForInStatement },
+                            { message_id => "too_deeply", data => { depth => 4, max_depth => 2 }, type => WhileStatement }
+                        ]
+                    },
+
+                    // object property options
+                    {
+                        code => "function foo() { if (true) { if (false) { if (true) { } } } }",
+                        options => [{ max => 2 }],
+                        errors => [{ message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, type => IfStatement }]
+                    },
+
+                    {
+                        code => "function foo() { if (a) { if (b) { if (c) { if (d) { if (e) {} } } } } }",
+                        options => [{}],
+                        errors => [{ message_id => "too_deeply", data => { depth => 5, max_depth => 4 } }]
+                    },
+                    {
+                        code => "function foo() { if (true) {} }",
+                        options => [{ max => 0 }],
+                        errors => [{ message_id => "too_deeply", data => { depth => 1, max_depth => 0 } }]
+                    },
+
+                    {
+                        code => "class C { static { if (1) { if (2) { if (3) {} } } } }",
+                        options => [2],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, line => 1, column => 38 }]
+                    },
+                    {
+                        code => "if (1) { class C { static { if (1) { if (2) { if (3) {} } } } } }",
+                        options => [2],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, line => 1, column => 47 }]
+                    },
+                    {
+                        code => "function foo() { if (1) { class C { static { if (1) { if (2) { if (3) {} } } } } } }",
+                        options => [2],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, line => 1, column => 64 }]
+                    },
+                    {
+                        code => "function foo() { if (1) { class C { static { if (1) { if (2) {} } } } if (2) { if (3) {} } } }",
+                        options => [2],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "too_deeply", data => { depth => 3, max_depth => 2 }, line => 1, column => 80 }]
+                    }
+                ]
+            },
+        )
+    }
+}
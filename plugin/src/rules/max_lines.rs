@@ -0,0 +1,390 @@
+use std::{collections::HashSet, iter::once, ops::Range as StdRange, sync::Arc};
+
+use itertools::Itertools;
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Point, Range},
+    violation, QueryMatchContext, Rule,
+};
+
+use crate::{all_comments::AllComments, utils::ast_utils};
+
+const DEFAULT_MAX: usize = 300;
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    #[serde(alias = "maximum")]
+    max: usize,
+    skip_comments: bool,
+    skip_blank_lines: bool,
+}
+
+impl Default for OptionsObject {
+    fn default() -> Self {
+        Self {
+            max: DEFAULT_MAX,
+            skip_comments: Default::default(),
+            skip_blank_lines: Default::default(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Options {
+    Usize(usize),
+    Object(OptionsObject),
+}
+
+impl Options {
+    pub fn max(&self) -> usize {
+        match self {
+            Self::Usize(value) => *value,
+            Self::Object(OptionsObject { max, .. }) => *max,
+        }
+    }
+
+    pub fn skip_comments(&self) -> bool {
+        match self {
+            Self::Object(OptionsObject { skip_comments, .. }) => *skip_comments,
+            _ => false,
+        }
+    }
+
+    pub fn skip_blank_lines(&self) -> bool {
+        match self {
+            Self::Object(OptionsObject {
+                skip_blank_lines, ..
+            }) => *skip_blank_lines,
+            _ => false,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::Usize(DEFAULT_MAX)
+    }
+}
+
+fn get_line_index(line_start_bytes: &[usize], byte: usize) -> usize {
+    line_start_bytes.partition_point(|&line_start_byte| line_start_byte <= byte) - 1
+}
+
+fn get_lines_without_code(
+    comment: Node,
+    line_start_bytes: &[usize],
+    context: &QueryMatchContext,
+) -> StdRange<usize> {
+    let mut start = get_line_index(line_start_bytes, comment.start_byte());
+    let mut end = get_line_index(line_start_bytes, comment.end_byte()) + 1;
+
+    if context
+        .maybe_get_token_before(comment, Option::<fn(Node) -> bool>::None)
+        .matches(|token| get_line_index(line_start_bytes, token.end_byte()) == start)
+    {
+        start += 1;
+    }
+    if context
+        .maybe_get_token_after(comment, Option::<fn(Node) -> bool>::None)
+        .matches(|token| get_line_index(line_start_bytes, token.start_byte()) == end - 1)
+    {
+        end -= 1;
+    }
+
+    start..end.max(start)
+}
+
+pub fn max_lines_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "max-lines",
+        languages => [Javascript],
+        messages => [
+            exceed => "File has too many lines ({{actual}}). Maximum allowed is {{max}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            max: usize = options.max(),
+            skip_comments: bool = options.skip_comments(),
+            skip_blank_lines: bool = options.skip_blank_lines(),
+        },
+        listeners => [
+            r#"
+              program:exit
+            "# => |node, context| {
+                let source_text = context.slice(0..node.end_byte());
+                let source_lines = ast_utils::LINE_BREAK_PATTERN
+                    .split(&source_text)
+                    .collect_vec();
+                let line_start_bytes = once(0)
+                    .chain(
+                        ast_utils::LINE_BREAK_PATTERN
+                            .find_iter(&source_text)
+                            .map(|line_break| line_break.end()),
+                    )
+                    .collect_vec();
+                let mut lines = source_lines.iter().copied().enumerate().collect_vec();
+
+                // If file ends with a linebreak, there will be one extra
+                // empty line at the end. Remove it.
+                if lines.len() > 1 && lines.last().unwrap().1.is_empty() {
+                    lines.pop();
+                }
+
+                if self.skip_blank_lines {
+                    lines.retain(|(_, text)| !text.trim().is_empty());
+                }
+
+                if self.skip_comments {
+                    let comment_lines = context
+                        .retrieve::<AllComments<'a>>()
+                        .iter()
+                        .flat_map(|&comment| {
+                            get_lines_without_code(comment, &line_start_bytes, context)
+                        })
+                        .collect::<HashSet<_>>();
+
+                    lines.retain(|(row, _)| !comment_lines.contains(row));
+                }
+
+                if lines.len() <= self.max {
+                    return;
+                }
+
+                let start_row = lines[self.max].0;
+                let start_byte = line_start_bytes[start_row];
+
+                context.report(violation! {
+                    node => node,
+                    range => Range {
+                        start_byte,
+                        end_byte: source_text.len(),
+                        start_point: Point {
+                            row: start_row,
+                            column: 0,
+                        },
+                        end_point: Point {
+                            row: source_lines.len() - 1,
+                            column: source_lines.last().unwrap().len(),
+                        },
+                    },
+                    message_id => "exceed",
+                    data => {
+                        max => self.max,
+                        actual => lines.len(),
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_max_lines_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            max_lines_rule(),
+            rule_tests! {
+                valid => [
+                    "var x;",
+                    "var xy;\nvar xy;",
+                    { code => "A", options => [1] },
+                    { code => "A\n", options => [1] },
+                    { code => "A\r", options => [1] },
+                    { code => "A\r\n", options => [1] },
+                    { code => "var xy;\nvar xy;", options => [2] },
+                    { code => "var xy;\nvar xy;\n", options => [2] },
+                    { code => "var xy;\nvar xy;", options => [{ max => 2 }] },
+                    {
+                        code => "//a single line comment\nvar xy;\nvar xy;\n /* a multiline\n really really\n long comment*/ ",
+                        options => [{ max => 2, skip_comments => true }]
+                    },
+                    {
+                        code => "var x; /* inline comment\n spanning multiple lines */ var z;",
+                        options => [{ max => 2, skip_comments => true }]
+                    },
+                    {
+                        code => "var x; /* inline comment\n spanning multiple lines */\n var z;",
+                        options => [{ max => 2, skip_comments => true }]
+                    },
+                    {
+                        code => "var x;\n\n\t\t\n  \nvar y;",
+                        options => [{ max => 2, skip_blank_lines => true }]
+                    },
+                    {
+                        code => "//a single line comment\nvar xy;\n \n var xy;\n /* a multiline\n really really\n long comment*/",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => true }]
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "var xyz;\nvar xyz;\nvar xyz;",
+                        options => [2],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 3,
+                            column => 1,
+                            end_line => 3,
+                            end_column => 9
+                        }]
+                    },
+                    {
+                        code => "/* a multiline comment\n that goes to many lines*/\nvar xy;\nvar xy;",
+                        options => [2],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 4 },
+                            line => 3,
+                            column => 1,
+                            end_line => 4,
+                            end_column => 8
+                        }]
+                    },
+                    {
+                        code => "//a single line comment\nvar xy;\nvar xy;",
+                        options => [2],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 3,
+                            column => 1,
+                            end_line => 3,
+                            end_column => 8
+                        }]
+                    },
+                    {
+                        code => "var x;\n\n\n\nvar y;",
+                        options => [{ max => 2 }],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 5 },
+                            line => 3,
+                            column => 1,
+                            end_line => 5,
+                            end_column => 7
+                        }]
+                    },
+                    {
+                        code => "//a single line comment\nvar xy;\n \n var xy;\n /* a multiline\n really really\n long comment*/",
+                        options => [{ max => 2, skip_comments => true }],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 4,
+                            column => 1,
+                            end_line => 7,
+                            end_column => 16
+                        }]
+                    },
+                    {
+                        code => "var x; // inline comment\nvar y;\nvar z;",
+                        options => [{ max => 2, skip_comments => true }],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 3,
+                            column => 1,
+                            end_line => 3,
+                            end_column => 7
+                        }]
+                    },
+                    {
+                        code => "var x; /* inline comment\n spanning multiple lines */\nvar y;\nvar z;",
+                        options => [{ max => 2, skip_comments => true }],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 4,
+                            column => 1,
+                            end_line => 4,
+                            end_column => 7
+                        }]
+                    },
+                    {
+                        code => "//a single line comment\nvar xy;\n \n var xy;\n /* a multiline\n really really\n long comment*/",
+                        options => [{ max => 2, skip_blank_lines => true }],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 6 },
+                            line => 4,
+                            column => 1,
+                            end_line => 7,
+                            end_column => 16
+                        }]
+                    },
+                    {
+                        code => "AAAAAAAA\n".repeat(301) + "BBBBBBBB\n" + &"CCCCCCCC\n".repeat(301),
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 300, actual => 603 },
+                            line => 301,
+                            column => 1,
+                            end_line => 604,
+                            end_column => 1
+                        }]
+                    },
+                    {
+                        code => "A\nB\nC",
+                        options => [2],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 3,
+                            column => 1,
+                            end_line => 3,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "A\rB\rC",
+                        options => [2],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 3,
+                            column => 1,
+                            end_line => 3,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "A\u{2028}B\u{2028}C",
+                        options => [2],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 3,
+                            column => 1,
+                            end_line => 3,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "\n\n\n",
+                        options => [2],
+                        errors => [{
+                            message_id => "exceed",
+                            data => { max => 2, actual => 3 },
+                            line => 3,
+                            column => 1,
+                            end_line => 4,
+                            end_column => 1
+                        }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
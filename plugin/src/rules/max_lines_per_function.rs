@@ -0,0 +1,568 @@
+use std::{collections::HashMap, iter::once, sync::Arc};
+
+use serde::Deserialize;
+use squalid::regex;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Point},
+    violation, NodeExt, QueryMatchContext, Rule,
+};
+
+use crate::{
+    all_comments::AllComments,
+    ast_helpers::NodeExtJs,
+    kind::{ArrowFunction, CallExpression, Function, GeneratorFunction},
+    string_utils::upper_case_first,
+    utils::ast_utils,
+};
+
+const DEFAULT_MAX: usize = 50;
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    #[serde(alias = "maximum")]
+    max: usize,
+    skip_comments: bool,
+    skip_blank_lines: bool,
+    iifes: bool,
+}
+
+impl Default for OptionsObject {
+    fn default() -> Self {
+        Self {
+            max: DEFAULT_MAX,
+            skip_comments: Default::default(),
+            skip_blank_lines: Default::default(),
+            iifes: Default::default(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Options {
+    Usize(usize),
+    Object(OptionsObject),
+}
+
+impl Options {
+    pub fn max(&self) -> usize {
+        match self {
+            Self::Usize(value) => *value,
+            Self::Object(OptionsObject { max, .. }) => *max,
+        }
+    }
+
+    pub fn skip_comments(&self) -> bool {
+        match self {
+            Self::Object(OptionsObject { skip_comments, .. }) => *skip_comments,
+            _ => false,
+        }
+    }
+
+    pub fn skip_blank_lines(&self) -> bool {
+        match self {
+            Self::Object(OptionsObject {
+                skip_blank_lines, ..
+            }) => *skip_blank_lines,
+            _ => false,
+        }
+    }
+
+    pub fn iifes(&self) -> bool {
+        match self {
+            Self::Object(OptionsObject { iifes, .. }) => *iifes,
+            _ => false,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::Usize(DEFAULT_MAX)
+    }
+}
+
+fn get_position(line_start_bytes: &[usize], byte: usize) -> Point {
+    let row = line_start_bytes.partition_point(|&line_start_byte| line_start_byte <= byte) - 1;
+    Point {
+        row,
+        column: byte - line_start_bytes[row],
+    }
+}
+
+fn get_comment_line_numbers<'a>(
+    comments: &[Node<'a>],
+    line_start_bytes: &[usize],
+) -> HashMap<usize, Node<'a>> {
+    let mut map: HashMap<usize, Node<'a>> = Default::default();
+    for &comment in comments {
+        for row in get_position(line_start_bytes, comment.start_byte()).row
+            ..=get_position(line_start_bytes, comment.end_byte()).row
+        {
+            map.insert(row, comment);
+        }
+    }
+    map
+}
+
+fn is_full_line_comment(line: &str, row: usize, comment: Node, line_start_bytes: &[usize]) -> bool {
+    let start = get_position(line_start_bytes, comment.start_byte());
+    let end = get_position(line_start_bytes, comment.end_byte());
+    let is_first_token_on_line = start.row == row && line[..start.column].trim().is_empty();
+    let is_last_token_on_line = end.row == row && line[end.column..].trim().is_empty();
+
+    (start.row < row || is_first_token_on_line) && (end.row > row || is_last_token_on_line)
+}
+
+fn is_iife(node: Node, context: &QueryMatchContext) -> bool {
+    if !matches!(node.kind(), Function | GeneratorFunction | ArrowFunction) {
+        return false;
+    }
+    let parent = node.next_non_parentheses_ancestor(context);
+    parent.kind() == CallExpression && parent.field("function").skip_parentheses() == node
+}
+
+pub fn max_lines_per_function_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "max-lines-per-function",
+        languages => [Javascript],
+        messages => [
+            exceed => "{{name}} has too many lines ({{line_count}}). Maximum allowed is {{max_lines}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            max_lines: usize = options.max(),
+            skip_comments: bool = options.skip_comments(),
+            skip_blank_lines: bool = options.skip_blank_lines(),
+            iifes: bool = options.iifes(),
+
+            [per-file-run]
+            lines: Vec<String>,
+            line_start_bytes: Vec<usize>,
+            comment_line_numbers: HashMap<usize, Node<'a>>,
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                let source_text = context.slice(0..node.end_byte());
+                self.lines = ast_utils::LINE_BREAK_PATTERN
+                    .split(&source_text)
+                    .map(ToOwned::to_owned)
+                    .collect();
+                self.line_start_bytes = once(0)
+                    .chain(
+                        ast_utils::LINE_BREAK_PATTERN
+                            .find_iter(&source_text)
+                            .map(|line_break| line_break.end()),
+                    )
+                    .collect();
+                if self.skip_comments {
+                    self.comment_line_numbers = get_comment_line_numbers(
+                        &context.retrieve::<AllComments<'a>>(),
+                        &self.line_start_bytes,
+                    );
+                }
+            },
+            r#"
+              (function_declaration) @c
+              (function) @c
+              (arrow_function) @c
+              (generator_function_declaration) @c
+              (generator_function) @c
+              (method_definition) @c
+            "# => |node, context| {
+                if !self.iifes && is_iife(node, context) {
+                    return;
+                }
+
+                let mut line_count = 0;
+                for row in get_position(&self.line_start_bytes, node.start_byte()).row
+                    ..=get_position(&self.line_start_bytes, node.end_byte()).row
+                {
+                    let line = &self.lines[row];
+
+                    if self.skip_comments {
+                        if let Some(&comment) = self.comment_line_numbers.get(&row) {
+                            if is_full_line_comment(line, row, comment, &self.line_start_bytes) {
+                                continue;
+                            }
+                        }
+                    }
+
+                    if self.skip_blank_lines && regex!(r#"^\s*$"#).is_match(line) {
+                        continue;
+                    }
+
+                    line_count += 1;
+                }
+
+                if line_count > self.max_lines {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "exceed",
+                        data => {
+                            name => upper_case_first(&ast_utils::get_function_name_with_kind(node, context)),
+                            line_count => line_count,
+                            max_lines => self.max_lines,
+                        }
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_max_lines_per_function_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            max_lines_per_function_rule(),
+            rule_tests! {
+                valid => [
+                    // Test code in global scope doesn't count
+                    {
+                        code => "var x = 5;\nvar x = 2;\n",
+                        options => [1]
+                    },
+
+                    // Test single line standalone function
+                    {
+                        code => "function name() {}",
+                        options => [1]
+                    },
+
+                    // Test standalone function with lines of code
+                    {
+                        code => "function name() {\nvar x = 5;\nvar x = 2;\n}",
+                        options => [4]
+                    },
+
+                    // Test inline arrow function
+                    {
+                        code => "const bar = () => 2",
+                        options => [1],
+                        environment => { ecma_version => 6 }
+                    },
+
+                    // Test arrow function
+                    {
+                        code => "const bar = () => {\nconst x = 2 + 1;\nreturn x;\n}",
+                        options => [4],
+                        environment => { ecma_version => 6 }
+                    },
+
+                    // skipBlankLines: false with simple standalone function
+                    {
+                        code => "function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}",
+                        options => [{ max => 7, skip_comments => false, skip_blank_lines => false }]
+                    },
+
+                    // skipBlankLines: true with simple standalone function
+                    {
+                        code => "function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}",
+                        options => [{ max => 4, skip_comments => false, skip_blank_lines => true }]
+                    },
+
+                    // skipComments: true with an individual single line comment
+                    {
+                        code => "function name() {\nvar x = 5;\nvar x = 2; // end of line comment\n}",
+                        options => [{ max => 4, skip_comments => true, skip_blank_lines => false }]
+                    },
+
+                    // skipComments: true with an individual single line comment
+                    {
+                        code => "function name() {\nvar x = 5;\n// a comment on it's own line\nvar x = 2; // end of line comment\n}",
+                        options => [{ max => 4, skip_comments => true, skip_blank_lines => false }]
+                    },
+
+                    // skipComments: true with single line comments
+                    {
+                        code => "function name() {\nvar x = 5;\n// a comment on it's own line\n// and another line comment\nvar x = 2; // end of line comment\n}",
+                        options => [{ max => 4, skip_comments => true, skip_blank_lines => false }]
+                    },
+
+                    // skipComments: true test with multiple different comment types
+                    {
+                        code => "function name() {\nvar x = 5;\n/* a \n multi \n line \n comment \n*/\n\nvar x = 2; // end of line comment\n}",
+                        options => [{ max => 5, skip_comments => true, skip_blank_lines => false }]
+                    },
+
+                    // skipComments: true with multiple different comment types, including trailing and leading whitespace
+                    {
+                        code => "function name() {\nvar x = 5;\n\t/* a comment with leading whitespace */\n/* a comment with trailing whitespace */\t\t\n\t/* a comment with trailing and leading whitespace */\t\t\n/* a \n multi \n line \n comment \n*/\t\t\n\nvar x = 2; // end of line comment\n}",
+                        options => [{ max => 5, skip_comments => true, skip_blank_lines => false }]
+                    },
+
+                    // Multiple params on separate lines test
+                    {
+                        code => "function foo(\n    aaa = 1,\n    bbb = 2,\n    ccc = 3\n) {\n    return aaa + bbb + ccc\n}",
+                        options => [{ max => 7, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 }
+                    },
+
+                    // IIFE validity test
+                    {
+                        code => "(\nfunction\n()\n{\n}\n)\n()",
+                        options => [{ max => 4, skip_comments => true, skip_blank_lines => false, iifes => true }]
+                    },
+
+                    // Nested function validity test
+                    {
+                        code => "function parent() {\nfunction child() {\n}\n}",
+                        options => [{ max => 4, skip_comments => true, skip_blank_lines => false }]
+                    },
+
+                    // Class method validity test
+                    {
+                        code => "class foo {\n    method() {\n        let y = 10;\n        let x = 20;\n        return y + x;\n    }\n}",
+                        options => [{ max => 5, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 }
+                    },
+
+                    // IIFEs should be recognised if IIFEs: true
+                    {
+                        code => "(function(){\n    let x = 0;\n    let y = 0;\n    let z = x + y;\n    let foo = {};\n    return bar;\n}());",
+                        options => [{ max => 7, skip_comments => true, skip_blank_lines => false, iifes => true }],
+                        environment => { ecma_version => 6 }
+                    },
+
+                    // IIFEs should not be recognised if IIFEs: false
+                    {
+                        code => "(function(){\n    let x = 0;\n    let y = 0;\n    let z = x + y;\n    let foo = {};\n    return bar;\n}());",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false, iifes => false }],
+                        environment => { ecma_version => 6 }
+                    },
+
+                    // Arrow IIFEs should be recognised if IIFEs: true
+                    {
+                        code => "(() => {\n    let x = 0;\n    let y = 0;\n    let z = x + y;\n    let foo = {};\n    return bar;\n})();",
+                        options => [{ max => 7, skip_comments => true, skip_blank_lines => false, iifes => true }],
+                        environment => { ecma_version => 6 }
+                    },
+
+                    // Arrow IIFEs should not be recognised if IIFEs: false
+                    {
+                        code => "(() => {\n    let x = 0;\n    let y = 0;\n    let z = x + y;\n    let foo = {};\n    return bar;\n})();",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false, iifes => false }],
+                        environment => { ecma_version => 6 }
+                    }
+                ],
+                invalid => [
+                    // Test simple standalone function is recognised
+                    {
+                        code => "function name() {\n}",
+                        options => [1],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 2, max_lines => 1 } }]
+                    },
+
+                    // Test anonymous function assigned to variable is recognised
+                    {
+                        code => "var func = function() {\n}",
+                        options => [1],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'func'", line_count => 2, max_lines => 1 } }]
+                    },
+
+                    // Test arrow functions are recognised
+                    {
+                        code => "const bar = () => {\nconst x = 2 + 1;\nreturn x;\n}",
+                        options => [3],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "exceed", data => { name => "Arrow function 'bar'", line_count => 4, max_lines => 3 } }]
+                    },
+
+                    // Test inline arrow functions are recognised
+                    {
+                        code => "const bar = () =>\n 2",
+                        options => [1],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "exceed", data => { name => "Arrow function 'bar'", line_count => 2, max_lines => 1 } }]
+                    },
+
+                    // Test that option defaults work as expected
+                    {
+                        code => format!("() => {{{}}}", "foo\n".repeat(60)),
+                        options => [{}],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "exceed", data => { name => "Arrow function", line_count => 61, max_lines => 50 } }]
+                    },
+
+                    // Test skipBlankLines: false
+                    {
+                        code => "function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}",
+                        options => [{ max => 6, skip_comments => false, skip_blank_lines => false }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 7, max_lines => 6 } }]
+                    },
+
+                    // Test skipBlankLines: false with CRLF line endings
+                    {
+                        code => "function name() {\r\nvar x = 5;\r\n\t\r\n \r\n\r\nvar x = 2;\r\n}",
+                        options => [{ max => 6, skip_comments => true, skip_blank_lines => false }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 7, max_lines => 6 } }]
+                    },
+
+                    // Test skipBlankLines: false with CR and U+2028 line endings
+                    {
+                        code => "function name() {\rvar x = 5;\r\t\r \r\rvar x = 2;\r}",
+                        options => [{ max => 6, skip_comments => true, skip_blank_lines => false }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 7, max_lines => 6 } }]
+                    },
+                    {
+                        code => "function name() {\u{2028}var x = 5;\u{2028}\t\u{2028} \u{2028}\u{2028}var x = 2;\u{2028}}",
+                        options => [{ max => 6, skip_comments => true, skip_blank_lines => false }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 7, max_lines => 6 } }]
+                    },
+
+                    // Test skipBlankLines: true
+                    {
+                        code => "function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => true }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 4, max_lines => 2 } }]
+                    },
+
+                    // Test skipBlankLines: true with CRLF line endings
+                    {
+                        code => "function name() {\r\nvar x = 5;\r\n\t\r\n \r\n\r\nvar x = 2;\r\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => true }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 4, max_lines => 2 } }]
+                    },
+
+                    // Test skipComments: true and skipBlankLines: false for multiple types of comment
+                    {
+                        code => "function name() { // end of line comment\nvar x = 5; /* mid line comment */\n\t// single line comment taking up whole line\n\t\n \n\nvar x = 2;\n}",
+                        options => [{ max => 6, skip_comments => true, skip_blank_lines => false }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 7, max_lines => 6 } }]
+                    },
+
+                    // Test skipComments: true and skipBlankLines: true for multiple types of comment
+                    {
+                        code => "function name() { // end of line comment\nvar x = 5; /* mid line comment */\n\t// single line comment taking up whole line\n\t\n \n\nvar x = 2;\n}",
+                        options => [{ max => 1, skip_comments => true, skip_blank_lines => true }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 4, max_lines => 1 } }]
+                    },
+
+                    // Test skipComments: false and skipBlankLines: true for multiple types of comment
+                    {
+                        code => "function name() { // end of line comment\nvar x = 5; /* mid line comment */\n\t// single line comment taking up whole line\n\t\n \n\nvar x = 2;\n}",
+                        options => [{ max => 1, skip_comments => false, skip_blank_lines => true }],
+                        errors => [{ message_id => "exceed", data => { name => "Function 'name'", line_count => 5, max_lines => 1 } }]
+                    },
+
+                    // Test simple standalone function with params on separate lines
+                    {
+                        code => "function foo(\n    aaa = 1,\n    bbb = 2,\n    ccc = 3\n) {\n    return aaa + bbb + ccc\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "exceed", data => { name => "Function 'foo'", line_count => 7, max_lines => 2 } }]
+                    },
+
+                    // Test IIFE "function" keyword is included in the count
+                    {
+                        code => "(\nfunction\n()\n{\n}\n)\n()",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false, iifes => true }],
+                        errors => [{ message_id => "exceed", data => { name => "Function", line_count => 4, max_lines => 2 } }]
+                    },
+
+                    // Test nested functions are included in it's parent's function count.
+                    {
+                        code => "function parent() {\nfunction child() {\n}\n}",
+                        options => [{ max => 1, skip_comments => true, skip_blank_lines => false }],
+                        errors => [
+                            { message_id => "exceed", data => { name => "Function 'parent'", line_count => 4, max_lines => 1 } },
+                            { message_id => "exceed", data => { name => "Function 'child'", line_count => 2, max_lines => 1 } }
+                        ]
+                    },
+
+                    // Test nested functions are included in it's parent's function count.
+                    {
+                        code => "function parent() {\nfunction child() {\n}\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false }],
+                        errors => [
+                            { message_id => "exceed", data => { name => "Function 'parent'", line_count => 4, max_lines => 2 } }
+                        ]
+                    },
+
+                    // Test regular methods are recognised
+                    {
+                        code => "class foo {\n    method() {\n        let y = 10;\n        let x = 20;\n        return y + x;\n    }\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "exceed", data => { name => "Method 'method'", line_count => 5, max_lines => 2 } }
+                        ]
+                    },
+
+                    // Test static methods are recognised
+                    {
+                        code => "class A {\n    static\n    foo\n    (a) {\n        return a\n    }\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "exceed", data => { name => "Static method 'foo'", line_count => 5, max_lines => 2 } }
+                        ]
+                    },
+
+                    // Test getters are recognised as properties
+                    {
+                        code => "var obj = {\n    get\n    foo\n    () {\n        return 1\n    }\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "exceed", data => { name => "Getter 'foo'", line_count => 5, max_lines => 2 } }
+                        ]
+                    },
+
+                    // Test setters are recognised as properties
+                    {
+                        code => "var obj = {\n    set\n    foo\n    ( val ) {\n        this._foo = val;\n    }\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "exceed", data => { name => "Setter 'foo'", line_count => 5, max_lines => 2 } }
+                        ]
+                    },
+
+                    // Test computed property names
+                    {
+                        code => "class A {\n    static\n    [\n        foo +\n            bar\n    ]\n    (a) {\n        return a\n    }\n}",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "exceed", data => { name => "Static method", line_count => 8, max_lines => 2 } }
+                        ]
+                    },
+
+                    // Test the IIFEs option includes IIFEs
+                    {
+                        code => "(function(){\n    let x = 0;\n    let y = 0;\n    let z = x + y;\n    let foo = {};\n    return bar;\n}());",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false, iifes => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "exceed", data => { name => "Function", line_count => 7, max_lines => 2 } }
+                        ]
+                    },
+
+                    // Test the IIFEs option includes arrow IIFEs
+                    {
+                        code => "(() => {\n    let x = 0;\n    let y = 0;\n    let z = x + y;\n    let foo = {};\n    return bar;\n})();",
+                        options => [{ max => 2, skip_comments => true, skip_blank_lines => false, iifes => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "exceed", data => { name => "Arrow function", line_count => 7, max_lines => 2 } }
+                        ]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,396 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, QueryMatchContext, Rule};
+
+use crate::kind::{
+    ClassStaticBlock, DoStatement, ExportStatement, ExpressionStatement, ForInStatement,
+    ForStatement, IfStatement, LabeledStatement, StatementBlock, WhileStatement,
+};
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct Options {
+    max: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { max: 1 }
+    }
+}
+
+fn is_single_child_allowed_parent(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        DoStatement
+            | ForStatement
+            | ForInStatement
+            | IfStatement
+            | LabeledStatement
+            | WhileStatement
+            | ExportStatement
+    )
+}
+
+fn is_ignored(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    match node.kind() {
+        StatementBlock => parent.kind() == ClassStaticBlock,
+        ExpressionStatement => {
+            parent.kind() == ForStatement && parent.child_by_field_name("body") != Some(node)
+        }
+        _ => false,
+    }
+}
+
+fn get_actual_last_token<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Node<'a> {
+    context.get_last_token(node, Some(|token: Node| token.kind() != ";"))
+}
+
+pub fn max_statements_per_line_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "max-statements-per-line",
+        languages => [Javascript],
+        messages => [
+            exceed => "This line has {{number_of_statements_on_this_line}} {{statements}}. Maximum allowed is {{max_statements_per_line}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            max_statements_per_line: usize = options.max,
+
+            [per-file-run]
+            last_statement_line: Option<usize>,
+            number_of_statements_on_this_line: usize,
+            first_extra_statement: Option<Node<'a>>,
+        },
+        methods => {
+            fn report_first_extra_statement_and_clear(&mut self, context: &QueryMatchContext<'a, '_>) {
+                if let Some(first_extra_statement) = self.first_extra_statement.take() {
+                    context.report(violation! {
+                        node => first_extra_statement,
+                        message_id => "exceed",
+                        data => {
+                            number_of_statements_on_this_line => self.number_of_statements_on_this_line,
+                            max_statements_per_line => self.max_statements_per_line,
+                            statements => if self.number_of_statements_on_this_line == 1 {
+                                "statement"
+                            } else {
+                                "statements"
+                            },
+                        }
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (statement_block) @c
+              (break_statement) @c
+              (class_declaration) @c
+              (continue_statement) @c
+              (debugger_statement) @c
+              (do_statement) @c
+              (expression_statement) @c
+              (for_in_statement) @c
+              (for_statement) @c
+              (function_declaration) @c
+              (generator_function_declaration) @c
+              (if_statement) @c
+              (import_statement) @c
+              (labeled_statement) @c
+              (return_statement) @c
+              (switch_statement) @c
+              (throw_statement) @c
+              (try_statement) @c
+              (variable_declaration) @c
+              (lexical_declaration) @c
+              (while_statement) @c
+              (with_statement) @c
+              (export_statement) @c
+            "# => |node, context| {
+                if is_ignored(node) {
+                    return;
+                }
+
+                let line = node.start_position().row;
+
+                // Skip to allow non-block statements if this is direct child of
+                // control statements.
+                // `if (a) foo();` is counted as 1.
+                // But `if (a) foo(); else foo();` should be counted as 2.
+                if is_single_child_allowed_parent(node.parent().unwrap()) {
+                    return;
+                }
+
+                if self.last_statement_line == Some(line) {
+                    self.number_of_statements_on_this_line += 1;
+                } else {
+                    self.report_first_extra_statement_and_clear(context);
+                    self.number_of_statements_on_this_line = 1;
+                    self.last_statement_line = Some(line);
+                }
+
+                if self.number_of_statements_on_this_line == self.max_statements_per_line + 1 {
+                    self.first_extra_statement.get_or_insert(node);
+                }
+            },
+            r#"
+              statement_block:exit,
+              break_statement:exit,
+              class_declaration:exit,
+              continue_statement:exit,
+              debugger_statement:exit,
+              do_statement:exit,
+              expression_statement:exit,
+              for_in_statement:exit,
+              for_statement:exit,
+              function_declaration:exit,
+              generator_function_declaration:exit,
+              if_statement:exit,
+              import_statement:exit,
+              labeled_statement:exit,
+              return_statement:exit,
+              switch_statement:exit,
+              throw_statement:exit,
+              try_statement:exit,
+              variable_declaration:exit,
+              lexical_declaration:exit,
+              while_statement:exit,
+              with_statement:exit,
+              export_statement:exit
+            "# => |node, context| {
+                if is_ignored(node) {
+                    return;
+                }
+
+                let line = get_actual_last_token(node, context).end_position().row;
+
+                if self.last_statement_line != Some(line) {
+                    self.report_first_extra_statement_and_clear(context);
+                    self.number_of_statements_on_this_line = 1;
+                    self.last_statement_line = Some(line);
+                }
+            },
+            r#"
+              program:exit
+            "# => |node, context| {
+                self.report_first_extra_statement_and_clear(context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_max_statements_per_line_rule() {
+        RuleTester::run(
+            max_statements_per_line_rule(),
+            rule_tests! {
+                valid => [
+                    { code => "{ }", options => [{ max => 1 }] },
+                    "var bar = 1;",
+                    { code => "var bar = 1;", options => [{ max => 1 }] },
+                    "var bar = 1;;",
+                    ";(function foo() {\n})()",
+                    { code => "if (condition) var bar = 1;", options => [{ max => 1 }] },
+                    { code => "if (condition) { }", options => [{ max => 1 }] },
+                    { code => "if (condition) { } else { }", options => [{ max => 1 }] },
+                    { code => "if (condition) {\nvar bar = 1;\n} else {\nvar bar = 1;\n}", options => [{ max => 1 }] },
+                    { code => "for (var i = 0; i < length; ++i) { }", options => [{ max => 1 }] },
+                    { code => "for (var i = 0; i < length; ++i) {\nvar bar = 1;\n}", options => [{ max => 1 }] },
+                    { code => "switch (discriminant) { default: }", options => [{ max => 1 }] },
+                    { code => "switch (discriminant) {\ndefault: break;\n}", options => [{ max => 1 }] },
+                    { code => "function foo() { }", options => [{ max => 1 }] },
+                    { code => "function foo() {\nif (condition) var bar = 1;\n}", options => [{ max => 1 }] },
+                    { code => "function foo() {\nif (condition) {\nvar bar = 1;\n}\n}", options => [{ max => 1 }] },
+                    { code => "(function() { })();", options => [{ max => 1 }] },
+                    { code => "(function() {\nvar bar = 1;\n})();", options => [{ max => 1 }] },
+                    { code => "var foo = function foo() { };", options => [{ max => 1 }] },
+                    { code => "var foo = function foo() {\nvar bar = 1;\n};", options => [{ max => 1 }] },
+                    { code => "var foo = { prop: () => { } };", options => [{ max => 1 }], environment => { ecma_version => 6 } },
+                    { code => "var foo = {\nprop: () => {\nvar bar = 1;\n}\n};", options => [{ max => 1 }], environment => { ecma_version => 6 } },
+                    { code => "var bar = 1; var baz = 2;", options => [{ max => 2 }] },
+                    { code => "if (condition) { var bar = 1; }", options => [{ max => 2 }] },
+                    { code => "if (condition) {\nvar bar = 1; var baz = 2;\n} else {\nvar bar = 1; var baz = 2;\n}", options => [{ max => 2 }] },
+                    { code => "for (var i = 0; i < length; ++i) { var bar = 1; }", options => [{ max => 2 }] },
+                    { code => "switch (discriminant) { default: break; }", options => [{ max => 2 }] },
+                    { code => "function foo() { var bar = 1; }", options => [{ max => 2 }] },
+                    { code => "(function() { var bar = 1; })();", options => [{ max => 2 }] },
+                    { code => "var foo = { prop: () => { var bar = 1; } };", options => [{ max => 2 }], environment => { ecma_version => 6 } },
+                    { code => "var a = 1; var b = 2; var c = 3; var d = 4;", options => [{ max => 4 }] },
+                    { code => "var a = 1; if (condition) { var b = 2; } var c = 3;", options => [{ max => 4 }] },
+                    { code => "var foo = {\nprop: () => { var bar = 1; }\n};", options => [{ max => 2 }], environment => { ecma_version => 6 } },
+                    { code => "export default foo = 0;", options => [{ max => 1 }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export * from './foo';", options => [{ max => 1 }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export { foo } from './foo';", options => [{ max => 1 }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export const foo = 0;", options => [{ max => 1 }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export function foo() {}", options => [{ max => 1 }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "export class Foo {}", options => [{ max => 1 }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "import { foo } from './foo';", options => [{ max => 1 }], environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "let a = 1;\nlet b = 2;", options => [{ max => 1 }], environment => { ecma_version => 6 } },
+                    { code => "class C { static { foo; } }", options => [{ max => 1 }], environment => { ecma_version => 2022 } },
+                    { code => "class C { static { foo; bar; } }", options => [{ max => 2 }], environment => { ecma_version => 2022 } }
+                ],
+                invalid => [
+                    {
+                        code => "var foo; var bar;",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "var bar = 1; var foo = 3;",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "var bar = 1; var baz = 2;",
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "var bar = 1; var baz = 2;",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) var bar = 1; if (condition) var baz = 2;",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) var bar = 1; else var baz = 1;",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) { } if (condition) { }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) { var bar = 1; } else { }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) { } else { var bar = 1; }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) { var bar = 1; } else { var bar = 1; }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 4, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "for (var i = 0; i < length; ++i) { var bar = 1; }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "switch (discriminant) { default: break; }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "function foo() { var bar = 1; }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "function foo() { if (condition) var bar = 1; }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "function foo() { if (condition) { var bar = 1; } }",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 4, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "(function() { var bar = 1; })();",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "var foo = function foo() { var bar = 1; };",
+                        options => [{ max => 1 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "var foo = { prop: () => { var bar = 1; } };",
+                        options => [{ max => 1 }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "var bar = 1; var baz = 2; var qux = 3;",
+                        options => [{ max => 2 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 2, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) { var bar = 1; var baz = 2; }",
+                        options => [{ max => 2 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 2, statements => "statements" } }]
+                    },
+                    {
+                        code => "if (condition) { var bar = 1; } else { var bar = 1; var baz = 2; }",
+                        options => [{ max => 2 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 5, max_statements_per_line => 2, statements => "statements" } }]
+                    },
+                    {
+                        code => "var a = 1; var b = 2; var c = 3; var d = 4; var e = 5;",
+                        options => [{ max => 4 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 5, max_statements_per_line => 4, statements => "statements" } }]
+                    },
+                    {
+                        code => "var foo = {\nprop: () => { var bar = 1; var baz = 2; }\n};",
+                        options => [{ max => 2 }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 3, max_statements_per_line => 2, statements => "statements" } }]
+                    },
+                    {
+                        code => "var bar = 1; var baz = 2;",
+                        options => [{ max => 0 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 0, statements => "statements" } }]
+                    },
+                    {
+                        code => "var bar = 1;",
+                        options => [{ max => 0 }],
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 1, max_statements_per_line => 0, statements => "statement" } }]
+                    },
+                    {
+                        code => "export default foo = 0; foo = 1;",
+                        options => [{ max => 1 }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "export const foo = 0; foo = 1;",
+                        options => [{ max => 1 }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "import { foo } from './foo'; foo();",
+                        options => [{ max => 1 }],
+                        environment => { ecma_version => 6, source_type => "module" },
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    },
+                    {
+                        code => "class C { static { foo; bar; } }",
+                        options => [{ max => 1 }],
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "exceed", data => { number_of_statements_on_this_line => 2, max_statements_per_line => 1, statements => "statements" } }]
+                    }
+                ]
+            },
+        )
+    }
+}
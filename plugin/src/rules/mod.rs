@@ -1,6 +1,9 @@
 mod accessor_pairs;
 mod array_bracket_newline;
 mod array_callback_return;
+mod arrow_body_style;
+mod block_scoped_var;
+mod capitalized_comments;
 mod class_methods_use_this;
 mod complexity;
 mod consistent_return;
@@ -10,15 +13,27 @@ mod default_case_last;
 mod default_param_last;
 mod dot_location;
 mod for_direction;
+mod func_name_matching;
+mod func_names;
+mod func_style;
 mod getter_return;
 mod guard_for_in;
 mod line_comment_position;
+mod logical_assignment_operators;
+mod max_classes_per_file;
+mod max_depth;
+mod max_lines;
+mod max_lines_per_function;
 mod max_nested_callbacks;
 mod max_params;
 mod max_statements;
+mod max_statements_per_line;
+mod multiline_comment_style;
+mod new_cap;
 mod no_array_constructor;
 mod no_async_promise_executor;
 mod no_await_in_loop;
+mod no_case_declarations;
 mod no_class_assign;
 mod no_compare_neg_zero;
 mod no_cond_assign;
@@ -28,6 +43,7 @@ mod no_constant_condition;
 mod no_constructor_return;
 mod no_control_regex;
 mod no_debugger;
+mod no_div_regex;
 mod no_dupe_args;
 mod no_dupe_class_members;
 mod no_dupe_else_if;
@@ -38,15 +54,21 @@ mod no_empty_character_class;
 mod no_empty_pattern;
 mod no_eq_null;
 mod no_ex_assign;
+mod no_extend_native;
 mod no_extra_bind;
+mod no_extra_boolean_cast;
 mod no_extra_label;
 mod no_fallthrough;
 mod no_func_assign;
 mod no_import_assign;
 mod no_inner_declarations;
 mod no_invalid_regexp;
+mod no_irregular_whitespace;
 mod no_labels;
+mod no_lone_blocks;
 mod no_lonely_if;
+mod no_loss_of_precision;
+mod no_misleading_character_class;
 mod no_mixed_operators;
 mod no_multi_assign;
 mod no_multi_str;
@@ -57,48 +79,80 @@ mod no_new_native_nonconstructor;
 mod no_new_object;
 mod no_new_symbol;
 mod no_new_wrappers;
+mod no_nonoctal_decimal_escape;
+mod no_obj_calls;
 mod no_octal;
 mod no_octal_escape;
 mod no_param_reassign;
 mod no_plusplus;
+mod no_promise_executor_return;
 mod no_proto;
+mod no_prototype_builtins;
 mod no_regex_spaces;
+mod no_restricted_exports;
+mod no_restricted_imports;
 mod no_restricted_properties;
+mod no_restricted_syntax;
 mod no_return_assign;
 mod no_script_url;
 mod no_self_assign;
+mod no_self_compare;
 mod no_sequences;
+mod no_setter_return;
+mod no_sparse_arrays;
+mod no_template_curly_in_string;
 mod no_ternary;
 mod no_this_before_super;
 mod no_throw_literal;
 mod no_undef;
+mod no_unexpected_multiline;
+mod no_unmodified_loop_condition;
 mod no_unneeded_ternary;
 mod no_unreachable;
 mod no_unreachable_loop;
 mod no_unsafe_finally;
 mod no_unsafe_negation;
 mod no_unsafe_optional_chaining;
+mod no_unused_expressions;
 mod no_unused_labels;
+mod no_unused_private_class_members;
 mod no_unused_vars;
+mod no_useless_backreference;
 mod no_useless_call;
 mod no_useless_catch;
+mod no_useless_computed_key;
+mod no_useless_concat;
+mod no_useless_constructor;
 mod no_useless_escape;
+mod no_useless_rename;
 mod no_useless_return;
+mod no_warning_comments;
+mod object_shorthand;
+mod one_var;
+mod operator_assignment;
+mod prefer_arrow_callback;
 mod prefer_destructuring;
+mod prefer_exponentiation_operator;
+mod prefer_named_capture_group;
 mod prefer_numeric_literals;
 mod prefer_object_has_own;
 mod prefer_promise_reject_errors;
+mod prefer_regex_literals;
 mod prefer_rest_params;
 mod prefer_spread;
 mod prefer_template;
 mod radix;
 mod require_await;
+mod require_unicode_regexp;
 mod require_yield;
 mod sort_imports;
 mod sort_keys;
 mod sort_vars;
 mod space_unary_ops;
 mod symbol_description;
+mod unicode_bom;
+mod use_isnan;
+mod valid_typeof;
 mod vars_on_top;
 mod wrap_regex;
 mod yield_star_spacing;
@@ -107,6 +161,9 @@ mod yoda;
 pub use accessor_pairs::accessor_pairs_rule;
 pub use array_bracket_newline::array_bracket_newline_rule;
 pub use array_callback_return::array_callback_return_rule;
+pub use arrow_body_style::arrow_body_style_rule;
+pub use block_scoped_var::block_scoped_var_rule;
+pub use capitalized_comments::capitalized_comments_rule;
 pub use class_methods_use_this::class_methods_use_this_rule;
 pub use complexity::complexity_rule;
 pub use consistent_return::consistent_return_rule;
@@ -116,15 +173,27 @@ pub use default_case_last::default_case_last_rule;
 pub use default_param_last::default_param_last_rule;
 pub use dot_location::dot_location_rule;
 pub use for_direction::for_direction_rule;
+pub use func_name_matching::func_name_matching_rule;
+pub use func_names::func_names_rule;
+pub use func_style::func_style_rule;
 pub use getter_return::getter_return_rule;
 pub use guard_for_in::guard_for_in_rule;
 pub use line_comment_position::line_comment_position_rule;
+pub use logical_assignment_operators::logical_assignment_operators_rule;
+pub use max_classes_per_file::max_classes_per_file_rule;
+pub use max_depth::max_depth_rule;
+pub use max_lines::max_lines_rule;
+pub use max_lines_per_function::max_lines_per_function_rule;
 pub use max_nested_callbacks::max_nested_callbacks_rule;
 pub use max_params::max_params_rule;
 pub use max_statements::max_statements_rule;
+pub use max_statements_per_line::max_statements_per_line_rule;
+pub use multiline_comment_style::multiline_comment_style_rule;
+pub use new_cap::new_cap_rule;
 pub use no_array_constructor::no_array_constructor_rule;
 pub use no_async_promise_executor::no_async_promise_executor_rule;
 pub use no_await_in_loop::no_await_in_loop_rule;
+pub use no_case_declarations::no_case_declarations_rule;
 pub use no_class_assign::no_class_assign_rule;
 pub use no_compare_neg_zero::no_compare_neg_zero_rule;
 pub use no_cond_assign::no_cond_assign_rule;
@@ -134,6 +203,7 @@ pub use no_constant_condition::no_constant_condition_rule;
 pub use no_constructor_return::no_constructor_return_rule;
 pub use no_control_regex::no_control_regex_rule;
 pub use no_debugger::no_debugger_rule;
+pub use no_div_regex::no_div_regex_rule;
 pub use no_dupe_args::no_dupe_args_rule;
 pub use no_dupe_class_members::no_dupe_class_members_rule;
 pub use no_dupe_else_if::no_dupe_else_if_rule;
@@ -144,15 +214,21 @@ pub use no_empty_character_class::no_empty_character_class_rule;
 pub use no_empty_pattern::no_empty_pattern_rule;
 pub use no_eq_null::no_eq_null_rule;
 pub use no_ex_assign::no_ex_assign_rule;
+pub use no_extend_native::no_extend_native_rule;
 pub use no_extra_bind::no_extra_bind_rule;
+pub use no_extra_boolean_cast::no_extra_boolean_cast_rule;
 pub use no_extra_label::no_extra_label_rule;
 pub use no_fallthrough::no_fallthrough_rule;
 pub use no_func_assign::no_func_assign_rule;
 pub use no_import_assign::no_import_assign_rule;
 pub use no_inner_declarations::no_inner_declarations_rule;
 pub use no_invalid_regexp::no_invalid_regexp_rule;
+pub use no_irregular_whitespace::no_irregular_whitespace_rule;
 pub use no_labels::no_labels_rule;
+pub use no_lone_blocks::no_lone_blocks_rule;
 pub use no_lonely_if::no_lonely_if_rule;
+pub use no_loss_of_precision::no_loss_of_precision_rule;
+pub use no_misleading_character_class::no_misleading_character_class_rule;
 pub use no_mixed_operators::no_mixed_operators_rule;
 pub use no_multi_assign::no_multi_assign_rule;
 pub use no_multi_str::no_multi_str_rule;
@@ -163,48 +239,80 @@ pub use no_new_native_nonconstructor::no_new_native_nonconstructor_rule;
 pub use no_new_object::no_new_object_rule;
 pub use no_new_symbol::no_new_symbol_rule;
 pub use no_new_wrappers::no_new_wrappers_rule;
+pub use no_nonoctal_decimal_escape::no_nonoctal_decimal_escape_rule;
+pub use no_obj_calls::no_obj_calls_rule;
 pub use no_octal::no_octal_rule;
 pub use no_octal_escape::no_octal_escape_rule;
 pub use no_param_reassign::no_param_reassign_rule;
 pub use no_plusplus::no_plusplus_rule;
+pub use no_promise_executor_return::no_promise_executor_return_rule;
 pub use no_proto::no_proto_rule;
+pub use no_prototype_builtins::no_prototype_builtins_rule;
 pub use no_regex_spaces::no_regex_spaces_rule;
+pub use no_restricted_exports::no_restricted_exports_rule;
+pub use no_restricted_imports::no_restricted_imports_rule;
 pub use no_restricted_properties::no_restricted_properties_rule;
+pub use no_restricted_syntax::no_restricted_syntax_rule;
 pub use no_return_assign::no_return_assign_rule;
 pub use no_script_url::no_script_url_rule;
 pub use no_self_assign::no_self_assign_rule;
+pub use no_self_compare::no_self_compare_rule;
 pub use no_sequences::no_sequences_rule;
+pub use no_setter_return::no_setter_return_rule;
+pub use no_sparse_arrays::no_sparse_arrays_rule;
+pub use no_template_curly_in_string::no_template_curly_in_string_rule;
 pub use no_ternary::no_ternary_rule;
 pub use no_this_before_super::no_this_before_super_rule;
 pub use no_throw_literal::no_throw_literal_rule;
 pub use no_undef::no_undef_rule;
+pub use no_unexpected_multiline::no_unexpected_multiline_rule;
+pub use no_unmodified_loop_condition::no_unmodified_loop_condition_rule;
 pub use no_unneeded_ternary::no_unneeded_ternary_rule;
 pub use no_unreachable::no_unreachable_rule;
 pub use no_unreachable_loop::no_unreachable_loop_rule;
 pub use no_unsafe_finally::no_unsafe_finally_rule;
 pub use no_unsafe_negation::no_unsafe_negation_rule;
 pub use no_unsafe_optional_chaining::no_unsafe_optional_chaining_rule;
+pub use no_unused_expressions::no_unused_expressions_rule;
 pub use no_unused_labels::no_unused_labels_rule;
+pub use no_unused_private_class_members::no_unused_private_class_members_rule;
 pub use no_unused_vars::no_unused_vars_rule;
+pub use no_useless_backreference::no_useless_backreference_rule;
 pub use no_useless_call::no_useless_call_rule;
 pub use no_useless_catch::no_useless_catch_rule;
+pub use no_useless_computed_key::no_useless_computed_key_rule;
+pub use no_useless_concat::no_useless_concat_rule;
+pub use no_useless_constructor::no_useless_constructor_rule;
 pub use no_useless_escape::no_useless_escape_rule;
+pub use no_useless_rename::no_useless_rename_rule;
 pub use no_useless_return::no_useless_return_rule;
+pub use no_warning_comments::no_warning_comments_rule;
+pub use object_shorthand::object_shorthand_rule;
+pub use one_var::one_var_rule;
+pub use operator_assignment::operator_assignment_rule;
+pub use prefer_arrow_callback::prefer_arrow_callback_rule;
 pub use prefer_destructuring::prefer_destructuring_rule;
+pub use prefer_exponentiation_operator::prefer_exponentiation_operator_rule;
+pub use prefer_named_capture_group::prefer_named_capture_group_rule;
 pub use prefer_numeric_literals::prefer_numeric_literals_rule;
 pub use prefer_object_has_own::prefer_object_has_own_rule;
 pub use prefer_promise_reject_errors::prefer_promise_reject_errors_rule;
+pub use prefer_regex_literals::prefer_regex_literals_rule;
 pub use prefer_rest_params::prefer_rest_params_rule;
 pub use prefer_spread::prefer_spread_rule;
 pub use prefer_template::prefer_template_rule;
 pub use radix::radix_rule;
 pub use require_await::require_await_rule;
+pub use require_unicode_regexp::require_unicode_regexp_rule;
 pub use require_yield::require_yield_rule;
 pub use sort_imports::sort_imports_rule;
 pub use sort_keys::sort_keys_rule;
 pub use sort_vars::sort_vars_rule;
 pub use space_unary_ops::space_unary_ops_rule;
 pub use symbol_description::symbol_description_rule;
+pub use unicode_bom::unicode_bom_rule;
+pub use use_isnan::use_isnan_rule;
+pub use valid_typeof::valid_typeof_rule;
 pub use vars_on_top::vars_on_top_rule;
 pub use wrap_regex::wrap_regex_rule;
 pub use yield_star_spacing::yield_star_spacing_rule;
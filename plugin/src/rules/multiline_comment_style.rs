@@ -0,0 +1,857 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+use squalid::{regex, OptionExt};
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Point, Range},
+    violation, NodeExt, QueryMatchContext, Rule, SkipOptionsBuilder,
+};
+
+use crate::{
+    all_comments::AllComments,
+    ast_helpers::{get_comment_contents, get_comment_type, CommentType},
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Style {
+    #[default]
+    StarredBlock,
+    BareBlock,
+    SeparateLines,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    #[serde(alias = "checkJSDoc")]
+    check_js_doc: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustStyle([Style; 1]),
+    StyleAndOptionsObject(Style, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+struct Options {
+    style: Style,
+    check_js_doc: bool,
+}
+
+impl Options {
+    pub fn from_style_and_options_object(style: Style, options_object: OptionsObject) -> Self {
+        Self {
+            style,
+            check_js_doc: options_object.check_js_doc,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_style_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustStyle(style) => {
+                Self::from_style_and_options_object(style[0], Default::default())
+            }
+            OptionsVariants::StyleAndOptionsObject(style, options_object) => {
+                Self::from_style_and_options_object(style, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+fn get_point(source_text: &str, byte: usize) -> Point {
+    let preceding_text = &source_text[..byte];
+    Point {
+        row: preceding_text.matches('\n').count(),
+        column: byte - preceding_text.rfind('\n').map_or(0, |index| index + 1),
+    }
+}
+
+fn get_range(source_text: &str, start_byte: usize, end_byte: usize) -> Range {
+    Range {
+        start_byte,
+        end_byte,
+        start_point: get_point(source_text, start_byte),
+        end_point: get_point(source_text, end_byte),
+    }
+}
+
+fn get_line_text(source_text: &str, line_start_byte: usize) -> &str {
+    let rest = &source_text[line_start_byte..];
+    rest[..rest.find('\n').unwrap_or(rest.len())].trim_end_matches('\r')
+}
+
+fn get_token_before_including_comments<'a>(
+    comment: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    context.maybe_get_token_before(
+        comment,
+        Some(
+            SkipOptionsBuilder::<fn(Node) -> bool>::default()
+                .include_comments(true)
+                .build()
+                .unwrap(),
+        ),
+    )
+}
+
+fn split_lines(value: &str) -> Vec<&str> {
+    ast_utils::LINE_BREAK_PATTERN.split(value).collect()
+}
+
+fn is_starred_comment_line(line: &str) -> bool {
+    regex!(r#"^\s*\*"#).is_match(line)
+}
+
+fn is_starred_block_comment(first_comment: Node, context: &QueryMatchContext) -> bool {
+    if get_comment_type(first_comment, context) != CommentType::Block {
+        return false;
+    }
+
+    let value = get_comment_contents(first_comment, context);
+    let lines = split_lines(&value);
+
+    // The first and last lines can only contain whitespace.
+    lines.len() > 1
+        && lines.iter().enumerate().all(|(i, line)| {
+            if i == 0 || i == lines.len() - 1 {
+                regex!(r#"^\s*$"#).is_match(line)
+            } else {
+                is_starred_comment_line(line)
+            }
+        })
+}
+
+fn is_js_doc_comment(first_comment: Node, context: &QueryMatchContext) -> bool {
+    if get_comment_type(first_comment, context) != CommentType::Block {
+        return false;
+    }
+
+    let value = get_comment_contents(first_comment, context);
+    let lines = split_lines(&value);
+
+    regex!(r#"^\*\s*$"#).is_match(lines[0])
+        && lines.len() >= 2
+        && lines[1..lines.len() - 1]
+            .iter()
+            .all(|line| regex!(r#"^\s* "#).is_match(line))
+        && regex!(r#"^\s*$"#).is_match(lines[lines.len() - 1])
+}
+
+fn process_separate_line_comments(
+    comment_group: &[Node],
+    context: &QueryMatchContext,
+) -> Vec<String> {
+    let values = comment_group
+        .iter()
+        .map(|&comment| get_comment_contents(comment, context))
+        .collect::<Vec<_>>();
+    let all_lines_have_leading_space = values
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.starts_with(' '));
+
+    values
+        .iter()
+        .map(|value| {
+            if all_lines_have_leading_space {
+                value.strip_prefix(' ').unwrap_or(&**value).to_owned()
+            } else {
+                value.to_string()
+            }
+        })
+        .collect()
+}
+
+fn process_starred_block_comment(comment: Node, context: &QueryMatchContext) -> Vec<String> {
+    let value = get_comment_contents(comment, context);
+    let lines = split_lines(&value);
+    let lines = lines[1..lines.len() - 1]
+        .iter()
+        .map(|line| if line.trim().is_empty() { "" } else { *line })
+        .collect::<Vec<_>>();
+    let all_lines_have_leading_space = lines
+        .iter()
+        .map(|line| regex!(r#"\s*\*"#).replace(line, ""))
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.starts_with(' '));
+
+    lines
+        .into_iter()
+        .map(|line| {
+            if all_lines_have_leading_space {
+                regex!(r#"\s*\* ?"#).replace(line, "").into_owned()
+            } else {
+                regex!(r#"\s*\*"#).replace(line, "").into_owned()
+            }
+        })
+        .collect()
+}
+
+fn process_bare_block_comment(comment: Node, context: &QueryMatchContext) -> Vec<String> {
+    let value = get_comment_contents(comment, context);
+    let lines = split_lines(&value)
+        .into_iter()
+        .map(|line| if line.trim().is_empty() { "" } else { line })
+        .collect::<Vec<_>>();
+    let leading_whitespace = format!("{}   ", get_initial_offset(comment, context));
+    let leading_whitespace_len = leading_whitespace.chars().count();
+    let mut offset = String::new();
+
+    // Calculate the offset of the least indented line and use that as the
+    // basis for offsetting all the lines. The first line should not be checked
+    // because it is inline with the opening block comment delimiter.
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() || i == 0 {
+            continue;
+        }
+        let line_offset_len = regex!(r#"^\s*\*?\s*"#)
+            .find(line)
+            .unwrap()
+            .as_str()
+            .chars()
+            .count();
+        if line_offset_len < leading_whitespace_len {
+            let new_offset = leading_whitespace
+                .chars()
+                .skip(line_offset_len)
+                .collect::<String>();
+            if new_offset.chars().count() > offset.chars().count() {
+                offset = new_offset;
+            }
+        }
+    }
+    let offset_len = offset.chars().count();
+
+    lines
+        .into_iter()
+        .map(|line| {
+            let captures = regex!(r#"^(\s*\*?\s*)(.*)"#).captures(line).unwrap();
+            let line_offset = &captures[1];
+            let line_contents = &captures[2];
+            let line_offset_len = line_offset.chars().count();
+
+            if line_offset_len > leading_whitespace_len {
+                return format!(
+                    "{}{line_contents}",
+                    line_offset
+                        .chars()
+                        .skip(leading_whitespace_len - offset_len)
+                        .collect::<String>()
+                );
+            }
+            line_contents.to_owned()
+        })
+        .collect()
+}
+
+fn get_comment_lines(comment_group: &[Node], context: &QueryMatchContext) -> Vec<String> {
+    let first_comment = comment_group[0];
+
+    if get_comment_type(first_comment, context) == CommentType::Line {
+        return process_separate_line_comments(comment_group, context);
+    }
+
+    if is_starred_block_comment(first_comment, context) {
+        return process_starred_block_comment(first_comment, context);
+    }
+
+    process_bare_block_comment(first_comment, context)
+}
+
+fn get_initial_offset<'a>(comment: Node, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    context.slice(comment.start_byte() - comment.start_position().column..comment.start_byte())
+}
+
+fn convert_to_starred_block(
+    first_comment: Node,
+    comment_lines_list: &[String],
+    context: &QueryMatchContext,
+) -> String {
+    let initial_offset = get_initial_offset(first_comment, context);
+    format!(
+        "/*\n{}\n{initial_offset} */",
+        comment_lines_list
+            .iter()
+            .map(|line| format!("{initial_offset} * {line}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+fn convert_to_separate_lines(
+    first_comment: Node,
+    comment_lines_list: &[String],
+    context: &QueryMatchContext,
+) -> String {
+    comment_lines_list
+        .iter()
+        .map(|line| format!("// {line}"))
+        .collect::<Vec<_>>()
+        .join(&format!("\n{}", get_initial_offset(first_comment, context)))
+}
+
+fn convert_to_block(
+    first_comment: Node,
+    comment_lines_list: &[String],
+    context: &QueryMatchContext,
+) -> String {
+    format!(
+        "/* {} */",
+        comment_lines_list.join(&format!(
+            "\n{}   ",
+            get_initial_offset(first_comment, context)
+        ))
+    )
+}
+
+pub fn multiline_comment_style_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "multiline-comment-style",
+        languages => [Javascript],
+        messages => [
+            expected_block => "Expected a block comment instead of consecutive line comments.",
+            expected_bare_block => "Expected a block comment without padding stars.",
+            start_newline => "Expected a linebreak after '/*'.",
+            end_newline => "Expected a linebreak before '*/'.",
+            missing_star => "Expected a '*' at the start of this line.",
+            alignment => "Expected this line to be aligned with the start of the comment.",
+            expected_lines => "Expected multiple line comments instead of a block comment.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            style: Style = options.style,
+            check_js_doc: bool = options.check_js_doc,
+        },
+        methods => {
+            fn check_starred_block(
+                &self,
+                comment_group: &[Node<'a>],
+                source_text: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let first_comment = comment_group[0];
+                let comment_lines = get_comment_lines(comment_group, context);
+
+                if comment_lines.iter().any(|value| value.contains("*/")) {
+                    return;
+                }
+
+                if comment_group.len() > 1 {
+                    let last_comment = *comment_group.last().unwrap();
+                    let range = get_range(
+                        source_text,
+                        first_comment.start_byte(),
+                        last_comment.end_byte(),
+                    );
+                    context.report(violation! {
+                        node => first_comment,
+                        range => range,
+                        message_id => "expected_block",
+                        fix => |fixer| {
+                            if comment_lines.iter().any(|value| value.starts_with('/')) {
+                                return;
+                            }
+                            fixer.replace_text_range(
+                                range,
+                                convert_to_starred_block(first_comment, &comment_lines, context),
+                            );
+                        },
+                    });
+                    return;
+                }
+
+                let value = get_comment_contents(first_comment, context);
+                let lines = split_lines(&value);
+                let expected_leading_whitespace = get_initial_offset(first_comment, context);
+                let expected_line_prefix = format!("{expected_leading_whitespace} *");
+
+                if !regex!(r#"^\*?\s*$"#).is_match(lines[0]) {
+                    let start = if value.starts_with('*') {
+                        first_comment.start_byte() + 1
+                    } else {
+                        first_comment.start_byte()
+                    };
+                    context.report(violation! {
+                        node => first_comment,
+                        range => get_range(
+                            source_text,
+                            first_comment.start_byte(),
+                            first_comment.start_byte() + 2,
+                        ),
+                        message_id => "start_newline",
+                        fix => |fixer| {
+                            fixer.replace_text_range(
+                                get_range(source_text, start, start + 2),
+                                format!(
+                                    "{}\n{expected_line_prefix}",
+                                    &source_text[start..start + 2]
+                                ),
+                            );
+                        },
+                    });
+                }
+
+                if !regex!(r#"^\s*$"#).is_match(lines[lines.len() - 1]) {
+                    let range = get_range(
+                        source_text,
+                        first_comment.end_byte() - 2,
+                        first_comment.end_byte(),
+                    );
+                    context.report(violation! {
+                        node => first_comment,
+                        range => range,
+                        message_id => "end_newline",
+                        fix => |fixer| {
+                            fixer.replace_text_range(
+                                range,
+                                format!("\n{expected_line_prefix}/"),
+                            );
+                        },
+                    });
+                }
+
+                let comment_text = first_comment.text(context);
+                let line_start_bytes = [first_comment.start_byte()
+                    - first_comment.start_position().column]
+                    .into_iter()
+                    .chain(
+                        comment_text
+                            .match_indices('\n')
+                            .map(|(index, _)| first_comment.start_byte() + index + 1),
+                    )
+                    .collect::<Vec<_>>();
+
+                for &line_start_byte in &line_start_bytes[1..] {
+                    let line_text = get_line_text(source_text, line_start_byte);
+                    let is_alignment_error = is_starred_comment_line(line_text);
+
+                    if line_text.starts_with(&expected_line_prefix) {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => first_comment,
+                        range => get_range(
+                            source_text,
+                            line_start_byte,
+                            line_start_byte + line_text.len(),
+                        ),
+                        message_id => if is_alignment_error {
+                            "alignment"
+                        } else {
+                            "missing_star"
+                        },
+                        fix => |fixer| {
+                            if is_alignment_error {
+                                let comment_text_prefix = regex!(r#"^\s*\*"#)
+                                    .find(line_text)
+                                    .map_or_default(|match_| match_.as_str());
+                                fixer.replace_text_range(
+                                    get_range(
+                                        source_text,
+                                        line_start_byte,
+                                        line_start_byte + comment_text_prefix.len(),
+                                    ),
+                                    expected_line_prefix.clone(),
+                                );
+                                return;
+                            }
+
+                            let comment_text_prefix = regex!(r#"^\s*"#)
+                                .find(line_text)
+                                .map_or_default(|match_| match_.as_str());
+                            let mut offset = String::new();
+
+                            for (idx, line) in lines.iter().enumerate() {
+                                if !regex!(r#"\S+"#).is_match(line) {
+                                    continue;
+                                }
+
+                                let Some(&line_to_align_with_start_byte) = line_start_bytes.get(idx) else {
+                                    break;
+                                };
+                                let line_text_to_align_with =
+                                    get_line_text(source_text, line_to_align_with_start_byte);
+                                let captures = regex!(r#"^(\s*(?:/?\*)?(\s*))"#)
+                                    .captures(line_text_to_align_with)
+                                    .unwrap();
+                                let prefix = &captures[1];
+                                let initial_offset = &captures[2];
+
+                                offset = format!(
+                                    "{}{initial_offset}",
+                                    comment_text_prefix.get(prefix.len()..).unwrap_or_default()
+                                );
+
+                                if regex!(r#"^\s*/"#).is_match(line_text) && offset.is_empty() {
+                                    offset.push(' ');
+                                }
+                                break;
+                            }
+
+                            fixer.replace_text_range(
+                                get_range(
+                                    source_text,
+                                    line_start_byte,
+                                    line_start_byte + comment_text_prefix.len(),
+                                ),
+                                format!("{expected_line_prefix}{offset}"),
+                            );
+                        },
+                    });
+                }
+            }
+
+            fn check_separate_lines(
+                &self,
+                comment_group: &[Node<'a>],
+                source_text: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let first_comment = comment_group[0];
+
+                let is_js_doc = is_js_doc_comment(first_comment, context);
+
+                if get_comment_type(first_comment, context) != CommentType::Block
+                    || (!self.check_js_doc && is_js_doc)
+                {
+                    return;
+                }
+
+                let mut comment_lines = get_comment_lines(comment_group, context);
+
+                let token_after = context.maybe_get_token_after(
+                    first_comment,
+                    Some(
+                        SkipOptionsBuilder::<fn(Node) -> bool>::default()
+                            .include_comments(true)
+                            .build()
+                            .unwrap(),
+                    ),
+                );
+
+                if token_after.matches(|token_after| {
+                    first_comment.end_position().row == token_after.start_position().row
+                }) {
+                    return;
+                }
+
+                if is_js_doc && comment_lines.len() >= 2 {
+                    comment_lines = comment_lines[1..comment_lines.len() - 1].to_owned();
+                }
+
+                context.report(violation! {
+                    node => first_comment,
+                    range => get_range(
+                        source_text,
+                        first_comment.start_byte(),
+                        first_comment.start_byte() + 2,
+                    ),
+                    message_id => "expected_lines",
+                    fix => |fixer| {
+                        fixer.replace_text(
+                            first_comment,
+                            convert_to_separate_lines(first_comment, &comment_lines, context),
+                        );
+                    },
+                });
+            }
+
+            fn check_bare_block(
+                &self,
+                comment_group: &[Node<'a>],
+                source_text: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let first_comment = comment_group[0];
+
+                if is_js_doc_comment(first_comment, context) {
+                    return;
+                }
+
+                let comment_lines = get_comment_lines(comment_group, context);
+
+                // Disallows consecutive line comments in favor of using a block comment.
+                if get_comment_type(first_comment, context) == CommentType::Line
+                    && comment_lines.len() > 1
+                    && !comment_lines.iter().any(|value| value.contains("*/"))
+                {
+                    let range = get_range(
+                        source_text,
+                        first_comment.start_byte(),
+                        comment_group.last().unwrap().end_byte(),
+                    );
+                    context.report(violation! {
+                        node => first_comment,
+                        range => range,
+                        message_id => "expected_block",
+                        fix => |fixer| {
+                            fixer.replace_text_range(
+                                range,
+                                convert_to_block(first_comment, &comment_lines, context),
+                            );
+                        },
+                    });
+                }
+
+                // Prohibits block comments from having a * at the beginning of each line.
+                if is_starred_block_comment(first_comment, context) {
+                    context.report(violation! {
+                        node => first_comment,
+                        range => get_range(
+                            source_text,
+                            first_comment.start_byte(),
+                            first_comment.start_byte() + 2,
+                        ),
+                        message_id => "expected_bare_block",
+                        fix => |fixer| {
+                            fixer.replace_text(
+                                first_comment,
+                                convert_to_block(first_comment, &comment_lines, context),
+                            );
+                        },
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                let source_text = context.slice(0..node.end_byte());
+
+                let comments = context
+                    .retrieve::<AllComments<'a>>()
+                    .iter()
+                    .copied()
+                    .filter(|&comment| {
+                        !ast_utils::COMMENTS_IGNORE_PATTERN
+                            .is_match(&get_comment_contents(comment, context))
+                    })
+                    .filter(|&comment| {
+                        get_token_before_including_comments(comment, context).is_none_or_matches(
+                            |token_before| {
+                                token_before.end_position().row < comment.start_position().row
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut comment_groups: Vec<Vec<Node<'a>>> = Default::default();
+                for (index, &comment) in comments.iter().enumerate() {
+                    let token_before = get_token_before_including_comments(comment, context);
+
+                    if get_comment_type(comment, context) == CommentType::Line
+                        && index > 0
+                        && get_comment_type(comments[index - 1], context) == CommentType::Line
+                        && token_before.matches(|token_before| {
+                            token_before.end_position().row + 1 == comment.start_position().row
+                                && token_before == comments[index - 1]
+                        })
+                    {
+                        comment_groups.last_mut().unwrap().push(comment);
+                    } else {
+                        comment_groups.push(vec![comment]);
+                    }
+                }
+
+                for comment_group in comment_groups.iter().filter(|comment_group| {
+                    !(comment_group.len() == 1
+                        && comment_group[0].start_position().row
+                            == comment_group[0].end_position().row)
+                }) {
+                    match self.style {
+                        Style::StarredBlock => {
+                            self.check_starred_block(comment_group, &source_text, context)
+                        }
+                        Style::BareBlock => {
+                            self.check_bare_block(comment_group, &source_text, context)
+                        }
+                        Style::SeparateLines => {
+                            self.check_separate_lines(comment_group, &source_text, context)
+                        }
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_multiline_comment_style_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            multiline_comment_style_rule(),
+            rule_tests! {
+                valid => [
+                    "
+                        /*
+                         * this is
+                         * a comment
+                         */
+                    ",
+                    "
+                        /**
+                         * this is
+                         * a JSDoc comment
+                         */
+                    ",
+                    "
+                        /* eslint semi: [
+                          \"error\"
+                        ] */
+                    ",
+                    "
+                        // this is a single-line comment
+                    ",
+                    "
+                        /* foo */
+                    ",
+                    "
+                        // this is a comment
+                        foo();
+                        // this is another comment
+                    ",
+                    "
+                        foo(/* this is an
+                            inline comment */);
+                    ",
+                    "
+                        // this comment contains */
+                        // and so it can't be a block comment
+                    ",
+                    { code => "// foo\n// bar", options => ["separate-lines"] },
+                    { code => "/* foo */", options => ["separate-lines"] },
+                    { code => "/**\n * JSDoc\n */", options => ["separate-lines"] },
+                    { code => "/* foo\n   bar */ baz();", options => ["separate-lines"] },
+                    { code => "/* foo\n   bar */", options => ["bare-block"] },
+                    { code => "/**\n * JSDoc\n */", options => ["bare-block"] },
+                    { code => "// foo", options => ["bare-block"] }
+                ],
+                invalid => [
+                    {
+                        code => "// foo\n// bar",
+                        output => "/*\n * foo\n * bar\n */",
+                        errors => [{ message_id => "expected_block", line => 1, column => 1, end_line => 2, end_column => 7 }]
+                    },
+                    {
+                        code => "    // foo\n    // bar",
+                        output => "    /*\n     * foo\n     * bar\n     */",
+                        errors => [{ message_id => "expected_block", line => 1 }]
+                    },
+                    {
+                        code => "//foo\n//bar",
+                        output => "/*\n * foo\n * bar\n */",
+                        errors => [{ message_id => "expected_block", line => 1 }]
+                    },
+                    {
+                        code => "// foo\n//  bar",
+                        output => "/*\n * foo\n *  bar\n */",
+                        errors => [{ message_id => "expected_block", line => 1 }]
+                    },
+                    {
+                        code => "/* foo\n * bar\n */",
+                        output => "/*\n * foo\n * bar\n */",
+                        errors => [{ message_id => "start_newline", line => 1, column => 1, end_column => 3 }]
+                    },
+                    {
+                        code => "/*\n * foo\n * bar */",
+                        output => "/*\n * foo\n * bar \n */",
+                        errors => [{ message_id => "end_newline", line => 3 }]
+                    },
+                    {
+                        code => "/*\n   * foo\n */",
+                        output => "/*\n * foo\n */",
+                        errors => [{ message_id => "alignment", line => 2, column => 1 }]
+                    },
+                    {
+                        code => "/*\n * foo\n   bar\n */",
+                        output => "/*\n * foo\n * bar\n */",
+                        errors => [{ message_id => "missing_star", line => 3, column => 1 }]
+                    },
+
+                    // bare-block
+                    {
+                        code => "// foo\n// bar",
+                        output => "/* foo\n   bar */",
+                        options => ["bare-block"],
+                        errors => [{ message_id => "expected_block", line => 1 }]
+                    },
+                    {
+                        code => "/*\n * foo\n * bar\n */",
+                        output => "/* foo\n   bar */",
+                        options => ["bare-block"],
+                        errors => [{ message_id => "expected_bare_block", line => 1, column => 1, end_column => 3 }]
+                    },
+
+                    // separate-lines
+                    {
+                        code => "/* foo\n   bar */",
+                        output => "// foo\n// bar ",
+                        options => ["separate-lines"],
+                        errors => [{ message_id => "expected_lines", line => 1, column => 1, end_column => 3 }]
+                    },
+                    {
+                        code => "/*\n * foo\n * bar\n */",
+                        output => "// foo\n// bar",
+                        options => ["separate-lines"],
+                        errors => [{ message_id => "expected_lines", line => 1 }]
+                    },
+                    {
+                        code => "    /*\n     * foo\n     * bar\n     */",
+                        output => "    // foo\n    // bar",
+                        options => ["separate-lines"],
+                        errors => [{ message_id => "expected_lines", line => 1 }]
+                    },
+                    {
+                        code => "/**\n * foo\n */",
+                        output => "// foo",
+                        options => ["separate-lines", { check_js_doc => true }],
+                        errors => [{ message_id => "expected_lines", line => 1 }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,461 @@
+use std::{borrow::Cow, collections::HashSet, sync::Arc};
+
+use regex::Regex;
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::{is_tagged_template_expression, skip_parenthesized_expressions},
+    kind::{CallExpression, Identifier, MemberExpression, SubscriptExpression},
+    utils::ast_utils,
+};
+
+const CAPS_ALLOWED: [&str; 11] = [
+    "Array", "Boolean", "Date", "Error", "Function", "Number", "Object", "RegExp", "String",
+    "Symbol", "BigInt",
+];
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct Options {
+    new_is_cap: bool,
+    cap_is_new: bool,
+    new_is_cap_exceptions: Vec<String>,
+    #[serde(with = "serde_regex")]
+    new_is_cap_exception_pattern: Option<Regex>,
+    cap_is_new_exceptions: Vec<String>,
+    #[serde(with = "serde_regex")]
+    cap_is_new_exception_pattern: Option<Regex>,
+    properties: bool,
+}
+
+impl Options {
+    fn cap_is_new_exceptions(&self) -> HashSet<String> {
+        self.cap_is_new_exceptions
+            .iter()
+            .cloned()
+            .chain(CAPS_ALLOWED.into_iter().map(ToOwned::to_owned))
+            .collect()
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            new_is_cap: true,
+            cap_is_new: true,
+            new_is_cap_exceptions: Default::default(),
+            new_is_cap_exception_pattern: Default::default(),
+            cap_is_new_exceptions: Default::default(),
+            cap_is_new_exception_pattern: Default::default(),
+            properties: true,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Cap {
+    NonAlpha,
+    Lower,
+    Upper,
+}
+
+fn get_cap(str: &str) -> Cap {
+    let Some(first_char) = str.chars().next() else {
+        return Cap::NonAlpha;
+    };
+    let first_char_lower = first_char.to_lowercase().collect::<String>();
+    let first_char_upper = first_char.to_uppercase().collect::<String>();
+
+    if first_char_lower == first_char_upper {
+        return Cap::NonAlpha;
+    }
+    if first_char_lower.chars().eq([first_char]) {
+        return Cap::Lower;
+    }
+    Cap::Upper
+}
+
+fn get_callee(node: Node) -> Node {
+    skip_parenthesized_expressions(node.field(if node.kind() == CallExpression {
+        "function"
+    } else {
+        "constructor"
+    }))
+}
+
+fn extract_name_from_expression<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    let callee = get_callee(node);
+    match callee.kind() {
+        Identifier => Some(callee.text(context)),
+        _ => ast_utils::get_static_property_name(callee, context),
+    }
+    .filter(|name| !name.is_empty())
+}
+
+fn is_member_access(node: Node) -> bool {
+    matches!(node.kind(), MemberExpression | SubscriptExpression)
+}
+
+pub fn new_cap_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "new-cap",
+        languages => [Javascript],
+        messages => [
+            upper => "A function with a name starting with an uppercase letter should only be used as a constructor.",
+            lower => "A constructor name should not start with a lowercase letter.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            new_is_cap: bool = options.new_is_cap,
+            cap_is_new: bool = options.cap_is_new,
+            skip_properties: bool = !options.properties,
+            new_is_cap_exceptions: HashSet<String> = options.new_is_cap_exceptions.iter().cloned().collect(),
+            new_is_cap_exception_pattern: Option<Regex> = options.new_is_cap_exception_pattern.clone(),
+            cap_is_new_exceptions: HashSet<String> = options.cap_is_new_exceptions(),
+            cap_is_new_exception_pattern: Option<Regex> = options.cap_is_new_exception_pattern.clone(),
+        },
+        methods => {
+            fn is_cap_allowed(
+                &self,
+                allowed: &HashSet<String>,
+                node: Node<'a>,
+                callee_name: &str,
+                pattern: Option<&Regex>,
+                context: &QueryMatchContext<'a, '_>,
+            ) -> bool {
+                let callee = get_callee(node);
+                let source_text = callee.text(context);
+
+                if allowed.contains(callee_name) || allowed.contains(&*source_text) {
+                    return true;
+                }
+
+                if pattern.matches(|pattern| pattern.is_match(&source_text)) {
+                    return true;
+                }
+
+                if callee_name == "UTC" && is_member_access(callee) {
+                    let object = callee.field("object");
+                    return object.kind() == Identifier && object.text(context) == "Date";
+                }
+
+                self.skip_properties && is_member_access(callee)
+            }
+
+            fn report(&self, node: Node<'a>, message_id: &'static str, context: &QueryMatchContext<'a, '_>) {
+                let callee = get_callee(node);
+                let callee = match callee.kind() {
+                    MemberExpression => callee.field("property"),
+                    SubscriptExpression => skip_parenthesized_expressions(callee.field("index")),
+                    _ => callee,
+                };
+
+                context.report(violation! {
+                    node => node,
+                    range => callee.range(),
+                    message_id => message_id,
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (new_expression) @c
+            "# => |node, context| {
+                if !self.new_is_cap {
+                    return;
+                }
+
+                let Some(constructor_name) = extract_name_from_expression(node, context) else {
+                    return;
+                };
+                let is_allowed = get_cap(&constructor_name) != Cap::Lower
+                    || self.is_cap_allowed(
+                        &self.new_is_cap_exceptions,
+                        node,
+                        &constructor_name,
+                        self.new_is_cap_exception_pattern.as_ref(),
+                        context,
+                    );
+
+                if !is_allowed {
+                    self.report(node, "lower", context);
+                }
+            },
+            r#"
+              (call_expression) @c
+            "# => |node, context| {
+                if !self.cap_is_new || is_tagged_template_expression(node) {
+                    return;
+                }
+
+                let Some(callee_name) = extract_name_from_expression(node, context) else {
+                    return;
+                };
+                let is_allowed = get_cap(&callee_name) != Cap::Upper
+                    || self.is_cap_allowed(
+                        &self.cap_is_new_exceptions,
+                        node,
+                        &callee_name,
+                        self.cap_is_new_exception_pattern.as_ref(),
+                        context,
+                    );
+
+                if !is_allowed {
+                    self.report(node, "upper", context);
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::NewExpression;
+
+    #[test]
+    fn test_new_cap_rule() {
+        RuleTester::run(
+            new_cap_rule(),
+            rule_tests! {
+                valid => [
+                    "var x = new Constructor();",
+                    "var x = new a.b.Constructor();",
+                    "var x = new a.b['Constructor']();",
+                    "var x = new a.b[Constructor]();",
+                    "var x = new a.b[constructor]();",
+                    "var x = new function(){};",
+                    "var x = new _;",
+                    "var x = new $();",
+                    "var x = new Σ();",
+                    "var x = new _x;",
+                    "var x = new $x();",
+                    "var x = new this;",
+                    "var x = Array(42)",
+                    "var x = Boolean(42)",
+                    "var x = Date(42)",
+                    "var x = Date.UTC(2000, 0)",
+                    "var x = Error('error')",
+                    "var x = Function('return 0')",
+                    "var x = Number(42)",
+                    "var x = Object(null)",
+                    "var x = RegExp(42)",
+                    "var x = String(42)",
+                    "var x = Symbol('symbol')",
+                    "var x = BigInt('1n')",
+                    "var x = _();",
+                    "var x = $();",
+                    { code => "var x = Foo(42)", options => { cap_is_new => false } },
+                    { code => "var o = { 1: function() {} }; o[1]();" },
+                    { code => "var o = { 1: function() {} }; new o[1]();" },
+                    { code => "var x = Foo(42);", options => { cap_is_new => true, cap_is_new_exceptions => ["Foo"] } },
+                    { code => "var x = Foo(42);", options => { cap_is_new_exception_pattern => "^Foo" } },
+                    { code => "var x = new foo(42);", options => { new_is_cap => true, new_is_cap_exceptions => ["foo"] } },
+                    { code => "var x = new foo(42);", options => { new_is_cap_exception_pattern => "^foo" } },
+                    { code => "var x = Object(42);", options => { cap_is_new_exceptions => ["Foo"] } },
+
+                    { code => "var x = Foo.Bar(42);", options => { cap_is_new_exceptions => ["Bar"] } },
+                    { code => "var x = Foo.Bar(42);", options => { cap_is_new_exceptions => ["Foo.Bar"] } },
+
+                    { code => "var x = Foo.Bar(42);", options => { cap_is_new_exception_pattern => "^Foo\\.." } },
+                    { code => "var x = new foo.bar(42);", options => { new_is_cap_exceptions => ["bar"] } },
+                    { code => "var x = new foo.bar(42);", options => { new_is_cap_exceptions => ["foo.bar"] } },
+
+                    { code => "var x = new foo.bar(42);", options => { new_is_cap_exception_pattern => "^foo\\.." } },
+                    { code => "var x = new foo.bar(42);", options => { properties => false } },
+                    { code => "var x = Foo.bar(42);", options => { properties => false } },
+                    { code => "var x = foo.Bar(42);", options => { cap_is_new => false, properties => false } },
+
+                    // Optional chaining
+                    {
+                        code => "foo?.bar();",
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "(foo?.bar)();",
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "new (foo?.Bar)();",
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "(foo?.Bar)();",
+                        options => { properties => false },
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "new (foo?.bar)();",
+                        options => { properties => false },
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "Date?.UTC();",
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "(Date?.UTC)();",
+                        environment => { ecma_version => 2020 }
+                    },
+
+                    // Tagged templates aren't calls
+                    {
+                        code => "Foo`bar`",
+                        environment => { ecma_version => 6 }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "var x = new c();",
+                        errors => [{ message_id => "lower", type => NewExpression }]
+                    },
+                    {
+                        code => "var x = new φ;",
+                        errors => [{ message_id => "lower", type => NewExpression }]
+                    },
+                    {
+                        code => "var x = new a.b.c;",
+                        errors => [{ message_id => "lower", type => NewExpression }]
+                    },
+                    {
+                        code => "var x = new a.b['c'];",
+                        errors => [{ message_id => "lower", type => NewExpression }]
+                    },
+                    {
+                        code => "var b = Foo();",
+                        errors => [{ message_id => "upper", type => CallExpression }]
+                    },
+                    {
+                        code => "var b = a.Foo();",
+                        errors => [{ message_id => "upper", type => CallExpression }]
+                    },
+                    {
+                        code => "var b = a['Foo']();",
+                        errors => [{ message_id => "upper", type => CallExpression }]
+                    },
+                    {
+                        code => "var b = a.Date.UTC();",
+                        errors => [{ message_id => "upper", type => CallExpression }]
+                    },
+                    {
+                        code => "var b = UTC();",
+                        errors => [{ message_id => "upper", type => CallExpression }]
+                    },
+                    {
+                        code => "var a = B.C();",
+                        errors => [
+                            {
+                                message_id => "upper",
+                                type => CallExpression,
+                                line => 1,
+                                column => 11
+                            }
+                        ]
+                    },
+                    {
+                        code => "var a = B\n.C();",
+                        errors => [
+                            {
+                                message_id => "upper",
+                                type => CallExpression,
+                                line => 2,
+                                column => 2
+                            }
+                        ]
+                    },
+                    {
+                        code => "var a = new B.c();",
+                        errors => [
+                            {
+                                message_id => "lower",
+                                type => NewExpression,
+                                line => 1,
+                                column => 15
+                            }
+                        ]
+                    },
+                    {
+                        code => "var a = new B.\nc();",
+                        errors => [
+                            {
+                                message_id => "lower",
+                                type => NewExpression,
+                                line => 2,
+                                column => 1
+                            }
+                        ]
+                    },
+                    {
+                        code => "var a = new c();",
+                        errors => [
+                            {
+                                message_id => "lower",
+                                type => NewExpression,
+                                line => 1,
+                                column => 13
+                            }
+                        ]
+                    },
+                    {
+                        code => "var a = new b[`foo`];",
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            {
+                                message_id => "lower",
+                                type => NewExpression,
+                                line => 1,
+                                column => 15
+                            }
+                        ]
+                    },
+
+                    {
+                        code => "var x = Foo.Bar(42);",
+                        options => { cap_is_new_exceptions => ["Foo"] },
+                        errors => [{ type => CallExpression, message_id => "upper" }]
+                    },
+                    {
+                        code => "var x = Bar.Foo(42);",
+                        options => { cap_is_new_exception_pattern => "^Foo\\.." },
+                        errors => [{ type => CallExpression, message_id => "upper" }]
+                    },
+                    {
+                        code => "var x = new foo.bar(42);",
+                        options => { new_is_cap_exceptions => ["foo"] },
+                        errors => [{ type => NewExpression, message_id => "lower" }]
+                    },
+                    {
+                        code => "var x = new bar.foo(42);",
+                        options => { new_is_cap_exception_pattern => "^foo\\.." },
+                        errors => [{ type => NewExpression, message_id => "lower" }]
+                    },
+
+                    // Optional chaining
+                    {
+                        code => "new (foo?.bar)();",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "lower", column => 11, end_column => 14 }]
+                    },
+                    {
+                        code => "foo?.Bar();",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "upper", column => 6, end_column => 9 }]
+                    },
+                    {
+                        code => "(foo?.Bar)();",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "upper", column => 7, end_column => 10 }]
+                    }
+                ]
+            },
+        )
+    }
+}
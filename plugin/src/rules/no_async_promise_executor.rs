@@ -2,39 +2,25 @@ use std::sync::Arc;
 
 use tree_sitter_lint::{rule, violation, Rule};
 
+use crate::ast_helpers::is_promise_executor;
+
 pub fn no_async_promise_executor_rule() -> Arc<dyn Rule> {
     rule! {
         name => "no-async-promise-executor",
         languages => [Javascript],
         listeners => [
-            r#"(
-              (new_expression
-                constructor: (identifier) @callee (#eq? @callee "Promise")
-                arguments: (arguments
-                  .
-                  [
-                    (arrow_function
-                      "async" @async_keyword
-                    )
-                    (function
-                      "async" @async_keyword
-                    )
-                    ; ok crazy test case
-                    (parenthesized_expression
-                      (parenthesized_expression
-                        (parenthesized_expression
-                          (parenthesized_expression
-                            (arrow_function
-                              "async" @async_keyword
-                            )
-                          )
-                        )
-                      )
-                    )
-                  ]
-                )
-              )
-            )"# => |captures, context| {
+            r#"
+              (arrow_function
+                "async" @async_keyword
+              ) @function
+              (function
+                "async" @async_keyword
+              ) @function
+            "# => |captures, context| {
+                if !is_promise_executor(captures["function"], context) {
+                    return;
+                }
+
                 context.report(violation! {
                     node => captures["async_keyword"],
                     message => "Promise executor functions should not be async.",
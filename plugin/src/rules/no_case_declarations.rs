@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, Rule};
+
+use crate::kind::{
+    ClassDeclaration, FunctionDeclaration, GeneratorFunctionDeclaration, LexicalDeclaration,
+};
+
+fn is_lexical_declaration(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        FunctionDeclaration | GeneratorFunctionDeclaration | ClassDeclaration | LexicalDeclaration
+    )
+}
+
+pub fn no_case_declarations_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-case-declarations",
+        languages => [Javascript],
+        messages => [
+            add_brackets => "Add {} brackets around the case block.",
+            unexpected => "Unexpected lexical declaration in case block.",
+        ],
+        listeners => [
+            r#"
+              (switch_case) @c
+              (switch_default) @c
+            "# => |node, context| {
+                let mut cursor = node.walk();
+                for statement in node.children_by_field_name("body", &mut cursor) {
+                    if is_lexical_declaration(statement) {
+                        context.report(violation! {
+                            node => statement,
+                            message_id => "unexpected",
+                            // TODO: suggestions?
+                            // suggest: [
+                            //     {
+                            //         messageId: "addBrackets",
+                            //         fix: fixer => [
+                            //             fixer.insertTextBefore(node.consequent[0], "{ "),
+                            //             fixer.insertTextAfter(node.consequent.at(-1), " }")
+                            //         ]
+                            //     }
+                            // ]
+                        });
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_no_case_declarations_rule() {
+        RuleTester::run(
+            no_case_declarations_rule(),
+            rule_tests! {
+                valid => [
+                    {
+                        code => "switch (a) { case 1: { let x = 1; break; } default: { let x = 2; break; } }",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "switch (a) { case 1: { const x = 1; break; } default: { const x = 2; break; } }",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "switch (a) { case 1: { function f() {} break; } default: { function f() {} break; } }",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "switch (a) { case 1: { class C {} break; } default: { class C {} break; } }",
+                        environment => { ecma_version => 6 }
+                    },
+                    "switch (a) { case 1: var x = 1; break; }",
+                    "switch (a) { case 1: x = f(function() { let y; }); }"
+                ],
+                invalid => [
+                    {
+                        code => "switch (a) {\n  case 1:\n    {}\n    function f() {}\n    break;\n}",
+                        // suggestions: [{ message_id => "add_brackets", output => "switch (a) {\n  case 1:\n    { {}\n    function f() {}\n    break; }\n}" }]
+                        errors => [{ message_id => "unexpected", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "switch (a) {\n  case 1:\n  case 2:\n    let x;\n}",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => LexicalDeclaration }]
+                    },
+                    {
+                        code => "switch (a) {\n  case 1:\n    let x;\n  case 2:\n    let y;\n}",
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "unexpected", type => LexicalDeclaration, line => 3 },
+                            { message_id => "unexpected", type => LexicalDeclaration, line => 5 }
+                        ]
+                    },
+                    {
+                        code => "switch (a) {\n  case 1:\n    let x;\n  default:\n    let y;\n}",
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            { message_id => "unexpected", type => LexicalDeclaration, line => 3 },
+                            { message_id => "unexpected", type => LexicalDeclaration, line => 5 }
+                        ]
+                    },
+                    {
+                        code => "switch (a) { case 1: let x = 1; break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => LexicalDeclaration }]
+                    },
+                    {
+                        code => "switch (a) { default: let x = 2; break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => LexicalDeclaration }]
+                    },
+                    {
+                        code => "switch (a) { case 1: const x = 1; break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => LexicalDeclaration }]
+                    },
+                    {
+                        code => "switch (a) { default: const x = 2; break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => LexicalDeclaration }]
+                    },
+                    {
+                        code => "switch (a) { case 1: function f() {} break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "switch (a) { default: function f() {} break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => FunctionDeclaration }]
+                    },
+                    {
+                        code => "switch (a) { case 1: class C {} break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => ClassDeclaration }]
+                    },
+                    {
+                        code => "switch (a) { default: class C {} break; }",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unexpected", type => ClassDeclaration }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+pub fn no_div_regex_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-div-regex",
+        languages => [Javascript],
+        messages => [
+            unexpected => "A regular expression literal can be confused with '/='.",
+        ],
+        fixable => true,
+        listeners => [
+            r#"
+              (regex) @c
+            "# => |node, context| {
+                let pattern_node = node.field("pattern");
+                let pattern = pattern_node.text(context);
+                if !pattern.starts_with('=') {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "unexpected",
+                    fix => |fixer| {
+                        fixer.replace_text(pattern_node, format!("[=]{}", &pattern[1..]));
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::Regex;
+
+    #[test]
+    fn test_no_div_regex_rule() {
+        RuleTester::run(
+            no_div_regex_rule(),
+            rule_tests! {
+                valid => [
+                    "var f = function() { return /foo/ig.test('bar'); };",
+                    "var f = function() { return /\\=foo/; };"
+                ],
+                invalid => [
+                    {
+                        code => "var f = function() { return /=foo/; };",
+                        output => "var f = function() { return /[=]foo/; };",
+                        errors => [{ message_id => "unexpected", type => Regex }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,288 @@
+use std::{collections::HashSet, sync::Arc};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::{get_call_expression_arguments, NodeExtJs},
+    conf::globals::BUILTIN,
+    kind::{
+        Arguments, AssignmentExpression, AugmentedAssignmentExpression, CallExpression,
+        MemberExpression, SubscriptExpression,
+    },
+    scope::ScopeManager,
+    utils::ast_utils,
+};
+
+static DEFINE_PROPERTY_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^definePropert(?:y|ies)$"#).unwrap());
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    exceptions: Vec<String>,
+}
+
+fn is_prototype_property_accessed(identifier_node: Node, context: &QueryMatchContext) -> bool {
+    identifier_node.parent().matches(|parent| {
+        matches!(parent.kind(), MemberExpression | SubscriptExpression)
+            && parent.field("object") == identifier_node
+            && ast_utils::get_static_property_name(parent, context)
+                .matches(|name| name == "prototype")
+    })
+}
+
+fn is_in_prototype_property_assignment<'a>(
+    prototype_node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let member_node = prototype_node.next_non_parentheses_ancestor(context);
+    if !matches!(member_node.kind(), MemberExpression | SubscriptExpression)
+        || member_node.field("object").skip_parentheses() != prototype_node
+    {
+        return None;
+    }
+
+    member_node
+        .maybe_next_non_parentheses_ancestor(context)
+        .filter(|parent| {
+            matches!(
+                parent.kind(),
+                AssignmentExpression | AugmentedAssignmentExpression
+            ) && parent.field("left").skip_parentheses() == member_node
+        })
+}
+
+fn is_in_define_property_call<'a>(
+    prototype_node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let arguments = prototype_node.next_non_parentheses_ancestor(context);
+    if arguments.kind() != Arguments {
+        return None;
+    }
+
+    arguments.parent().filter(|&call_expression| {
+        call_expression.kind() == CallExpression
+            && get_call_expression_arguments(call_expression)
+                .and_then(|mut arguments| arguments.next())
+                .matches(|first_argument| first_argument.skip_parentheses() == prototype_node)
+            && ast_utils::is_specific_member_access(
+                call_expression.field("function").skip_parentheses(),
+                Some("Object"),
+                Some(&*DEFINE_PROPERTY_PATTERN),
+                context,
+            )
+    })
+}
+
+pub fn no_extend_native_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-extend-native",
+        languages => [Javascript],
+        messages => [
+            unexpected => "{{builtin}} prototype is read only, properties should not be added.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            modified_builtins: HashSet<String> = {
+                let exceptions = options.exceptions.iter().collect::<HashSet<_>>();
+                BUILTIN
+                    .keys()
+                    .filter(|builtin| {
+                        builtin.chars().next().matches(|ch| ch.is_uppercase())
+                    })
+                    .map(|builtin| builtin.to_string())
+                    .filter(|builtin| !exceptions.contains(builtin))
+                    .collect()
+            },
+        },
+        listeners => [
+            "program:exit" => |node, context| {
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let global_scope = scope_manager.get_scope(node);
+
+                for builtin in &self.modified_builtins {
+                    let set = global_scope.set();
+                    let Some(builtin_var) = set.get(&**builtin) else {
+                        continue;
+                    };
+
+                    for reference in builtin_var.references() {
+                        let identifier_node = reference.identifier();
+                        if !is_prototype_property_accessed(identifier_node, context) {
+                            continue;
+                        }
+                        let prototype_node = identifier_node.parent().unwrap();
+
+                        let report_node = is_in_prototype_property_assignment(
+                            prototype_node,
+                            context,
+                        )
+                        .or_else(|| is_in_define_property_call(prototype_node, context));
+                        if let Some(report_node) = report_node {
+                            context.report(violation! {
+                                node => report_node,
+                                message_id => "unexpected",
+                                data => {
+                                    builtin => builtin.clone(),
+                                }
+                            });
+                        }
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_no_extend_native_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_extend_native_rule(),
+            rule_tests! {
+                valid => [
+                    "x.prototype.p = 0",
+                    "x.prototype['p'] = function() {}",
+                    "Object.p = 0",
+                    "Object.toString.bind = 0",
+                    "Object['toString'].bind = 0",
+                    "Object.defineProperty(x, 'p', {value: 0})",
+                    "Object.defineProperties(x, {p: {value: 0}})",
+                    "global.Object.prototype.toString = 0",
+                    "this.Object.prototype.toString = 0",
+                    "with(Object) { prototype.p = 0; }",
+                    "o = Object; o.prototype.toString = 0",
+                    "eval('Object.prototype.toString = 0')",
+                    "parseFloat.prototype.x = 1",
+                    {
+                        code => "Object.prototype.g = 0",
+                        options => { exceptions => ["Object"] }
+                    },
+                    "obj[Object.prototype] = 0",
+
+                    // https://github.com/eslint/eslint/issues/4438
+                    "Object.defineProperty()",
+                    "Object.defineProperties()",
+
+                    // https://github.com/eslint/eslint/issues/8461
+                    "function foo() { var Object = function() {}; Object.prototype.p = 0 }",
+                    {
+                        code => "{ let Object = function() {}; Object.prototype.p = 0 }",
+                        environment => { ecma_version => 6 }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "Object.prototype.p = 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Object" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "BigInt.prototype.p = 0",
+                        environment => { env => { es2020 => true } },
+                        errors => [{ message_id => "unexpected", data => { builtin => "BigInt" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "WeakRef.prototype.p = 0",
+                        environment => { env => { es2021 => true } },
+                        errors => [{ message_id => "unexpected", data => { builtin => "WeakRef" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "FinalizationRegistry.prototype.p = 0",
+                        environment => { env => { es2021 => true } },
+                        errors => [{ message_id => "unexpected", data => { builtin => "FinalizationRegistry" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "AggregateError.prototype.p = 0",
+                        environment => { env => { es2021 => true } },
+                        errors => [{ message_id => "unexpected", data => { builtin => "AggregateError" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "Function.prototype['p'] = 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Function" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "String['prototype'].p = 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "String" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "Number['prototype']['p'] = 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Number" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "Object.defineProperty(Array.prototype, 'p', {value: 0})",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Array" }, type => CallExpression }]
+                    },
+                    {
+                        code => "Object.defineProperties(Array.prototype, {p: {value: 0}})",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Array" }, type => CallExpression }]
+                    },
+                    {
+                        code => "Object.defineProperties(Array.prototype, {p: {value: 0}, q: {value: 0}})",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Array" }, type => CallExpression }]
+                    },
+                    {
+                        code => "Number['prototype']['p'] = 0",
+                        options => { exceptions => ["Object"] },
+                        errors => [{ message_id => "unexpected", data => { builtin => "Number" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "Object.prototype.p = 0; Object.prototype.q = 0",
+                        errors => [
+                            { message_id => "unexpected", data => { builtin => "Object" }, type => AssignmentExpression, column => 1 },
+                            { message_id => "unexpected", data => { builtin => "Object" }, type => AssignmentExpression, column => 25 }
+                        ]
+                    },
+                    {
+                        code => "function foo() { Object.prototype.p = 0 }",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Object" }, type => AssignmentExpression }]
+                    },
+
+                    // Optional chaining
+                    {
+                        code => "(Object?.prototype).p = 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Object" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "Object.defineProperty(Object?.prototype, 'p', { value: 0 })",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Object" }, type => CallExpression }]
+                    },
+                    {
+                        code => "Object?.defineProperty(Object.prototype, 'p', { value: 0 })",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Object" }, type => CallExpression }]
+                    },
+                    {
+                        code => "(Object?.defineProperty)(Object.prototype, 'p', { value: 0 })",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Object" }, type => CallExpression }]
+                    },
+
+                    // Logical assignments
+                    {
+                        code => "Array.prototype.p &&= 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Array" }, type => AugmentedAssignmentExpression }]
+                    },
+                    {
+                        code => "Array.prototype.p ||= 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Array" }, type => AugmentedAssignmentExpression }]
+                    },
+                    {
+                        code => "Array.prototype.p ??= 0",
+                        errors => [{ message_id => "unexpected", data => { builtin => "Array" }, type => AugmentedAssignmentExpression }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
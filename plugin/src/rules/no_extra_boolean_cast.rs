@@ -0,0 +1,685 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{get_call_expression_arguments, NodeExtJs},
+    kind::{
+        Arguments, BinaryExpression, CallExpression, DoStatement, ExpressionStatement,
+        ForStatement, Identifier, IfStatement, NewExpression, SequenceExpression, SpreadElement,
+        TernaryExpression, UnaryExpression, WhileStatement,
+    },
+    utils::ast_utils,
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    enforce_for_logical_operands: bool,
+}
+
+fn get_parent<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Node<'a> {
+    let parent = node.next_non_parentheses_ancestor(context);
+    if parent.kind() == ExpressionStatement {
+        let grandparent = parent.parent().unwrap();
+        if grandparent.kind() == ForStatement
+            && grandparent.child_by_field_name("condition") == Some(parent)
+        {
+            return grandparent;
+        }
+    }
+    parent
+}
+
+fn is_boolean_function_or_constructor_call(node: Node, context: &QueryMatchContext) -> bool {
+    let callee = match node.kind() {
+        CallExpression => node.field("function"),
+        NewExpression => node.field("constructor"),
+        _ => return false,
+    };
+    callee.kind() == Identifier && callee.text(context) == "Boolean"
+}
+
+fn is_test(node: Node, parent: Node) -> bool {
+    match parent.kind() {
+        IfStatement | DoStatement | WhileStatement => true,
+        TernaryExpression => parent.field("condition").skip_parentheses() == node,
+        ForStatement => parent
+            .child_by_field_name("condition")
+            .filter(|condition| condition.kind() == ExpressionStatement)
+            .and_then(|condition| {
+                condition
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .next()
+            })
+            .matches(|condition| condition.skip_parentheses() == node),
+        _ => false,
+    }
+}
+
+fn is_in_boolean_context<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let parent = get_parent(node, context);
+    match parent.kind() {
+        Arguments => {
+            is_boolean_function_or_constructor_call(parent.parent().unwrap(), context)
+                && parent
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .next()
+                    .matches(|first_argument| first_argument.skip_parentheses() == node)
+        }
+        UnaryExpression => parent.field("operator").kind() == "!",
+        _ => is_test(node, parent),
+    }
+}
+
+fn is_logical_context(node: Node, enforce_for_logical_operands: bool) -> bool {
+    enforce_for_logical_operands
+        && node.kind() == BinaryExpression
+        && matches!(node.field("operator").kind(), "||" | "&&")
+}
+
+fn is_in_flagged_context<'a>(
+    node: Node<'a>,
+    enforce_for_logical_operands: bool,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if is_in_boolean_context(node, context) {
+        return true;
+    }
+
+    let parent = get_parent(node, context);
+    is_logical_context(parent, enforce_for_logical_operands)
+        // For nested logical statements
+        && is_in_flagged_context(parent, enforce_for_logical_operands, context)
+}
+
+fn has_comments_inside(node: Node, context: &QueryMatchContext) -> bool {
+    context.get_comments_inside(node).next().is_some()
+}
+
+fn needs_parens<'a>(
+    previous_node: Node<'a>,
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if ast_utils::is_parenthesised(previous_node) {
+        // parentheses around the previous node will stay, so there is no need for an additional pair
+        return false;
+    }
+
+    // parent of the previous node will become parent of the replacement node
+    let parent = get_parent(previous_node, context);
+    match parent.kind() {
+        Arguments => node.kind() == SequenceExpression,
+        IfStatement | DoStatement | WhileStatement | ForStatement => false,
+        TernaryExpression => ast_utils::get_precedence(node) <= ast_utils::get_precedence(parent),
+        UnaryExpression => ast_utils::get_precedence(node) < ast_utils::get_precedence(parent),
+        BinaryExpression => {
+            if ast_utils::is_mixed_logical_and_coalesce_expressions(node, parent) {
+                return true;
+            }
+            if parent.field("left").skip_parentheses() == previous_node {
+                return ast_utils::get_precedence(node) < ast_utils::get_precedence(parent);
+            }
+            ast_utils::get_precedence(node) <= ast_utils::get_precedence(parent)
+        }
+        _ => unreachable!("Unexpected parent type: {}", parent.kind()),
+    }
+}
+
+fn get_prefix<'a>(
+    node: Node<'a>,
+    replacement: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> &'static str {
+    let first_replacement_token =
+        context.get_first_token(replacement, Option::<fn(Node) -> bool>::None);
+    if context
+        .maybe_get_token_before(node, Option::<fn(Node) -> bool>::None)
+        .matches(|token_before| {
+            token_before.end_byte() == node.start_byte()
+                && !ast_utils::can_tokens_be_adjacent(
+                    token_before,
+                    first_replacement_token,
+                    context,
+                )
+        })
+    {
+        " "
+    } else {
+        ""
+    }
+}
+
+pub fn no_extra_boolean_cast_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-extra-boolean-cast",
+        languages => [Javascript],
+        messages => [
+            unexpected_call => "Redundant Boolean call.",
+            unexpected_negation => "Redundant double negation.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            enforce_for_logical_operands: bool = options.enforce_for_logical_operands,
+        },
+        listeners => [
+            r#"
+              (unary_expression
+                operator: "!"
+              ) @c
+            "# => |node, context| {
+                let parent = node.next_non_parentheses_ancestor(context);
+                // Exit early if it's guaranteed not to match
+                if parent.kind() != UnaryExpression || parent.field("operator").kind() != "!" {
+                    return;
+                }
+
+                if !is_in_flagged_context(parent, self.enforce_for_logical_operands, context) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => parent,
+                    message_id => "unexpected_negation",
+                    fix => |fixer| {
+                        if has_comments_inside(parent, context) {
+                            return;
+                        }
+
+                        let argument = node.field("argument").skip_parentheses();
+                        if needs_parens(parent, argument, context) {
+                            fixer.replace_text(parent, format!("({})", argument.text(context)));
+                            return;
+                        }
+
+                        fixer.replace_text(
+                            parent,
+                            format!("{}{}", get_prefix(parent, argument, context), argument.text(context)),
+                        );
+                    }
+                });
+            },
+            r#"
+              (call_expression
+                function: (identifier) @callee (#eq? @callee "Boolean")
+              ) @call_expression
+            "# => |captures, context| {
+                let node = captures["call_expression"];
+                let Some(arguments) = get_call_expression_arguments(node) else {
+                    return;
+                };
+                let arguments = arguments.collect::<Vec<_>>();
+
+                if !is_in_flagged_context(node, self.enforce_for_logical_operands, context) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "unexpected_call",
+                    fix => |fixer| {
+                        let parent = node.next_non_parentheses_ancestor(context);
+
+                        match arguments.len() {
+                            0 => {
+                                if parent.kind() == UnaryExpression && parent.field("operator").kind() == "!" {
+                                    // !Boolean() -> true
+
+                                    if has_comments_inside(parent, context) {
+                                        return;
+                                    }
+
+                                    let replacement = "true";
+                                    let prefix = if context
+                                        .maybe_get_token_before(parent, Option::<fn(Node) -> bool>::None)
+                                        .matches(|token_before| {
+                                            token_before.end_byte() == parent.start_byte()
+                                                && !ast_utils::can_tokens_be_adjacent(token_before, replacement, context)
+                                        }) {
+                                        " "
+                                    } else {
+                                        ""
+                                    };
+
+                                    fixer.replace_text(parent, format!("{prefix}{replacement}"));
+                                    return;
+                                }
+
+                                // Boolean() -> false
+                                if has_comments_inside(node, context) {
+                                    return;
+                                }
+
+                                fixer.replace_text(node, "false");
+                            }
+                            1 => {
+                                let argument = arguments[0].skip_parentheses();
+
+                                if arguments[0].kind() == SpreadElement || has_comments_inside(node, context) {
+                                    return;
+                                }
+
+                                if needs_parens(node, argument, context) {
+                                    fixer.replace_text(node, format!("({})", argument.text(context)));
+                                    return;
+                                }
+
+                                fixer.replace_text(node, argument.text(context));
+                            }
+                            // two or more arguments
+                            _ => (),
+                        }
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_no_extra_boolean_cast_rule() {
+        RuleTester::run(
+            no_extra_boolean_cast_rule(),
+            rule_tests! {
+                valid => [
+                    "Boolean(bar, !!baz);",
+                    "var foo = !!bar;",
+                    "function foo() { return !!bar; }",
+                    "var foo = bar() ? !!baz : !!bat",
+                    "for(!!foo;;) {}",
+                    "for(;; !!foo) {}",
+                    "for (; x; !!foo) {}",
+                    "var foo = Boolean(bar);",
+                    "function foo() { return Boolean(bar); }",
+                    "var foo = bar() ? Boolean(baz) : Boolean(bat)",
+                    "for(Boolean(foo);;) {}",
+                    "for(;; Boolean(foo)) {}",
+                    "for (; x; Boolean(foo)) {}",
+                    "if (new Boolean(foo)) {}",
+                    "if ((Boolean(1) && Boolean(1))) {}",
+                    "var x = !!y || z;",
+                    "var x = Boolean(y) || z;",
+                    "var foo = Boolean(...bar);",
+                    "if (Boolean`foo`) {}",
+                    {
+                        code => "var foo = bar || !!baz",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "var foo = bar && !!baz",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "var foo = bar || (baz && !!bat)",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "function foo() { return (!!bar || baz); }",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "var foo = bar() ? (!!baz && bat) : (!!bat && qux)",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "for(!!(foo && bar);;) {}",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "for(;; !!(foo || bar)) {}",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "var foo = Boolean(bar) || baz;",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "var foo = bar || Boolean(baz);",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "if (bar ?? !!baz) {}",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "if (!!bar ?? baz) {}",
+                        options => { enforce_for_logical_operands => true }
+                    },
+                    {
+                        code => "if ((!!foo || bar) && bat) {}",
+                        options => { enforce_for_logical_operands => false }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "if (!!foo) {}",
+                        output => "if (foo) {}",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 5 }]
+                    },
+                    {
+                        code => "do {} while (!!foo)",
+                        output => "do {} while (foo)",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 14 }]
+                    },
+                    {
+                        code => "while (!!foo) {}",
+                        output => "while (foo) {}",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 8 }]
+                    },
+                    {
+                        code => "!!foo ? bar : baz",
+                        output => "foo ? bar : baz",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 1 }]
+                    },
+                    {
+                        code => "for (; !!foo;) {}",
+                        output => "for (; foo;) {}",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 8 }]
+                    },
+                    {
+                        code => "!!!foo",
+                        output => "!foo",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 2 }]
+                    },
+                    {
+                        code => "Boolean(!!foo)",
+                        output => "Boolean(foo)",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 9 }]
+                    },
+                    {
+                        code => "new Boolean(!!foo)",
+                        output => "new Boolean(foo)",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 13 }]
+                    },
+                    {
+                        code => "if (Boolean(foo)) {}",
+                        output => "if (foo) {}",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "do {} while (Boolean(foo))",
+                        output => "do {} while (foo)",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "while (Boolean(foo)) {}",
+                        output => "while (foo) {}",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "Boolean(foo) ? bar : baz",
+                        output => "foo ? bar : baz",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "for (; Boolean(foo);) {}",
+                        output => "for (; foo;) {}",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(foo)",
+                        output => "!foo",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(foo && bar)",
+                        output => "!(foo && bar)",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(foo + bar)",
+                        output => "!(foo + bar)",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(+foo)",
+                        output => "!+foo",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(foo())",
+                        output => "!foo()",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(foo = bar)",
+                        output => "!(foo = bar)",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(...foo);",
+                        output => None,
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean(foo, bar());",
+                        output => None,
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean((foo, bar()));",
+                        output => "!(foo, bar());",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!Boolean();",
+                        output => "true;",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!(Boolean());",
+                        output => "true;",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "if (!Boolean()) { foo() }",
+                        output => "if (true) { foo() }",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "while (!Boolean()) { foo() }",
+                        output => "while (true) { foo() }",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "var foo = Boolean() ? bar() : baz()",
+                        output => "var foo = false ? bar() : baz()",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "if (Boolean()) { foo() }",
+                        output => "if (false) { foo() }",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "while (Boolean()) { foo() }",
+                        output => "while (false) { foo() }",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "void!Boolean()",
+                        output => "void true",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "typeof!Boolean()",
+                        output => "typeof true",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "(!Boolean())",
+                        output => "(true)",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "if (!!(a, b)) {}",
+                        output => "if (a, b) {}",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (Boolean((a, b))) {}",
+                        output => "if (a, b) {}",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!!!(a, b)",
+                        output => "!(a, b)",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "!Boolean(a ? b : c)",
+                        output => "!(a ? b : c)",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "!!!a ? b : c",
+                        output => "!a ? b : c",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (!!(a = b)) {}",
+                        output => "if (a = b) {}",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "Boolean(a = b) ? c : d",
+                        output => "(a = b) ? c : d",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+
+                    // comments
+                    {
+                        code => "if (!/**/!foo) {}",
+                        output => None,
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (!!/**/foo) {}",
+                        output => None,
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (Boolean(/**/foo)) {}",
+                        output => None,
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "if (Boolean(foo/**/)) {}",
+                        output => None,
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "if (/**/!!foo) {}",
+                        output => "if (/**/foo) {}",
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (/**/Boolean(foo)) {}",
+                        output => "if (/**/foo) {}",
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+
+                    // enforceForLogicalOperands
+                    {
+                        code => "if (!!foo || bar) {}",
+                        output => "if (foo || bar) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 5 }]
+                    },
+                    {
+                        code => "if (!!foo && bar) {}",
+                        output => "if (foo && bar) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 5 }]
+                    },
+                    {
+                        code => "if ((!!foo || bar) && bat) {}",
+                        output => "if ((foo || bar) && bat) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 6 }]
+                    },
+                    {
+                        code => "if (foo && !!bar) {}",
+                        output => "if (foo && bar) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 12 }]
+                    },
+                    {
+                        code => "do {} while (!!foo || bar)",
+                        output => "do {} while (foo || bar)",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 14 }]
+                    },
+                    {
+                        code => "!!foo && bat ? bar : baz",
+                        output => "foo && bat ? bar : baz",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 1 }]
+                    },
+                    {
+                        code => "for (; (!!foo || bar);) {}",
+                        output => "for (; (foo || bar);) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 9 }]
+                    },
+                    {
+                        code => "!!!foo || bar",
+                        output => "!foo || bar",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 2 }]
+                    },
+                    {
+                        code => "Boolean(!!foo || bar)",
+                        output => "Boolean(foo || bar)",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression, column => 9 }]
+                    },
+                    {
+                        code => "if (Boolean(foo) || bar) {}",
+                        output => "if (foo || bar) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    },
+                    {
+                        code => "if (x || !!(a ?? b)) {}",
+                        output => "if (x || (a ?? b)) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (x && !!(a || b)) {}",
+                        output => "if (x && (a || b)) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (!!(a && b) || x) {}",
+                        output => "if (a && b || x) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_negation", type => UnaryExpression }]
+                    },
+                    {
+                        code => "if (x || Boolean(a && b)) {}",
+                        output => "if (x || a && b) {}",
+                        options => { enforce_for_logical_operands => true },
+                        errors => [{ message_id => "unexpected_call", type => CallExpression }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,278 @@
+use std::{ops, sync::Arc};
+
+use serde::Deserialize;
+use squalid::regex;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Point, Range},
+    violation, Rule,
+};
+
+use crate::{all_comments::AllComments, kind::TemplateSubstitution};
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct Options {
+    skip_comments: bool,
+    skip_strings: bool,
+    skip_reg_exps: bool,
+    skip_templates: bool,
+    skip_jsx_text: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            skip_comments: Default::default(),
+            skip_strings: true,
+            skip_reg_exps: Default::default(),
+            skip_templates: Default::default(),
+            skip_jsx_text: Default::default(),
+        }
+    }
+}
+
+fn get_point(source_text: &str, byte: usize) -> Point {
+    let preceding_text = &source_text[..byte];
+    Point {
+        row: preceding_text.matches('\n').count(),
+        column: byte - preceding_text.rfind('\n').map_or(0, |index| index + 1),
+    }
+}
+
+pub fn no_irregular_whitespace_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-irregular-whitespace",
+        languages => [Javascript],
+        messages => [
+            no_irregular_whitespace => "Irregular whitespace not allowed.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            skip_comments: bool = options.skip_comments,
+            skip_strings: bool = options.skip_strings,
+            skip_reg_exps: bool = options.skip_reg_exps,
+            skip_templates: bool = options.skip_templates,
+            skip_jsx_text: bool = options.skip_jsx_text,
+
+            [per-file-run]
+            skipped_ranges: Vec<ops::Range<usize>>,
+        },
+        listeners => [
+            r#"
+              (string) @c
+            "# => |node, context| {
+                if self.skip_strings {
+                    self.skipped_ranges.push(node.start_byte()..node.end_byte());
+                }
+            },
+            r#"
+              (regex) @c
+            "# => |node, context| {
+                if self.skip_reg_exps {
+                    self.skipped_ranges.push(node.start_byte()..node.end_byte());
+                }
+            },
+            r#"
+              (template_string) @c
+            "# => |node, context| {
+                if !self.skip_templates {
+                    return;
+                }
+
+                let mut start = node.start_byte();
+                let mut cursor = node.walk();
+                for substitution in node
+                    .named_children(&mut cursor)
+                    .filter(|child| child.kind() == TemplateSubstitution)
+                {
+                    self.skipped_ranges.push(start..substitution.start_byte());
+                    start = substitution.end_byte();
+                }
+                self.skipped_ranges.push(start..node.end_byte());
+            },
+            r#"
+              (jsx_text) @c
+            "# => |node, context| {
+                if self.skip_jsx_text {
+                    self.skipped_ranges.push(node.start_byte()..node.end_byte());
+                }
+            },
+            "program:exit" => |node, context| {
+                let source_text = context.slice(0..node.end_byte());
+
+                if self.skip_comments {
+                    self.skipped_ranges.extend(
+                        context
+                            .retrieve::<AllComments<'a>>()
+                            .iter()
+                            .map(|comment| comment.start_byte()..comment.end_byte()),
+                    );
+                }
+
+                // A leading BOM isn't part of the source text as far as
+                // ESLint is concerned.
+                let scan_start = if source_text.starts_with('\u{feff}') {
+                    '\u{feff}'.len_utf8()
+                } else {
+                    0
+                };
+
+                for match_ in regex!(
+                    r#"[\f\v\u{85}\u{feff}\u{a0}\u{1680}\u{180e}\u{2000}-\u{200b}\u{202f}\u{205f}\u{3000}]+|[\u{2028}\u{2029}]"#
+                )
+                .find_iter(&source_text[scan_start..])
+                {
+                    let start_byte = scan_start + match_.start();
+                    let end_byte = scan_start + match_.end();
+
+                    if self
+                        .skipped_ranges
+                        .iter()
+                        .any(|range| range.start <= start_byte && end_byte <= range.end)
+                    {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => node,
+                        range => Range {
+                            start_byte,
+                            end_byte,
+                            start_point: get_point(&source_text, start_byte),
+                            end_point: get_point(&source_text, end_byte),
+                        },
+                        message_id => "no_irregular_whitespace",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_no_irregular_whitespace_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_irregular_whitespace_rule(),
+            rule_tests! {
+                valid => [
+                    "'\\u000B';",
+                    "'\\u000C';",
+                    "'\\u0085';",
+                    "'\\u00A0';",
+                    "'\\u180E';",
+                    "'\\ufeff';",
+                    "'\\u2000';",
+                    "'\\u200B';",
+                    "'\\u2028';",
+                    "'\\u2029';",
+                    "'\\u3000';",
+                    "'\u{000B}';",
+                    "'\u{000C}';",
+                    "'\u{0085}';",
+                    "'\u{00A0}';",
+                    "'\u{180E}';",
+                    "'\u{feff}';",
+                    "'\u{2000}';",
+                    "'\u{200B}';",
+                    "'\u{3000}';",
+                    "\u{feff}var foo = 'bar';",
+                    { code => "// \u{000B}", options => { skip_comments => true } },
+                    { code => "// \u{00A0}", options => { skip_comments => true } },
+                    { code => "// \u{3000}", options => { skip_comments => true } },
+                    { code => "/* \u{000B} */", options => { skip_comments => true } },
+                    { code => "/* \u{2028} */", options => { skip_comments => true } },
+                    { code => "/* \u{3000} */", options => { skip_comments => true } },
+                    { code => "/\u{000B}/", options => { skip_reg_exps => true } },
+                    { code => "/\u{00A0}/", options => { skip_reg_exps => true } },
+                    { code => "/\u{3000}/", options => { skip_reg_exps => true } },
+                    { code => "`\u{000B}`", options => { skip_templates => true } },
+                    { code => "`\u{00A0}`", options => { skip_templates => true } },
+                    { code => "`\u{3000}`", options => { skip_templates => true } },
+                    { code => "`\u{3000}${foo}\u{3000}`", options => { skip_templates => true } },
+                    { code => "<div>\u{00A0}</div>;", options => { skip_jsx_text => true } },
+                    { code => "<div>\u{3000}</div>;", options => { skip_jsx_text => true } },
+                    "var foo = 'bar';",
+                    "`foo${bar}baz`"
+                ],
+                invalid => [
+                    {
+                        code => "var any \u{000B} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 9 }]
+                    },
+                    {
+                        code => "var any \u{000C} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 9 }]
+                    },
+                    {
+                        code => "var any \u{00A0} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 9 }]
+                    },
+                    {
+                        code => "var any \u{feff} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 9 }]
+                    },
+                    {
+                        code => "var any \u{2000} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 9 }]
+                    },
+                    {
+                        code => "var any \u{3000} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 9 }]
+                    },
+                    {
+                        code => "var any \u{00A0}\u{00A0} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 9 }]
+                    },
+                    {
+                        code => "var a = 'b',\u{2028}c = 'd';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 13 }]
+                    },
+                    {
+                        code => "var any = 'thing';\nvar other \u{3000} = 'thing';",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 2, column => 11 }]
+                    },
+                    {
+                        code => "var any = '\u{3000}';",
+                        options => { skip_strings => false },
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 12 }]
+                    },
+                    {
+                        code => "// \u{00A0}",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 4 }]
+                    },
+                    {
+                        code => "/* \u{3000} */",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 4 }]
+                    },
+                    {
+                        code => "var any = /\u{3000}/;",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 12 }]
+                    },
+                    {
+                        code => "var any = `\u{3000}`;",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 12 }]
+                    },
+                    {
+                        code => "var any = `${foo \u{3000}}`;",
+                        options => { skip_templates => true },
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 18 }]
+                    },
+                    {
+                        code => "<div>\u{3000}</div>;",
+                        errors => [{ message_id => "no_irregular_whitespace", line => 1, column => 6 }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,406 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, Rule,
+};
+
+use crate::{
+    kind::{Program, StatementBlock, SwitchCase, SwitchDefault},
+    scope::{ScopeManager, ScopeType, VariableType},
+};
+
+fn is_lone_block(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    match parent.kind() {
+        StatementBlock | Program => true,
+        // Don't report blocks in switch cases if the block is the only statement of the case.
+        SwitchCase | SwitchDefault => {
+            let mut cursor = parent.walk();
+            let mut body = parent.children_by_field_name("body", &mut cursor);
+            !(body.next() == Some(node) && body.next().is_none())
+        }
+        _ => false,
+    }
+}
+
+fn has_single_statement(node: Node) -> bool {
+    let mut statements = node.non_comment_named_children(SupportedLanguage::Javascript);
+    statements.next().is_some() && statements.next().is_none()
+}
+
+fn declares_block_scoped_variables<'a>(node: Node<'a>, scope_manager: &ScopeManager<'a>) -> bool {
+    let Some(scope) = scope_manager
+        .acquire(node, None)
+        .filter(|scope| scope.type_() == ScopeType::Block)
+    else {
+        return false;
+    };
+
+    scope.variables().any(|variable| {
+        variable.defs().any(|def| {
+            // function declarations are only block-scoped in strict mode
+            def.type_() != VariableType::FunctionName
+                || scope_manager.acquire(def.node(), None).unwrap().is_strict()
+        })
+    })
+}
+
+pub fn no_lone_blocks_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-lone-blocks",
+        languages => [Javascript],
+        messages => [
+            redundant_block => "Block is redundant.",
+            redundant_nested_block => "Nested block is redundant.",
+        ],
+        listeners => [
+            r#"
+              (statement_block) @c
+            "# => |node, context| {
+                if !is_lone_block(node) {
+                    return;
+                }
+
+                let parent = node.parent().unwrap();
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                if declares_block_scoped_variables(node, scope_manager)
+                    && !(parent.kind() == StatementBlock && has_single_statement(parent))
+                {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => if parent.kind() == StatementBlock {
+                        "redundant_nested_block"
+                    } else {
+                        "redundant_block"
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_no_lone_blocks_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_lone_blocks_rule(),
+            rule_tests! {
+                valid => [
+                    "if (foo) { if (bar) { baz(); } }",
+                    "do { bar(); } while (foo)",
+                    "function foo() { while (bar) { baz() } }",
+
+                    // Block-level bindings
+                    { code => "{ let x = 1; }", environment => { ecma_version => 6 } },
+                    { code => "{ const y = 1; }", environment => { ecma_version => 6 } },
+                    { code => "'use strict'; { function bar() {} }", environment => { ecma_version => 6 } },
+                    { code => "{ function bar() {} }", environment => { ecma_version => 6, source_type => "module" } },
+                    { code => "{ class Bar {} }", environment => { ecma_version => 6 } },
+
+                    { code => "{ {let y = 1;} let x = 1; }", environment => { ecma_version => 6 } },
+                    {
+                        code => r#"
+                          switch (foo) {
+                            case bar: {
+                              baz;
+                            }
+                          }
+                        "#
+                    },
+                    {
+                        code => r#"
+                          switch (foo) {
+                            case bar: {
+                              baz;
+                            }
+                            case qux: {
+                              boop;
+                            }
+                          }
+                        "#
+                    },
+                    {
+                        code => r#"
+                          switch (foo) {
+                            case bar:
+                            {
+                              baz;
+                            }
+                          }
+                        "#
+                    },
+                    { code => "function foo() { { const x = 4 } const x = 3 }", environment => { ecma_version => 6 } },
+
+                    { code => "class C { static {} }", environment => { ecma_version => 2022 } },
+                    { code => "class C { static { foo; } }", environment => { ecma_version => 2022 } },
+                    { code => "class C { static { if (foo) { block; } } }", environment => { ecma_version => 2022 } },
+                    { code => "class C { static { lbl: { block; } } }", environment => { ecma_version => 2022 } },
+                    { code => "class C { static { { let block; } something; } }", environment => { ecma_version => 2022 } },
+                    { code => "class C { static { something; { const block = 1; } } }", environment => { ecma_version => 2022 } },
+                    { code => "class C { static { { function block(){} } something; } }", environment => { ecma_version => 2022 } },
+                    { code => "class C { static { something; { class block {}  } } }", environment => { ecma_version => 2022 } }
+                ],
+                invalid => [
+                    {
+                        code => "{}",
+                        errors => [{
+                            message_id => "redundant_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "{var x = 1;}",
+                        errors => [{
+                            message_id => "redundant_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "foo(); {} a = b;",
+                        errors => [{
+                            message_id => "redundant_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "if (foo) { bar(); {} baz(); }",
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "{ \n{ } }",
+                        errors => [
+                            {
+                                message_id => "redundant_block",
+                                type => StatementBlock,
+                                line => 1
+                            },
+                            {
+                                message_id => "redundant_nested_block",
+                                type => StatementBlock,
+                                line => 2
+                            }
+                        ]
+                    },
+                    {
+                        code => "function foo() { bar(); {} baz(); }",
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "while (foo) { {} }",
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+
+                    // Non-block-level bindings, even in ES6
+                    {
+                        code => "{ function bar() {} }",
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "redundant_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "{var x = 1;}",
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "redundant_block",
+                            type => StatementBlock
+                        }]
+                    },
+
+                    {
+                        code => "{ \n{var x = 1;}\n let y = 2; } {let z = 1;}",
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock,
+                            line => 2
+                        }]
+                    },
+                    {
+                        code => "{ \n{let x = 1;}\n var y = 2; } {let z = 1;}",
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "redundant_block",
+                            type => StatementBlock,
+                            line => 1
+                        }]
+                    },
+                    {
+                        code => "{ \n{var x = 1;}\n var y = 2; }\n {var z = 1;}",
+                        environment => { ecma_version => 6 },
+                        errors => [
+                            {
+                                message_id => "redundant_block",
+                                type => StatementBlock,
+                                line => 1
+                            },
+                            {
+                                message_id => "redundant_nested_block",
+                                type => StatementBlock,
+                                line => 2
+                            },
+                            {
+                                message_id => "redundant_block",
+                                type => StatementBlock,
+                                line => 4
+                            }
+                        ]
+                    },
+                    {
+                        code => r#"
+                          switch (foo) {
+                            case 1:
+                              foo();
+                              {
+                                bar;
+                              }
+                          }
+                        "#,
+                        errors => [{
+                            message_id => "redundant_block",
+                            line => 5,
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => r#"
+                          switch (foo) {
+                            case 1:
+                            {
+                              bar;
+                            }
+                            foo();
+                          }
+                        "#,
+                        errors => [{
+                            message_id => "redundant_block",
+                            line => 4,
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => r#"
+                          function foo () {
+                            {
+                              const x = 4;
+                            }
+                          }
+                        "#,
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            line => 3,
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => r#"
+                          function foo () {
+                            {
+                              var x = 4;
+                            }
+                          }
+                        "#,
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            line => 3,
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { if (foo) { {} } } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { { block; } } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { { block; } something; } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { something; { block; } } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { { let block; } } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { { const block = 1; } } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { { function block() {} } } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { { class block {} } } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    },
+                    {
+                        code => "class C { static { { var block; } something; } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "redundant_nested_block",
+                            type => StatementBlock
+                        }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,363 @@
+use std::sync::Arc;
+
+use squalid::regex;
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+use crate::ast_helpers::{is_bigint_literal, is_binary_literal, is_hex_literal, is_octal_literal};
+
+fn is_base_ten(raw: &str) -> bool {
+    !is_hex_literal(raw)
+        && !is_binary_literal(raw)
+        && !is_octal_literal(raw)
+        && !regex!(r#"^0[0-7]+$"#).is_match(raw)
+}
+
+fn not_base_ten_loses_precision(raw: &str) -> bool {
+    let (digits, bits_per_digit) = if is_binary_literal(raw) {
+        (&raw[2..], 1)
+    } else if is_hex_literal(raw) {
+        (&raw[2..], 4)
+    } else if is_octal_literal(raw) {
+        (&raw[2..], 3)
+    } else {
+        (&raw[1..], 3)
+    };
+
+    let bits = digits
+        .chars()
+        .map(|digit| {
+            format!(
+                "{:0width$b}",
+                digit.to_digit(16).unwrap(),
+                width = bits_per_digit
+            )
+        })
+        .collect::<String>();
+    let bits = bits.trim_start_matches('0');
+    let significant_bits = bits.trim_end_matches('0');
+
+    // A (non-zero) integer is exactly representable as a double iff its
+    // significant bits fit in the 53-bit significand and it doesn't
+    // overflow the exponent range
+    significant_bits.len() > 53 || bits.len() > 1024
+}
+
+fn add_decimal_point_to_number(string_number: &str) -> String {
+    if string_number.is_empty() {
+        return ".".to_owned();
+    }
+    format!("{}.{}", &string_number[..1], &string_number[1..])
+}
+
+fn remove_leading_zeros(number_as_string: &str) -> &str {
+    match number_as_string.trim_start_matches('0') {
+        "" => number_as_string,
+        trimmed => trimmed,
+    }
+}
+
+fn remove_trailing_zeros(number_as_string: &str) -> &str {
+    match number_as_string.trim_end_matches('0') {
+        "" => number_as_string,
+        trimmed => trimmed,
+    }
+}
+
+struct NormalizedNumber {
+    magnitude: i64,
+    coefficient: String,
+}
+
+fn normalize_integer(string_integer: &str) -> NormalizedNumber {
+    let significant_digits = remove_trailing_zeros(remove_leading_zeros(string_integer));
+
+    NormalizedNumber {
+        magnitude: if string_integer.starts_with('0') {
+            string_integer.len() as i64 - 2
+        } else {
+            string_integer.len() as i64 - 1
+        },
+        coefficient: add_decimal_point_to_number(significant_digits),
+    }
+}
+
+fn normalize_float(string_float: &str) -> NormalizedNumber {
+    let trimmed_float = remove_leading_zeros(string_float);
+
+    if let Some(decimal_digits) = trimmed_float.strip_prefix('.') {
+        let significant_digits = remove_leading_zeros(decimal_digits);
+
+        return NormalizedNumber {
+            magnitude: significant_digits.len() as i64 - decimal_digits.len() as i64 - 1,
+            coefficient: add_decimal_point_to_number(significant_digits),
+        };
+    }
+
+    NormalizedNumber {
+        magnitude: trimmed_float.find('.').unwrap() as i64 - 1,
+        coefficient: add_decimal_point_to_number(&trimmed_float.replacen('.', "", 1)),
+    }
+}
+
+fn convert_number_to_scientific_notation(string_number: &str) -> String {
+    let string_number = string_number.replacen('E', "e", 1);
+    let mut split_number = string_number.split('e');
+    let original_coefficient = split_number.next().unwrap();
+    let normalized_number = if string_number.contains('.') {
+        normalize_float(original_coefficient)
+    } else {
+        normalize_integer(original_coefficient)
+    };
+    let magnitude = match split_number.next() {
+        Some(exponent) => exponent.parse::<i64>().unwrap_or_default() + normalized_number.magnitude,
+        None => normalized_number.magnitude,
+    };
+
+    format!("{}e{}", normalized_number.coefficient, magnitude)
+}
+
+fn base_ten_loses_precision(raw: &str, value: f64) -> bool {
+    let normalized_raw_number_as_string = convert_number_to_scientific_notation(raw);
+    let requested_precision = normalized_raw_number_as_string
+        .split('e')
+        .next()
+        .unwrap()
+        .replacen('.', "", 1)
+        .len();
+
+    if requested_precision > 100 {
+        return true;
+    }
+
+    let stored_number = format!("{:.*e}", requested_precision - 1, value);
+    let normalized_stored_number = convert_number_to_scientific_notation(&stored_number);
+
+    normalized_raw_number_as_string != normalized_stored_number
+}
+
+pub fn no_loss_of_precision_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-loss-of-precision",
+        languages => [Javascript],
+        messages => [
+            no_loss_of_precision => "This number literal will lose precision at runtime.",
+        ],
+        listeners => [
+            r#"
+              (number) @c
+            "# => |node, context| {
+                let raw = node.text(context).replace('_', "");
+                if is_bigint_literal(&raw) {
+                    return;
+                }
+
+                let loses_precision = if is_base_ten(&raw) {
+                    let value = raw.parse::<f64>().unwrap();
+                    if value == 0.0 {
+                        return;
+                    }
+                    base_ten_loses_precision(&raw, value)
+                } else {
+                    not_base_ten_loses_precision(&raw)
+                };
+
+                if loses_precision {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "no_loss_of_precision",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::Number;
+
+    #[test]
+    fn test_no_loss_of_precision_rule() {
+        RuleTester::run(
+            no_loss_of_precision_rule(),
+            rule_tests! {
+                valid => [
+                    "var x = 12345",
+                    "var x = 123.456",
+                    "var x = -123.456",
+                    "var x = -123456",
+                    "var x = 123e34",
+                    "var x = 123.0e34",
+                    "var x = 123e-34",
+                    "var x = -123e34",
+                    "var x = -123e-34",
+                    "var x = 12.3e34",
+                    "var x = 12.3e-34",
+                    "var x = -12.3e34",
+                    "var x = -12.3e-34",
+                    "var x = 12300000000000000000000000",
+                    "var x = -12300000000000000000000000",
+                    "var x = 0.00000000000000000000000123",
+                    "var x = -0.00000000000000000000000123",
+                    "var x = 9007199254740991",
+                    "var x = 0",
+                    "var x = 0.0",
+                    "var x = 0.000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                    "var x = -0",
+                    "var x = 123.0000000000000000000000",
+                    "var x = 019.5",
+                    "var x = 0195",
+                    "var x = 0e5",
+
+                    { code => "var x = 12_34_56", environment => { ecma_version => 2021 } },
+                    { code => "var x = 12_3.4_56", environment => { ecma_version => 2021 } },
+                    { code => "var x = -12_3.4_56", environment => { ecma_version => 2021 } },
+                    { code => "var x = -12_34_56", environment => { ecma_version => 2021 } },
+                    { code => "var x = 12_3e3_4", environment => { ecma_version => 2021 } },
+                    { code => "var x = 123.0e3_4", environment => { ecma_version => 2021 } },
+                    { code => "var x = -1_23e-3_4", environment => { ecma_version => 2021 } },
+                    { code => "var x = 12_3.0e-3_4", environment => { ecma_version => 2021 } },
+                    { code => "var x = 12_300_000_000_000_000_000_000_000", environment => { ecma_version => 2021 } },
+                    { code => "var x = -12_300_000_000_000_000_000_000_000", environment => { ecma_version => 2021 } },
+                    { code => "var x = 0.000_000_000_000_000_000_000_001_23", environment => { ecma_version => 2021 } },
+                    { code => "var x = 9_007_199_254_740_991", environment => { ecma_version => 2021 } },
+                    { code => "var x = 0.000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000", environment => { ecma_version => 2021 } },
+
+                    "var x = 0x1FFFFFFFFFFFFF",
+                    "var x = 0X1FFFFFFFFFFFFF",
+                    "var x = 0b11111111111111111111111111111111111111111111111111111",
+                    "var x = 0B11111111111111111111111111111111111111111111111111111",
+                    { code => "var x = 0o377777777777777777", environment => { ecma_version => 6 } },
+                    { code => "var x = 0O377777777777777777", environment => { ecma_version => 6 } },
+                    "var x = 0377777777777777777",
+                    "var x = 0x20000000000000",
+
+                    { code => "var x = 0b1_1111111_1111111111111111111111111111111111111111111", environment => { ecma_version => 2021 } },
+
+                    "var x = 1e308",
+                    "var x = 5e-324",
+
+                    "var x = 9007199254740993n",
+                    "var x = 12345678901234567890n"
+                ],
+                invalid => [
+                    {
+                        code => "var x = 9007199254740993",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 9007199254740.993e3",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 9.007199254740993e15",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = -9007199254740993",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 900719.9254740994",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = -900719.9254740994",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 900719925474099_3",
+                        environment => { ecma_version => 2021 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 90_0719925_4740.9_93e3",
+                        environment => { ecma_version => 2021 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 9.0_0719925_474099_3e15",
+                        environment => { ecma_version => 2021 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = -9_00719_9254_740993",
+                        environment => { ecma_version => 2021 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 900_719.92_54740_994",
+                        environment => { ecma_version => 2021 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = -900_719.92_5474_0994",
+                        environment => { ecma_version => 2021 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 5123000000000000000000000000001",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = -5123000000000000000000000000001",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 1230000000000000000000000.0",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 1.0000000000000000000000123",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 17498005798264095394980017816940970922825355447145699491406164851279623993595007385788105416184430592",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 2e999",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = .1230000000000000000000000",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 0b100000000000000000000000000000000000000000000000000001",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 0B100000000000000000000000000000000000000000000000000001",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 0o400000000000000001",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 0O400000000000000001",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 0400000000000000001",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 0x20000000000001",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    },
+                    {
+                        code => "var x = 0X20000000000001",
+                        errors => [{ message_id => "no_loss_of_precision", type => Number }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,475 @@
+use std::{borrow::Cow, cell::RefCell, mem, sync::Arc};
+
+use regexpp_js::{
+    id_arena::Id, visit_reg_exp_ast, visitor, AllArenas, CodePoint, RegExpParser,
+    ValidatePatternFlags, Wtf16,
+};
+use squalid::{regex, OptionExt};
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::get_call_expression_arguments,
+    kind,
+    scope::ScopeManager,
+    utils::{ast_utils, ast_utils::get_static_string_value},
+};
+
+fn iterate_character_sequence(
+    nodes: &[Id<regexpp_js::Node>],
+    arena: &AllArenas,
+) -> Vec<Vec<CodePoint>> {
+    let mut seqs = vec![];
+    let mut seq = vec![];
+
+    for &node in nodes {
+        match &*arena.node(node) {
+            regexpp_js::Node::Character(character) => {
+                seq.push(character.value);
+            }
+            regexpp_js::Node::CharacterClassRange(character_class_range) => {
+                seq.push(arena.node(character_class_range.min).as_character().value);
+                seqs.push(mem::take(&mut seq));
+                seq.push(arena.node(character_class_range.max).as_character().value);
+            }
+            regexpp_js::Node::CharacterSet(_) => {
+                if !seq.is_empty() {
+                    seqs.push(mem::take(&mut seq));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    if !seq.is_empty() {
+        seqs.push(seq);
+    }
+    seqs
+}
+
+fn is_surrogate_pair(lead: CodePoint, tail: CodePoint) -> bool {
+    (0xd800..=0xdbff).contains(&lead) && (0xdc00..=0xdfff).contains(&tail)
+}
+
+fn is_combining_character(code_point: CodePoint) -> bool {
+    char::from_u32(code_point)
+        .matches(|ch| regex!(r#"^[\p{Mc}\p{Me}\p{Mn}]$"#).is_match(ch.encode_utf8(&mut [0; 4])))
+}
+
+fn is_emoji_modifier(code_point: CodePoint) -> bool {
+    (0x1f3fb..=0x1f3ff).contains(&code_point)
+}
+
+fn is_regional_indicator_symbol(code_point: CodePoint) -> bool {
+    (0x1f1e6..=0x1f1ff).contains(&code_point)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Kind {
+    SurrogatePairWithoutUFlag,
+    CombiningClass,
+    EmojiModifier,
+    RegionalIndicatorSymbol,
+    Zwj,
+}
+
+impl Kind {
+    const ALL: [Self; 5] = [
+        Self::SurrogatePairWithoutUFlag,
+        Self::CombiningClass,
+        Self::EmojiModifier,
+        Self::RegionalIndicatorSymbol,
+        Self::Zwj,
+    ];
+
+    fn message_id(self) -> &'static str {
+        match self {
+            Self::SurrogatePairWithoutUFlag => "surrogate_pair_without_u_flag",
+            Self::CombiningClass => "combining_class",
+            Self::EmojiModifier => "emoji_modifier",
+            Self::RegionalIndicatorSymbol => "regional_indicator_symbol",
+            Self::Zwj => "zwj",
+        }
+    }
+
+    fn has_character_sequence(self, chars: &[CodePoint]) -> bool {
+        match self {
+            Self::SurrogatePairWithoutUFlag => chars
+                .windows(2)
+                .any(|window| is_surrogate_pair(window[0], window[1])),
+            Self::CombiningClass => chars.windows(2).any(|window| {
+                is_combining_character(window[1]) && !is_combining_character(window[0])
+            }),
+            Self::EmojiModifier => chars
+                .windows(2)
+                .any(|window| is_emoji_modifier(window[1]) && !is_emoji_modifier(window[0])),
+            Self::RegionalIndicatorSymbol => chars.windows(2).any(|window| {
+                is_regional_indicator_symbol(window[1]) && is_regional_indicator_symbol(window[0])
+            }),
+            Self::Zwj => chars
+                .windows(3)
+                .any(|window| window[1] == 0x200d && window[0] != 0x200d && window[2] != 0x200d),
+        }
+    }
+}
+
+fn verify<'a>(
+    node: Node<'a>,
+    pattern: &str,
+    flags: Option<&str>,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let arena = AllArenas::default();
+    let mut parser = RegExpParser::new(&arena, None);
+    let pattern_as_wtf16: Wtf16 = pattern.into();
+    let Ok(pattern_node) = parser.parse_pattern(
+        &pattern_as_wtf16,
+        Some(0),
+        Some(pattern_as_wtf16.len()),
+        Some(ValidatePatternFlags {
+            unicode: Some(flags.matches(|flags| flags.contains('u'))),
+            unicode_sets: Some(flags.matches(|flags| flags.contains('v'))),
+        }),
+    ) else {
+        return;
+    };
+
+    struct Handlers<'b> {
+        arena: &'b AllArenas,
+        has: RefCell<Vec<Kind>>,
+    }
+
+    impl<'b> visitor::Handlers for Handlers<'b> {
+        fn on_character_class_enter(&self, cc_node: Id<regexpp_js::Node /*CharacterClass*/>) {
+            let cc_node_ref = self.arena.node(cc_node);
+            for chars in
+                iterate_character_sequence(&cc_node_ref.as_character_class().elements, self.arena)
+            {
+                let mut has = self.has.borrow_mut();
+                for kind in Kind::ALL {
+                    if !has.contains(&kind) && kind.has_character_sequence(&chars) {
+                        has.push(kind);
+                    }
+                }
+            }
+        }
+    }
+
+    let handlers = Handlers {
+        arena: &arena,
+        has: Default::default(),
+    };
+
+    visit_reg_exp_ast(pattern_node, &handlers, &arena);
+
+    let has = handlers.has.borrow();
+    for kind in Kind::ALL {
+        if has.contains(&kind) {
+            context.report(violation! {
+                node => node,
+                message_id => kind.message_id(),
+                // TODO: suggestions?
+                // const suggest = [];
+                //
+                // if (kind === "surrogatePairWithoutUFlag") {
+                //     suggest.push({
+                //         messageId: "suggestUnicodeFlag",
+                //         fix(fixer) {
+                //             return fixer.insertTextAfter(node, "u");
+                //         }
+                //     });
+                // }
+            });
+        }
+    }
+}
+
+pub fn no_misleading_character_class_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-misleading-character-class",
+        languages => [Javascript],
+        messages => [
+            surrogate_pair_without_u_flag => "Unexpected surrogate pair in character class. Use 'u' flag.",
+            combining_class => "Unexpected combined character in character class.",
+            emoji_modifier => "Unexpected modified Emoji in character class.",
+            regional_indicator_symbol => "Unexpected national flag in character class.",
+            zwj => "Unexpected joined character sequence in character class.",
+            suggest_unicode_flag => "Add unicode 'u' flag to regex.",
+        ],
+        listeners => [
+            r#"
+              (regex) @c
+            "# => |node, context| {
+                let pattern = node.field("pattern").text(context);
+                let flags = node.child_by_field_name("flags").map(|flags| flags.text(context));
+
+                verify(node, &pattern, flags.as_deref(), context);
+            },
+            r#"
+              (call_expression
+                function: (identifier) @regexp (#eq? @regexp "RegExp")
+                arguments: (arguments
+                  .
+                  (string) @pattern
+                )
+              ) @call_expression
+              (new_expression
+                constructor: (identifier) @regexp (#eq? @regexp "RegExp")
+                arguments: (arguments
+                  .
+                  (string) @pattern
+                )
+              ) @call_expression
+            "# => |captures, context| {
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let node = captures["call_expression"];
+                let scope = scope_manager.get_scope(node);
+                let reg_exp_var = ast_utils::get_variable_by_name(scope, "RegExp");
+                let shadowed = reg_exp_var.matches(|reg_exp_var| reg_exp_var.defs().next().is_some());
+                if shadowed {
+                    return;
+                }
+
+                let pattern = get_static_string_value(captures["pattern"], context).unwrap();
+                let flags: Option<Cow<'_, str>> = get_call_expression_arguments(node)
+                    .unwrap()
+                    .nth(1)
+                    .filter(|flags| flags.kind() == kind::String)
+                    .and_then(|flags| get_static_string_value(flags, context));
+
+                verify(node, &pattern, flags.as_deref(), context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    #[test]
+    fn test_no_misleading_character_class_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_misleading_character_class_rule(),
+            rule_tests! {
+                valid => [
+                    "var r = /[👍]/u",
+                    "var r = /[\\uD83D\\uDC4D]/u",
+                    "var r = /[\\u{1F44D}]/u",
+                    "var r = /❇\u{FE0F}/",
+                    "var r = /A\u{301}/",
+                    "var r = /[❇]/",
+                    "var r = /👶🏻/",
+                    "var r = /[👶]/u",
+                    "var r = /🇯🇵/",
+                    "var r = /[JP]/",
+                    "var r = /👨\u{200D}👩\u{200D}👦/",
+
+                    // Ignore solo lead/tail surrogate.
+                    "var r = /[\\uD83D]/",
+                    "var r = /[\\uDC4D]/",
+                    "var r = /[\\uD83D]/u",
+                    "var r = /[\\uDC4D]/u",
+
+                    // Ignore solo combining char.
+                    "var r = /[\\u0301]/",
+                    "var r = /[\\uFE0F]/",
+                    "var r = /[\\u0301]/u",
+                    "var r = /[\\uFE0F]/u",
+
+                    // Ignore solo emoji modifier.
+                    "var r = /[\\u{1F3FB}]/u",
+                    "var r = /[🏻]/u",
+
+                    // Ignore solo regional indicator symbol.
+                    "var r = /[🇯]/u",
+                    "var r = /[🇵]/u",
+
+                    // Ignore solo ZWJ.
+                    "var r = /[\\u200D]/",
+                    "var r = /[\\u200D]/u",
+
+                    // don't report and don't crash on invalid regex
+                    "var r = new RegExp('[A\u{301}] [ ');",
+                    "var r = RegExp('{ [A\u{301}]', 'u');",
+                    "var r = new globalThis.RegExp('[A\u{301}] [ ');",
+                    "var r = globalThis.RegExp('{ [A\u{301}]', 'u');",
+
+                    // don't report when the RegExp is shadowed
+                    "function foo() { var RegExp; new RegExp('[👍]'); }"
+                ],
+                invalid => [
+                    // RegExp Literals.
+                    {
+                        code => "var r = /[👍]/",
+                        errors => [{
+                            column => 9,
+                            end_column => 15,
+                            message_id => "surrogate_pair_without_u_flag",
+                            // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = /[👍]/u" }]
+                        }]
+                    },
+                    {
+                        code => "var r = /[\\uD83D\\uDC4D]/",
+                        errors => [{
+                            message_id => "surrogate_pair_without_u_flag",
+                            // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = /[\\uD83D\\uDC4D]/u" }]
+                        }]
+                    },
+                    {
+                        code => "var r = /[A\u{301}]/",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[A\u{301}]/u",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[\\u0041\\u0301]/",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[\\u0041\\u0301]/u",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[\\u{41}\\u{301}]/u",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[❇\u{FE0F}]/",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[❇\u{FE0F}]/u",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[\\u2747\\uFE0F]/",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[\\u2747\\uFE0F]/u",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[\\u{2747}\\u{FE0F}]/u",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = /[👶🏻]/",
+                        errors => [{
+                            message_id => "surrogate_pair_without_u_flag",
+                            // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = /[👶🏻]/u" }]
+                        }]
+                    },
+                    {
+                        code => "var r = /[👶🏻]/u",
+                        errors => [{ message_id => "emoji_modifier" }]
+                    },
+                    {
+                        code => "var r = /[\\uD83D\\uDC76\\uD83C\\uDFFB]/u",
+                        errors => [{ message_id => "emoji_modifier" }]
+                    },
+                    {
+                        code => "var r = /[\\u{1F476}\\u{1F3FB}]/u",
+                        errors => [{ message_id => "emoji_modifier" }]
+                    },
+                    {
+                        code => "var r = /[🇯🇵]/",
+                        errors => [{
+                            message_id => "surrogate_pair_without_u_flag",
+                            // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = /[🇯🇵]/u" }]
+                        }]
+                    },
+                    {
+                        code => "var r = /[🇯🇵]/i",
+                        errors => [{
+                            message_id => "surrogate_pair_without_u_flag",
+                            // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = /[🇯🇵]/iu" }]
+                        }]
+                    },
+                    {
+                        code => "var r = /[🇯🇵]/u",
+                        errors => [{ message_id => "regional_indicator_symbol" }]
+                    },
+                    {
+                        code => "var r = /[\\uD83C\\uDDEF\\uD83C\\uDDF5]/u",
+                        errors => [{ message_id => "regional_indicator_symbol" }]
+                    },
+                    {
+                        code => "var r = /[\\u{1F1EF}\\u{1F1F5}]/u",
+                        errors => [{ message_id => "regional_indicator_symbol" }]
+                    },
+                    {
+                        code => "var r = /[👨\u{200D}👩\u{200D}👦]/",
+                        errors => [
+                            {
+                                message_id => "surrogate_pair_without_u_flag",
+                                // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = /[👨\u{200D}👩\u{200D}👦]/u" }]
+                            },
+                            { message_id => "zwj" }
+                        ]
+                    },
+                    {
+                        code => "var r = /[👨\u{200D}👩\u{200D}👦]/u",
+                        errors => [{ message_id => "zwj" }]
+                    },
+                    {
+                        code => "var r = /[\\uD83D\\uDC68\\u200D\\uD83D\\uDC69\\u200D\\uD83D\\uDC66]/u",
+                        errors => [{ message_id => "zwj" }]
+                    },
+                    {
+                        code => "var r = /[\\u{1F468}\\u{200D}\\u{1F469}\\u{200D}\\u{1F466}]/u",
+                        errors => [{ message_id => "zwj" }]
+                    },
+
+                    // RegExp constructors.
+                    {
+                        code => "var r = new RegExp('[👍]', '')",
+                        errors => [{
+                            column => 9,
+                            end_column => 31,
+                            message_id => "surrogate_pair_without_u_flag",
+                            // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = new RegExp('[👍]', 'u')" }]
+                        }]
+                    },
+                    {
+                        code => "var r = RegExp('[👍]')",
+                        errors => [{
+                            message_id => "surrogate_pair_without_u_flag",
+                            // suggestions: [{ message_id => "suggestUnicodeFlag", output => "var r = RegExp('[👍]', \"u\")" }]
+                        }]
+                    },
+                    {
+                        code => "var r = new RegExp('[A\u{301}]', '')",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = new RegExp('[A\u{301}]', 'u')",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = new RegExp('[\\\\u0041\\\\u0301]', 'u')",
+                        errors => [{ message_id => "combining_class" }]
+                    },
+                    {
+                        code => "var r = new RegExp('[👶🏻]', 'u')",
+                        errors => [{ message_id => "emoji_modifier" }]
+                    },
+                    {
+                        code => "var r = new RegExp('[🇯🇵]', 'u')",
+                        errors => [{ message_id => "regional_indicator_symbol" }]
+                    },
+                    {
+                        code => "var r = new RegExp('[👨\u{200D}👩\u{200D}👦]', 'u')",
+                        errors => [{ message_id => "zwj" }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
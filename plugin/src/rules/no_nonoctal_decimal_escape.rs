@@ -0,0 +1,302 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+use crate::{kind::EscapeSequence, utils::ast_utils};
+
+pub fn no_nonoctal_decimal_escape_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-nonoctal-decimal-escape",
+        languages => [Javascript],
+        messages => [
+            decimal_escape => "Don't use '{{decimal_escape}}' escape sequence.",
+
+            // suggestions
+            refactor => "Replace '{{original}}' with '{{replacement}}'. This maintains the current functionality.",
+            escape_backslash => "Replace '{{original}}' with '{{replacement}}' to include the actual backslash character.",
+        ],
+        listeners => [
+            r#"
+              (string) @c
+            "# => |node, context| {
+                if !ast_utils::has_octal_or_non_octal_decimal_escape_sequence(&node.text(context)) {
+                    return;
+                }
+
+                let mut cursor = node.walk();
+                for escape_sequence in node
+                    .named_children(&mut cursor)
+                    .filter(|child| child.kind() == EscapeSequence)
+                {
+                    let decimal_escape = escape_sequence.text(context);
+                    if !matches!(&*decimal_escape, "\\8" | "\\9") {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => node,
+                        range => escape_sequence.range(),
+                        message_id => "decimal_escape",
+                        data => {
+                            decimal_escape => decimal_escape,
+                        },
+                        // TODO: suggestions?
+                        // const suggest = [];
+                        //
+                        // if (previousEscape === "\\0") {
+                        //
+                        //     /*
+                        //      * Now we have a NULL escape "\0" immediately followed by a decimal escape, e.g.: "\0\8".
+                        //      * Fixing this to "\08" would turn "\0" into a legacy octal escape. To avoid producing
+                        //      * an octal escape while fixing a decimal escape, we provide different suggestions.
+                        //      */
+                        //     suggest.push(
+                        //         createSuggestion( // "\0\8" -> "\u00008"
+                        //             "refactor",
+                        //             [previousEscapeRangeStart, decimalEscapeRangeEnd],
+                        //             `${NULL_CHAR}${decimalEscapeContent}`
+                        //         ),
+                        //         createSuggestion( // "\8" -> "\\8"
+                        //             "escapeBackslash",
+                        //             decimalEscapeRange,
+                        //             `\\${decimalEscape}`
+                        //         )
+                        //     );
+                        // } else {
+                        //     suggest.push(
+                        //         createSuggestion( // "\8" -> "8"
+                        //             "refactor",
+                        //             decimalEscapeRange,
+                        //             decimalEscapeContent
+                        //         ),
+                        //         createSuggestion( // "\8" -> "\\8"
+                        //             "escapeBackslash",
+                        //             decimalEscapeRange,
+                        //             `\\${decimalEscape}`
+                        //         )
+                        //     );
+                        // }
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind;
+
+    #[test]
+    fn test_no_nonoctal_decimal_escape_rule() {
+        RuleTester::run(
+            no_nonoctal_decimal_escape_rule(),
+            rule_tests! {
+                valid => [
+                    "8",
+                    "var \\u8888",
+                    "/\\8/",
+                    "''",
+                    "'foo'",
+                    "'8'",
+                    "'9'",
+                    "'foo8'",
+                    "'foo9bar'",
+                    "'\\ '",
+                    "'\\\\'",
+                    "'\\a'",
+                    "'\\n'",
+                    "'\\0'",
+                    "'\\1'",
+                    "'\\7'",
+                    "'\\01'",
+                    "'\\08'",
+                    "'\\19'",
+                    "'\\t9'",
+                    "'\\👍8'",
+                    "'\\\\8'",
+                    "'\\\\9'",
+                    "'\\\\8\\\\9'",
+                    "'\\\\ \\\\8'",
+                    "'\\\\\\\\9'",
+                    "'\\\\9bar'",
+                    "'a\\\\8'",
+                    "'foo\\\\8'",
+                    "'foo\\\\9'",
+                    "'foo\\\\8bar'",
+                    "'foo\\\\9bar'",
+                    "'\\\\ '",
+                    "'\\\\\\\\'",
+                    "'\\\\\\\\\\\\'",
+                    "'\\\\\\\\\\\\\\\\'",
+                    "'\\\\0'",
+                    "'\\\\7'",
+                    "'\\\\8\\\\8'",
+                    "'\\u0038'",
+                    "'\\u0039'",
+                    "'\\x38'",
+                    "'\\x39'"
+                ],
+                invalid => [
+                    {
+                        code => "'\\8'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\8" },
+                            type => kind::String,
+                            line => 1,
+                            column => 2,
+                            end_column => 4,
+                            // suggestions: [
+                            //     { message_id => "refactor", data => { original => "\\8", replacement => "8" }, output => "'8'" },
+                            //     { message_id => "escape_backslash", data => { original => "\\8", replacement => "\\\\8" }, output => "'\\\\8'" }
+                            // ]
+                        }]
+                    },
+                    {
+                        code => "'\\9'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\9" },
+                            type => kind::String,
+                            line => 1,
+                            column => 2,
+                            end_column => 4,
+                            // suggestions: [
+                            //     { message_id => "refactor", data => { original => "\\9", replacement => "9" }, output => "'9'" },
+                            //     { message_id => "escape_backslash", data => { original => "\\9", replacement => "\\\\9" }, output => "'\\\\9'" }
+                            // ]
+                        }]
+                    },
+                    {
+                        code => "\"\\8\"",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\8" },
+                            type => kind::String,
+                            line => 1,
+                            column => 2,
+                            end_column => 4
+                        }]
+                    },
+                    {
+                        code => "'f\\9'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\9" },
+                            type => kind::String,
+                            line => 1,
+                            column => 3,
+                            end_column => 5
+                        }]
+                    },
+                    {
+                        code => "'fo\\9'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\9" },
+                            column => 4,
+                            end_column => 6
+                        }]
+                    },
+                    {
+                        code => "'foo\\9'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\9" },
+                            column => 5,
+                            end_column => 7
+                        }]
+                    },
+                    {
+                        code => "'foo\\8bar'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\8" },
+                            column => 5,
+                            end_column => 7
+                        }]
+                    },
+                    {
+                        code => "'\\ \\8'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\8" },
+                            column => 4,
+                            end_column => 6
+                        }]
+                    },
+                    {
+                        code => "'\\\\\\9'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\9" },
+                            column => 4,
+                            end_column => 6
+                        }]
+                    },
+                    {
+                        code => "'\\\\\\\\\\9'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\9" },
+                            column => 6,
+                            end_column => 8
+                        }]
+                    },
+                    {
+                        code => "'\\0\\8'",
+                        errors => [{
+                            message_id => "decimal_escape",
+                            data => { decimal_escape => "\\8" },
+                            column => 4,
+                            end_column => 6,
+                            // suggestions: [
+                            //     { message_id => "refactor", data => { original => "\\0\\8", replacement => "\\u00008" }, output => "'\\u00008'" },
+                            //     { message_id => "refactor", data => { original => "\\8", replacement => "\\u0038" }, output => "'\\0\\u0038'" },
+                            //     { message_id => "escape_backslash", data => { original => "\\8", replacement => "\\\\8" }, output => "'\\0\\\\8'" }
+                            // ]
+                        }]
+                    },
+                    {
+                        code => "'\\8\\9'",
+                        errors => [
+                            {
+                                message_id => "decimal_escape",
+                                data => { decimal_escape => "\\8" },
+                                column => 2,
+                                end_column => 4
+                            },
+                            {
+                                message_id => "decimal_escape",
+                                data => { decimal_escape => "\\9" },
+                                column => 4,
+                                end_column => 6
+                            }
+                        ]
+                    },
+                    {
+                        code => "'\\8', '\\9'",
+                        errors => [
+                            {
+                                message_id => "decimal_escape",
+                                data => { decimal_escape => "\\8" },
+                                column => 2,
+                                end_column => 4
+                            },
+                            {
+                                message_id => "decimal_escape",
+                                data => { decimal_escape => "\\9" },
+                                column => 8,
+                                end_column => 10
+                            }
+                        ]
+                    }
+                ]
+            },
+        )
+    }
+}
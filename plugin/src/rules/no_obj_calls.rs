@@ -0,0 +1,330 @@
+use std::{borrow::Cow, sync::Arc};
+
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::NodeExtJs,
+    kind::{CallExpression, MemberExpression, SubscriptExpression},
+    scope::ScopeManager,
+    utils::{
+        ast_utils,
+        eslint_utils::{ReferenceTracker, TraceMap},
+    },
+};
+
+const NON_CALLABLE_GLOBALS: [&str; 5] = ["Atomics", "JSON", "Math", "Reflect", "Intl"];
+
+fn get_report_node_name<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    match node.kind() {
+        MemberExpression | SubscriptExpression => {
+            ast_utils::get_static_property_name(node, context).unwrap_or_else(|| node.text(context))
+        }
+        _ => node.text(context),
+    }
+}
+
+pub fn no_obj_calls_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-obj-calls",
+        languages => [Javascript],
+        messages => [
+            unexpected_call => "'{{name}}' is not a function.",
+            unexpected_ref_call => "'{{name}}' is reference to '{{ref}}', which is not a function.",
+        ],
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let scope = scope_manager.get_scope(node);
+                let mut tracker = ReferenceTracker::new(scope);
+                let trace_map = TraceMap {
+                    children: NON_CALLABLE_GLOBALS
+                        .into_iter()
+                        .map(|global_name| {
+                            (
+                                global_name.to_owned(),
+                                TraceMap {
+                                    call: Some(()),
+                                    construct: Some(()),
+                                    ..Default::default()
+                                },
+                            )
+                        })
+                        .collect(),
+                    ..Default::default()
+                };
+
+                for reference in tracker.iterate_global_references(&trace_map, context) {
+                    let ref_node = reference.node;
+                    let callee = if ref_node.kind() == CallExpression {
+                        ref_node.field("function")
+                    } else {
+                        ref_node.field("constructor")
+                    }
+                    .skip_parentheses();
+                    let name = get_report_node_name(callee, context);
+                    let ref_ = &reference.path[0];
+
+                    context.report(violation! {
+                        node => ref_node,
+                        message_id => if name == *ref_ {
+                            "unexpected_call"
+                        } else {
+                            "unexpected_ref_call"
+                        },
+                        data => {
+                            name => name,
+                            ref => ref_,
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::NewExpression};
+
+    #[test]
+    fn test_no_obj_calls_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_obj_calls_rule(),
+            rule_tests! {
+                valid => [
+                    "globalThis[foo]();",
+                    "new globalThis[foo]();",
+                    "var x = Math;",
+                    "var x = Math.random();",
+                    "var x = Math.PI;",
+                    "var x = foo.Math();",
+                    "var x = new foo.Math();",
+                    "var x = new Math.foo;",
+                    "var x = new Math.foo.bar;",
+                    "var x = new Math.foo.bar();",
+                    "JSON.parse(foo)",
+                    "new JSON.parse",
+                    "Reflect.get(foo, 'x')",
+                    "new Reflect.foo(a, b)",
+                    "Atomics.load(foo, 0)",
+                    "new Atomics.foo()",
+                    {
+                        code => "new Intl.Segmenter()",
+                        environment => { env => { browser => true } }
+                    },
+                    {
+                        code => "Intl.foo()",
+                        environment => { env => { browser => true } }
+                    },
+                    {
+                        code => "globalThis.Math();",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "var x = globalThis.Math();",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "f(globalThis.Math());",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "var x = globalThis.JSON();",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "var x = globalThis.Atomics();",
+                        environment => { ecma_version => 2017 }
+                    },
+
+                    // non-existing variables
+                    "/*globals Math: off*/ Math();",
+                    "/*globals Math: off*/ new Math();",
+                    {
+                        code => "JSON();",
+                        environment => { ecma_version => 3 }
+                    },
+                    {
+                        code => "Reflect();",
+                        environment => { ecma_version => 5 }
+                    },
+                    {
+                        code => "Atomics();",
+                        environment => { ecma_version => 2016 }
+                    },
+                    "Intl()",
+
+                    // shadowed variables
+                    "var Math; Math();",
+                    "var Math; new Math();",
+                    "let JSON; JSON();",
+                    "let JSON; new JSON();",
+                    "if (foo) { const Reflect = 1; Reflect(); }",
+                    "if (foo) { const Reflect = 1; new Reflect(); }",
+                    "function foo(Math) { Math(); }",
+                    "function foo(JSON) { new JSON(); }",
+                    "function foo(Atomics) { Atomics(); }",
+                    "function foo() { if (bar) { let Atomics; if (baz) { new Atomics(); } } }",
+                    "function foo() { var JSON; JSON(); }",
+                    "function foo() { var Atomics = bar(); var baz = Atomics(5); }",
+                    "var construct = typeof Reflect !== \"undefined\" ? Reflect.construct : undefined; construct();",
+                    {
+                        code => "function foo(Intl) { Intl(); }",
+                        environment => { env => { browser => true } }
+                    },
+                    {
+                        code => "if (foo) { const Intl = 1; Intl(); }",
+                        environment => { env => { browser => true } }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "Math();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = Math();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression }]
+                    },
+                    {
+                        code => "f(Math());",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression, column => 3, end_column => 9 }]
+                    },
+                    {
+                        code => "Math().foo;",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression, column => 1, end_column => 7 }]
+                    },
+                    {
+                        code => "new Math;",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => NewExpression }]
+                    },
+                    {
+                        code => "new Math();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => NewExpression }]
+                    },
+                    {
+                        code => "new Math(foo);",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => NewExpression }]
+                    },
+                    {
+                        code => "new Math().foo;",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => NewExpression }]
+                    },
+                    {
+                        code => "(new Math).foo();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => NewExpression }]
+                    },
+                    {
+                        code => "var x = JSON();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "JSON", ref => "JSON" }, type => CallExpression }]
+                    },
+                    {
+                        code => "x = JSON(str);",
+                        errors => [{ message_id => "unexpected_call", data => { name => "JSON", ref => "JSON" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = new JSON();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "JSON", ref => "JSON" }, type => NewExpression }]
+                    },
+                    {
+                        code => "Math( JSON() );",
+                        errors => [
+                            { message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression, column => 1, end_column => 15 },
+                            { message_id => "unexpected_call", data => { name => "JSON", ref => "JSON" }, type => CallExpression, column => 7, end_column => 13 }
+                        ]
+                    },
+                    {
+                        code => "var x = Reflect();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Reflect", ref => "Reflect" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = new Reflect();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Reflect", ref => "Reflect" }, type => NewExpression }]
+                    },
+                    {
+                        code => "var x = Atomics();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Atomics", ref => "Atomics" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = new Atomics();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Atomics", ref => "Atomics" }, type => NewExpression }]
+                    },
+                    {
+                        code => "var x = Intl();",
+                        environment => { env => { browser => true } },
+                        errors => [{ message_id => "unexpected_call", data => { name => "Intl", ref => "Intl" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = new Intl();",
+                        environment => { env => { browser => true } },
+                        errors => [{ message_id => "unexpected_call", data => { name => "Intl", ref => "Intl" }, type => NewExpression }]
+                    },
+                    {
+                        code => "var x = globalThis.Math();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = new globalThis.Math();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => NewExpression }]
+                    },
+                    {
+                        code => "f(globalThis.Math());",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression, column => 3, end_column => 20 }]
+                    },
+                    {
+                        code => "var x = globalThis?.Reflect();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Reflect", ref => "Reflect" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = (globalThis?.Reflect)();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Reflect", ref => "Reflect" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = window.Atomics();",
+                        environment => { env => { browser => true } },
+                        errors => [{ message_id => "unexpected_call", data => { name => "Atomics", ref => "Atomics" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var x = self['Math']();",
+                        environment => { env => { browser => true } },
+                        errors => [{ message_id => "unexpected_call", data => { name => "Math", ref => "Math" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var foo = bar ? baz : JSON; foo();",
+                        errors => [{ message_id => "unexpected_ref_call", data => { name => "foo", ref => "JSON" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var foo = bar ? baz : JSON; new foo();",
+                        errors => [{ message_id => "unexpected_ref_call", data => { name => "foo", ref => "JSON" }, type => NewExpression }]
+                    },
+                    {
+                        code => "var foo = window.Atomics; foo();",
+                        environment => { env => { browser => true } },
+                        errors => [{ message_id => "unexpected_ref_call", data => { name => "foo", ref => "Atomics" }, type => CallExpression }]
+                    },
+                    {
+                        code => "var foo = window.Atomics; new foo;",
+                        environment => { env => { browser => true } },
+                        errors => [{ message_id => "unexpected_ref_call", data => { name => "foo", ref => "Atomics" }, type => NewExpression }]
+                    },
+                    {
+                        code => "var { Math: m } = globalThis; m();",
+                        errors => [{ message_id => "unexpected_ref_call", data => { name => "m", ref => "Math" }, type => CallExpression }]
+                    },
+
+                    // Optional chaining
+                    {
+                        code => "var x = globalThis?.Reflect?.();",
+                        errors => [{ message_id => "unexpected_call", data => { name => "Reflect", ref => "Reflect" }, type => CallExpression }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,333 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{is_promise_executor, NodeExtJs},
+    kind::{ArrowFunction, Function, StatementBlock, UnaryExpression},
+    utils::ast_utils,
+    CodePathAnalyzer,
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_void: bool,
+}
+
+fn is_promise_executor_to_check<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    matches!(node.kind(), ArrowFunction | Function)
+        && is_promise_executor(node, context)
+        && ast_utils::is_global_reference(
+            node.next_non_parentheses_ancestor(context)
+                .parent()
+                .unwrap()
+                .field("constructor"),
+            context,
+        )
+}
+
+fn expression_is_void(node: Node) -> bool {
+    let node = node.skip_parentheses();
+    node.kind() == UnaryExpression && node.field("operator").kind() == "void"
+}
+
+pub fn no_promise_executor_return_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-promise-executor-return",
+        languages => [Javascript],
+        messages => [
+            returns_value => "Return values from promise executor functions cannot be read.",
+
+            // arrow and function suggestions
+            prepend_void => "Prepend `void` to the expression.",
+
+            // only arrow suggestions
+            wrap_braces => "Wrap the expression in `{}`.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_void: bool = options.allow_void,
+        },
+        listeners => [
+            r#"
+              (arrow_function
+                body: (_) @body
+              ) @arrow_function
+            "# => |captures, context| {
+                let body = captures["body"];
+                if body.kind() == StatementBlock
+                    || !is_promise_executor_to_check(captures["arrow_function"], context)
+                {
+                    return;
+                }
+
+                if self.allow_void && expression_is_void(body) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => body.skip_parentheses(),
+                    message_id => "returns_value",
+                    // TODO: suggestions?
+                    // const suggest = [];
+                    //
+                    // // prevent useless refactors
+                    // if (allowVoid) {
+                    //     suggest.push({
+                    //         messageId: "prependVoid",
+                    //         fix(fixer) {
+                    //             return voidPrependFixer(sourceCode, node.body, fixer);
+                    //         }
+                    //     });
+                    // }
+                    //
+                    // // Do not suggest wrapping an unnamed FunctionExpression in braces as that would be invalid syntax.
+                    // if (!(node.body.type === "FunctionExpression" && !node.body.id)) {
+                    //     suggest.push({
+                    //         messageId: "wrapBraces",
+                    //         fix(fixer) {
+                    //             return curlyWrapFixer(sourceCode, node, fixer);
+                    //         }
+                    //     });
+                    // }
+                });
+            },
+            r#"
+              (return_statement) @c
+            "# => |node, context| {
+                let Some(argument) = node
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .next()
+                else {
+                    return;
+                };
+
+                let code_path_analyzer = context.retrieve::<CodePathAnalyzer<'a>>();
+
+                let code_path = code_path_analyzer.get_innermost_code_path(node);
+                let code_path_root_node = code_path_analyzer.code_path_arena[code_path]
+                    .root_node(&code_path_analyzer.code_path_segment_arena);
+                if !is_promise_executor_to_check(code_path_root_node, context) {
+                    return;
+                }
+
+                if self.allow_void && expression_is_void(argument) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "returns_value",
+                    // TODO: suggestions?
+                    // // prevent useless refactors
+                    // const suggest = allowVoid
+                    //     ? [{
+                    //         messageId: "prependVoid",
+                    //         fix(fixer) {
+                    //             return voidPrependFixer(sourceCode, node.argument, fixer);
+                    //         }
+                    //     }]
+                    //     : [];
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{CallExpression, Identifier, NewExpression, Number, ReturnStatement},
+    };
+
+    #[test]
+    fn test_no_promise_executor_return_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_promise_executor_return_rule(),
+            rule_tests! {
+                valid => [
+                    // not a promise executor
+                    "function foo(resolve, reject) { return 1; }",
+                    "function Promise(resolve, reject) { return 1; }",
+                    "(function (resolve, reject) { return 1; })",
+                    "(function foo(resolve, reject) { return 1; })",
+                    "(function Promise(resolve, reject) { return 1; })",
+                    "var foo = function (resolve, reject) { return 1; }",
+                    "var foo = function Promise(resolve, reject) { return 1; }",
+                    "var Promise = function (resolve, reject) { return 1; }",
+                    "(resolve, reject) => { return 1; }",
+                    "(resolve, reject) => 1",
+                    "var foo = (resolve, reject) => { return 1; }",
+                    "var Promise = (resolve, reject) => { return 1; }",
+                    "var foo = (resolve, reject) => 1",
+                    "var Promise = (resolve, reject) => 1",
+                    "var foo = { bar(resolve, reject) { return 1; } }",
+                    "var Promise = { bar(resolve, reject) { return 1; } }",
+                    "class foo { bar(resolve, reject) { return 1; } }",
+                    "foo(function (resolve, reject) { return 1; });",
+                    "foo((resolve, reject) => { return 1; });",
+                    "foo((resolve, reject) => 1);",
+                    "new foo(function (resolve, reject) { return 1; });",
+                    "new foo((resolve, reject) => { return 1; });",
+                    "new foo((resolve, reject) => 1);",
+                    "new Promise(foo, function (resolve, reject) { return 1; });",
+                    "new Promise(foo, (resolve, reject) => { return 1; });",
+                    "new Promise(foo, (resolve, reject) => 1);",
+                    "Promise(function (resolve, reject) { return 1; });",
+                    "Promise((resolve, reject) => { return 1; });",
+                    "Promise((resolve, reject) => 1);",
+                    "new foo.Promise(function (resolve, reject) { return 1; });",
+                    "new Promise.foo(function (resolve, reject) { return 1; });",
+
+                    // not the global Promise
+                    "function foo(Promise) { new Promise(function (resolve, reject) { return 1; }); }",
+                    "class Promise {} new Promise(function (resolve, reject) { return 1; });",
+                    "let Promise; new Promise((resolve, reject) => 1);",
+
+                    // no return value
+                    "new Promise(function (resolve, reject) {})",
+                    "new Promise(function (resolve, reject) { return; })",
+                    "new Promise(function (resolve, reject) { reject(new Error('foo')); return; })",
+                    "new Promise(function (resolve, reject) { if (foo) { return; } })",
+                    "new Promise((resolve, reject) => {})",
+                    "new Promise((resolve, reject) => { return; })",
+                    "new Promise((resolve, reject) => { if (foo) { resolve(1); return; } reject(new Error('foo')); })",
+
+                    // return values from nested functions
+                    "new Promise(function (resolve, reject) { function foo() { return 1; } })",
+                    "new Promise((resolve, reject) => { function foo() { return 1; } })",
+                    "new Promise(function (resolve, reject) { (function () { return 1; }); })",
+                    "new Promise((resolve, reject) => { (() => { return 1; }); })",
+                    "new Promise(function (resolve, reject) { (() => 1); })",
+                    "new Promise((resolve, reject) => { foo(() => 1); })",
+
+                    // allowVoid
+                    {
+                        code => "new Promise((r) => void cbf(r));",
+                        options => { allow_void => true }
+                    },
+                    {
+                        code => "new Promise(r => void 0)",
+                        options => { allow_void => true }
+                    },
+                    {
+                        code => "new Promise(r => (void 0))",
+                        options => { allow_void => true }
+                    },
+                    {
+                        code => "new Promise(r => { return void 0 })",
+                        options => { allow_void => true }
+                    },
+                    {
+                        code => "new Promise(r => { if (foo) { return void 0 } return void 1 })",
+                        options => { allow_void => true }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "new Promise(function (resolve, reject) { return 1; })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 42 }]
+                    },
+                    {
+                        code => "new Promise(function foo(resolve, reject) { return 1; })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 45 }]
+                    },
+                    {
+                        code => "new Promise((resolve, reject) => { return 1; })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 36 }]
+                    },
+                    {
+                        code => "new Promise((resolve, reject) => resolve(1))",
+                        // suggestions: [{ message_id => "wrap_braces", output => "new Promise((resolve, reject) => {resolve(1)})" }]
+                        errors => [{ message_id => "returns_value", type => CallExpression, column => 34 }]
+                    },
+                    {
+                        code => "new Promise(r => 1)",
+                        errors => [{ message_id => "returns_value", type => Number, column => 18 }]
+                    },
+                    {
+                        code => "new Promise(r => (1))",
+                        errors => [{ message_id => "returns_value", type => Number, column => 19 }]
+                    },
+                    {
+                        code => "new Promise(((((r => 1)))))",
+                        errors => [{ message_id => "returns_value", type => Number, column => 22 }]
+                    },
+                    {
+                        code => "new Promise(r => void 0)",
+                        errors => [{ message_id => "returns_value", type => UnaryExpression, column => 18 }]
+                    },
+                    {
+                        code => "new Promise(r => { return void 0 })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 20 }]
+                    },
+                    {
+                        code => "new Promise(r => 1)",
+                        options => { allow_void => true },
+                        // suggestions: [
+                        //     { message_id => "wrap_braces", output => "new Promise(r => {1})" },
+                        //     { message_id => "prepend_void", output => "new Promise(r => void 1)" }
+                        // ]
+                        errors => [{ message_id => "returns_value", type => Number, column => 18 }]
+                    },
+                    {
+                        code => "new Promise(r => { return 1 })",
+                        options => { allow_void => true },
+                        // suggestions: [{ message_id => "prepend_void", output => "new Promise(r => { return void 1 })" }]
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 20 }]
+                    },
+                    {
+                        code => "new Promise(function (resolve, reject) { if (foo) { return 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 53 }]
+                    },
+                    {
+                        code => "new Promise(function (resolve, reject) { try { return 1; } catch(e) {} })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 48 }]
+                    },
+                    {
+                        code => "new Promise(function (resolve, reject) { return 1; function foo() { return 2; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 42 }]
+                    },
+                    {
+                        code => "new Promise(function (resolve, reject) { (() => 1); return resolve(foo); })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 53 }]
+                    },
+                    {
+                        code => "new Promise((resolve, reject) => { return reject(foo); })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 36 }]
+                    },
+                    {
+                        code => "new Promise(function (resolve, reject) { return foo; }); new Promise(bar);",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 42 }]
+                    },
+                    {
+                        code => "new Promise(r => foo)",
+                        errors => [{ message_id => "returns_value", type => Identifier, column => 18 }]
+                    },
+                    {
+                        code => "new Promise(() => new Promise(() => 1))",
+                        errors => [
+                            { message_id => "returns_value", type => NewExpression, column => 19 },
+                            { message_id => "returns_value", type => Number, column => 37 }
+                        ]
+                    },
+                    {
+                        code => "function foo() { new Promise(function (resolve, reject) { return 1; }); }",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 59 }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+use crate::{
+    ast_helpers::NodeExtJs,
+    kind::{MemberExpression, SubscriptExpression},
+    utils::ast_utils,
+};
+
+const DISALLOWED_PROPS: [&str; 3] = ["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
+
+pub fn no_prototype_builtins_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-prototype-builtins",
+        languages => [Javascript],
+        messages => [
+            prototype_build_in => "Do not access Object.prototype method '{{prop}}' from target object.",
+            call_object_prototype => "Call Object.prototype.{{prop}} explicitly.",
+        ],
+        listeners => [
+            r#"
+              (call_expression
+                function: (_) @callee
+              ) @call_expression
+            "# => |captures, context| {
+                let callee = captures["callee"].skip_parentheses();
+                let property = match callee.kind() {
+                    MemberExpression => callee.field("property"),
+                    SubscriptExpression => callee.field("index"),
+                    _ => return,
+                };
+
+                let Some(prop_name) = ast_utils::get_static_property_name(callee, context) else {
+                    return;
+                };
+                if !DISALLOWED_PROPS.contains(&&*prop_name) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => captures["call_expression"],
+                    range => property.range(),
+                    message_id => "prototype_build_in",
+                    data => {
+                        prop => prop_name,
+                    },
+                    // TODO: suggestions?
+                    // suggest: [
+                    //     {
+                    //         messageId: "callObjectPrototype",
+                    //         data: { prop: propName },
+                    //         fix(fixer) {
+                    //             const sourceCode = context.sourceCode;
+                    //             const objectVariable = astUtils.getVariableByName(sourceCode.getScope(node), "Object");
+                    //
+                    //             /*
+                    //              * We can't use Object if the global Object was shadowed,
+                    //              * or Object does not exist in the global scope for some reason
+                    //              */
+                    //             if (!objectVariable || objectVariable.scope.type !== "global" || objectVariable.defs.length > 0) {
+                    //                 return null;
+                    //             }
+                    //
+                    //             let objectText = sourceCode.getText(callee.object);
+                    //
+                    //             if (astUtils.getPrecedence(callee.object) <= astUtils.getPrecedence({ type: "SequenceExpression" })) {
+                    //                 objectText = `(${objectText})`;
+                    //             }
+                    //
+                    //             /*
+                    //              * A call after an optional chain (e.g. a?.b.hasOwnProperty(c))
+                    //              * must be an optional call (e.g. Object.prototype.hasOwnProperty.call?.(a?.b, c))
+                    //              */
+                    //             const isOptionalCall = isAfterOptional(node);
+                    //
+                    //             const openParenToken = sourceCode.getTokenAfter(
+                    //                 node.callee,
+                    //                 astUtils.isOpeningParenToken
+                    //             );
+                    //             const isEmptyParameters = node.arguments.length === 0;
+                    //             const delim = isEmptyParameters ? "" : ", ";
+                    //             const fixes = [
+                    //                 fixer.replaceText(callee, `Object.prototype.${propName}.call`),
+                    //                 fixer.insertTextAfter(openParenToken, objectText + delim)
+                    //             ];
+                    //
+                    //             if (isOptionalCall) {
+                    //                 fixes.push(fixer.insertTextBefore(openParenToken, "?."));
+                    //             }
+                    //
+                    //             return fixes;
+                    //         }
+                    //     }
+                    // ]
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::CallExpression;
+
+    #[test]
+    fn test_no_prototype_builtins_rule() {
+        RuleTester::run(
+            no_prototype_builtins_rule(),
+            rule_tests! {
+                valid => [
+                    "Object.prototype.hasOwnProperty.call(foo, 'bar')",
+                    "Object.prototype.isPrototypeOf.call(foo, 'bar')",
+                    "Object.prototype.propertyIsEnumerable.call(foo, 'bar')",
+                    "Object.prototype.hasOwnProperty.apply(foo, ['bar'])",
+                    "Object.prototype.isPrototypeOf.apply(foo, ['bar'])",
+                    "Object.prototype.propertyIsEnumerable.apply(foo, ['bar'])",
+                    "foo.hasOwnProperty",
+                    "foo.hasOwnProperty.bar()",
+                    "foo(hasOwnProperty)",
+                    "hasOwnProperty(foo, 'bar')",
+                    "isPrototypeOf(foo, 'bar')",
+                    "propertyIsEnumerable(foo, 'bar')",
+                    "({}.hasOwnProperty.call(foo, 'bar'))",
+                    "({}.isPrototypeOf.call(foo, 'bar'))",
+                    "({}.propertyIsEnumerable.call(foo, 'bar'))",
+                    "({}.hasOwnProperty.apply(foo, ['bar']))",
+                    "({}.isPrototypeOf.apply(foo, ['bar']))",
+                    "({}.propertyIsEnumerable.apply(foo, ['bar']))",
+                    "foo[hasOwnProperty]('bar')",
+                    "foo['HasOwnProperty']('bar')",
+                    "foo[`isPrototypeOff`]('bar')",
+                    "foo?.['propertyIsEnumerabl']('bar')",
+                    "foo[1]('bar')",
+                    "foo[null]('bar')",
+                    "class C { #hasOwnProperty; foo() { obj.#hasOwnProperty('bar'); } }",
+
+                    // out of scope for this rule
+                    "foo['hasOwn' + 'Property']('bar')",
+                    "foo[`hasOwnProperty${''}`]('bar')"
+                ],
+                invalid => [
+                    {
+                        code => "foo.hasOwnProperty('bar')",
+                        errors => [{
+                            line => 1,
+                            column => 5,
+                            end_line => 1,
+                            end_column => 19,
+                            message_id => "prototype_build_in",
+                            data => { prop => "hasOwnProperty" },
+                            type => CallExpression,
+                            // suggestions: [{
+                            //     message_id => "call_object_prototype",
+                            //     data => { prop => "hasOwnProperty" },
+                            //     output => "Object.prototype.hasOwnProperty.call(foo, 'bar')"
+                            // }]
+                        }]
+                    },
+                    {
+                        code => "foo.isPrototypeOf('bar')",
+                        errors => [{
+                            line => 1,
+                            column => 5,
+                            end_line => 1,
+                            end_column => 18,
+                            message_id => "prototype_build_in",
+                            data => { prop => "isPrototypeOf" },
+                            type => CallExpression
+                        }]
+                    },
+                    {
+                        code => "foo.propertyIsEnumerable('bar')",
+                        errors => [{
+                            line => 1,
+                            column => 5,
+                            end_line => 1,
+                            end_column => 25,
+                            message_id => "prototype_build_in",
+                            data => { prop => "propertyIsEnumerable" }
+                        }]
+                    },
+                    {
+                        code => "foo.bar.hasOwnProperty('bar')",
+                        errors => [{
+                            line => 1,
+                            column => 9,
+                            end_line => 1,
+                            end_column => 23,
+                            message_id => "prototype_build_in",
+                            data => { prop => "hasOwnProperty" },
+                            type => CallExpression
+                        }]
+                    },
+                    {
+                        code => "foo.bar.baz.isPrototypeOf('bar')",
+                        errors => [{
+                            line => 1,
+                            column => 13,
+                            end_line => 1,
+                            end_column => 26,
+                            message_id => "prototype_build_in",
+                            data => { prop => "isPrototypeOf" },
+                            type => CallExpression
+                        }]
+                    },
+                    {
+                        code => "foo['hasOwnProperty']('bar')",
+                        errors => [{
+                            line => 1,
+                            column => 5,
+                            end_line => 1,
+                            end_column => 21,
+                            message_id => "prototype_build_in",
+                            data => { prop => "hasOwnProperty" },
+                            type => CallExpression
+                        }]
+                    },
+                    {
+                        code => "foo[`isPrototypeOf`]('bar').baz",
+                        errors => [{
+                            line => 1,
+                            column => 5,
+                            end_line => 1,
+                            end_column => 20,
+                            message_id => "prototype_build_in",
+                            data => { prop => "isPrototypeOf" },
+                            type => CallExpression
+                        }]
+                    },
+                    {
+                        code => "foo.bar[\"propertyIsEnumerable\"]('baz')",
+                        errors => [{
+                            line => 1,
+                            column => 9,
+                            end_line => 1,
+                            end_column => 31,
+                            message_id => "prototype_build_in",
+                            data => { prop => "propertyIsEnumerable" },
+                            type => CallExpression
+                        }]
+                    },
+
+                    // Optional chaining
+                    {
+                        code => "foo?.hasOwnProperty('bar')",
+                        errors => [{ message_id => "prototype_build_in", data => { prop => "hasOwnProperty" } }]
+                    },
+                    {
+                        code => "(foo?.hasOwnProperty)('bar')",
+                        errors => [{ message_id => "prototype_build_in", data => { prop => "hasOwnProperty" } }]
+                    },
+                    {
+                        code => "foo?.['hasOwnProperty']('bar')",
+                        errors => [{ message_id => "prototype_build_in", data => { prop => "hasOwnProperty" } }]
+                    },
+                    {
+                        code => "(foo?.[`hasOwnProperty`])('bar')",
+                        errors => [{ message_id => "prototype_build_in", data => { prop => "hasOwnProperty" } }]
+                    },
+                    {
+                        code => "foo?.bar.hasOwnProperty('baz')",
+                        errors => [{ message_id => "prototype_build_in", data => { prop => "hasOwnProperty" } }]
+                    },
+                    {
+                        code => "foo.hasOwnProperty?.('bar')",
+                        errors => [{ message_id => "prototype_build_in", data => { prop => "hasOwnProperty" } }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,390 @@
+use std::{collections::HashSet, sync::Arc};
+
+use regex::Regex;
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::is_export_default,
+    kind::{
+        ClassDeclaration, ExportClause, ExportSpecifier, FunctionDeclaration,
+        GeneratorFunctionDeclaration, LexicalDeclaration, NamespaceExport, VariableDeclaration,
+    },
+    scope::ScopeManager,
+    utils::ast_utils,
+};
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+struct RestrictDefaultExports {
+    direct: bool,
+    named: bool,
+    default_from: bool,
+    named_from: bool,
+    namespace_from: bool,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    restricted_named_exports: Vec<String>,
+    #[serde(with = "serde_regex")]
+    restricted_named_exports_pattern: Option<Regex>,
+    restrict_default_exports: Option<RestrictDefaultExports>,
+}
+
+pub fn no_restricted_exports_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-restricted-exports",
+        languages => [Javascript],
+        messages => [
+            restricted_named => "'{{name}}' is restricted from being used as an exported name.",
+            restricted_default => "Exporting 'default' is restricted.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            restricted_names: HashSet<String> = options.restricted_named_exports.clone().into_iter().collect(),
+            restricted_name_pattern: Option<Regex> = options.restricted_named_exports_pattern.clone(),
+            restrict_default_exports: Option<RestrictDefaultExports> = options.restrict_default_exports,
+        },
+        methods => {
+            fn check_exported_name(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let name = ast_utils::get_module_export_name(node, context);
+
+                let matches_restricted_name_pattern = name != "default"
+                    && self.restricted_name_pattern.as_ref().is_some_and(|restricted_name_pattern| {
+                        restricted_name_pattern.is_match(&name)
+                    });
+
+                if matches_restricted_name_pattern || self.restricted_names.contains(&*name) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "restricted_named",
+                        data => {
+                            name => name,
+                        }
+                    });
+                    return;
+                }
+
+                if name != "default" {
+                    return;
+                }
+                let Some(restrict_default_exports) = self.restrict_default_exports else {
+                    return;
+                };
+
+                let parent = node.parent().unwrap();
+                if parent.kind() == NamespaceExport {
+                    if restrict_default_exports.namespace_from {
+                        context.report(violation! {
+                            node => node,
+                            message_id => "restricted_default",
+                        });
+                    }
+                    return;
+                }
+
+                assert_eq!(parent.kind(), ExportSpecifier);
+                let is_source_specified = parent
+                    .parent()
+                    .unwrap()
+                    .parent()
+                    .unwrap()
+                    .child_by_field_name("source")
+                    .is_some();
+                if !is_source_specified {
+                    if restrict_default_exports.named {
+                        context.report(violation! {
+                            node => node,
+                            message_id => "restricted_default",
+                        });
+                    }
+                    return;
+                }
+
+                let specifier_local_name = ast_utils::get_module_export_name(parent.field("name"), context);
+                if specifier_local_name == "default" && restrict_default_exports.default_from
+                    || specifier_local_name != "default" && restrict_default_exports.named_from
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "restricted_default",
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (export_statement) @c
+            "# => |node, context| {
+                if is_export_default(node) {
+                    if self.restrict_default_exports.is_some_and(|restrict_default_exports| {
+                        restrict_default_exports.direct
+                    }) {
+                        context.report(violation! {
+                            node => node,
+                            message_id => "restricted_default",
+                        });
+                    }
+                    return;
+                }
+
+                if let Some(declaration) = node.child_by_field_name("declaration") {
+                    match declaration.kind() {
+                        FunctionDeclaration | GeneratorFunctionDeclaration | ClassDeclaration => {
+                            self.check_exported_name(declaration.field("name"), context);
+                        }
+                        LexicalDeclaration | VariableDeclaration => {
+                            let scope_manager = context.retrieve::<ScopeManager<'a>>();
+
+                            scope_manager
+                                .get_declared_variables(declaration)
+                                .filter_map(|variable| {
+                                    variable
+                                        .defs()
+                                        .find(|def| def.parent() == Some(declaration))
+                                        .map(|def| def.name())
+                                })
+                                .for_each(|name| {
+                                    self.check_exported_name(name, context);
+                                });
+                        }
+                        _ => (),
+                    }
+                    return;
+                }
+
+                if let Some(namespace_export) = node.maybe_first_child_of_kind(NamespaceExport) {
+                    if let Some(exported) = namespace_export.maybe_first_non_comment_named_child(SupportedLanguage::Javascript) {
+                        self.check_exported_name(exported, context);
+                    }
+                    return;
+                }
+
+                if let Some(export_clause) = node.maybe_first_child_of_kind(ExportClause) {
+                    export_clause
+                        .children_of_kind(ExportSpecifier)
+                        .for_each(|specifier| {
+                            self.check_exported_name(
+                                specifier
+                                    .child_by_field_name("alias")
+                                    .unwrap_or_else(|| specifier.field("name")),
+                                context,
+                            );
+                        });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use squalid::json_object;
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{self, ExportStatement, Identifier},
+    };
+
+    #[test]
+    fn test_no_restricted_exports_rule() {
+        RuleTester::run_with_instance_provider_and_environment(
+            no_restricted_exports_rule(),
+            rule_tests! {
+                valid => [
+                    // nothing configured
+                    "export var a;",
+                    "export function a() {}",
+                    "export class A {}",
+                    "var a; export { a };",
+                    "var b; export { b as a };",
+                    "export { a } from 'foo';",
+                    "export { b as a } from 'foo';",
+                    "export * as a from 'foo';",
+                    { code => "export var a;", options => {} },
+                    { code => "export var a;", options => { restricted_named_exports => [] } },
+
+                    // not a restricted name
+                    { code => "export var a;", options => { restricted_named_exports => ["x"] } },
+                    { code => "export let a;", options => { restricted_named_exports => ["x"] } },
+                    { code => "export const a = 1;", options => { restricted_named_exports => ["x"] } },
+                    { code => "export function a() {}", options => { restricted_named_exports => ["x"] } },
+                    { code => "export function *a() {}", options => { restricted_named_exports => ["x"] } },
+                    { code => "export async function a() {}", options => { restricted_named_exports => ["x"] } },
+                    { code => "export async function *a() {}", options => { restricted_named_exports => ["x"] } },
+                    { code => "export class A {}", options => { restricted_named_exports => ["x"] } },
+                    { code => "var a; export { a };", options => { restricted_named_exports => ["x"] } },
+                    { code => "var b; export { b as a };", options => { restricted_named_exports => ["x"] } },
+                    { code => "export { a } from 'foo';", options => { restricted_named_exports => ["x"] } },
+                    { code => "export { b as a } from 'foo';", options => { restricted_named_exports => ["x"] } },
+                    { code => "export * as a from 'foo';", options => { restricted_named_exports => ["x"] } },
+                    { code => "export { 'a' } from 'foo';", options => { restricted_named_exports => ["x"] } },
+                    { code => "export { '' } from 'foo';", options => { restricted_named_exports => ["undefined"] } },
+                    { code => "export { ' ' } from 'foo';", options => { restricted_named_exports => [""] } },
+                    { code => "export { ' a', 'a ' } from 'foo';", options => { restricted_named_exports => ["a"] } },
+
+                    // does not mention restricted names
+                    { code => "export { b as a };", options => { restricted_named_exports => ["b"] } },
+                    { code => "export { b as a } from 'foo';", options => { restricted_named_exports => ["b"] } },
+                    { code => "export * from 'foo';", options => { restricted_named_exports => ["a"] } },
+                    { code => "export * from 'a';", options => { restricted_named_exports => ["a"] } },
+
+                    // does not mention restricted names in patterns
+                    { code => "export var [b = a] = [];", options => { restricted_named_exports => ["a"] } },
+                    { code => "export var { a: b } = {};", options => { restricted_named_exports => ["a"] } },
+                    { code => "export var { b = a } = {};", options => { restricted_named_exports => ["a"] } },
+
+                    // default exports are not restricted by restricted_named_exports alone
+                    { code => "export default 1;", options => { restricted_named_exports => ["a"] } },
+
+                    // restricted_named_exports_pattern
+                    { code => "export var b;", options => { restricted_named_exports_pattern => "^(?:a|c)$" } },
+                    { code => "export default 1;", options => { restricted_named_exports_pattern => "default" } },
+                    { code => "export { default } from 'mod';", options => { restricted_named_exports_pattern => "default" } },
+
+                    // restrict_default_exports
+                    { code => "export default 1;", options => { restrict_default_exports => { direct => false } } },
+                    { code => "var a; export { a as default };", options => { restrict_default_exports => { named => false } } },
+                    { code => "export { default } from 'mod';", options => { restrict_default_exports => { default_from => false } } },
+                    { code => "export { foo as default } from 'mod';", options => { restrict_default_exports => { named_from => false } } },
+                    { code => "export * as default from 'mod';", options => { restrict_default_exports => { namespace_from => false } } },
+                    { code => "export { default } from 'mod';", options => { restrict_default_exports => { named_from => true } } },
+                    { code => "export { foo as default } from 'mod';", options => { restrict_default_exports => { default_from => true } } }
+                ],
+                invalid => [
+                    {
+                        code => "export function someFunction() {}",
+                        options => { restricted_named_exports => ["someFunction"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "someFunction" }, type => Identifier }]
+                    },
+                    {
+                        code => "export var a;",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 12 }]
+                    },
+                    {
+                        code => "export const a = 1;",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 14 }]
+                    },
+                    {
+                        code => "export function *a() {}",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 18 }]
+                    },
+                    {
+                        code => "export class A {}",
+                        options => { restricted_named_exports => ["A"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "A" }, type => Identifier, column => 14 }]
+                    },
+                    {
+                        code => "let a; export { a };",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 17 }]
+                    },
+                    {
+                        code => "let b; export { b as a };",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 22 }]
+                    },
+                    {
+                        code => "export { a } from 'foo';",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 10 }]
+                    },
+                    {
+                        code => "export * as a from 'foo';",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 13 }]
+                    },
+                    {
+                        code => "export { 'a' } from 'foo';",
+                        options => { restricted_named_exports => ["a"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "a" }, type => kind::String, column => 10 }]
+                    },
+                    {
+                        code => "export { '' } from 'foo';",
+                        options => { restricted_named_exports => [""] },
+                        errors => [{ message_id => "restricted_named", data => { name => "" }, type => kind::String, column => 10 }]
+                    },
+                    {
+                        code => "export var [a] = [], { b } = {}, { c: d } = {}, [...e] = [];",
+                        options => { restricted_named_exports => ["a", "b", "d", "e"] },
+                        errors => [
+                            { message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 13 },
+                            { message_id => "restricted_named", data => { name => "b" }, type => Identifier, column => 24 },
+                            { message_id => "restricted_named", data => { name => "d" }, type => Identifier, column => 39 },
+                            { message_id => "restricted_named", data => { name => "e" }, type => Identifier, column => 55 }
+                        ]
+                    },
+                    {
+                        code => "export var a, b;",
+                        options => { restricted_named_exports_pattern => "^(?:a|b)$" },
+                        errors => [
+                            { message_id => "restricted_named", data => { name => "a" }, type => Identifier, column => 12 },
+                            { message_id => "restricted_named", data => { name => "b" }, type => Identifier, column => 15 }
+                        ]
+                    },
+                    {
+                        code => "export { default } from 'mod';",
+                        options => { restricted_named_exports => ["default"] },
+                        errors => [{ message_id => "restricted_named", data => { name => "default" }, type => Identifier, column => 10 }]
+                    },
+                    {
+                        code => "export default 1;",
+                        options => { restrict_default_exports => { direct => true } },
+                        errors => [{ message_id => "restricted_default", type => ExportStatement, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "export default function foo() {}",
+                        options => { restrict_default_exports => { direct => true } },
+                        errors => [{ message_id => "restricted_default", type => ExportStatement, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "var a; export { a as default };",
+                        options => { restrict_default_exports => { named => true } },
+                        errors => [{ message_id => "restricted_default", type => Identifier, line => 1, column => 22 }]
+                    },
+                    {
+                        code => "var a; export { a as 'default' };",
+                        options => { restrict_default_exports => { named => true } },
+                        errors => [{ message_id => "restricted_default", type => kind::String, line => 1, column => 22 }]
+                    },
+                    {
+                        code => "export { default } from 'mod';",
+                        options => { restrict_default_exports => { default_from => true } },
+                        errors => [{ message_id => "restricted_default", type => Identifier, line => 1, column => 10 }]
+                    },
+                    {
+                        code => "export { default as default } from 'mod';",
+                        options => { restrict_default_exports => { default_from => true } },
+                        errors => [{ message_id => "restricted_default", type => Identifier, line => 1, column => 21 }]
+                    },
+                    {
+                        code => "export { foo as default } from 'mod';",
+                        options => { restrict_default_exports => { named_from => true } },
+                        errors => [{ message_id => "restricted_default", type => Identifier, line => 1, column => 17 }]
+                    },
+                    {
+                        code => "export * as default from 'mod';",
+                        options => { restrict_default_exports => { namespace_from => true } },
+                        errors => [{ message_id => "restricted_default", type => Identifier, line => 1, column => 13 }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+            json_object!({
+                "ecma_version": 2022,
+                "source_type": "module",
+            }),
+        )
+    }
+}
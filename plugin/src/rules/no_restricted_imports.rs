@@ -0,0 +1,1682 @@
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use squalid::{CowStrExt, OptionExt};
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::is_default_import,
+    kind::{
+        ExportClause, ExportSpecifier, ExportStatement, ImportClause, ImportSpecifier,
+        NamedImports, NamespaceExport, NamespaceImport,
+    },
+    utils::ast_utils,
+};
+
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+enum PathOption {
+    Name(String),
+    Object(PathOptionObject),
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PathOptionObject {
+    name: String,
+    message: Option<String>,
+    import_names: Option<Vec<String>>,
+    allow_import_names: Option<Vec<String>>,
+    allow_type_imports: bool,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+enum PatternOption {
+    Pattern(String),
+    Object(PatternOptionObject),
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PatternOptionObject {
+    group: Option<Vec<String>>,
+    regex: Option<String>,
+    import_names: Option<Vec<String>>,
+    #[serde(with = "serde_regex")]
+    import_name_pattern: Option<Regex>,
+    allow_import_names: Option<Vec<String>>,
+    #[serde(with = "serde_regex")]
+    allow_import_name_pattern: Option<Regex>,
+    message: Option<String>,
+    case_sensitive: bool,
+    allow_type_imports: bool,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PathsAndPatterns {
+    paths: Vec<PathOption>,
+    patterns: Vec<PatternOption>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Options {
+    PathsAndPatterns([PathsAndPatterns; 1]),
+    Paths(Vec<PathOption>),
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::Paths(Default::default())
+    }
+}
+
+impl Options {
+    pub fn paths(&self) -> &[PathOption] {
+        match self {
+            Options::PathsAndPatterns([paths_and_patterns]) => &paths_and_patterns.paths,
+            Options::Paths(paths) => paths,
+        }
+    }
+
+    pub fn patterns(&self) -> Vec<PatternOptionObject> {
+        match self {
+            Options::PathsAndPatterns([paths_and_patterns]) => {
+                let patterns = &paths_and_patterns.patterns;
+                match patterns.first() {
+                    // standardize to a single group if we have a list of strings
+                    Some(PatternOption::Pattern(_)) => vec![PatternOptionObject {
+                        group: Some(
+                            patterns
+                                .iter()
+                                .filter_map(|pattern| match pattern {
+                                    PatternOption::Pattern(pattern) => Some(pattern.clone()),
+                                    _ => None,
+                                })
+                                .collect(),
+                        ),
+                        ..Default::default()
+                    }],
+                    _ => patterns
+                        .iter()
+                        .filter_map(|pattern| match pattern {
+                            PatternOption::Object(pattern) => Some(pattern.clone()),
+                            _ => None,
+                        })
+                        .collect(),
+                }
+            }
+            Options::Paths(_) => Default::default(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct RestrictedPathEntry {
+    message: Option<String>,
+    import_names: Option<Vec<String>>,
+    allow_import_names: Option<Vec<String>>,
+    allow_type_imports: bool,
+}
+
+type GroupedRestrictedPaths = HashMap<String, Vec<RestrictedPathEntry>>;
+
+fn get_grouped_restricted_paths(options: &Options) -> GroupedRestrictedPaths {
+    options.paths().iter().fold(
+        Default::default(),
+        |mut grouped_restricted_paths, path_option| {
+            match path_option {
+                PathOption::Name(name) => {
+                    grouped_restricted_paths
+                        .entry(name.clone())
+                        .or_default()
+                        .push(RestrictedPathEntry {
+                            message: Default::default(),
+                            import_names: Default::default(),
+                            allow_import_names: Default::default(),
+                            allow_type_imports: Default::default(),
+                        });
+                }
+                PathOption::Object(path_option) => {
+                    grouped_restricted_paths
+                        .entry(path_option.name.clone())
+                        .or_default()
+                        .push(RestrictedPathEntry {
+                            message: path_option.message.clone(),
+                            import_names: path_option.import_names.clone(),
+                            allow_import_names: path_option.allow_import_names.clone(),
+                            allow_type_imports: path_option.allow_type_imports,
+                        });
+                }
+            }
+            grouped_restricted_paths
+        },
+    )
+}
+
+#[derive(Clone)]
+enum PatternMatcher {
+    Gitignore(Gitignore),
+    Regex(Regex),
+}
+
+impl PatternMatcher {
+    fn is_match(&self, import_source: &str) -> bool {
+        match self {
+            PatternMatcher::Gitignore(gitignore) => gitignore
+                .matched_path_or_any_parents(import_source.trim_start_matches('/'), false)
+                .is_ignore(),
+            PatternMatcher::Regex(regex) => regex.is_match(import_source),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct RestrictedPatternGroup {
+    matcher: PatternMatcher,
+    custom_message: Option<String>,
+    import_names: Option<Vec<String>>,
+    import_name_pattern: Option<Regex>,
+    allow_import_names: Option<Vec<String>>,
+    allow_import_name_pattern: Option<Regex>,
+    allow_type_imports: bool,
+}
+
+fn get_restricted_pattern_groups(options: &Options) -> Vec<RestrictedPatternGroup> {
+    options
+        .patterns()
+        .into_iter()
+        .map(|pattern| RestrictedPatternGroup {
+            matcher: match pattern.regex.as_ref() {
+                Some(regex) => PatternMatcher::Regex(
+                    RegexBuilder::new(regex)
+                        .case_insensitive(!pattern.case_sensitive)
+                        .build()
+                        .unwrap(),
+                ),
+                None => PatternMatcher::Gitignore({
+                    let mut builder = GitignoreBuilder::new("");
+                    builder.case_insensitive(!pattern.case_sensitive).unwrap();
+                    for line in pattern.group.as_deref().unwrap_or_default() {
+                        builder.add_line(None, line).unwrap();
+                    }
+                    builder.build().unwrap()
+                }),
+            },
+            custom_message: pattern.message,
+            import_names: pattern.import_names,
+            import_name_pattern: pattern.import_name_pattern,
+            allow_import_names: pattern.allow_import_names,
+            allow_import_name_pattern: pattern.allow_import_name_pattern,
+            allow_type_imports: pattern.allow_type_imports,
+        })
+        .collect()
+}
+
+fn format_pattern(pattern: &Regex) -> String {
+    format!("/{}/u", pattern.as_str())
+}
+
+type ImportNames<'a> = Vec<(Cow<'a, str>, Vec<Node<'a>>)>;
+
+fn add_import_name<'a>(import_names: &mut ImportNames<'a>, name: Cow<'a, str>, node: Node<'a>) {
+    match import_names
+        .iter_mut()
+        .find(|(existing_name, _)| *existing_name == name)
+    {
+        Some((_, specifiers)) => specifiers.push(node),
+        None => import_names.push((name, vec![node])),
+    }
+}
+
+fn get_import_names<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> ImportNames<'a> {
+    let mut import_names: ImportNames<'a> = Default::default();
+    match node.kind() {
+        ExportStatement => {
+            for child in node.non_comment_children(SupportedLanguage::Javascript) {
+                match child.kind() {
+                    "*" => add_import_name(&mut import_names, "*".into(), child),
+                    NamespaceExport => add_import_name(
+                        &mut import_names,
+                        "*".into(),
+                        child.maybe_first_child_of_kind("*").unwrap(),
+                    ),
+                    ExportClause => {
+                        for specifier in child.children_of_kind(ExportSpecifier) {
+                            add_import_name(
+                                &mut import_names,
+                                ast_utils::get_module_export_name(specifier.field("name"), context),
+                                specifier,
+                            );
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        _ => {
+            let Some(import_clause) = node.maybe_first_child_of_kind(ImportClause) else {
+                return import_names;
+            };
+            for child in import_clause.non_comment_named_children(SupportedLanguage::Javascript) {
+                match child.kind() {
+                    _ if is_default_import(child) => {
+                        add_import_name(&mut import_names, "default".into(), child)
+                    }
+                    NamespaceImport => add_import_name(&mut import_names, "*".into(), child),
+                    NamedImports => {
+                        for specifier in child.children_of_kind(ImportSpecifier) {
+                            add_import_name(
+                                &mut import_names,
+                                ast_utils::get_module_export_name(specifier.field("name"), context),
+                                specifier,
+                            );
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+    import_names
+}
+
+fn is_type_only_import_or_export(node: Node) -> bool {
+    if node.has_child_of_kind("type") {
+        return true;
+    }
+    let specifiers = match node.kind() {
+        ExportStatement => node
+            .maybe_first_child_of_kind(ExportClause)
+            .map(|export_clause| {
+                export_clause
+                    .children_of_kind(ExportSpecifier)
+                    .collect::<Vec<_>>()
+            }),
+        _ => node
+            .maybe_first_child_of_kind(ImportClause)
+            .filter(|import_clause| {
+                import_clause
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .all(|child| child.kind() == NamedImports)
+            })
+            .and_then(|import_clause| import_clause.maybe_first_child_of_kind(NamedImports))
+            .map(|named_imports| {
+                named_imports
+                    .children_of_kind(ImportSpecifier)
+                    .collect::<Vec<_>>()
+            }),
+    };
+    specifiers.matches(|specifiers| {
+        !specifiers.is_empty()
+            && specifiers
+                .iter()
+                .all(|specifier| specifier.has_child_of_kind("type"))
+    })
+}
+
+pub fn no_restricted_imports_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-restricted-imports",
+        languages => [Javascript, Typescript],
+        messages => [
+            path => "'{{import_source}}' import is restricted from being used.",
+            path_with_custom_message =>
+                "'{{import_source}}' import is restricted from being used. {{custom_message}}",
+
+            patterns => "'{{import_source}}' import is restricted from being used by a pattern.",
+            pattern_with_custom_message =>
+                "'{{import_source}}' import is restricted from being used by a pattern. {{custom_message}}",
+
+            pattern_and_import_name =>
+                "'{{import_name}}' import from '{{import_source}}' is restricted from being used by a pattern.",
+            pattern_and_import_name_with_custom_message =>
+                "'{{import_name}}' import from '{{import_source}}' is restricted from being used by a pattern. {{custom_message}}",
+
+            pattern_and_everything =>
+                "* import is invalid because '{{import_names}}' from '{{import_source}}' is restricted from being used by a pattern.",
+            pattern_and_everything_with_regex_import_name =>
+                "* import is invalid because import name matching '{{import_names}}' pattern from '{{import_source}}' is restricted from being used.",
+            pattern_and_everything_with_custom_message =>
+                "* import is invalid because '{{import_names}}' from '{{import_source}}' is restricted from being used by a pattern. {{custom_message}}",
+            pattern_and_everything_with_regex_import_name_and_custom_message =>
+                "* import is invalid because import name matching '{{import_names}}' pattern from '{{import_source}}' is restricted from being used. {{custom_message}}",
+
+            everything =>
+                "* import is invalid because '{{import_names}}' from '{{import_source}}' is restricted.",
+            everything_with_custom_message =>
+                "* import is invalid because '{{import_names}}' from '{{import_source}}' is restricted. {{custom_message}}",
+
+            import_name => "'{{import_name}}' import from '{{import_source}}' is restricted.",
+            import_name_with_custom_message =>
+                "'{{import_name}}' import from '{{import_source}}' is restricted. {{custom_message}}",
+
+            allowed_import_name =>
+                "'{{import_name}}' import from '{{import_source}}' is restricted because only '{{allowed_import_names}}' import(s) is/are allowed.",
+            allowed_import_name_with_custom_message =>
+                "'{{import_name}}' import from '{{import_source}}' is restricted because only '{{allowed_import_names}}' import(s) is/are allowed. {{custom_message}}",
+
+            everything_with_allow_import_names =>
+                "* import is invalid because only '{{allowed_import_names}}' from '{{import_source}}' is/are allowed.",
+            everything_with_allow_import_names_and_custom_message =>
+                "* import is invalid because only '{{allowed_import_names}}' from '{{import_source}}' is/are allowed. {{custom_message}}",
+
+            allowed_import_name_pattern =>
+                "'{{import_name}}' import from '{{import_source}}' is restricted because only imports that match the pattern '{{allowed_import_name_pattern}}' are allowed from '{{import_source}}'.",
+            allowed_import_name_pattern_with_custom_message =>
+                "'{{import_name}}' import from '{{import_source}}' is restricted because only imports that match the pattern '{{allowed_import_name_pattern}}' are allowed from '{{import_source}}'. {{custom_message}}",
+
+            everything_with_allowed_import_name_pattern =>
+                "* import is invalid because only imports that match the pattern '{{allowed_import_name_pattern}}' from '{{import_source}}' are allowed.",
+            everything_with_allowed_import_name_pattern_with_custom_message =>
+                "* import is invalid because only imports that match the pattern '{{allowed_import_name_pattern}}' from '{{import_source}}' are allowed. {{custom_message}}",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            grouped_restricted_paths: GroupedRestrictedPaths = get_grouped_restricted_paths(&options),
+            restricted_pattern_groups: Vec<RestrictedPatternGroup> = get_restricted_pattern_groups(&options),
+        },
+        methods => {
+            fn check_restricted_path_and_report(
+                &self,
+                import_source: &str,
+                import_names: &ImportNames,
+                is_type_only: bool,
+                node: Node,
+                context: &QueryMatchContext,
+            ) {
+                let Some(restricted_path_entries) = self.grouped_restricted_paths.get(import_source) else {
+                    return;
+                };
+
+                for restricted_path_entry in restricted_path_entries {
+                    if is_type_only && restricted_path_entry.allow_type_imports {
+                        continue;
+                    }
+
+                    let custom_message = restricted_path_entry.message.as_deref();
+                    let restricted_import_names = restricted_path_entry.import_names.as_ref();
+                    let allowed_import_names = restricted_path_entry.allow_import_names.as_ref();
+
+                    if restricted_import_names.is_none() && allowed_import_names.is_none() {
+                        context.report(violation! {
+                            node => node,
+                            message_id => if custom_message.is_some() {
+                                "path_with_custom_message"
+                            } else {
+                                "path"
+                            },
+                            data => {
+                                import_source => import_source,
+                                custom_message => custom_message.unwrap_or_default(),
+                            }
+                        });
+                        continue;
+                    }
+
+                    for (import_name, specifiers) in import_names {
+                        if import_name == "*" {
+                            let specifier = specifiers[0];
+                            if let Some(restricted_import_names) = restricted_import_names {
+                                context.report(violation! {
+                                    node => node,
+                                    message_id => if custom_message.is_some() {
+                                        "everything_with_custom_message"
+                                    } else {
+                                        "everything"
+                                    },
+                                    range => specifier.range(),
+                                    data => {
+                                        import_source => import_source,
+                                        import_names => restricted_import_names.join(","),
+                                        custom_message => custom_message.unwrap_or_default(),
+                                    }
+                                });
+                            } else if let Some(allowed_import_names) = allowed_import_names {
+                                context.report(violation! {
+                                    node => node,
+                                    message_id => if custom_message.is_some() {
+                                        "everything_with_allow_import_names_and_custom_message"
+                                    } else {
+                                        "everything_with_allow_import_names"
+                                    },
+                                    range => specifier.range(),
+                                    data => {
+                                        import_source => import_source,
+                                        allowed_import_names => allowed_import_names.join(","),
+                                        custom_message => custom_message.unwrap_or_default(),
+                                    }
+                                });
+                            }
+                            continue;
+                        }
+
+                        if restricted_import_names.matches(|restricted_import_names| {
+                            restricted_import_names.iter().any(|name| name == import_name)
+                        }) {
+                            for &specifier in specifiers {
+                                context.report(violation! {
+                                    node => node,
+                                    message_id => if custom_message.is_some() {
+                                        "import_name_with_custom_message"
+                                    } else {
+                                        "import_name"
+                                    },
+                                    range => specifier.range(),
+                                    data => {
+                                        import_source => import_source,
+                                        custom_message => custom_message.unwrap_or_default(),
+                                        import_name => import_name,
+                                    }
+                                });
+                            }
+                        }
+
+                        if let Some(allowed_import_names) = allowed_import_names.filter(|allowed_import_names| {
+                            !allowed_import_names.iter().any(|name| name == import_name)
+                        }) {
+                            for &specifier in specifiers {
+                                context.report(violation! {
+                                    node => node,
+                                    message_id => if custom_message.is_some() {
+                                        "allowed_import_name_with_custom_message"
+                                    } else {
+                                        "allowed_import_name"
+                                    },
+                                    range => specifier.range(),
+                                    data => {
+                                        import_source => import_source,
+                                        custom_message => custom_message.unwrap_or_default(),
+                                        import_name => import_name,
+                                        allowed_import_names => allowed_import_names.join(","),
+                                    }
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+
+            fn report_path_for_patterns(
+                &self,
+                import_source: &str,
+                group: &RestrictedPatternGroup,
+                import_names: &ImportNames,
+                node: Node,
+                context: &QueryMatchContext,
+            ) {
+                let custom_message = group.custom_message.as_deref();
+                let restricted_import_names = group.import_names.as_ref();
+                let restricted_import_name_pattern = group.import_name_pattern.as_ref();
+                let allowed_import_names = group.allow_import_names.as_ref();
+                let allowed_import_name_pattern = group.allow_import_name_pattern.as_ref();
+
+                if restricted_import_names.is_none()
+                    && restricted_import_name_pattern.is_none()
+                    && allowed_import_names.is_none()
+                    && allowed_import_name_pattern.is_none()
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => if custom_message.is_some() {
+                            "pattern_with_custom_message"
+                        } else {
+                            "patterns"
+                        },
+                        data => {
+                            import_source => import_source,
+                            custom_message => custom_message.unwrap_or_default(),
+                        }
+                    });
+                    return;
+                }
+
+                for (import_name, specifiers) in import_names {
+                    if import_name == "*" {
+                        let specifier = specifiers[0];
+                        if let Some(restricted_import_names) = restricted_import_names {
+                            context.report(violation! {
+                                node => node,
+                                message_id => if custom_message.is_some() {
+                                    "pattern_and_everything_with_custom_message"
+                                } else {
+                                    "pattern_and_everything"
+                                },
+                                range => specifier.range(),
+                                data => {
+                                    import_source => import_source,
+                                    import_names => restricted_import_names.join(","),
+                                    custom_message => custom_message.unwrap_or_default(),
+                                }
+                            });
+                        } else if let Some(allowed_import_names) = allowed_import_names {
+                            context.report(violation! {
+                                node => node,
+                                message_id => if custom_message.is_some() {
+                                    "everything_with_allow_import_names_and_custom_message"
+                                } else {
+                                    "everything_with_allow_import_names"
+                                },
+                                range => specifier.range(),
+                                data => {
+                                    import_source => import_source,
+                                    allowed_import_names => allowed_import_names.join(","),
+                                    custom_message => custom_message.unwrap_or_default(),
+                                }
+                            });
+                        } else if let Some(allowed_import_name_pattern) = allowed_import_name_pattern {
+                            context.report(violation! {
+                                node => node,
+                                message_id => if custom_message.is_some() {
+                                    "everything_with_allowed_import_name_pattern_with_custom_message"
+                                } else {
+                                    "everything_with_allowed_import_name_pattern"
+                                },
+                                range => specifier.range(),
+                                data => {
+                                    import_source => import_source,
+                                    allowed_import_name_pattern => format_pattern(allowed_import_name_pattern),
+                                    custom_message => custom_message.unwrap_or_default(),
+                                }
+                            });
+                        } else {
+                            context.report(violation! {
+                                node => node,
+                                message_id => if custom_message.is_some() {
+                                    "pattern_and_everything_with_regex_import_name_and_custom_message"
+                                } else {
+                                    "pattern_and_everything_with_regex_import_name"
+                                },
+                                range => specifier.range(),
+                                data => {
+                                    import_source => import_source,
+                                    import_names => format_pattern(restricted_import_name_pattern.unwrap()),
+                                    custom_message => custom_message.unwrap_or_default(),
+                                }
+                            });
+                        }
+                        continue;
+                    }
+
+                    if restricted_import_names.matches(|restricted_import_names| {
+                        restricted_import_names.iter().any(|name| name == import_name)
+                    }) || restricted_import_name_pattern.matches(|restricted_import_name_pattern| {
+                        restricted_import_name_pattern.is_match(import_name)
+                    }) {
+                        for &specifier in specifiers {
+                            context.report(violation! {
+                                node => node,
+                                message_id => if custom_message.is_some() {
+                                    "pattern_and_import_name_with_custom_message"
+                                } else {
+                                    "pattern_and_import_name"
+                                },
+                                range => specifier.range(),
+                                data => {
+                                    import_source => import_source,
+                                    custom_message => custom_message.unwrap_or_default(),
+                                    import_name => import_name,
+                                }
+                            });
+                        }
+                    }
+
+                    if let Some(allowed_import_names) = allowed_import_names.filter(|allowed_import_names| {
+                        !allowed_import_names.iter().any(|name| name == import_name)
+                    }) {
+                        for &specifier in specifiers {
+                            context.report(violation! {
+                                node => node,
+                                message_id => if custom_message.is_some() {
+                                    "allowed_import_name_with_custom_message"
+                                } else {
+                                    "allowed_import_name"
+                                },
+                                range => specifier.range(),
+                                data => {
+                                    import_source => import_source,
+                                    custom_message => custom_message.unwrap_or_default(),
+                                    import_name => import_name,
+                                    allowed_import_names => allowed_import_names.join(","),
+                                }
+                            });
+                        }
+                    } else if let Some(allowed_import_name_pattern) = allowed_import_name_pattern.filter(|allowed_import_name_pattern| {
+                        !allowed_import_name_pattern.is_match(import_name)
+                    }) {
+                        for &specifier in specifiers {
+                            context.report(violation! {
+                                node => node,
+                                message_id => if custom_message.is_some() {
+                                    "allowed_import_name_pattern_with_custom_message"
+                                } else {
+                                    "allowed_import_name_pattern"
+                                },
+                                range => specifier.range(),
+                                data => {
+                                    import_source => import_source,
+                                    custom_message => custom_message.unwrap_or_default(),
+                                    import_name => import_name,
+                                    allowed_import_name_pattern => format_pattern(allowed_import_name_pattern),
+                                }
+                            });
+                        }
+                    }
+                }
+            }
+
+            fn check_node(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                // TS `import x = require("y")` has no `source` field.
+                let Some(source) = node.child_by_field_name("source") else {
+                    return;
+                };
+                let import_source = ast_utils::get_static_string_value(
+                    source,
+                    context,
+                ).unwrap().trimmed();
+                let import_names = get_import_names(node, context);
+                let is_type_only = is_type_only_import_or_export(node);
+
+                self.check_restricted_path_and_report(
+                    &import_source,
+                    &import_names,
+                    is_type_only,
+                    node,
+                    context,
+                );
+                self.restricted_pattern_groups.iter().filter(|group| {
+                    !(is_type_only && group.allow_type_imports)
+                        && group.matcher.is_match(&import_source)
+                }).for_each(|group| {
+                    self.report_path_for_patterns(
+                        &import_source,
+                        group,
+                        &import_names,
+                        node,
+                        context,
+                    );
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (import_statement) @c
+              (export_statement
+                source: (_)
+              ) @c
+            "# => |node, context| {
+                if self.grouped_restricted_paths.is_empty() && self.restricted_pattern_groups.is_empty() {
+                    return;
+                }
+
+                self.check_node(node, context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::{ExportStatement, ImportStatement};
+
+    #[test]
+    fn test_no_restricted_imports_rule() {
+        RuleTester::run(
+            no_restricted_imports_rule(),
+            rule_tests! {
+                valid => [
+                    "import os from \"os\";",
+                    { code => "import os from \"os\";", options => ["osx"] },
+                    { code => "import fs from \"fs\";", options => ["crypto"] },
+                    { code => "import path from \"path\";", options => ["crypto", "stream", "os"] },
+                    "import async from \"async\";",
+                    { code => "import \"foo\"", options => ["crypto"] },
+                    { code => "import \"foo/bar\";", options => ["foo"] },
+                    { code => "import withPaths from \"foo/bar\";", options => [{ paths => ["foo", "bar"] }] },
+                    { code => "import withPatterns from \"foo/bar\";", options => [{ patterns => ["foo/c*"] }] },
+                    { code => "import foo from 'foo';", options => ["../foo"] },
+                    { code => "import foo from 'foo';", options => [{ paths => ["../foo"] }] },
+                    { code => "import foo from 'foo';", options => [{ patterns => ["../foo"] }] },
+                    { code => "import foo from 'foo';", options => ["/foo"] },
+                    { code => "import foo from 'foo';", options => [{ paths => ["/foo"] }] },
+                    "import relative from '../foo';",
+                    { code => "import relative from '../foo';", options => ["../notFoo"] },
+                    { code => "import relativeWithPaths from '../foo';", options => [{ paths => ["../notFoo"] }] },
+                    { code => "import relativeWithPatterns from '../foo';", options => [{ patterns => ["notFoo"] }] },
+                    "import absolute from '/foo';",
+                    { code => "import absolute from '/foo';", options => ["/notFoo"] },
+                    { code => "import absoluteWithPaths from '/foo';", options => [{ paths => ["/notFoo"] }] },
+                    { code => "import absoluteWithPatterns from '/foo';", options => [{ patterns => ["notFoo"] }] },
+                    {
+                        code => "import withPatternsAndPaths from \"foo/bar\";",
+                        options => [{ paths => ["foo"], patterns => ["foo/c*"] }]
+                    },
+                    {
+                        code => "import withGitignores from \"foo/bar\";",
+                        options => [{ patterns => ["foo/*", "!foo/bar"] }]
+                    },
+                    {
+                        code => "import withPatterns from \"foo/bar\";",
+                        options => [{ patterns => [{ group => ["foo/*", "!foo/bar"], message => "foo is forbidden, use bar instead" }] }]
+                    },
+                    {
+                        code => "import withPatternsCaseSensitive from 'foo';",
+                        options => [{
+                            patterns => [{
+                                group => ["FOO"],
+                                message => "foo is forbidden, use bar instead",
+                                case_sensitive => true
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import AllowedObject from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import DisallowedObject from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { AllowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import from 'bar' instead."
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { 'AllowedObject' as bar } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import from 'bar' instead."
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { ' ' as bar } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => [""]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { DisallowedObject as AllowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "bar",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import from 'baz' instead."
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import AllowedObject, { DisallowedObject as AllowedObjectTwo } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "bar",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import from 'baz' instead."
+                            }]
+                        }]
+                    },
+                    {
+                        code => "export * from \"foo\";",
+                        options => ["bar"]
+                    },
+                    {
+                        code => "export * from \"foo\";",
+                        options => [{
+                            name => "bar",
+                            import_names => ["DisallowedObject"]
+                        }]
+                    },
+                    {
+                        code => "export { 'AllowedObject' } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "export { bar } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "export { bar as 'DisallowedObject' } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { Bar } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_names => ["Foo"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import Foo from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_names => ["Foo"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { Bar } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_name_pattern => "^Foo"
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { Bar } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_name_pattern => "^Foo",
+                                import_names => ["Foo"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { Foo } from 'foo';",
+                        options => [{
+                            patterns => [{
+                                regex => "my/relative-module",
+                                import_name_pattern => "^Foo"
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { Foo } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                regex => "my/relative-module",
+                                import_name_pattern => "^Bar"
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { Foo } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                regex => "my/[a-z]+-module",
+                                case_sensitive => true
+                            }, {
+                                regex => "my/relative-Module",
+                                case_sensitive => true
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { AllowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                allow_import_names => ["AllowedObject"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { foo } from 'foo';",
+                        options => [{
+                            patterns => [{
+                                group => ["foo"],
+                                allow_import_names => ["foo"]
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import { hasValue } from 'foo';",
+                        options => [{
+                            patterns => [{
+                                group => ["foo"],
+                                allow_import_name_pattern => "^has"
+                            }]
+                        }]
+                    },
+                    {
+                        code => "import type { a } from \"x\";",
+                        options => [{ paths => [{ name => "x", allow_type_imports => true }] }],
+                        supported_language => SupportedLanguage::Typescript
+                    },
+                    {
+                        code => "import { type a } from \"x\";",
+                        options => [{ paths => [{ name => "x", allow_type_imports => true }] }],
+                        supported_language => SupportedLanguage::Typescript
+                    },
+                    {
+                        code => "export type { a } from \"x\";",
+                        options => [{ paths => [{ name => "x", allow_type_imports => true }] }],
+                        supported_language => SupportedLanguage::Typescript
+                    },
+                    {
+                        code => "import type { a } from \"x/y\";",
+                        options => [{ patterns => [{ group => ["x/*"], allow_type_imports => true }] }],
+                        supported_language => SupportedLanguage::Typescript
+                    },
+                    {
+                        code => "import x = require(\"x\");",
+                        options => ["x"],
+                        supported_language => SupportedLanguage::Typescript
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "import \"fs\"",
+                        options => ["fs"],
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "fs", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 12
+                        }]
+                    },
+                    {
+                        code => "import os from \"os \";",
+                        options => ["fs", "crypto ", "stream", "os"],
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "os", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 22
+                        }]
+                    },
+                    {
+                        code => "import \"foo/bar\";",
+                        options => ["foo/bar"],
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "foo/bar", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 18
+                        }]
+                    },
+                    {
+                        code => "import withPaths from \"foo/bar\";",
+                        options => [{ paths => ["foo/bar"] }],
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "foo/bar", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 33
+                        }]
+                    },
+                    {
+                        code => "import withPatterns from \"foo/bar\";",
+                        options => [{ patterns => ["foo"] }],
+                        errors => [{
+                            message_id => "patterns",
+                            data => { import_source => "foo/bar", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 36
+                        }]
+                    },
+                    {
+                        code => "import withPatterns from \"foo/bar\";",
+                        options => [{ patterns => ["bar"] }],
+                        errors => [{
+                            message_id => "patterns",
+                            data => { import_source => "foo/bar", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 36
+                        }]
+                    },
+                    {
+                        code => "import withPatterns from \"foo/baz\";",
+                        options => [{ patterns => [{ group => ["foo/*", "!foo/bar"], message => "foo is forbidden, use foo/bar instead" }] }],
+                        errors => [{
+                            message_id => "pattern_with_custom_message",
+                            data => { import_source => "foo/baz", custom_message => "foo is forbidden, use foo/bar instead" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 36
+                        }]
+                    },
+                    {
+                        code => "import withPatterns from \"foo/baz\";",
+                        options => [{ patterns => [{ group => ["foo/bar", "foo/baz"], message => "some foo subimports are restricted" }] }],
+                        errors => [{
+                            message_id => "pattern_with_custom_message",
+                            data => { import_source => "foo/baz", custom_message => "some foo subimports are restricted" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 36
+                        }]
+                    },
+                    {
+                        code => "import withPatterns from \"foo/bar\";",
+                        options => [{ patterns => [{ group => ["foo/bar"] }] }],
+                        errors => [{
+                            message_id => "patterns",
+                            data => { import_source => "foo/bar", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 36
+                        }]
+                    },
+                    {
+                        code => "import withPatternsCaseInsensitive from 'foo';",
+                        options => [{ patterns => [{ group => ["FOO"] }] }],
+                        errors => [{
+                            message_id => "patterns",
+                            data => { import_source => "foo", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 47
+                        }]
+                    },
+                    {
+                        code => "import withGitignores from \"foo/bar\";",
+                        options => [{ patterns => ["foo/*", "!foo/baz"] }],
+                        errors => [{
+                            message_id => "patterns",
+                            data => { import_source => "foo/bar", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 38
+                        }]
+                    },
+                    {
+                        code => "export * from \"fs\";",
+                        options => ["fs"],
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "fs", custom_message => "" },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 20
+                        }]
+                    },
+                    {
+                        code => "export * as ns from \"fs\";",
+                        options => ["fs"],
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "fs", custom_message => "" },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 26
+                        }]
+                    },
+                    {
+                        code => "export {a} from \"fs\";",
+                        options => ["fs"],
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "fs", custom_message => "" },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 22
+                        }]
+                    },
+                    {
+                        code => "export {foo as b} from \"fs\";",
+                        options => [{
+                            paths => [{
+                                name => "fs",
+                                import_names => ["foo"],
+                                message => "Don\"t import \"foo\"."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "import_name_with_custom_message",
+                            data => { import_source => "fs", import_name => "foo", custom_message => "Don\"t import \"foo\"." },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 9,
+                            end_column => 17
+                        }]
+                    },
+                    {
+                        code => "export {'foo' as b} from \"fs\";",
+                        options => [{
+                            paths => [{
+                                name => "fs",
+                                import_names => ["foo"],
+                                message => "Don\"t import \"foo\"."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "import_name_with_custom_message",
+                            data => { import_source => "fs", import_name => "foo", custom_message => "Don\"t import \"foo\"." },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 9,
+                            end_column => 19
+                        }]
+                    },
+                    {
+                        code => "export * as ns from \"fs\";",
+                        options => [{
+                            paths => [{
+                                name => "fs",
+                                import_names => ["foo"],
+                                message => "Don\"t import \"foo\"."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "everything_with_custom_message",
+                            data => { import_source => "fs", import_names => "foo", custom_message => "Don\"t import \"foo\"." },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 9
+                        }]
+                    },
+                    {
+                        code => "import withGitignores from \"foo\";",
+                        options => [{
+                            name => "foo",
+                            message => "Please import from 'bar' instead."
+                        }],
+                        errors => [{
+                            message_id => "path_with_custom_message",
+                            data => { import_source => "foo", custom_message => "Please import from 'bar' instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 34
+                        }]
+                    },
+                    {
+                        code => "import withGitignores from \"bar\";",
+                        options => [
+                            "foo",
+                            {
+                                name => "bar",
+                                message => "Please import from 'baz' instead."
+                            },
+                            "baz"
+                        ],
+                        errors => [{
+                            message_id => "path_with_custom_message",
+                            data => { import_source => "bar", custom_message => "Please import from 'baz' instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 34
+                        }]
+                    },
+                    {
+                        code => "import DisallowedObject from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["default"],
+                                message => "Please import the default import of 'foo' from /bar/ instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "import_name_with_custom_message",
+                            data => { import_source => "foo", import_name => "default", custom_message => "Please import the default import of 'foo' from /bar/ instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 24
+                        }]
+                    },
+                    {
+                        code => "import * as All from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import 'DisallowedObject' from /bar/ instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "everything_with_custom_message",
+                            data => { import_source => "foo", import_names => "DisallowedObject", custom_message => "Please import 'DisallowedObject' from /bar/ instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 16
+                        }]
+                    },
+                    {
+                        code => "export * from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import 'DisallowedObject' from /bar/ instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "everything_with_custom_message",
+                            data => { import_source => "foo", import_names => "DisallowedObject", custom_message => "Please import 'DisallowedObject' from /bar/ instead." },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 9
+                        }]
+                    },
+                    {
+                        code => "export * from \"foo\";",
+                        options => [{
+                            name => "",
+                            import_names => ["DisallowedObject1, DisallowedObject2"]
+                        }, {
+                            name => "foo",
+                            import_names => ["DisallowedObject1", "DisallowedObject2"]
+                        }],
+                        errors => [{
+                            message_id => "everything",
+                            data => { import_source => "foo", import_names => "DisallowedObject1,DisallowedObject2" },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 9
+                        }]
+                    },
+                    {
+                        code => "import { DisallowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import 'DisallowedObject' from /bar/ instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "import_name_with_custom_message",
+                            data => { import_source => "foo", import_name => "DisallowedObject", custom_message => "Please import 'DisallowedObject' from /bar/ instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 10,
+                            end_column => 26
+                        }]
+                    },
+                    {
+                        code => "import { DisallowedObject as AllowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import 'DisallowedObject' from /bar/ instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "import_name_with_custom_message",
+                            data => { import_source => "foo", import_name => "DisallowedObject", custom_message => "Please import 'DisallowedObject' from /bar/ instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 10,
+                            end_column => 43
+                        }]
+                    },
+                    {
+                        code => "import { 'DisallowedObject' as AllowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import 'DisallowedObject' from /bar/ instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "import_name_with_custom_message",
+                            data => { import_source => "foo", import_name => "DisallowedObject", custom_message => "Please import 'DisallowedObject' from /bar/ instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 10,
+                            end_column => 45
+                        }]
+                    },
+                    {
+                        code => "import { DisallowedObject, DisallowedObject as AllowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"]
+                            }]
+                        }],
+                        errors => [
+                            {
+                                message_id => "import_name",
+                                data => { import_source => "foo", import_name => "DisallowedObject" },
+                                type => ImportStatement,
+                                line => 1,
+                                column => 10,
+                                end_column => 26
+                            },
+                            {
+                                message_id => "import_name",
+                                data => { import_source => "foo", import_name => "DisallowedObject" },
+                                type => ImportStatement,
+                                line => 1,
+                                column => 28,
+                                end_column => 61
+                            }
+                        ]
+                    },
+                    {
+                        code => "import AllowedObject, * as AllowedObjectTwo from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                import_names => ["DisallowedObject"],
+                                message => "Please import 'DisallowedObject' from /bar/ instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "everything_with_custom_message",
+                            data => { import_source => "foo", import_names => "DisallowedObject", custom_message => "Please import 'DisallowedObject' from /bar/ instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 23,
+                            end_column => 44
+                        }]
+                    },
+                    {
+                        code => "import { Foo } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_names => ["Foo"]
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "pattern_and_import_name",
+                            data => { import_source => "../../my/relative-module", import_name => "Foo" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 10,
+                            end_column => 13
+                        }]
+                    },
+                    {
+                        code => "import * as All from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_names => ["Foo", "Bar"],
+                                message => "Import from @/utils instead."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "pattern_and_everything_with_custom_message",
+                            data => { import_source => "../../my/relative-module", import_names => "Foo,Bar", custom_message => "Import from @/utils instead." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 16
+                        }]
+                    },
+                    {
+                        code => "import { Foo } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_name_pattern => "^Foo"
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "pattern_and_import_name",
+                            data => { import_source => "../../my/relative-module", import_name => "Foo" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 10,
+                            end_column => 13
+                        }]
+                    },
+                    {
+                        code => "import * as Foo from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                group => ["**/my/relative-module"],
+                                import_name_pattern => "^Foo"
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "pattern_and_everything_with_regex_import_name",
+                            data => { import_source => "../../my/relative-module", import_names => "/^Foo/u" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 16
+                        }]
+                    },
+                    {
+                        code => "import { Foo } from '../../my/relative-module';",
+                        options => [{
+                            patterns => [{
+                                regex => "my/relative-module",
+                                import_name_pattern => "^Foo"
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "pattern_and_import_name",
+                            data => { import_source => "../../my/relative-module", import_name => "Foo" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 10,
+                            end_column => 13
+                        }]
+                    },
+                    {
+                        code => "import withPatternsCaseSensitive from 'FOO';",
+                        options => [{
+                            patterns => [{
+                                regex => "FOO",
+                                message => "foo is forbidden, use bar instead",
+                                case_sensitive => true
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "pattern_with_custom_message",
+                            data => { import_source => "FOO", custom_message => "foo is forbidden, use bar instead" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 45
+                        }]
+                    },
+                    {
+                        code => "import { AllowedObject, DisallowedObject } from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                allow_import_names => ["AllowedObject"]
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "allowed_import_name",
+                            data => { import_source => "foo", import_name => "DisallowedObject", allowed_import_names => "AllowedObject" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 25,
+                            end_column => 41
+                        }]
+                    },
+                    {
+                        code => "import * as AllowedObject from \"foo\";",
+                        options => [{
+                            paths => [{
+                                name => "foo",
+                                allow_import_names => ["AllowedObject"],
+                                message => "Only 'AllowedObject' is allowed to be imported from 'foo'."
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "everything_with_allow_import_names_and_custom_message",
+                            data => { import_source => "foo", allowed_import_names => "AllowedObject", custom_message => "Only 'AllowedObject' is allowed to be imported from 'foo'." },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 26
+                        }]
+                    },
+                    {
+                        code => "import { foo, bar } from 'foo';",
+                        options => [{
+                            patterns => [{
+                                group => ["foo"],
+                                allow_import_names => ["foo"]
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "allowed_import_name",
+                            data => { import_source => "foo", import_name => "bar", allowed_import_names => "foo" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 15,
+                            end_column => 18
+                        }]
+                    },
+                    {
+                        code => "import { hasValue, getValue } from 'foo';",
+                        options => [{
+                            patterns => [{
+                                group => ["foo"],
+                                allow_import_name_pattern => "^has"
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "allowed_import_name_pattern",
+                            data => { import_source => "foo", import_name => "getValue", allowed_import_name_pattern => "/^has/u" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 20,
+                            end_column => 28
+                        }]
+                    },
+                    {
+                        code => "import * as foo from 'foo';",
+                        options => [{
+                            patterns => [{
+                                group => ["foo"],
+                                allow_import_name_pattern => "^has"
+                            }]
+                        }],
+                        errors => [{
+                            message_id => "everything_with_allowed_import_name_pattern",
+                            data => { import_source => "foo", allowed_import_name_pattern => "/^has/u" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 8,
+                            end_column => 16
+                        }]
+                    },
+                    {
+                        code => "import type { a } from \"x\";",
+                        options => [{ paths => [{ name => "x", allow_type_imports => false }] }],
+                        supported_language => SupportedLanguage::Typescript,
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "x", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 28
+                        }]
+                    },
+                    {
+                        code => "import { type a, b } from \"x\";",
+                        options => [{ paths => [{ name => "x", allow_type_imports => true }] }],
+                        supported_language => SupportedLanguage::Typescript,
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "x", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 31
+                        }]
+                    },
+                    {
+                        code => "export type { a } from \"x\";",
+                        options => [{ paths => ["x"] }],
+                        supported_language => SupportedLanguage::Typescript,
+                        errors => [{
+                            message_id => "path",
+                            data => { import_source => "x", custom_message => "" },
+                            type => ExportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 28
+                        }]
+                    },
+                    {
+                        code => "import type { a } from \"x/y\";",
+                        options => [{ patterns => [{ group => ["x/*"], allow_type_imports => false }] }],
+                        supported_language => SupportedLanguage::Typescript,
+                        errors => [{
+                            message_id => "patterns",
+                            data => { import_source => "x/y", custom_message => "" },
+                            type => ImportStatement,
+                            line => 1,
+                            column => 1,
+                            end_column => 30
+                        }]
+                    }
+                ]
+            },
+        )
+    }
+}
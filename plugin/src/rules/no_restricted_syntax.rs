@@ -0,0 +1,231 @@
+use std::{iter, sync::Arc};
+
+use serde::{de, Deserialize};
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Query, QueryCursor},
+    tree_sitter_grep::SupportedLanguage,
+    violation, NodeExt, Rule,
+};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RestrictedSyntaxSpec {
+    Query(String),
+    QueryAndMessage {
+        query: String,
+        message: Option<String>,
+    },
+}
+
+#[derive(Clone)]
+struct RestrictedSyntax {
+    query: Arc<Query>,
+    // captures whose names start with "_" can be used for eg predicates
+    // without getting reported themselves
+    reported_capture_indices: Vec<u32>,
+    message: String,
+}
+
+impl<'de> Deserialize<'de> for RestrictedSyntax {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (query_source, message) = match RestrictedSyntaxSpec::deserialize(deserializer)? {
+            RestrictedSyntaxSpec::Query(query) => (query, None),
+            RestrictedSyntaxSpec::QueryAndMessage { query, message } => (query, message),
+        };
+
+        let query = Query::new(SupportedLanguage::Javascript.language(None), &query_source)
+            .map_err(|err| de::Error::custom(format!("invalid query {query_source:?}: {err}")))?;
+
+        let reported_capture_indices = query
+            .capture_names()
+            .iter()
+            .enumerate()
+            .filter(|(_, capture_name)| !capture_name.starts_with('_'))
+            .map(|(index, _)| index as u32)
+            .collect::<Vec<_>>();
+        if reported_capture_indices.is_empty() {
+            return Err(de::Error::custom(format!(
+                "query {query_source:?} doesn't have any (non-underscore-prefixed) captures"
+            )));
+        }
+
+        Ok(Self {
+            message: message
+                .unwrap_or_else(|| format!("Using '{}' is not allowed.", query_source.trim())),
+            query: Arc::new(query),
+            reported_capture_indices,
+        })
+    }
+}
+
+pub fn no_restricted_syntax_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-restricted-syntax",
+        languages => [Javascript],
+        messages => [
+            restricted_syntax => "{{message}}",
+        ],
+        options_type => Vec<RestrictedSyntax>,
+        state => {
+            [per-config]
+            restricted_syntaxes: Vec<RestrictedSyntax> = options.clone(),
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                for restricted_syntax in &self.restricted_syntaxes {
+                    let mut query_cursor = QueryCursor::new();
+                    for query_match in query_cursor.matches(
+                        &restricted_syntax.query,
+                        node,
+                        |node: Node| iter::once(node.text(context).into_owned().into_bytes()),
+                    ) {
+                        for capture in query_match.captures {
+                            if !restricted_syntax.reported_capture_indices.contains(&capture.index) {
+                                continue;
+                            }
+
+                            context.report(violation! {
+                                node => capture.node,
+                                message_id => "restricted_syntax",
+                                data => {
+                                    message => &*restricted_syntax.message,
+                                }
+                            });
+                        }
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::{
+        CallExpression, CatchClause, ClassDeclaration, DoStatement, EmptyStatement,
+        FunctionDeclaration, Identifier, TryStatement, VariableDeclaration, WithStatement,
+    };
+
+    #[test]
+    fn test_no_restricted_syntax_rule() {
+        RuleTester::run(
+            no_restricted_syntax_rule(),
+            rule_tests! {
+                valid => [
+                    // string format
+                    "doSomething();",
+                    { code => "var foo = 42;", options => ["(with_statement) @c"] },
+                    { code => "foo += 42;", options => ["(variable_declaration) @c", "(function_expression) @c"] },
+                    { code => "foo;", options => ["((identifier) @c (#eq? @c \"bar\"))"] },
+                    { code => "() => 5", environment => { ecma_version => 6 }, options => ["(arrow_function body: (statement_block)) @c"] },
+                    { code => "({ foo: 1, bar: 2 })", options => ["((property_identifier) @c (#eq? @c \"baz\"))"] },
+
+                    // object format
+                    { code => "var foo = 42;", options => [{ query => "(with_statement) @c" }] },
+                    { code => "foo += 42;", options => [{ query => "(variable_declaration) @c", message => "No variable declarations." }] },
+
+                    // underscore-prefixed captures are only used for matching
+                    { code => "foo(bar);", options => ["(call_expression function: (identifier) @_function (#eq? @_function \"bar\")) @c"] }
+                ],
+                invalid => [
+                    // string format
+                    {
+                        code => "var foo = 41;",
+                        options => ["(variable_declaration) @c"],
+                        errors => [{ message_id => "restricted_syntax", data => { message => "Using '(variable_declaration) @c' is not allowed." }, type => VariableDeclaration }]
+                    },
+                    {
+                        code => ";function lol(a) { return 42; }",
+                        options => ["(function_declaration) @c", "(empty_statement) @c"],
+                        errors => [
+                            { message_id => "restricted_syntax", data => { message => "Using '(empty_statement) @c' is not allowed." }, type => EmptyStatement },
+                            { message_id => "restricted_syntax", data => { message => "Using '(function_declaration) @c' is not allowed." }, type => FunctionDeclaration }
+                        ]
+                    },
+                    {
+                        code => "try { voila(); } catch (e) { oops(); }",
+                        options => ["(try_statement) @c", "(catch_clause) @c", "(call_expression) @c"],
+                        errors => [
+                            { message_id => "restricted_syntax", data => { message => "Using '(try_statement) @c' is not allowed." }, type => TryStatement },
+                            { message_id => "restricted_syntax", data => { message => "Using '(call_expression) @c' is not allowed." }, type => CallExpression },
+                            { message_id => "restricted_syntax", data => { message => "Using '(catch_clause) @c' is not allowed." }, type => CatchClause },
+                            { message_id => "restricted_syntax", data => { message => "Using '(call_expression) @c' is not allowed." }, type => CallExpression }
+                        ]
+                    },
+                    {
+                        code => "bar;",
+                        options => ["((identifier) @c (#eq? @c \"bar\"))"],
+                        errors => [{ message_id => "restricted_syntax", data => { message => "Using '((identifier) @c (#eq? @c \"bar\"))' is not allowed." }, type => Identifier }]
+                    },
+                    {
+                        code => "bar;",
+                        options => ["  (identifier) @c  "],
+                        errors => [{ message_id => "restricted_syntax", data => { message => "Using '(identifier) @c' is not allowed." }, type => Identifier }]
+                    },
+
+                    // object format
+                    {
+                        code => "var foo = 41;",
+                        options => [{ query => "(variable_declaration) @c" }],
+                        errors => [{ message_id => "restricted_syntax", data => { message => "Using '(variable_declaration) @c' is not allowed." }, type => VariableDeclaration }]
+                    },
+                    {
+                        code => "with (foo) { bar(); }",
+                        options => [{ query => "(with_statement) @c", message => "with is not allowed." }],
+                        errors => [{ message_id => "restricted_syntax", data => { message => "with is not allowed." }, type => WithStatement }]
+                    },
+                    {
+                        code => "do { bar(); } while (foo)",
+                        options => [
+                            { query => "(do_statement) @c", message => "no do-while" },
+                            "(call_expression) @c"
+                        ],
+                        errors => [
+                            { message_id => "restricted_syntax", data => { message => "no do-while" }, type => DoStatement },
+                            { message_id => "restricted_syntax", data => { message => "Using '(call_expression) @c' is not allowed." }, type => CallExpression }
+                        ]
+                    },
+                    {
+                        code => "class A {}",
+                        environment => { ecma_version => 6 },
+                        options => [{ query => "(class_declaration name: (identifier) @_name (#match? @_name \"^[A-Z]$\")) @c", message => "Single-letter class names aren't allowed." }],
+                        errors => [{ message_id => "restricted_syntax", data => { message => "Single-letter class names aren't allowed." }, type => ClassDeclaration }]
+                    },
+
+                    // underscore-prefixed captures are only used for matching
+                    {
+                        code => "foo(bar);",
+                        options => ["(call_expression function: (identifier) @_function (#eq? @_function \"foo\")) @c"],
+                        errors => [{
+                            message_id => "restricted_syntax",
+                            data => { message => "Using '(call_expression function: (identifier) @_function (#eq? @_function \"foo\")) @c' is not allowed." },
+                            type => CallExpression,
+                            line => 1,
+                            column => 1,
+                            end_column => 9
+                        }]
+                    },
+
+                    // every non-underscore-prefixed capture gets reported
+                    {
+                        code => "foo(bar);",
+                        options => [{ query => "(call_expression function: (identifier) @function arguments: (arguments (identifier) @argument))", message => "no" }],
+                        errors => [
+                            { message_id => "restricted_syntax", data => { message => "no" }, type => Identifier, column => 1 },
+                            { message_id => "restricted_syntax", data => { message => "no" }, type => Identifier, column => 5 }
+                        ]
+                    }
+                ]
+            },
+        )
+    }
+}
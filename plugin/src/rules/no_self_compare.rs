@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+use crate::{ast_helpers::NodeExtJs, utils::ast_utils};
+
+pub fn no_self_compare_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-self-compare",
+        languages => [Javascript],
+        messages => [
+            comparing_to_self => "Comparing to itself is potentially pointless.",
+        ],
+        listeners => [
+            r#"
+              (binary_expression
+                operator: [
+                  "==="
+                  "=="
+                  "!=="
+                  "!="
+                  ">"
+                  "<"
+                  ">="
+                  "<="
+                ]
+              ) @c
+            "# => |node, context| {
+                if ast_utils::equal_tokens(
+                    node.field("left").skip_parentheses(),
+                    node.field("right").skip_parentheses(),
+                    context,
+                ) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "comparing_to_self",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::BinaryExpression;
+
+    #[test]
+    fn test_no_self_compare_rule() {
+        RuleTester::run(
+            no_self_compare_rule(),
+            rule_tests! {
+                valid => [
+                    "if (x === y) { }",
+                    "if (1 === 2) { }",
+                    "y=x*x",
+                    "foo.bar.baz === foo.bar.qux",
+                    "x === (x + 1)",
+                    {
+                        code => "class C { #field; foo() { this.#field === this['#field']; } }",
+                        environment => { ecma_version => 2022 }
+                    },
+                    {
+                        code => "class C { #field; foo() { this['#field'] === this.#field; } }",
+                        environment => { ecma_version => 2022 }
+                    }
+                ],
+                invalid => [
+                    { code => "if (x === x) { }", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "if (x !== x) { }", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "if (x > x) { }", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "if ('x' > 'x') { }", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "do {} while (x === x)", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x === x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x !== x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x == x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x != x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x > x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x < x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x >= x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "x <= x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "(x) === x", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    { code => "foo.bar().baz.qux >= foo.bar ().baz .qux", errors => [{ message_id => "comparing_to_self", type => BinaryExpression }] },
+                    {
+                        code => "class C { #field; foo() { this.#field === this.#field; } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "comparing_to_self", type => BinaryExpression }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,354 @@
+use std::sync::Arc;
+
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::{
+        get_call_expression_arguments, get_method_definition_kind, MethodDefinitionKind, NodeExtJs,
+    },
+    kind::{
+        Arguments, ArrowFunction, CallExpression, Function, MethodDefinition, Object, Pair,
+        StatementBlock,
+    },
+    utils::ast_utils,
+    CodePathAnalyzer,
+};
+
+fn is_argument_of_global_method_call<'a>(
+    node: Node<'a>,
+    index: usize,
+    object_name: &str,
+    method_names: &[&str],
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let parent = node.next_non_parentheses_ancestor(context);
+    if parent.kind() != Arguments {
+        return false;
+    }
+    let call_expression = parent.parent().unwrap();
+    if call_expression.kind() != CallExpression {
+        return false;
+    }
+    if !get_call_expression_arguments(call_expression)
+        .and_then(|mut arguments| arguments.nth(index))
+        .matches(|argument| argument.skip_parentheses() == node)
+    {
+        return false;
+    }
+
+    let callee = call_expression.field("function").skip_parentheses();
+    method_names.iter().any(|&method_name| {
+        ast_utils::is_specific_member_access(callee, Some(object_name), Some(method_name), context)
+    }) && ast_utils::is_global_reference(callee.field("object").skip_parentheses(), context)
+}
+
+fn is_property_descriptor<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    // Object.defineProperty(o, "foo", { set(val) {} })
+    // Reflect.defineProperty(o, "foo", { set(val) {} })
+    if is_argument_of_global_method_call(node, 2, "Object", &["defineProperty"], context)
+        || is_argument_of_global_method_call(node, 2, "Reflect", &["defineProperty"], context)
+    {
+        return true;
+    }
+
+    // Object.defineProperties(o, { foo: { set(val) {} } })
+    // Object.create(proto, { foo: { set(val) {} } })
+    let parent = node.next_non_parentheses_ancestor(context);
+    parent.kind() == Pair
+        && parent.field("value").skip_parentheses() == node
+        && is_argument_of_global_method_call(
+            parent.parent().unwrap(),
+            1,
+            "Object",
+            &["defineProperties", "create"],
+            context,
+        )
+}
+
+fn is_setter<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    match node.kind() {
+        MethodDefinition => {
+            // Object literal setter, or class setter
+            if get_method_definition_kind(node, context) == MethodDefinitionKind::Set {
+                return true;
+            }
+
+            let parent = node.parent().unwrap();
+            parent.kind() == Object
+                && ast_utils::get_static_property_name(node, context).as_deref() == Some("set")
+                && is_property_descriptor(parent, context)
+        }
+        Function | ArrowFunction => {
+            let parent = node.next_non_parentheses_ancestor(context);
+            parent.kind() == Pair
+                && parent.field("value").skip_parentheses() == node
+                && ast_utils::get_static_property_name(parent, context).as_deref() == Some("set")
+                && is_property_descriptor(parent.parent().unwrap(), context)
+        }
+        _ => false,
+    }
+}
+
+pub fn no_setter_return_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-setter-return",
+        languages => [Javascript],
+        messages => [
+            returns_value => "Setter cannot return a value.",
+        ],
+        listeners => [
+            r#"
+              (return_statement) @c
+            "# => |node, context| {
+                if !node.has_non_comment_named_children(context) {
+                    return;
+                }
+
+                let code_path_analyzer = context.retrieve::<CodePathAnalyzer<'a>>();
+
+                let code_path = code_path_analyzer.get_innermost_code_path(node);
+                let code_path_root_node = code_path_analyzer.code_path_arena[code_path]
+                    .root_node(&code_path_analyzer.code_path_segment_arena);
+                if is_setter(code_path_root_node, context) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "returns_value",
+                    });
+                }
+            },
+            r#"
+              (arrow_function
+                body: (_) @body
+              ) @arrow_function
+            "# => |captures, context| {
+                let body = captures["body"];
+                if body.kind() == StatementBlock {
+                    return;
+                }
+
+                if is_setter(captures["arrow_function"], context) {
+                    context.report(violation! {
+                        node => body.skip_parentheses(),
+                        message_id => "returns_value",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{Number, ReturnStatement},
+    };
+
+    #[test]
+    fn test_no_setter_return_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_setter_return_rule(),
+            rule_tests! {
+                valid => [
+                    // not a setter
+                    "function foo() { return 1; }",
+                    "function set(val) { return 1; }",
+                    "var foo = function() { return 1; };",
+                    "var foo = function set() { return 1; };",
+                    "var set = function() { return 1; };",
+                    "var set = function set(val) { return 1; };",
+                    "var set = val => { return 1; };",
+                    "var set = val => 1;",
+
+                    // setters do not have effect on other functions (test function info tracking)
+                    "({ set a(val) { }}); function foo() { return 1; }",
+                    "({ set a(val) { }}); (function () { return 1; });",
+                    "({ set a(val) { }}); (() => { return 1; });",
+                    "({ set a(val) { }}); (() => 1);",
+
+                    // return without a value is allowed
+                    "({ set foo(val) { return; } })",
+                    "({ set foo(val) { if (val) { return; } } })",
+                    "class A { set foo(val) { return; } }",
+                    "(class { set foo(val) { if (val) { return; } else { return; } return; } })",
+                    "class A { set foo(val) { try {} catch(e) { return; } } }",
+                    "Object.defineProperty(foo, 'bar', { set(val) { return; } })",
+
+                    // not a setter
+                    "({ get foo() { return 1; } })",
+                    "({ get set() { return 1; } })",
+                    "({ set(val) { return 1; } })",
+                    "({ set: function(val) { return 1; } })",
+                    "({ foo: function set(val) { return 1; } })",
+                    "class A { constructor(val) { return 1; } }",
+                    "class A { get foo() { return 1; } }",
+                    "class A { get set() { return 1; } }",
+                    "class A { set(val) { return 1; } }",
+                    "class A { static set(val) { return 1; } }",
+                    "({ set: set = function set(val) { return 1; } } = {})",
+                    "({ set: set = (val) => 1 } = {})",
+
+                    // not returning from the setter
+                    "({ set foo(val) { function foo(val) { return 1; } } })",
+                    "({ set foo(val) { var foo = function(val) { return 1; } } })",
+                    "({ set foo(val) { var foo = (val) => { return 1; } } })",
+                    "({ set foo(val) { var foo = (val) => 1; } })",
+                    "class A { set foo(val) { function foo(val) { return 1; } } }",
+                    "Object.defineProperty(foo, 'bar', { set(val) { function foo() { return 1; } } })",
+
+                    // not a property descriptor
+                    "Object.defineProperty(foo, 'bar', { get(val) { return 1; } })",
+                    "Object.defineProperty(foo, 'bar', { foo(val) { return 1; } })",
+                    "Object.defineProperty(foo, 'bar', 'baz', { set(val) { return 1; } })",
+                    "Object.defineProperty(foo, { set(val) { return 1; } })",
+                    "Object.defineProperties(foo, { set(val) { return 1; } })",
+                    "Object.defineProperties(foo, { bar: { get(val) { return 1; } } })",
+                    "Object.create(null, { set(val) { return 1; } })",
+                    "Object.DefineProperty(foo, 'bar', { set(val) { return 1; } })",
+                    "Reflect.defineProperties(foo, 'bar', { set(val) { return 1; } })",
+                    "object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+                    "foo.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+                    "defineProperty(foo, 'bar', { set(val) { return 1; } })",
+
+                    // global object doesn't exist
+                    "let Object; Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+                    "function f(Reflect) { Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } }) }"
+                ],
+                invalid => [
+                    {
+                        code => "({ set a(val){ return val + 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 16 }]
+                    },
+                    {
+                        code => "({ set a(val) { return 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 17 }]
+                    },
+                    {
+                        code => "class A { set a(val) { return 1; } }",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 24 }]
+                    },
+                    {
+                        code => "class A { static set a(val) { return 1; } }",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "(class { set a(val) { return 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "({ set a(val) { return val; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "class A { set a(val) { return undefined; } }",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "(class { set a(val) { return null; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "({ set a(val) { return x + y; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "class A { set a(val) { return foo(); } }",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "(class { set a(val) { return this._a; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "({ set a(val) { if (foo) { return 1; }; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "class A { set a(val) { try { return 1; } catch(e) {} } }",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "(class { set a(val) { while (foo){ if (bar) break; else return 1; } } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "({ set a(val) { return 1; }, set b(val) { return 1; } })",
+                        errors => [
+                            { message_id => "returns_value", type => ReturnStatement, column => 17 },
+                            { message_id => "returns_value", type => ReturnStatement, column => 43 }
+                        ]
+                    },
+                    {
+                        code => "class A { set a(val) { return 1; } set b(val) { return 1; } }",
+                        errors => [
+                            { message_id => "returns_value", type => ReturnStatement, column => 24 },
+                            { message_id => "returns_value", type => ReturnStatement, column => 49 }
+                        ]
+                    },
+                    {
+                        code => "({ set a(val) { function b(val) { return 1; } return 2; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 47 }]
+                    },
+                    {
+                        code => "({ set a(val) { return 1; function b(val) { return 2; } } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 17 }]
+                    },
+                    {
+                        code => "({ set a(val) { (val) => { return 1; }; return 2; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 41 }]
+                    },
+
+                    // property descriptors
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 48 }]
+                    },
+                    {
+                        code => "Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 49 }]
+                    },
+                    {
+                        code => "Object.defineProperties(foo, { baz: { set(val) { return 1; } } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 50 }]
+                    },
+                    {
+                        code => "Object.create(null, { baz: { set(val) { return 1; } } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 41 }]
+                    },
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { set: function(val) { return 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 58 }]
+                    },
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { set: (val) => { return 1; } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement, column => 53 }]
+                    },
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { set: val => 1 })",
+                        errors => [{ message_id => "returns_value", type => Number, column => 49 }]
+                    },
+                    {
+                        code => "Reflect.defineProperty(foo, 'bar', { set: val => (1) })",
+                        errors => [{ message_id => "returns_value", type => Number, column => 51 }]
+                    },
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } else { return 1; } } })",
+                        errors => [{ message_id => "returns_value", type => ReturnStatement }]
+                    },
+                    {
+                        code => "Object.defineProperty(foo, 'bar', { set(val) { return 1; } }); Object.defineProperty(foo, 'bar', { set(val) { return 2; } })",
+                        errors => [
+                            { message_id => "returns_value", type => ReturnStatement, column => 48 },
+                            { message_id => "returns_value", type => ReturnStatement, column => 111 }
+                        ]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
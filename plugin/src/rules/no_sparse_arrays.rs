@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, Rule};
+
+use crate::ast_helpers::get_comma_separated_optional_non_comment_named_children;
+
+pub fn no_sparse_arrays_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-sparse-arrays",
+        languages => [Javascript],
+        messages => [
+            unexpected_sparse_array => "Unexpected comma in middle of array.",
+        ],
+        listeners => [
+            r#"
+              (array) @c
+            "# => |node, context| {
+                if get_comma_separated_optional_non_comment_named_children(node).any(|element| element.is_none()) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "unexpected_sparse_array",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::Array;
+
+    #[test]
+    fn test_no_sparse_arrays_rule() {
+        RuleTester::run(
+            no_sparse_arrays_rule(),
+            rule_tests! {
+                valid => [
+                    "var a = [ 1, 2, ]",
+                    "var a = [];",
+                    "var a = [1, 2, 3];",
+                    "var a = [1, /* comment */ 2];",
+                    "var [, a] = foo;"
+                ],
+                invalid => [
+                    {
+                        code => "var a = [,];",
+                        errors => [{ message_id => "unexpected_sparse_array", type => Array }]
+                    },
+                    {
+                        code => "var a = [ 1,, 2];",
+                        errors => [{ message_id => "unexpected_sparse_array", type => Array }]
+                    },
+                    {
+                        code => "var a = [ /* comment */ , 1];",
+                        errors => [{ message_id => "unexpected_sparse_array", type => Array }]
+                    },
+                    {
+                        code => "var a = [1, 2,,];",
+                        errors => [{ message_id => "unexpected_sparse_array", type => Array }]
+                    },
+                    {
+                        code => "var a = [[,], 1];",
+                        errors => [{ message_id => "unexpected_sparse_array", type => Array, column => 10 }]
+                    }
+                ]
+            },
+        )
+    }
+}
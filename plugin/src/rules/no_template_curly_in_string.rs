@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use squalid::regex;
+use tree_sitter_lint::{rule, violation, Rule};
+
+use crate::utils::ast_utils;
+
+pub fn no_template_curly_in_string_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-template-curly-in-string",
+        languages => [Javascript],
+        messages => [
+            unexpected_template_expression => "Unexpected template string expression.",
+        ],
+        listeners => [
+            r#"
+              (string) @c
+            "# => |node, context| {
+                if regex!(r#"\$\{[^}]+\}"#).is_match(
+                    &ast_utils::get_static_string_value(node, context).unwrap(),
+                ) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "unexpected_template_expression",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind;
+
+    #[test]
+    fn test_no_template_curly_in_string_rule() {
+        RuleTester::run(
+            no_template_curly_in_string_rule(),
+            rule_tests! {
+                valid => [
+                    "`Hello, ${name}`;",
+                    "templateFunction`Hello, ${name}`;",
+                    "`Hello, name`;",
+                    "'Hello, name';",
+                    "'Hello, ' + name;",
+                    "`Hello, ${index + 1}`",
+                    "`Hello, ${name + \" foo\"}`",
+                    "`Hello, ${name || \"foo\"}`",
+                    "`Hello, ${{foo: \"bar\"}.foo}`",
+                    "'$2'",
+                    "'${'",
+                    "'$}'",
+                    "'{foo}'",
+                    "'{foo: \"bar\"}'",
+                    "const number = 3",
+                    "'${}'"
+                ],
+                invalid => [
+                    {
+                        code => "'Hello, ${name}'",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "\"Hello, ${name}\"",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "'${greeting}, ${name}'",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "'Hello, ${index + 1}'",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "'Hello, ${name + \" foo\"}'",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "'Hello, ${name || \"foo\"}'",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "'Hello, ${{foo: \"bar\"}.foo}'",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "'Hello, \\${name}'",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    },
+                    {
+                        code => "foo({ 'bar${baz}': 1 })",
+                        errors => [{ message_id => "unexpected_template_expression", type => kind::String }]
+                    }
+                ]
+            },
+        )
+    }
+}
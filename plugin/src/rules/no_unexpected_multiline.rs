@@ -0,0 +1,351 @@
+use std::sync::Arc;
+
+use squalid::{regex, OptionExt};
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::get_call_expression_arguments,
+    kind::{Identifier, OptionalChain},
+};
+
+fn check_for_break_after<'a>(
+    node: Node<'a>,
+    message_id: &'static str,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let open_paren = context.get_token_after(node, Option::<fn(Node) -> bool>::None);
+    let token_before = context.get_token_before(open_paren, Option::<fn(Node) -> bool>::None);
+
+    if open_paren.start_position().row != token_before.end_position().row {
+        context.report(violation! {
+            node => node,
+            range => open_paren.range(),
+            message_id => message_id,
+        });
+    }
+}
+
+pub fn no_unexpected_multiline_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unexpected-multiline",
+        languages => [Javascript],
+        messages => [
+            function => "Unexpected newline between function and ( of function call.",
+            property => "Unexpected newline between object and [ of property access.",
+            tagged_template => "Unexpected newline between template tag and template literal.",
+            division => "Unexpected newline between numerator and division operator.",
+        ],
+        listeners => [
+            r#"
+              (subscript_expression) @c
+            "# => |node, context| {
+                if node.has_child_of_kind(OptionalChain) {
+                    return;
+                }
+
+                check_for_break_after(node.field("object"), "property", context);
+            },
+            r#"
+              (call_expression
+                arguments: (template_string) @quasi
+              )
+            "# => |captures, context| {
+                let quasi = captures["quasi"];
+                let token_before = context.get_token_before(quasi, Option::<fn(Node) -> bool>::None);
+                if token_before.end_position().row != quasi.start_position().row {
+                    context.report(violation! {
+                        node => quasi.parent().unwrap(),
+                        range => context.get_first_token(quasi, Option::<fn(Node) -> bool>::None).range(),
+                        message_id => "tagged_template",
+                    });
+                }
+            },
+            r#"
+              (call_expression) @c
+            "# => |node, context| {
+                if !get_call_expression_arguments(node)
+                    .matches(|mut arguments| arguments.next().is_some())
+                    || node.has_child_of_kind(OptionalChain)
+                {
+                    return;
+                }
+
+                check_for_break_after(node.field("function"), "function", context);
+            },
+            r#"
+              (binary_expression
+                left: (binary_expression
+                  operator: "/"
+                ) @numerator
+                operator: "/" @second_slash
+              )
+            "# => |captures, context| {
+                let token_after_operator = context.get_token_after(
+                    captures["second_slash"],
+                    Option::<fn(Node) -> bool>::None,
+                );
+                if token_after_operator.kind() == Identifier
+                    && regex!(r#"^[gimsuyvd]+$"#).is_match(&token_after_operator.text(context))
+                    && captures["second_slash"].end_byte() == token_after_operator.start_byte()
+                {
+                    check_for_break_after(captures["numerator"].field("left"), "division", context);
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_no_unexpected_multiline_rule() {
+        RuleTester::run(
+            no_unexpected_multiline_rule(),
+            rule_tests! {
+                valid => [
+                    "(x || y).aFunction()",
+                    "[a, b, c].forEach(doSomething)",
+                    "var a = b;\n(x || y).doSomething()",
+                    "var a = b\n;(x || y).doSomething()",
+                    "var a = b\nvoid (x || y).doSomething()",
+                    "var a = b;\n[1, 2, 3].forEach(console.log)",
+                    "var a = b\nvoid [1, 2, 3].forEach(console.log)",
+                    "\"abc\\\n(123)\"",
+                    "var a = (\n(123)\n)",
+                    "f(\n(x)\n)",
+                    "(\nfunction () {}\n)[1]",
+                    "foo()\n()",
+                    {
+                        code => "let x = function() {};\n   `hello`",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "let x = function() {}\nx `hello`",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "String.raw `Hi\n${2+3}!`;",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "x\n.y\nz `Valid Test Case`",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "f(x\n)`Valid Test Case`",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "x.\ny `Valid Test Case`",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "(x\n)`Valid Test Case`",
+                        environment => { ecma_version => 6 }
+                    },
+                    "\n            foo\n            / bar /2\n        ",
+                    "\n            foo\n            / bar / mgy\n        ",
+                    "\n            foo\n            / bar /\n            gym\n        ",
+                    "\n            foo\n            / bar\n            / ygm\n        ",
+                    "\n            foo\n            / bar /GYM\n        ",
+                    "\n            foo\n            / bar / baz\n        ",
+                    "foo /bar/g",
+                    "\n            foo\n            /denominator/\n            2\n        ",
+                    "\n            foo\n            / /abc/\n        ",
+                    "\n            5 / (5\n            / 5)\n        ",
+
+                    // optional chaining
+                    {
+                        code => "var a = b\n  ?.(x || y).doSomething()",
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "var a = b\n  ?.[a, b, c].forEach(doSomething)",
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "var a = b?.\n  (x || y).doSomething()",
+                        environment => { ecma_version => 2020 }
+                    },
+                    {
+                        code => "var a = b?.\n  [a, b, c].forEach(doSomething)",
+                        environment => { ecma_version => 2020 }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "var a = b\n(x || y).doSomething()",
+                        errors => [{
+                            message_id => "function",
+                            line => 2,
+                            column => 1,
+                            end_line => 2,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "var a = (a || b)\n(x || y).doSomething()",
+                        errors => [{
+                            message_id => "function",
+                            line => 2,
+                            column => 1,
+                            end_line => 2,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "var a = (a || b)\n(x).doSomething()",
+                        errors => [{
+                            message_id => "function",
+                            line => 2,
+                            column => 1,
+                            end_line => 2,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "var a = b\n[a, b, c].forEach(doSomething)",
+                        errors => [{
+                            message_id => "property",
+                            line => 2,
+                            column => 1,
+                            end_line => 2,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "var a = b\n    (x || y).doSomething()",
+                        errors => [{
+                            message_id => "function",
+                            line => 2,
+                            column => 5,
+                            end_line => 2,
+                            end_column => 6
+                        }]
+                    },
+                    {
+                        code => "var a = b\n  [a, b, c].forEach(doSomething)",
+                        errors => [{
+                            message_id => "property",
+                            line => 2,
+                            column => 3,
+                            end_line => 2,
+                            end_column => 4
+                        }]
+                    },
+                    {
+                        code => "let x = function() {}\n `hello`",
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "tagged_template",
+                            line => 2,
+                            column => 2,
+                            end_line => 2,
+                            end_column => 3
+                        }]
+                    },
+                    {
+                        code => "let x = function() {}\nx\n`hello`",
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "tagged_template",
+                            line => 3,
+                            column => 1,
+                            end_line => 3,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "x\n.y\nz\n`Invalid Test Case`",
+                        environment => { ecma_version => 6 },
+                        errors => [{
+                            message_id => "tagged_template",
+                            line => 4,
+                            column => 1,
+                            end_line => 4,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "\n                foo\n                / bar /gym\n            ",
+                        errors => [{
+                            message_id => "division",
+                            line => 3,
+                            column => 17,
+                            end_line => 3,
+                            end_column => 18
+                        }]
+                    },
+                    {
+                        code => "\n                foo\n                / bar /g\n            ",
+                        errors => [{
+                            message_id => "division",
+                            line => 3,
+                            column => 17,
+                            end_line => 3,
+                            end_column => 18
+                        }]
+                    },
+                    {
+                        code => "\n                foo\n                / bar /g.test(baz)\n            ",
+                        errors => [{
+                            message_id => "division",
+                            line => 3,
+                            column => 17,
+                            end_line => 3,
+                            end_column => 18
+                        }]
+                    },
+                    {
+                        code => "\n                foo\n                /bar/gimuygimuygimuy.test(baz)\n            ",
+                        errors => [{
+                            message_id => "division",
+                            line => 3,
+                            column => 17,
+                            end_line => 3,
+                            end_column => 18
+                        }]
+                    },
+                    {
+                        code => "\n                foo\n                /bar/s.test(baz)\n            ",
+                        errors => [{
+                            message_id => "division",
+                            line => 3,
+                            column => 17,
+                            end_line => 3,
+                            end_column => 18
+                        }]
+                    },
+
+                    // Class fields
+                    {
+                        code => "class C { field1 = obj\n[field2]; }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "property",
+                            line => 2,
+                            column => 1,
+                            end_line => 2,
+                            end_column => 2
+                        }]
+                    },
+                    {
+                        code => "class C { field1 = function() {}\n[field2]; }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{
+                            message_id => "property",
+                            line => 2,
+                            column => 1,
+                            end_line => 2,
+                            end_column => 2
+                        }]
+                    }
+                ]
+            },
+        )
+    }
+}
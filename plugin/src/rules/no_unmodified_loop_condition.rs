@@ -0,0 +1,372 @@
+use std::{collections::HashMap, sync::Arc};
+
+use itertools::Itertools;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    kind::{
+        is_statement_kind, ArrowFunction, BinaryExpression, CallExpression, Class, DoStatement,
+        ExpressionStatement, ForStatement, Function, FunctionDeclaration, GeneratorFunction,
+        GeneratorFunctionDeclaration, MemberExpression, NewExpression, SubscriptExpression,
+        TernaryExpression, VariableDeclaration, WhileStatement, YieldExpression,
+    },
+    scope::{Reference, ScopeManager, Variable, VariableType},
+    utils::ast_utils,
+};
+
+type NodeId = usize;
+
+struct LoopCondition<'a> {
+    identifier: Node<'a>,
+    group: Option<Node<'a>>,
+    loop_: Node<'a>,
+    modified: bool,
+}
+
+impl<'a> LoopCondition<'a> {
+    fn is_in_loop(&self, node: Node) -> bool {
+        if self.loop_.kind() == ForStatement {
+            if let Some(initializer) = self.loop_.child_by_field_name("initializer") {
+                if initializer.start_byte() <= node.start_byte()
+                    && node.end_byte() <= initializer.end_byte()
+                {
+                    return false;
+                }
+            }
+        }
+        self.loop_.start_byte() <= node.start_byte() && node.end_byte() <= self.loop_.end_byte()
+    }
+}
+
+fn is_sentinel_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        CallExpression
+            | NewExpression
+            | MemberExpression
+            | SubscriptExpression
+            | YieldExpression
+            | Class
+            | Function
+            | ArrowFunction
+            | GeneratorFunction
+    ) || is_statement_kind(kind)
+}
+
+fn is_dynamic_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        CallExpression | NewExpression | MemberExpression | SubscriptExpression | YieldExpression
+    )
+}
+
+fn is_group_kind(node: Node) -> bool {
+    match node.kind() {
+        TernaryExpression => true,
+        BinaryExpression => !matches!(node.field("operator").kind(), "&&" | "||" | "??"),
+        _ => false,
+    }
+}
+
+fn has_dynamic_expressions(root: Node) -> bool {
+    let mut cursor = root.walk();
+    let ret = root
+        .named_children(&mut cursor)
+        .any(|child| match child.kind() {
+            ArrowFunction | Class | Function | GeneratorFunction => false,
+            kind if is_dynamic_kind(kind) => true,
+            _ => has_dynamic_expressions(child),
+        });
+    ret
+}
+
+fn is_write_reference(reference: &Reference) -> bool {
+    if reference.init() == Some(true) {
+        let Some(resolved) = reference.resolved() else {
+            return false;
+        };
+        let Some(def) = resolved.defs().next() else {
+            return false;
+        };
+        if def.type_() != VariableType::Variable
+            || !def
+                .parent()
+                .matches(|parent| parent.kind() == VariableDeclaration)
+        {
+            return false;
+        }
+    }
+    reference.is_write()
+}
+
+fn to_loop_condition<'a>(reference: &Reference<'a, '_>) -> Option<LoopCondition<'a>> {
+    if reference.init() == Some(true) {
+        return None;
+    }
+
+    let mut group: Option<Node<'a>> = Default::default();
+    let mut child = reference.identifier();
+    let mut node = child.parent();
+
+    while let Some(current) = node {
+        if is_sentinel_kind(current.kind()) {
+            let (loop_, test) = match current.parent() {
+                Some(parent)
+                    if current.kind() == ExpressionStatement && parent.kind() == ForStatement =>
+                {
+                    (parent, current)
+                }
+                _ => (current, child),
+            };
+            if matches!(loop_.kind(), WhileStatement | DoStatement | ForStatement)
+                && loop_.child_by_field_name("condition") == Some(test)
+            {
+                return Some(LoopCondition {
+                    identifier: reference.identifier(),
+                    group,
+                    loop_,
+                    modified: false,
+                });
+            }
+            break;
+        }
+
+        if is_group_kind(current) {
+            if has_dynamic_expressions(current) {
+                break;
+            }
+            group = Some(current);
+        }
+
+        child = current;
+        node = current.parent();
+    }
+
+    None
+}
+
+fn get_enclose_function_declaration(reference: &Reference) -> Option<Node<'_>> {
+    let mut node = reference.identifier().parent();
+
+    while let Some(current) = node {
+        if matches!(
+            current.kind(),
+            FunctionDeclaration | GeneratorFunctionDeclaration
+        ) {
+            return Some(current);
+        }
+        node = current.parent();
+    }
+
+    None
+}
+
+fn update_modified_flag<'a>(
+    conditions: &mut [LoopCondition<'a>],
+    modifiers: &[Reference<'a, '_>],
+    context: &QueryMatchContext<'a, '_>,
+) {
+    for condition in conditions {
+        for modifier in modifiers {
+            if condition.modified {
+                break;
+            }
+
+            condition.modified = condition.is_in_loop(modifier.identifier())
+                || get_enclose_function_declaration(modifier)
+                    .and_then(|func_node| {
+                        ast_utils::get_variable_by_name(
+                            modifier.from().upper(),
+                            &func_node.field("name").text(context),
+                        )
+                    })
+                    .matches(|func_var| {
+                        func_var
+                            .references()
+                            .any(|reference| condition.is_in_loop(reference.identifier()))
+                    });
+        }
+    }
+}
+
+fn check_references<'a>(
+    variable: &Variable<'a, '_>,
+    group_map: &mut HashMap<NodeId, Vec<LoopCondition<'a>>>,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let mut conditions = variable
+        .references()
+        .filter_map(|reference| to_loop_condition(&reference))
+        .collect::<Vec<_>>();
+
+    if conditions.is_empty() {
+        return;
+    }
+
+    let modifiers = variable
+        .references()
+        .filter(is_write_reference)
+        .collect::<Vec<_>>();
+
+    if !modifiers.is_empty() {
+        update_modified_flag(&mut conditions, &modifiers, context);
+    }
+
+    for condition in conditions {
+        match condition.group {
+            Some(group) => group_map.entry(group.id()).or_default().push(condition),
+            None => {
+                if !condition.modified {
+                    report(&condition, context);
+                }
+            }
+        }
+    }
+}
+
+fn report<'a>(condition: &LoopCondition<'a>, context: &QueryMatchContext<'a, '_>) {
+    let node = condition.identifier;
+
+    context.report(violation! {
+        node => node,
+        message_id => "loop_condition_not_modified",
+        data => {
+            name => node.text(context),
+        },
+    });
+}
+
+pub fn no_unmodified_loop_condition_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unmodified-loop-condition",
+        languages => [Javascript],
+        messages => [
+            loop_condition_not_modified => "'{{name}}' is not modified in this loop.",
+        ],
+        listeners => [
+            "program:exit" => |node, context| {
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let mut group_map: HashMap<NodeId, Vec<LoopCondition<'a>>> = Default::default();
+
+                for scope in scope_manager.scopes() {
+                    for variable in scope.variables() {
+                        check_references(&variable, &mut group_map, context);
+                    }
+                }
+
+                // Report the groups in source order rather than in (unspecified)
+                // `HashMap` iteration order
+                let groups = group_map.into_values().sorted_by_key(|conditions| {
+                    let group = conditions[0].group.unwrap();
+                    (group.start_byte(), group.end_byte())
+                });
+
+                for conditions in groups {
+                    if conditions.iter().all(|condition| !condition.modified) {
+                        for condition in &conditions {
+                            report(condition, context);
+                        }
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::Identifier};
+
+    #[test]
+    fn test_no_unmodified_loop_condition_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_unmodified_loop_condition_rule(),
+            rule_tests! {
+                valid => [
+                    "var foo = 0; while (foo) { ++foo; }",
+                    "var foo = 0; while (foo) { foo += 1; }",
+                    "var foo = 0; while (foo++) { }",
+                    "var foo = 0; while (foo = next()) { }",
+                    "var foo = 0; while (ok(foo)) { }",
+                    "var foo = 0, bar = 0; while (++foo < bar) { }",
+                    "var foo = 0, obj = {}; while (foo === obj.bar) { }",
+                    "var foo = 0, f = {}, bar = {}; while (foo === f(bar)) { }",
+                    "var foo = 0, f = {}; while (foo === f()) { }",
+                    "var foo = 0, tag = 0; while (foo === tag`abc`) { }",
+                    "function* foo() { var foo = 0; while (yield foo) { } }",
+                    "function* foo() { var foo = 0; while (foo === (yield)) { } }",
+                    "var foo = 0; while (foo.ok) { }",
+                    "var foo = 0; while (foo) { update(); } function update() { ++foo; }",
+                    "var foo = 0, bar = 9; while (foo < bar) { foo += 1; }",
+                    "var foo = 0, bar = 1, baz = 2; while (foo ? bar : baz) { foo += 1; }",
+                    "var foo = 0, bar = 0; while (foo && bar) { ++foo; ++bar; }",
+                    "var foo = 0, bar = 0; while (foo || bar) { ++foo; ++bar; }",
+                    "var foo = 0; do { ++foo; } while (foo);",
+                    "var foo = 0; do { } while (foo++);",
+                    "for (var foo = 0; foo; ++foo) { }",
+                    "for (var foo = 0; foo;) { ++foo }",
+                    "var foo = 0, bar = 0; for (bar; foo;) { ++foo }",
+                    "var foo; if (foo) { }",
+                    "var a = [1, 2, 3]; var len = a.length; for (var i = 0; i < len - 1; i++) {}"
+                ],
+                invalid => [
+                    {
+                        code => "var foo = 0; while (foo) { } foo = 1;",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    },
+                    {
+                        code => "var foo = 0; while (!foo) { } foo = 1;",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    },
+                    {
+                        code => "var foo = 0; while (foo != null) { } foo = 1;",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    },
+                    {
+                        code => "var foo = 0, bar = 9; while (foo < bar) { } foo = 1;",
+                        errors => [
+                            { message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier },
+                            { message_id => "loop_condition_not_modified", data => { name => "bar" }, type => Identifier }
+                        ]
+                    },
+                    {
+                        code => "var foo = 0, bar = 0; while (foo && bar) { ++bar; } foo = 1;",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    },
+                    {
+                        code => "var foo = 0, bar = 0; while (foo && bar) { ++foo; } foo = 1;",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "bar" }, type => Identifier }]
+                    },
+                    {
+                        code => "var a, b, c; while (a < c && b < c) { ++a; } foo = 1;",
+                        errors => [
+                            { message_id => "loop_condition_not_modified", data => { name => "b" }, type => Identifier },
+                            { message_id => "loop_condition_not_modified", data => { name => "c" }, type => Identifier }
+                        ]
+                    },
+                    {
+                        code => "var foo = 0; while (foo ? 1 : 0) { } foo = 1;",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    },
+                    {
+                        code => "var foo = 0; while (foo) { update(); } function update(foo) { ++foo; }",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    },
+                    {
+                        code => "var foo; do { } while (foo);",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    },
+                    {
+                        code => "for (var foo = 0; foo < 10; ) { } foo = 1;",
+                        errors => [{ message_id => "loop_condition_not_modified", data => { name => "foo" }, type => Identifier }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,326 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{is_logical_expression, is_tagged_template_expression, maybe_get_directive},
+    kind::{
+        self, Array, ArrowFunction, BinaryExpression, CallExpression, Class, False, ForStatement,
+        Function, GeneratorFunction, Identifier, JsxElement, JsxSelfClosingElement,
+        MemberExpression, MetaProperty, Null, Number, Object, ParenthesizedExpression, Program,
+        Regex, SequenceExpression, StatementBlock, SubscriptExpression, TemplateString,
+        TernaryExpression, This, True, UnaryExpression, Undefined,
+    },
+    utils::ast_utils,
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_short_circuit: bool,
+    allow_ternary: bool,
+    allow_tagged_templates: bool,
+    enforce_for_jsx: bool,
+}
+
+fn looks_like_directive(node: Node, context: &QueryMatchContext) -> bool {
+    maybe_get_directive(node, context).is_some()
+}
+
+fn is_for_statement_header(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    parent.kind() == ForStatement && parent.field("body") != node
+}
+
+fn is_directive(node: Node, context: &QueryMatchContext) -> bool {
+    let parent = node.parent().unwrap();
+    let is_directive_prologue_container = parent.kind() == Program
+        || parent.kind() == StatementBlock && ast_utils::is_function(parent.parent().unwrap());
+    if !is_directive_prologue_container {
+        return false;
+    }
+
+    parent
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .take_while(|&statement| looks_like_directive(statement, context))
+        .any(|statement| statement == node)
+}
+
+pub fn no_unused_expressions_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unused-expressions",
+        languages => [Javascript],
+        messages => [
+            unused_expression => "Expected an assignment or function call and instead saw an expression.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_short_circuit: bool = options.allow_short_circuit,
+            allow_ternary: bool = options.allow_ternary,
+            allow_tagged_templates: bool = options.allow_tagged_templates,
+            enforce_for_jsx: bool = options.enforce_for_jsx,
+        },
+        methods => {
+            fn is_disallowed(&self, node: Node) -> bool {
+                match node.kind() {
+                    ParenthesizedExpression => self.is_disallowed(node.first_non_comment_named_child(SupportedLanguage::Javascript)),
+                    TernaryExpression => {
+                        if self.allow_ternary {
+                            return self.is_disallowed(node.field("consequence"))
+                                || self.is_disallowed(node.field("alternative"));
+                        }
+                        true
+                    }
+                    BinaryExpression if is_logical_expression(node) => {
+                        if self.allow_short_circuit {
+                            return self.is_disallowed(node.field("right"));
+                        }
+                        true
+                    }
+                    CallExpression => {
+                        is_tagged_template_expression(node) && !self.allow_tagged_templates
+                    }
+                    JsxElement | JsxSelfClosingElement => self.enforce_for_jsx,
+                    UnaryExpression => !matches!(
+                        node.field("operator").kind(),
+                        "void" | "delete"
+                    ),
+                    Array | ArrowFunction | BinaryExpression | Class | Function | GeneratorFunction
+                    | Identifier | Undefined | kind::String | Number | Regex | True | False | Null
+                    | MemberExpression | SubscriptExpression | MetaProperty | Object
+                    | SequenceExpression | TemplateString | This => true,
+                    _ => false,
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (expression_statement) @c
+            "# => |node, context| {
+                if is_for_statement_header(node) {
+                    return;
+                }
+
+                if self.is_disallowed(node.first_non_comment_named_child(SupportedLanguage::Javascript))
+                    && !is_directive(node, context)
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "unused_expression",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::ExpressionStatement;
+
+    #[test]
+    fn test_no_unused_expressions_rule() {
+        RuleTester::run(
+            no_unused_expressions_rule(),
+            rule_tests! {
+                valid => [
+                    "function f(){}",
+                    "a = b",
+                    "new a",
+                    "{}",
+                    "f(); g()",
+                    "i++",
+                    "a()",
+                    "for (i; i < 10; i) {}",
+                    { code => "a && a()", options => [{ allow_short_circuit => true }] },
+                    { code => "a() || (b = c)", options => [{ allow_short_circuit => true }] },
+                    { code => "a ? b() : c()", options => [{ allow_ternary => true }] },
+                    { code => "a ? b() || (c = d) : e()", options => [{ allow_short_circuit => true, allow_ternary => true }] },
+                    "delete foo.bar",
+                    "void new C",
+                    "\"use strict\";",
+                    "\"directive one\"; \"directive two\"; f();",
+                    "function foo() {\"use strict\"; return true; }",
+                    { code => "var foo = () => {\"use strict\"; return true; }", environment => { ecma_version => 6 } },
+                    "function foo() {\"directive one\"; \"directive two\"; f(); }",
+                    "function foo() { var foo = \"use strict\"; return true; }",
+                    {
+                        code => "function* foo(){ yield 0; }",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "async function foo() { await 5; }",
+                        environment => { ecma_version => 8 }
+                    },
+                    {
+                        code => "async function foo() { await foo.bar; }",
+                        environment => { ecma_version => 8 }
+                    },
+                    {
+                        code => "async function foo() { bar && await baz; }",
+                        options => [{ allow_short_circuit => true }],
+                        environment => { ecma_version => 8 }
+                    },
+                    {
+                        code => "async function foo() { foo ? await bar : await baz; }",
+                        options => [{ allow_ternary => true }],
+                        environment => { ecma_version => 8 }
+                    },
+                    {
+                        code => "tag`tagged template literal`",
+                        options => [{ allow_tagged_templates => true }],
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "shouldNotBeAffectedByAllowTemplateTagsOption()",
+                        options => [{ allow_tagged_templates => true }],
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "import(\"foo\")",
+                        environment => { ecma_version => 11 }
+                    },
+                    {
+                        code => "func?.(\"foo\")",
+                        environment => { ecma_version => 11 }
+                    },
+                    {
+                        code => "obj?.foo(\"bar\")",
+                        environment => { ecma_version => 11 }
+                    },
+
+                    // JSX
+                    {
+                        code => "<div />",
+                        /*environment => { ecma_features => { jsx => true } }*/
+                    },
+                    {
+                        code => "<></>",
+                        /*environment => { ecma_features => { jsx => true } }*/
+                    },
+                    {
+                        code => "var partial = <div />",
+                        /*environment => { ecma_features => { jsx => true } }*/
+                    },
+                    {
+                        code => "var partial = <div />",
+                        options => [{ enforce_for_jsx => true }],
+                        /*environment => { ecma_features => { jsx => true } }*/
+                    },
+                    {
+                        code => "var partial = <></>",
+                        options => [{ enforce_for_jsx => true }],
+                        /*environment => { ecma_features => { jsx => true } }*/
+                    }
+                ],
+                invalid => [
+                    { code => "0", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "f(), 0", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "{0}", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "[]", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a && b();", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a() || false", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a || (b = c)", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a ? b() || (c = d) : e", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    {
+                        code => "`untagged template literal`",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+                    {
+                        code => "tag`tagged template literal`",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+                    { code => "a && b()", options => [{ allow_ternary => true }], errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a ? b() : c()", options => [{ allow_short_circuit => true }], errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a || b", options => [{ allow_short_circuit => true }], errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a() && b", options => [{ allow_short_circuit => true }], errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a ? b : 0", options => [{ allow_ternary => true }], errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "a ? b : c()", options => [{ allow_ternary => true }], errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "foo.bar;", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "!a", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "+a", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "\"directive one\"; f(); \"directive two\";", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "function foo() {\"directive one\"; f(); \"directive two\"; }", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "if (0) { \"not a directive\"; f(); }", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "function foo() { var foo = true; \"use strict\"; }", errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    { code => "var foo = () => { var foo = true; \"use strict\"; }", environment => { ecma_version => 6 }, errors => [{ message_id => "unused_expression", type => ExpressionStatement }] },
+                    {
+                        code => "`untagged template literal`",
+                        options => [{ allow_tagged_templates => true }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unused_expression" }]
+                    },
+                    {
+                        code => "`untagged template literal`",
+                        options => [{ allow_tagged_templates => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unused_expression" }]
+                    },
+                    {
+                        code => "tag`tagged template literal`",
+                        options => [{ allow_tagged_templates => false }],
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "unused_expression" }]
+                    },
+
+                    // Optional chaining
+                    {
+                        code => "obj?.foo",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+                    {
+                        code => "obj?.foo.bar",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+                    {
+                        code => "obj?.foo().bar",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+
+                    // JSX
+                    {
+                        code => "<div />",
+                        options => [{ enforce_for_jsx => true }],
+                        /*environment => { ecma_features => { jsx => true } }*/,
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+                    {
+                        code => "<></>",
+                        options => [{ enforce_for_jsx => true }],
+                        /*environment => { ecma_features => { jsx => true } }*/,
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+
+                    // class static blocks do not have directive prologues
+                    {
+                        code => "class C { static { 'use strict'; } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [{ message_id => "unused_expression", type => ExpressionStatement }]
+                    },
+                    {
+                        code => "class C { static { \n'foo'\n'bar'\n } }",
+                        environment => { ecma_version => 2022 },
+                        errors => [
+                            { message_id => "unused_expression", type => ExpressionStatement, line => 2 },
+                            { message_id => "unused_expression", type => ExpressionStatement, line => 3 }
+                        ]
+                    }
+                ]
+            },
+        )
+    }
+}
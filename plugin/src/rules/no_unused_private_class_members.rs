@@ -0,0 +1,328 @@
+use std::{borrow::Cow, sync::Arc};
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{
+        ArrayPattern, AssignmentExpression, AssignmentPattern, AugmentedAssignmentExpression,
+        ExpressionStatement, FieldDefinition, ForInStatement, MethodDefinition, PairPattern,
+        PrivatePropertyIdentifier, RestPattern, UpdateExpression,
+    },
+};
+
+struct PrivateMember<'a> {
+    name: Cow<'a, str>,
+    declared_node: Node<'a>,
+    key: Node<'a>,
+    is_accessor: bool,
+    is_used: bool,
+}
+
+fn is_write_only_assignment(private_identifier_node: Node) -> bool {
+    let member_access = private_identifier_node.parent().unwrap();
+    let parent_statement = member_access.parent().unwrap();
+
+    match parent_statement.kind() {
+        AssignmentExpression | ForInStatement | AssignmentPattern => {
+            // It is a write-only usage, since we still allow usages on the right for reads
+            parent_statement.field("left") == member_access
+        }
+        AugmentedAssignmentExpression => {
+            // For any other operator (such as '+=') we still consider it a read operation.
+            // However, if the read operation is "discarded" in an empty statement, then
+            // we consider it write only.
+            parent_statement.field("left") == member_access
+                && parent_statement.parent().unwrap().kind() == ExpressionStatement
+        }
+        _ => false,
+    }
+}
+
+pub fn no_unused_private_class_members_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unused-private-class-members",
+        languages => [Javascript],
+        messages => [
+            unused_private_class_member => "'{{class_member_name}}' is defined but never used.",
+        ],
+        state => {
+            [per-file-run]
+            tracked_classes: Vec<Vec<PrivateMember<'a>>>,
+        },
+        methods => {
+            fn find_member(&mut self, name: &str) -> Option<&mut PrivateMember<'a>> {
+                self.tracked_classes
+                    .iter_mut()
+                    .rev()
+                    .find_map(|class_members| {
+                        class_members.iter_mut().find(|member| member.name == name)
+                    })
+            }
+
+            fn collect_private_members(
+                &self,
+                class_body_node: Node<'a>,
+                context: &QueryMatchContext<'a, '_>,
+            ) -> Vec<PrivateMember<'a>> {
+                class_body_node
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .filter_map(|body_member| {
+                        let key = match body_member.kind() {
+                            FieldDefinition => body_member.field("property"),
+                            MethodDefinition => body_member.field("name"),
+                            _ => return None,
+                        };
+                        (key.kind() == PrivatePropertyIdentifier).then(|| PrivateMember {
+                            name: key.text(context),
+                            declared_node: body_member,
+                            key,
+                            is_accessor: body_member.kind() == MethodDefinition
+                                && matches!(
+                                    get_method_definition_kind(body_member, context),
+                                    MethodDefinitionKind::Get | MethodDefinitionKind::Set
+                                ),
+                            is_used: false,
+                        })
+                    })
+                    .collect()
+            }
+        },
+        listeners => [
+            r#"
+              (class_body) @c
+            "# => |node, context| {
+                let private_members = self.collect_private_members(node, context);
+                self.tracked_classes.push(private_members);
+            },
+            r#"
+              (private_property_identifier) @c
+            "# => |node, context| {
+                let name = node.text(context);
+                let Some(member_definition) = self.find_member(&name) else {
+                    return;
+                };
+
+                // In case any other usage was already detected, we can short circuit the logic here.
+                if member_definition.is_used {
+                    return;
+                }
+
+                let parent = node.parent().unwrap();
+
+                // The definition of the class member itself
+                if matches!(parent.kind(), FieldDefinition | MethodDefinition) {
+                    return;
+                }
+
+                // Any usage of an accessor is considered a read, as the getter/setter can have
+                // side-effects in its definition.
+                if member_definition.is_accessor {
+                    member_definition.is_used = true;
+                    return;
+                }
+
+                // Any assignments to this member, except for assignments that also read
+                if is_write_only_assignment(node) {
+                    return;
+                }
+
+                let wrapping_expression = parent.parent().unwrap();
+
+                match wrapping_expression.kind() {
+                    // A statement which only increments (`this.#x++;`)
+                    UpdateExpression
+                        if wrapping_expression.parent().unwrap().kind() == ExpressionStatement =>
+                    {
+                        return;
+                    }
+                    // ({ x: this.#usedInDestructuring } = bar);
+                    //
+                    // But should treat the following as a read:
+                    // ({ [this.#x]: a } = foo);
+                    PairPattern if wrapping_expression.field("value") == parent => {
+                        return;
+                    }
+                    // [...this.#unusedInRestPattern] = bar;
+                    // [this.#unusedInAssignmentPattern] = bar;
+                    RestPattern | ArrayPattern => {
+                        return;
+                    }
+                    _ => (),
+                }
+
+                member_definition.is_used = true;
+            },
+            r#"
+              class_body:exit
+            "# => |node, context| {
+                let unused_private_members = self.tracked_classes.pop().unwrap();
+
+                for member in unused_private_members {
+                    if member.is_used {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => member.declared_node,
+                        range => member.key.range(),
+                        message_id => "unused_private_class_member",
+                        data => {
+                            class_member_name => member.name,
+                        }
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_no_unused_private_class_members_rule() {
+        RuleTester::run(
+            no_unused_private_class_members_rule(),
+            rule_tests! {
+                valid => [
+                    "class Foo {}",
+                    "class Foo {\n    publicMember = 42;\n}",
+                    "class Foo {\n    #usedMember = 42;\n    method() {\n        return this.#usedMember;\n    }\n}",
+                    "class Foo {\n    #usedMember = 42;\n    anotherMember = this.#usedMember;\n}",
+                    "class Foo {\n    #usedMember = 42;\n    foo() {\n        anotherMember = this.#usedMember;\n    }\n}",
+                    "class C {\n    #usedMember;\n\n    foo() {\n        bar(this.#usedMember += 1);\n    }\n}",
+                    "class Foo {\n    #usedMember = 42;\n    method() {\n        return someGlobalMethod(this.#usedMember);\n    }\n}",
+                    "class C {\n    #usedInOuterClass;\n\n    foo() {\n        return class {};\n    }\n\n    bar() {\n        return this.#usedInOuterClass;\n    }\n}",
+                    "class Foo {\n    #usedInForInLoop;\n    method() {\n        for (const bar in this.#usedInForInLoop) {\n\n        }\n    }\n}",
+                    "class Foo {\n    #usedInForOfLoop;\n    method() {\n        for (const bar of this.#usedInForOfLoop) {\n\n        }\n    }\n}",
+                    "class Foo {\n    #usedInAssignmentPattern;\n    method() {\n        [bar = 1] = this.#usedInAssignmentPattern;\n    }\n}",
+                    "class Foo {\n    #usedInArrayPattern;\n    method() {\n        [bar] = this.#usedInArrayPattern;\n    }\n}",
+                    "class Foo {\n    #usedInAssignmentPattern;\n    method() {\n        [bar] = this.#usedInAssignmentPattern;\n    }\n}",
+                    "class C {\n    #usedInObjectAssignment;\n\n    method() {\n        ({ [this.#usedInObjectAssignment]: a } = foo);\n    }\n}",
+                    "class C {\n    set #accessorWithSetterFirst(value) {\n        doSomething(value);\n    }\n    get #accessorWithSetterFirst() {\n        return something();\n    }\n    method() {\n        this.#accessorWithSetterFirst += 1;\n    }\n}",
+                    "class Foo {\n    set #accessorUsedInMemberAccess(value) {}\n\n    method(a) {\n        [this.#accessorUsedInMemberAccess] = a;\n    }\n}",
+                    "class C {\n    get #accessorWithGetterFirst() {\n        return something();\n    }\n    set #accessorWithGetterFirst(value) {\n        doSomething(value);\n    }\n    method() {\n        this.#accessorWithGetterFirst += 1;\n    }\n}",
+                    "class C {\n    #usedInInnerClass;\n\n    method(a) {\n        return class {\n            foo = a.#usedInInnerClass;\n        }\n    }\n}",
+
+                    // Methods
+                    "class Foo {\n    #usedMethod() {\n        return 42;\n    }\n    anotherMethod() {\n        return this.#usedMethod();\n    }\n}",
+                    "class C {\n    set #x(value) {\n        doSomething(value);\n    }\n\n    foo() {\n        this.#x = 1;\n    }\n}",
+
+                    // `in` checks
+                    "class C {\n    #x;\n    static check(obj) {\n        return #x in obj;\n    }\n}"
+                ],
+                invalid => [
+                    {
+                        code => "class Foo {\n    #unusedMember = 5;\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedMember" } }]
+                    },
+                    {
+                        code => "class First {}\nclass Second {\n    #unusedMemberInSecondClass = 5;\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedMemberInSecondClass" } }]
+                    },
+                    {
+                        code => "class First {\n    #unusedMemberInFirstClass = 5;\n}\nclass Second {}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedMemberInFirstClass" } }]
+                    },
+                    {
+                        code => "class First {\n    #firstUnusedMemberInSameClass = 5;\n    #secondUnusedMemberInSameClass = 5;\n}",
+                        errors => [
+                            { message_id => "unused_private_class_member", data => { class_member_name => "#firstUnusedMemberInSameClass" } },
+                            { message_id => "unused_private_class_member", data => { class_member_name => "#secondUnusedMemberInSameClass" } }
+                        ]
+                    },
+                    {
+                        code => "class Foo {\n    #usedOnlyInWrite = 5;\n    method() {\n        this.#usedOnlyInWrite = 42;\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#usedOnlyInWrite" } }]
+                    },
+                    {
+                        code => "class Foo {\n    #usedOnlyInWriteStatement = 5;\n    method() {\n        this.#usedOnlyInWriteStatement += 42;\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#usedOnlyInWriteStatement" } }]
+                    },
+                    {
+                        code => "class C {\n    #usedOnlyInIncrement;\n\n    foo() {\n        this.#usedOnlyInIncrement++;\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#usedOnlyInIncrement" } }]
+                    },
+                    {
+                        code => "class C {\n    #unusedInOuterClass;\n\n    foo() {\n        return class {\n            #unusedInOuterClass;\n\n            bar() {\n                return this.#unusedInOuterClass;\n            }\n        };\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedInOuterClass" } }]
+                    },
+                    {
+                        code => "class C {\n    #unusedOnlyInSecondNestedClass;\n\n    foo() {\n        return class {\n            #unusedOnlyInSecondNestedClass;\n\n            bar() {\n                return this.#unusedOnlyInSecondNestedClass;\n            }\n        };\n    }\n\n    baz() {\n        return this.#unusedOnlyInSecondNestedClass;\n    }\n\n    bar() {\n        return class {\n            #unusedOnlyInSecondNestedClass;\n        }\n    }\n}",
+                        errors => [{
+                            message_id => "unused_private_class_member",
+                            data => { class_member_name => "#unusedOnlyInSecondNestedClass" },
+                            line => 22
+                        }]
+                    },
+
+                    // Unused method definitions in class
+                    {
+                        code => "class First {\n    #unusedMethod() {}\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedMethod" } }]
+                    },
+                    {
+                        code => "class First {\n    #unusedMethod() {}\n    #usedMethod() {\n        return 42;\n    }\n    publicMethod() {\n        return this.#usedMethod();\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedMethod" } }]
+                    },
+                    {
+                        code => "class First {\n    #unusedSetter(value) {}\n    publicMethod() {\n        this.#unusedSetter = 42;\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedSetter" } }]
+                    },
+                    {
+                        code => "class Foo {\n    #unusedForInLoop;\n    method() {\n        for (this.#unusedForInLoop in bar) {\n\n        }\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedForInLoop" } }]
+                    },
+                    {
+                        code => "class Foo {\n    #unusedForOfLoop;\n    method() {\n        for (this.#unusedForOfLoop of bar) {\n\n        }\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedForOfLoop" } }]
+                    },
+                    {
+                        code => "class Foo {\n    #unusedInDestructuring;\n    method() {\n        ({ x: this.#unusedInDestructuring } = bar);\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedInDestructuring" } }]
+                    },
+                    {
+                        code => "class Foo {\n    #unusedInRestPattern;\n    method() {\n        [...this.#unusedInRestPattern] = bar;\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedInRestPattern" } }]
+                    },
+                    {
+                        code => "class Foo {\n    #unusedInAssignmentPattern;\n    method() {\n        [this.#unusedInAssignmentPattern = 1] = bar;\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedInAssignmentPattern" } }]
+                    },
+                    {
+                        code => "class Foo {\n    #unusedInAssignmentPattern;\n    method() {\n        [this.#unusedInAssignmentPattern] = bar;\n    }\n}",
+                        errors => [{ message_id => "unused_private_class_member", data => { class_member_name => "#unusedInAssignmentPattern" } }]
+                    },
+                    {
+                        code => "class C {\n    #usedOnlyInTheSecondInnerClass;\n\n    method(a) {\n        return class {\n            #usedOnlyInTheSecondInnerClass;\n\n            method2(b) {\n                foo = b.#usedOnlyInTheSecondInnerClass;\n            }\n\n            method3(b) {\n                foo = b.#usedOnlyInTheSecondInnerClass;\n            }\n        }\n    }\n}",
+                        errors => [{
+                            message_id => "unused_private_class_member",
+                            data => { class_member_name => "#usedOnlyInTheSecondInnerClass" },
+                            line => 2
+                        }]
+                    },
+
+                    // Range of the reported member
+                    {
+                        code => "class C {\n    static #unusedStaticField = 1;\n}",
+                        errors => [{
+                            message_id => "unused_private_class_member",
+                            data => { class_member_name => "#unusedStaticField" },
+                            line => 2,
+                            column => 12,
+                            end_line => 2,
+                            end_column => 30
+                        }]
+                    }
+                ]
+            },
+        )
+    }
+}
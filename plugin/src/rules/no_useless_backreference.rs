@@ -0,0 +1,433 @@
+use std::{borrow::Cow, sync::Arc};
+
+use regexpp_js::{
+    id_arena::Id, visit_reg_exp_ast, visitor, AllArenas, AssertionKind, NodeInterface,
+    RegExpParser, ValidatePatternFlags, Wtf16,
+};
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::get_call_expression_arguments,
+    kind,
+    scope::ScopeManager,
+    utils::{ast_utils, ast_utils::get_static_string_value},
+};
+
+fn get_path_to_root(node: Id<regexpp_js::Node>, arena: &AllArenas) -> Vec<Id<regexpp_js::Node>> {
+    let mut path = vec![];
+    let mut current = Some(node);
+    while let Some(current_node) = current {
+        path.push(current_node);
+        current = arena.node(current_node).parent();
+    }
+    path
+}
+
+fn get_lookaround_kind(node: Id<regexpp_js::Node>, arena: &AllArenas) -> Option<AssertionKind> {
+    match &*arena.node(node) {
+        regexpp_js::Node::Assertion(assertion)
+            if matches!(
+                assertion.kind,
+                AssertionKind::Lookahead | AssertionKind::Lookbehind
+            ) =>
+        {
+            Some(assertion.kind)
+        }
+        _ => None,
+    }
+}
+
+fn is_negative_lookaround(node: Id<regexpp_js::Node>, arena: &AllArenas) -> bool {
+    get_lookaround_kind(node, arena).is_some()
+        && arena.node(node).as_assertion().negate == Some(true)
+}
+
+fn get_raw(node: Id<regexpp_js::Node>, arena: &AllArenas) -> String {
+    String::from_utf16_lossy(&arena.node(node).raw())
+}
+
+fn check_regex<'a>(
+    node: Node<'a>,
+    pattern: &str,
+    flags: Option<&str>,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let arena = AllArenas::default();
+    let mut parser = RegExpParser::new(&arena, None);
+    let pattern_as_wtf16: Wtf16 = pattern.into();
+    let Ok(reg_exp_ast) = parser.parse_pattern(
+        &pattern_as_wtf16,
+        Some(0),
+        Some(pattern_as_wtf16.len()),
+        Some(ValidatePatternFlags {
+            unicode: Some(flags.matches(|flags| flags.contains('u'))),
+            unicode_sets: Some(flags.matches(|flags| flags.contains('v'))),
+        }),
+    ) else {
+        return;
+    };
+
+    struct Handlers<'a, 'b, 'c> {
+        arena: &'c AllArenas,
+        context: &'c QueryMatchContext<'a, 'b>,
+        node: Node<'a>,
+    }
+
+    impl<'a, 'b, 'c> visitor::Handlers for Handlers<'a, 'b, 'c> {
+        fn on_backreference_enter(&self, bref: Id<regexpp_js::Node /*Backreference*/>) {
+            let arena = self.arena;
+            let group = arena.node(bref).as_backreference().resolved;
+            let bref_path = get_path_to_root(bref, arena);
+            let group_path = get_path_to_root(group, arena);
+
+            let message_id = if bref_path.contains(&group) {
+                // group is bref's ancestor => bref is nested ('nested reference') => group hasn't matched yet when bref starts to match.
+                Some("nested")
+            } else {
+                // Start from the root to find the lowest common ancestor.
+                let mut i = bref_path.len() - 1;
+                let mut j = group_path.len() - 1;
+
+                loop {
+                    i -= 1;
+                    j -= 1;
+                    if bref_path[i] != group_path[j] {
+                        break;
+                    }
+                }
+
+                let index_of_lowest_common_ancestor = j + 1;
+                let group_cut = &group_path[..index_of_lowest_common_ancestor];
+                let common_path = &group_path[index_of_lowest_common_ancestor..];
+                let is_matching_backward = common_path
+                    .iter()
+                    .find_map(|&node| get_lookaround_kind(node, arena))
+                    == Some(AssertionKind::Lookbehind);
+
+                let bref_ref = arena.node(bref);
+                let group_ref = arena.node(group);
+
+                if !is_matching_backward && bref_ref.end() <= group_ref.start() {
+                    // bref is left, group is right, and both are matching forward => group hasn't matched yet when bref starts to match.
+                    Some("forward")
+                } else if is_matching_backward && group_ref.end() <= bref_ref.start() {
+                    // the opposite of the previous when the regex is matching backward in a lookbehind context.
+                    Some("backward")
+                } else if matches!(
+                    &*arena.node(*group_cut.last().unwrap()),
+                    regexpp_js::Node::Alternative(_)
+                ) {
+                    // group's and bref's ancestor nodes below the lowest common ancestor are sibling alternatives => they're disjunctive.
+                    Some("disjunctive")
+                } else if group_cut
+                    .iter()
+                    .any(|&node| is_negative_lookaround(node, arena))
+                {
+                    // group is in a negative lookaround which isn't bref's ancestor => group has already failed when bref starts to match.
+                    Some("into_negative_lookaround")
+                } else {
+                    None
+                }
+            };
+
+            if let Some(message_id) = message_id {
+                self.context.report(violation! {
+                    node => self.node,
+                    message_id => message_id,
+                    data => {
+                        bref => get_raw(bref, arena),
+                        group => get_raw(group, arena),
+                    }
+                });
+            }
+        }
+    }
+
+    let handlers = Handlers {
+        arena: &arena,
+        context,
+        node,
+    };
+
+    visit_reg_exp_ast(reg_exp_ast, &handlers, &arena);
+}
+
+pub fn no_useless_backreference_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-useless-backreference",
+        languages => [Javascript],
+        messages => [
+            nested => "Backreference '{{ bref }}' will be ignored. It references group '{{ group }}' from within that group.",
+            forward => "Backreference '{{ bref }}' will be ignored. It references group '{{ group }}' which appears later in the pattern.",
+            backward => "Backreference '{{ bref }}' will be ignored. It references group '{{ group }}' which appears before in the same lookbehind.",
+            disjunctive => "Backreference '{{ bref }}' will be ignored. It references group '{{ group }}' which is in another alternative.",
+            into_negative_lookaround => "Backreference '{{ bref }}' will be ignored. It references group '{{ group }}' which is in a negative lookaround.",
+        ],
+        listeners => [
+            r#"
+              (regex) @c
+            "# => |node, context| {
+                let pattern = node.field("pattern").text(context);
+                let flags = node.child_by_field_name("flags").map(|flags| flags.text(context));
+
+                check_regex(node, &pattern, flags.as_deref(), context);
+            },
+            r#"
+              (call_expression
+                function: (identifier) @regexp (#eq? @regexp "RegExp")
+                arguments: (arguments
+                  .
+                  (string) @pattern
+                )
+              ) @call_expression
+              (new_expression
+                constructor: (identifier) @regexp (#eq? @regexp "RegExp")
+                arguments: (arguments
+                  .
+                  (string) @pattern
+                )
+              ) @call_expression
+            "# => |captures, context| {
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let node = captures["call_expression"];
+                let scope = scope_manager.get_scope(node);
+                let reg_exp_var = ast_utils::get_variable_by_name(scope, "RegExp");
+                let shadowed = reg_exp_var.matches(|reg_exp_var| reg_exp_var.defs().next().is_some());
+                if shadowed {
+                    return;
+                }
+
+                let pattern = get_static_string_value(captures["pattern"], context).unwrap();
+                let flags: Option<Cow<'_, str>> = get_call_expression_arguments(node)
+                    .unwrap()
+                    .nth(1)
+                    .filter(|flags| flags.kind() == kind::String)
+                    .and_then(|flags| get_static_string_value(flags, context));
+
+                check_regex(node, &pattern, flags.as_deref(), context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{CallExpression, NewExpression, Regex},
+    };
+
+    #[test]
+    fn test_no_useless_backreference_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_useless_backreference_rule(),
+            rule_tests! {
+                valid => [
+                    // not a regular expression
+                    "'\\1(a)'",
+                    "regExp('\\\\1(a)')",
+                    "new Regexp('\\\\1(a)', 'u')",
+                    "RegExp.foo('\\\\1(a)', 'u')",
+                    "new foo.RegExp('\\\\1(a)')",
+
+                    // unknown pattern
+                    "RegExp(p)",
+                    "new RegExp(p, 'u')",
+                    "RegExp('\\\\1(a)' + suffix)",
+
+                    // not the global RegExp
+                    "function foo() { var RegExp; RegExp('\\\\1(a)', 'g'); }",
+                    "let RegExp; new RegExp('\\\\1(a)');",
+
+                    // no capturing groups
+                    "/(?:)/",
+                    "/(?:a)/",
+                    "new RegExp('')",
+                    "RegExp('(?:a)|(?:b)*')",
+                    "/^ab|[cd].\\n$/",
+
+                    // no backreferences
+                    "/(a)/",
+                    "RegExp('(a)|(b)')",
+                    "new RegExp('\\\\n\\\\d(a)')",
+
+                    // not a backreference (octal escape in non-unicode mode)
+                    "/\\1a/",
+                    "RegExp('\\\\1a')",
+
+                    // valid backreferences
+                    "/(a)\\1/",
+                    "/(a).\\1/",
+                    "RegExp('(a)\\\\1(b)')",
+                    "/(a)(b)\\2(c)/",
+                    "RegExp('(?<a>a)\\\\k<a>')",
+                    "new RegExp('(.)\\\\1')",
+                    "RegExp('(a)\\\\1(?:b)')",
+                    "/(a)b\\1/",
+                    "/((a)\\2)/",
+                    "/((a)b\\2)/",
+                    "/(a)|(b)\\2/",
+                    "/(a)(?:\\1)/",
+                    "/(a)\\1|b/",
+                    "/(?<a>a)(?<b>b)\\k<b>\\k<a>/",
+
+                    // valid in lookarounds
+                    "/(?=(a))\\1/",
+                    "/(?=(a)\\1)/",
+                    "/(?<=\\1(a))b/",
+                    "/(?<=\\1(?:(a)|b))/",
+                    "/(?!(a)\\1)/",
+                    "/(?<!\\1(a))/",
+
+                    // ignore regular expressions with syntax errors
+                    "RegExp('\\\\1(a)[')",
+                    "new RegExp('\\\\1(a){', 'u')"
+                ],
+                invalid => [
+                    // full message tests
+                    {
+                        code => "/(b)(\\2a)/",
+                        errors => [{
+                            message => "Backreference '\\2' will be ignored. It references group '(\\2a)' from within that group.",
+                            type => Regex
+                        }]
+                    },
+                    {
+                        code => "/\\k<foo>(?<foo>bar)/",
+                        errors => [{
+                            message => "Backreference '\\k<foo>' will be ignored. It references group '(?<foo>bar)' which appears later in the pattern.",
+                            type => Regex
+                        }]
+                    },
+                    {
+                        code => "RegExp('(a|bc)|\\\\1')",
+                        errors => [{
+                            message => "Backreference '\\1' will be ignored. It references group '(a|bc)' which is in another alternative.",
+                            type => CallExpression
+                        }]
+                    },
+                    {
+                        code => "new RegExp('(?!(?<foo>\\\\n))\\\\1')",
+                        errors => [{
+                            message => "Backreference '\\1' will be ignored. It references group '(?<foo>\\n)' which is in a negative lookaround.",
+                            type => NewExpression
+                        }]
+                    },
+                    {
+                        code => "/(?<!(a)\\1)b/",
+                        errors => [{
+                            message => "Backreference '\\1' will be ignored. It references group '(a)' which appears before in the same lookbehind.",
+                            type => Regex
+                        }]
+                    },
+
+                    // nested
+                    {
+                        code => "new RegExp('(\\\\1)')",
+                        errors => [{ message_id => "nested", data => { bref => "\\1", group => "(\\1)" }, type => NewExpression }]
+                    },
+                    {
+                        code => "/^(a\\1)$/",
+                        errors => [{ message_id => "nested", data => { bref => "\\1", group => "(a\\1)" }, type => Regex }]
+                    },
+                    {
+                        code => "/^((?!\\1))$/",
+                        errors => [{ message_id => "nested", data => { bref => "\\1", group => "((?!\\1))" }, type => Regex }]
+                    },
+
+                    // forward
+                    {
+                        code => "/\\1(a)/",
+                        errors => [{ message_id => "forward", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/\\1.(a)/",
+                        errors => [{ message_id => "forward", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?:\\1)(?:(a))/",
+                        errors => [{ message_id => "forward", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?:\\1)(?:((a)))/",
+                        errors => [{ message_id => "forward", data => { bref => "\\1", group => "((a))" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?:\\2)(?:((a)))/",
+                        errors => [{ message_id => "forward", data => { bref => "\\2", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "RegExp('\\\\1(a)')",
+                        errors => [{ message_id => "forward", data => { bref => "\\1", group => "(a)" }, type => CallExpression }]
+                    },
+                    {
+                        code => "/(?=\\1(a))/",
+                        errors => [{ message_id => "forward", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+
+                    // backward in the same lookbehind
+                    {
+                        code => "/(?<=(a)\\1)b/",
+                        errors => [{ message_id => "backward", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?<=(?:(a)|b)\\1)b/",
+                        errors => [{ message_id => "backward", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+
+                    // into another alternative
+                    {
+                        code => "/(a)|\\1b/",
+                        errors => [{ message_id => "disjunctive", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/\\1b|(a)/",
+                        errors => [{ message_id => "disjunctive", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?:(a)|\\1b)/",
+                        errors => [{ message_id => "disjunctive", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?=(a)|\\1b)/",
+                        errors => [{ message_id => "disjunctive", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+
+                    // into a negative lookaround
+                    {
+                        code => "/(?!(a))\\1/",
+                        errors => [{ message_id => "into_negative_lookaround", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?<!(a))\\1/",
+                        errors => [{ message_id => "into_negative_lookaround", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+                    {
+                        code => "/(?!(?:(a)))\\1/",
+                        errors => [{ message_id => "into_negative_lookaround", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+
+                    // valid and invalid
+                    {
+                        code => "/\\1(a)(b)\\2/",
+                        errors => [{ message_id => "forward", data => { bref => "\\1", group => "(a)" }, type => Regex }]
+                    },
+
+                    // multiple invalid
+                    {
+                        code => "/\\1(a)\\1|\\1/",
+                        errors => [
+                            { message_id => "forward", data => { bref => "\\1", group => "(a)" }, type => Regex },
+                            { message_id => "disjunctive", data => { bref => "\\1", group => "(a)" }, type => Regex }
+                        ]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,393 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{is_class_member_static, skip_parenthesized_expressions},
+    kind::{self, FieldDefinition, MethodDefinition, Object, Pair},
+    utils::ast_utils,
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    enforce_for_class_members: bool,
+}
+
+fn has_useless_computed_key(node: Node, key: Node, context: &QueryMatchContext) -> bool {
+    if !matches!(key.kind(), kind::String | kind::Number) {
+        return false;
+    }
+
+    let value = ast_utils::get_static_string_value(key, context).unwrap();
+
+    match node.kind() {
+        Pair => value != "__proto__",
+        MethodDefinition if node.parent().unwrap().kind() == Object => value != "__proto__",
+        FieldDefinition => {
+            if is_class_member_static(node, context) {
+                return value != "constructor" && value != "prototype";
+            }
+            value != "constructor"
+        }
+        MethodDefinition => {
+            if is_class_member_static(node, context) {
+                return value != "prototype";
+            }
+            value != "constructor"
+        }
+        _ => true,
+    }
+}
+
+pub fn no_useless_computed_key_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-useless-computed-key",
+        languages => [Javascript],
+        messages => [
+            unnecessarily_computed_property => "Unnecessarily computed property [{{property}}] found.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            enforce_for_class_members: bool = options.enforce_for_class_members,
+        },
+        listeners => [
+            r#"
+              (pair
+                key: (computed_property_name) @c
+              )
+              (pair_pattern
+                key: (computed_property_name) @c
+              )
+              (method_definition
+                name: (computed_property_name) @c
+              )
+              (field_definition
+                property: (computed_property_name) @c
+              )
+            "# => |node, context| {
+                let property = node.parent().unwrap();
+                if !self.enforce_for_class_members
+                    && matches!(property.kind(), MethodDefinition | FieldDefinition)
+                    && property.parent().unwrap().kind() != Object
+                {
+                    return;
+                }
+
+                let key = skip_parenthesized_expressions(
+                    node.first_non_comment_named_child(SupportedLanguage::Javascript),
+                );
+                if !has_useless_computed_key(property, key, context) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => property,
+                    message_id => "unnecessarily_computed_property",
+                    data => {
+                        property => key.text(context),
+                    },
+                    fix => |fixer| {
+                        // If there are comments between the brackets and the property name, don't do a fix.
+                        if context.get_comments_inside(node).next().is_some() {
+                            return;
+                        }
+
+                        // Insert a space before the key to avoid changing identifiers, e.g., ({ get[2]() {} }) to ({ get2() {} })
+                        let needs_space_before_key = context
+                            .maybe_get_token_before(node, Option::<fn(Node) -> bool>::None)
+                            .matches(|token_before_left_bracket| {
+                                token_before_left_bracket.end_byte() == node.start_byte()
+                                    && !ast_utils::can_tokens_be_adjacent(
+                                        token_before_left_bracket,
+                                        key,
+                                        context,
+                                    )
+                            });
+
+                        fixer.replace_text(
+                            node,
+                            format!(
+                                "{}{}",
+                                if needs_space_before_key { " " } else { "" },
+                                key.text(context),
+                            ),
+                        );
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::PairPattern;
+
+    #[test]
+    fn test_no_useless_computed_key_rule() {
+        RuleTester::run(
+            no_useless_computed_key_rule(),
+            rule_tests! {
+                valid => [
+                    "({ 'a': 0, b(){} })",
+                    "({ [x]: 0 });",
+                    "({ a: 0, [b](){} })",
+                    "({ ['__proto__']: [] })",
+                    { code => "class Foo { a() {} }", options => { enforce_for_class_members => true } },
+                    { code => "class Foo { [x]() {} }", options => { enforce_for_class_members => true } },
+                    { code => "class Foo { ['constructor']() {} }", options => { enforce_for_class_members => true } },
+                    { code => "class Foo { static ['prototype']() {} }", options => { enforce_for_class_members => true } },
+                    { code => "(class { 'a'() {} })", options => { enforce_for_class_members => true } },
+                    { code => "(class { [x]() {} })", options => { enforce_for_class_members => true } },
+                    { code => "(class { ['constructor']() {} })", options => { enforce_for_class_members => true } },
+                    { code => "(class { static ['prototype']() {} })", options => { enforce_for_class_members => true } },
+                    "class Foo { 'x'() {} }",
+                    "(class { [x]() {} })",
+                    "class Foo { static constructor() {} }",
+                    "class Foo { prototype() {} }",
+                    { code => "class Foo { ['x']() {} }", options => { enforce_for_class_members => false } },
+                    { code => "(class { ['x']() {} })", options => { enforce_for_class_members => false } },
+                    { code => "class Foo { static ['constructor']() {} }", options => { enforce_for_class_members => false } },
+                    { code => "class Foo { ['prototype']() {} }", options => { enforce_for_class_members => false } },
+                    { code => "class Foo { a }", options => { enforce_for_class_members => true } },
+                    { code => "class Foo { ['constructor'] }", options => { enforce_for_class_members => true } },
+                    { code => "class Foo { static ['constructor'] }", options => { enforce_for_class_members => true } },
+                    { code => "class Foo { static ['prototype'] }", options => { enforce_for_class_members => true } },
+                    { code => "class Foo { ['x'] }", options => { enforce_for_class_members => false } }
+                ],
+                invalid => [
+                    {
+                        code => "({ ['0']: 0 })",
+                        output => "({ '0': 0 })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'0'" }, type => Pair }]
+                    },
+                    {
+                        code => "var { ['0']: a } = obj",
+                        output => "var { '0': a } = obj",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'0'" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({ ['0+1,234']: 0 })",
+                        output => "({ '0+1,234': 0 })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'0+1,234'" }, type => Pair }]
+                    },
+                    {
+                        code => "({ [0]: 0 })",
+                        output => "({ 0: 0 })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "0" }, type => Pair }]
+                    },
+                    {
+                        code => "var { [0]: a } = obj",
+                        output => "var { 0: a } = obj",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "0" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({ ['x']: 0 })",
+                        output => "({ 'x': 0 })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => Pair }]
+                    },
+                    {
+                        code => "var { ['x']: a } = obj",
+                        output => "var { 'x': a } = obj",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => PairPattern }]
+                    },
+                    {
+                        code => "var { ['__proto__']: a } = obj",
+                        output => "var { '__proto__': a } = obj",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'__proto__'" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({ ['x']() {} })",
+                        output => "({ 'x'() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ [/* this comment prevents a fix */ 'x']: 0 })",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => Pair }]
+                    },
+                    {
+                        code => "({ ['x' /* this comment also prevents a fix */]: 0 })",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => Pair }]
+                    },
+                    {
+                        code => "({ [('x')]: 0 })",
+                        output => "({ 'x': 0 })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => Pair }]
+                    },
+                    {
+                        code => "var { [('x')]: a } = obj",
+                        output => "var { 'x': a } = obj",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({ *['x']() {} })",
+                        output => "({ *'x'() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ async ['x']() {} })",
+                        output => "({ async 'x'() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ get[.2]() {} })",
+                        output => "({ get.2() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => ".2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ [2]() {} })",
+                        output => "({ 2() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ get [2]() {} })",
+                        output => "({ get 2() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ set [2](value) {} })",
+                        output => "({ set 2(value) {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ get[2]() {} })",
+                        output => "({ get 2() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ set[2](value) {} })",
+                        output => "({ set 2(value) {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ async[2]() {} })",
+                        output => "({ async 2() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ get['foo']() {} })",
+                        output => "({ get'foo'() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'foo'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ *[2]() {} })",
+                        output => "({ *2() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ async*[2]() {} })",
+                        output => "({ async*2() {} })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ ['constructor']: 1 })",
+                        output => "({ 'constructor': 1 })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'constructor'" }, type => Pair }]
+                    },
+                    {
+                        code => "({ ['prototype']: 1 })",
+                        output => "({ 'prototype': 1 })",
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'prototype'" }, type => Pair }]
+                    },
+                    {
+                        code => "class Foo { ['0']() {} }",
+                        output => "class Foo { '0'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'0'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { ['0+1,234']() {} }",
+                        output => "class Foo { '0+1,234'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'0+1,234'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { ['x']() {} }",
+                        output => "class Foo { 'x'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { [/* this comment prevents a fix */ 'x']() {} }",
+                        output => None,
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { [('x')]() {} }",
+                        output => "class Foo { 'x'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { *['x']() {} }",
+                        output => "class Foo { *'x'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { async ['x']() {} }",
+                        output => "class Foo { async 'x'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { get[2]() {} }",
+                        output => "class Foo { get 2() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "2" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { static ['constructor']() {} }",
+                        output => "class Foo { static 'constructor'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'constructor'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { ['prototype']() {} }",
+                        output => "class Foo { 'prototype'() {} }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'prototype'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "(class { ['x']() {} })",
+                        output => "(class { 'x'() {} })",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => MethodDefinition }]
+                    },
+                    {
+                        code => "class Foo { ['x']; }",
+                        output => "class Foo { 'x'; }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class Foo { ['prototype'] }",
+                        output => "class Foo { 'prototype' }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'prototype'" }, type => FieldDefinition }]
+                    },
+                    {
+                        code => "class Foo { static ['x'] = 1 }",
+                        output => "class Foo { static 'x' = 1 }",
+                        options => { enforce_for_class_members => true },
+                        errors => [{ message_id => "unnecessarily_computed_property", data => { property => "'x'" }, type => FieldDefinition }]
+                    }
+                ]
+            },
+        )
+    }
+}
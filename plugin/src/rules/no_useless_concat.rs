@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, Rule};
+
+use crate::{
+    ast_helpers::skip_parenthesized_expressions, kind::BinaryExpression, utils::ast_utils,
+};
+
+fn is_concatenation(node: Node) -> bool {
+    node.kind() == BinaryExpression && node.field("operator").kind() == "+"
+}
+
+fn get_left(node: Node) -> Node {
+    let mut left = skip_parenthesized_expressions(node.field("left"));
+
+    while is_concatenation(left) {
+        left = skip_parenthesized_expressions(left.field("right"));
+    }
+    left
+}
+
+fn get_right(node: Node) -> Node {
+    let mut right = skip_parenthesized_expressions(node.field("right"));
+
+    while is_concatenation(right) {
+        right = skip_parenthesized_expressions(right.field("left"));
+    }
+    right
+}
+
+pub fn no_useless_concat_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-useless-concat",
+        languages => [Javascript],
+        messages => [
+            unexpected_concat => "Unexpected string concatenation of literals.",
+        ],
+        listeners => [
+            r#"
+              (binary_expression
+                operator: "+"
+              ) @c
+            "# => |node, context| {
+                // account for the `foo + "a" + "b"` case
+                let left = get_left(node);
+                let right = get_right(node);
+
+                if ast_utils::is_string_literal(left)
+                    && ast_utils::is_string_literal(right)
+                    && ast_utils::is_token_on_same_line(left, right)
+                {
+                    context.report(violation! {
+                        node => node,
+                        range => node.field("operator").range(),
+                        message_id => "unexpected_concat",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_no_useless_concat_rule() {
+        RuleTester::run(
+            no_useless_concat_rule(),
+            rule_tests! {
+                valid => [
+                    "var a = 1 + 1;",
+                    "var a = 1 * '2';",
+                    "var a = 1 - 2;",
+                    "var a = foo + bar;",
+                    "var a = 'foo' + bar;",
+                    "var foo = 'foo' +\n 'bar';",
+
+                    // https://github.com/eslint/eslint/issues/3575
+                    "var string = (number + 1) + 'px';",
+                    "'a' + 1",
+                    "1 + '1'",
+                    "1 + `1`",
+                    "`1` + 1",
+                    "(1 + +2) + `b`"
+                ],
+                invalid => [
+                    {
+                        code => "'a' + 'b'",
+                        errors => [{ message_id => "unexpected_concat", line => 1, column => 5 }]
+                    },
+                    {
+                        code => "foo + 'a' + 'b'",
+                        errors => [{ message_id => "unexpected_concat", line => 1, column => 11 }]
+                    },
+                    {
+                        code => "'a' + 'b' + 'c'",
+                        errors => [
+                            { message_id => "unexpected_concat", line => 1, column => 5 },
+                            { message_id => "unexpected_concat", line => 1, column => 11 }
+                        ]
+                    },
+                    {
+                        code => "(foo + 'a') + ('b' + 'c')",
+                        errors => [
+                            { message_id => "unexpected_concat", column => 13 },
+                            { message_id => "unexpected_concat", column => 20 }
+                        ]
+                    },
+                    {
+                        code => "`a` + 'b'",
+                        errors => [{ message_id => "unexpected_concat" }]
+                    },
+                    {
+                        code => "`a` + `b`",
+                        errors => [{ message_id => "unexpected_concat" }]
+                    },
+                    {
+                        code => "foo + `a` + `b`",
+                        errors => [{ message_id => "unexpected_concat" }]
+                    }
+                ]
+            },
+        )
+    }
+}
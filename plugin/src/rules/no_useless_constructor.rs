@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use squalid::{EverythingExt, OptionExt};
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{
+        get_call_expression_arguments, get_method_definition_kind, MethodDefinitionKind,
+    },
+    kind::{
+        CallExpression, ClassHeritage, ExpressionStatement, FieldDefinition, Identifier,
+        RestPattern, SpreadElement, Super,
+    },
+};
+
+fn is_single_super_call(body: &[Node]) -> bool {
+    body.len() == 1
+        && body[0].kind() == ExpressionStatement
+        && body[0]
+            .first_non_comment_named_child(SupportedLanguage::Javascript)
+            .thrush(|expression| {
+                expression.kind() == CallExpression && expression.field("function").kind() == Super
+            })
+}
+
+fn is_simple(node: Node) -> bool {
+    matches!(node.kind(), Identifier | RestPattern)
+}
+
+fn is_spread_arguments(super_args: &[Node], context: &QueryMatchContext) -> bool {
+    super_args.len() == 1
+        && super_args[0].kind() == SpreadElement
+        && super_args[0]
+            .first_non_comment_named_child(SupportedLanguage::Javascript)
+            .thrush(|argument| {
+                argument.kind() == Identifier && argument.text(context) == "arguments"
+            })
+}
+
+fn is_valid_identifier_pair(
+    ctor_param: Node,
+    super_arg: Node,
+    context: &QueryMatchContext,
+) -> bool {
+    ctor_param.kind() == Identifier
+        && super_arg.kind() == Identifier
+        && ctor_param.text(context) == super_arg.text(context)
+}
+
+fn is_valid_rest_spread_pair(
+    ctor_param: Node,
+    super_arg: Node,
+    context: &QueryMatchContext,
+) -> bool {
+    ctor_param.kind() == RestPattern
+        && super_arg.kind() == SpreadElement
+        && is_valid_identifier_pair(
+            ctor_param.first_non_comment_named_child(SupportedLanguage::Javascript),
+            super_arg.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        )
+}
+
+fn is_valid_pair(ctor_param: Node, super_arg: Node, context: &QueryMatchContext) -> bool {
+    is_valid_identifier_pair(ctor_param, super_arg, context)
+        || is_valid_rest_spread_pair(ctor_param, super_arg, context)
+}
+
+fn is_passing_through(
+    ctor_params: &[Node],
+    super_args: &[Node],
+    context: &QueryMatchContext,
+) -> bool {
+    ctor_params.len() == super_args.len()
+        && ctor_params
+            .iter()
+            .zip(super_args)
+            .all(|(&ctor_param, &super_arg)| is_valid_pair(ctor_param, super_arg, context))
+}
+
+fn is_redundant_super_call(
+    body: &[Node],
+    ctor_params: &[Node],
+    context: &QueryMatchContext,
+) -> bool {
+    if !is_single_super_call(body) || !ctor_params.iter().all(|&param| is_simple(param)) {
+        return false;
+    }
+
+    let super_args = get_call_expression_arguments(
+        body[0].first_non_comment_named_child(SupportedLanguage::Javascript),
+    )
+    .map(|super_args| super_args.collect::<Vec<_>>());
+    let Some(super_args) = super_args else {
+        return false;
+    };
+
+    is_spread_arguments(&super_args, context)
+        || is_passing_through(ctor_params, &super_args, context)
+}
+
+fn needs_preceding_semicolon(node: Node, context: &QueryMatchContext) -> bool {
+    // class A { foo = bar\n constructor() {}\n [baz]() {} }
+    context
+        .maybe_get_token_after(node, Option::<fn(Node) -> bool>::None)
+        .matches(|next_token| next_token.kind() == "[")
+        && node
+            .prev_named_sibling()
+            .matches(|prev_sibling| prev_sibling.kind() == FieldDefinition)
+        && context
+            .maybe_get_token_before(node, Option::<fn(Node) -> bool>::None)
+            .matches(|prev_token| prev_token.kind() != ";")
+}
+
+pub fn no_useless_constructor_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-useless-constructor",
+        languages => [Javascript],
+        messages => [
+            no_useless_constructor => "Useless constructor.",
+        ],
+        fixable => true,
+        listeners => [
+            r#"
+              (method_definition) @c
+            "# => |node, context| {
+                if get_method_definition_kind(node, context) != MethodDefinitionKind::Constructor {
+                    return;
+                }
+
+                let body = node
+                    .field("body")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .collect::<Vec<_>>();
+                let ctor_params = node
+                    .field("parameters")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .collect::<Vec<_>>();
+                let has_super_class = node
+                    .parent()
+                    .unwrap()
+                    .parent()
+                    .unwrap()
+                    .maybe_first_child_of_kind(ClassHeritage)
+                    .is_some();
+
+                let is_useless = if has_super_class {
+                    is_redundant_super_call(&body, &ctor_params, context)
+                } else {
+                    body.is_empty()
+                };
+                if !is_useless {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_useless_constructor",
+                    fix => |fixer| {
+                        if context.get_comments_inside(node).next().is_some() {
+                            return;
+                        }
+
+                        if needs_preceding_semicolon(node, context) {
+                            fixer.replace_text(node, ";");
+                        } else {
+                            fixer.remove(node);
+                        }
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::MethodDefinition;
+
+    #[test]
+    fn test_no_useless_constructor_rule() {
+        RuleTester::run(
+            no_useless_constructor_rule(),
+            rule_tests! {
+                valid => [
+                    "class A { }",
+                    "class A { constructor(){ doSomething(); } }",
+                    "class A extends B { constructor(){} }",
+                    "class A extends B { constructor(){ super('foo'); } }",
+                    "class A extends B { constructor(foo, bar){ super(foo, bar, 1); } }",
+                    "class A extends B { constructor(){ super(); doSomething(); } }",
+                    "class A extends B { constructor(...args){ super(...args); doSomething(); } }",
+                    "class A { dummyMethod(){ doSomething(); } }",
+                    "class A extends B.C { constructor() { super(foo); } }",
+                    "class A extends B.C { constructor([a, b, c]) { super(...arguments); } }",
+                    "class A extends B.C { constructor(a = f()) { super(...arguments); } }",
+                    "class A extends B { constructor(a, b, c) { super(a, b); } }",
+                    "class A extends B { constructor(foo, bar){ super(foo); } }",
+                    "class A extends B { constructor(test) { super(); } }",
+                    "class A extends B { constructor() { foo; } }",
+                    "class A extends B { constructor(foo, bar) { super(bar); } }",
+                    "class A { static constructor() {} }",
+                    "var a = { constructor() {} };"
+                ],
+                invalid => [
+                    {
+                        code => "class A { constructor(){} }",
+                        output => "class A {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A { 'constructor'(){} }",
+                        output => "class A {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A extends B { constructor() { super(); } }",
+                        output => "class A extends B {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A extends B { constructor(foo){ super(foo); } }",
+                        output => "class A extends B {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A extends B { constructor(foo, bar){ super(foo, bar); } }",
+                        output => "class A extends B {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A extends B { constructor(...args){ super(...args); } }",
+                        output => "class A extends B {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A extends B.C { constructor() { super(...arguments); } }",
+                        output => "class A extends B.C {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A extends B.C { constructor(a, b, ...c) { super(...arguments); } }",
+                        output => "class A extends B.C {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A extends B.C { constructor(a, b, ...c) { super(a, b, ...c); } }",
+                        output => "class A extends B.C {  }",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A {\n    foo = 'bar'\n    constructor() { }\n    [0]() { }\n}",
+                        output => "class A {\n    foo = 'bar'\n    ;\n    [0]() { }\n}",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A {\n    foo = 'bar';\n    constructor() { }\n    [0]() { }\n}",
+                        output => "class A {\n    foo = 'bar';\n    \n    [0]() { }\n}",
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    },
+                    {
+                        code => "class A { constructor() { /* comment */ } }",
+                        output => None,
+                        errors => [{ message_id => "no_useless_constructor", type => MethodDefinition }]
+                    }
+                ]
+            },
+        )
+    }
+}
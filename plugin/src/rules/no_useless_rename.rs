@@ -0,0 +1,468 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::NodeExtJs,
+    kind::{self, AssignmentPattern, Identifier, PropertyIdentifier},
+    utils::ast_utils,
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    ignore_destructuring: bool,
+    ignore_import: bool,
+    ignore_export: bool,
+}
+
+fn report_error<'a>(
+    node: Node<'a>,
+    name: Cow<'a, str>,
+    replacement_node: Node<'a>,
+    type_: &'static str,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    context.report(violation! {
+        node => node,
+        message_id => "unnecessarily_renamed",
+        data => {
+            name => name,
+            type => type_,
+        },
+        fix => |fixer| {
+            if context.get_comments_inside(node).count()
+                > context.get_comments_inside(replacement_node).count()
+            {
+                return;
+            }
+
+            // Avoid removing parentheses around the assignment pattern's left side
+            if replacement_node.kind() == AssignmentPattern
+                && ast_utils::is_parenthesised(replacement_node.field("left"))
+            {
+                return;
+            }
+
+            fixer.replace_text(node, replacement_node.text(context));
+        }
+    });
+}
+
+pub fn no_useless_rename_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-useless-rename",
+        languages => [Javascript],
+        messages => [
+            unnecessarily_renamed => "{{type}} {{name}} unnecessarily renamed.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            ignore_destructuring: bool = options.ignore_destructuring,
+            ignore_import: bool = options.ignore_import,
+            ignore_export: bool = options.ignore_export,
+        },
+        listeners => [
+            r#"
+              (object_pattern
+                (pair_pattern) @c
+              )
+            "# => |node, context| {
+                if self.ignore_destructuring {
+                    return;
+                }
+
+                let key = node.field("key");
+                let key_name = match key.kind() {
+                    PropertyIdentifier => key.text(context),
+                    kind::String => ast_utils::get_static_string_value(key, context).unwrap(),
+                    _ => return,
+                };
+
+                let value = node.field("value");
+                let (renamed_key, replacement_node) = match value.kind() {
+                    AssignmentPattern => (value.field("left").skip_parentheses(), value),
+                    _ => (value.skip_parentheses(), value.skip_parentheses()),
+                };
+                if renamed_key.kind() != Identifier || renamed_key.text(context) != key_name {
+                    return;
+                }
+
+                report_error(node, key_name, replacement_node, "Destructuring assignment", context);
+            },
+            r#"
+              (import_specifier
+                alias: (_)
+              ) @c
+            "# => |node, context| {
+                if self.ignore_import {
+                    return;
+                }
+
+                let imported = node.field("name");
+                let local = node.field("alias");
+                let imported_name = ast_utils::get_module_export_name(imported, context);
+                if imported_name != local.text(context) {
+                    return;
+                }
+
+                report_error(node, imported_name, local, "Import", context);
+            },
+            r#"
+              (export_specifier
+                alias: (_)
+              ) @c
+            "# => |node, context| {
+                if self.ignore_export {
+                    return;
+                }
+
+                let local = node.field("name");
+                let exported = node.field("alias");
+                let local_name = ast_utils::get_module_export_name(local, context);
+                if local_name != ast_utils::get_module_export_name(exported, context) {
+                    return;
+                }
+
+                report_error(node, local_name, local, "Export", context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::{ExportSpecifier, ImportSpecifier, PairPattern};
+
+    #[test]
+    fn test_no_useless_rename_rule() {
+        RuleTester::run(
+            no_useless_rename_rule(),
+            rule_tests! {
+                valid => [
+                    "let {foo} = obj;",
+                    "let {foo: bar} = obj;",
+                    "let {foo: bar, baz: qux} = obj;",
+                    "let {foo: {bar: baz}} = obj;",
+                    "let {foo, bar: {baz: qux}} = obj;",
+                    "let {'foo': bar} = obj;",
+                    "let {'foo': bar, 'baz': qux} = obj;",
+                    "let {'foo': {'bar': baz}} = obj;",
+                    "let {foo, 'bar': {'baz': qux}} = obj;",
+                    "let {['foo']: bar} = obj;",
+                    "let {['foo']: bar, ['baz']: qux} = obj;",
+                    "let {['foo']: {['bar']: baz}} = obj;",
+                    "let {foo, ['bar']: {['baz']: qux}} = obj;",
+                    "let {[foo]: foo} = obj;",
+                    "let {['foo']: foo} = obj;",
+                    "let {[foo]: bar} = obj;",
+                    "function func({foo}) {}",
+                    "function func({foo: bar}) {}",
+                    "function func({foo: bar, baz: qux}) {}",
+                    "({foo}) => {}",
+                    "({foo: bar}) => {}",
+                    "({foo: bar, baz: qui}) => {}",
+                    "import * as foo from 'foo';",
+                    "import foo from 'foo';",
+                    "import {foo} from 'foo';",
+                    "import {foo as bar} from 'foo';",
+                    "import {foo as bar, baz as qux} from 'foo';",
+                    "import {'foo' as bar} from 'baz';",
+                    "export {foo} from 'foo';",
+                    "var foo = 0;export {foo as bar};",
+                    "var foo = 0; var baz = 0; export {foo as bar, baz as qux};",
+                    "export {foo as bar} from 'foo';",
+                    "export {foo as bar, baz as qux} from 'foo';",
+                    "var foo = 0; export {foo as 'bar'};",
+                    "export {foo as 'bar'} from 'baz';",
+                    "export {'foo' as bar} from 'baz';",
+                    "export {'foo' as 'bar'} from 'baz';",
+                    "export {'' as ' '} from 'baz';",
+                    "export {' ' as ''} from 'baz';",
+                    "export {'foo'} from 'bar';",
+                    "const {...stuff} = myObject;",
+                    "const {foo, ...stuff} = myObject;",
+                    "const {foo: bar, ...stuff} = myObject;",
+                    "({foo: this.foo} = obj);",
+
+                    // { ignore_destructuring: true }
+                    {
+                        code => "let {foo: foo} = obj;",
+                        options => { ignore_destructuring => true }
+                    },
+                    {
+                        code => "let {foo: foo, bar: baz} = obj;",
+                        options => { ignore_destructuring => true }
+                    },
+                    {
+                        code => "let {foo: foo, bar: bar} = obj;",
+                        options => { ignore_destructuring => true }
+                    },
+
+                    // { ignore_import: true }
+                    {
+                        code => "import {foo as foo} from 'foo';",
+                        options => { ignore_import => true }
+                    },
+                    {
+                        code => "import {foo as foo, bar as baz} from 'foo';",
+                        options => { ignore_import => true }
+                    },
+                    {
+                        code => "import {foo as foo, bar as bar} from 'foo';",
+                        options => { ignore_import => true }
+                    },
+
+                    // { ignore_export: true }
+                    {
+                        code => "var foo = 0;export {foo as foo};",
+                        options => { ignore_export => true }
+                    },
+                    {
+                        code => "var foo = 0;var bar = 0;export {foo as foo, bar as baz};",
+                        options => { ignore_export => true }
+                    },
+                    {
+                        code => "export {foo as foo} from 'foo';",
+                        options => { ignore_export => true }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "let {foo: foo} = obj;",
+                        output => "let {foo} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {a, foo: foo} = obj;",
+                        output => "let {a, foo} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {foo: foo, bar: baz} = obj;",
+                        output => "let {foo, bar: baz} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {foo: bar, baz: baz} = obj;",
+                        output => "let {foo: bar, baz} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "baz", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {foo: foo, bar: bar} = obj;",
+                        output => "let {foo, bar} = obj;",
+                        errors => [
+                            { message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern },
+                            { message_id => "unnecessarily_renamed", data => { name => "bar", type => "Destructuring assignment" }, type => PairPattern }
+                        ]
+                    },
+                    {
+                        code => "let {foo: {bar: bar}} = obj;",
+                        output => "let {foo: {bar}} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "bar", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {foo: {bar: bar}, baz: baz} = obj;",
+                        output => "let {foo: {bar}, baz} = obj;",
+                        errors => [
+                            { message_id => "unnecessarily_renamed", data => { name => "bar", type => "Destructuring assignment" }, type => PairPattern },
+                            { message_id => "unnecessarily_renamed", data => { name => "baz", type => "Destructuring assignment" }, type => PairPattern }
+                        ]
+                    },
+                    {
+                        code => "let {'foo': foo} = obj;",
+                        output => "let {foo} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {'foo': foo, 'bar': baz} = obj;",
+                        output => "let {foo, 'bar': baz} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {'foo': bar, 'baz': baz} = obj;",
+                        output => "let {'foo': bar, baz} = obj;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "baz", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "let {'foo': {'bar': bar}, 'baz': baz} = obj;",
+                        output => "let {'foo': {bar}, baz} = obj;",
+                        errors => [
+                            { message_id => "unnecessarily_renamed", data => { name => "bar", type => "Destructuring assignment" }, type => PairPattern },
+                            { message_id => "unnecessarily_renamed", data => { name => "baz", type => "Destructuring assignment" }, type => PairPattern }
+                        ]
+                    },
+                    {
+                        code => "function func({foo: foo}) {}",
+                        output => "function func({foo}) {}",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "function func({foo: bar, baz: baz}) {}",
+                        output => "function func({foo: bar, baz}) {}",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "baz", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "function func({foo: foo = 'default'}) {}",
+                        output => "function func({foo = 'default'}) {}",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({foo: foo}) => {}",
+                        output => "({foo}) => {}",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({foo: foo} = obj);",
+                        output => "({foo} = obj);",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "const {foo: foo, bar: baz, ...stuff} = myObject;",
+                        output => "const {foo, bar: baz, ...stuff} = myObject;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "import {foo as foo} from 'foo';",
+                        output => "import {foo} from 'foo';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Import" }, type => ImportSpecifier }]
+                    },
+                    {
+                        code => "import {'foo' as foo} from 'foo';",
+                        output => "import {foo} from 'foo';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Import" }, type => ImportSpecifier }]
+                    },
+                    {
+                        code => "import {foo as foo, bar as baz} from 'foo';",
+                        output => "import {foo, bar as baz} from 'foo';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Import" }, type => ImportSpecifier }]
+                    },
+                    {
+                        code => "import {foo as bar, baz as baz} from 'foo';",
+                        output => "import {foo as bar, baz} from 'foo';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "baz", type => "Import" }, type => ImportSpecifier }]
+                    },
+                    {
+                        code => "import {foo as foo, bar as bar} from 'foo';",
+                        output => "import {foo, bar} from 'foo';",
+                        errors => [
+                            { message_id => "unnecessarily_renamed", data => { name => "foo", type => "Import" }, type => ImportSpecifier },
+                            { message_id => "unnecessarily_renamed", data => { name => "bar", type => "Import" }, type => ImportSpecifier }
+                        ]
+                    },
+                    {
+                        code => "var foo = 0; export {foo as foo};",
+                        output => "var foo = 0; export {foo};",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Export" }, type => ExportSpecifier }]
+                    },
+                    {
+                        code => "var foo = 0; export {foo as 'foo'};",
+                        output => "var foo = 0; export {foo};",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Export" }, type => ExportSpecifier }]
+                    },
+                    {
+                        code => "export {foo as 'foo'} from 'bar';",
+                        output => "export {foo} from 'bar';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Export" }, type => ExportSpecifier }]
+                    },
+                    {
+                        code => "export {'foo' as foo} from 'bar';",
+                        output => "export {'foo'} from 'bar';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Export" }, type => ExportSpecifier }]
+                    },
+                    {
+                        code => "export {'foo' as 'foo'} from 'bar';",
+                        output => "export {'foo'} from 'bar';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Export" }, type => ExportSpecifier }]
+                    },
+                    {
+                        code => "export {'' as ''} from 'bar';",
+                        output => "export {''} from 'bar';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "", type => "Export" }, type => ExportSpecifier }]
+                    },
+                    {
+                        code => "var a = 0, b = 0; export {a as a, b as b};",
+                        output => "var a = 0, b = 0; export {a, b};",
+                        errors => [
+                            { message_id => "unnecessarily_renamed", data => { name => "a", type => "Export" }, type => ExportSpecifier },
+                            { message_id => "unnecessarily_renamed", data => { name => "b", type => "Export" }, type => ExportSpecifier }
+                        ]
+                    },
+                    {
+                        code => "export {foo as foo} from 'foo';",
+                        output => "export {foo} from 'foo';",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "foo", type => "Export" }, type => ExportSpecifier }]
+                    },
+
+                    // Comments
+                    {
+                        code => "const {/* comment */a: a} = foo;",
+                        output => "const {/* comment */a} = foo;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "const {a/* comment */: a} = foo;",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "const {a: /* comment */a} = foo;",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "const {a: a/* comment */} = foo;",
+                        output => "const {a/* comment */} = foo;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "const {a//comment\n: a} = foo;",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "const {a: a = /* comment */ 1} = foo;",
+                        output => "const {a = /* comment */ 1} = foo;",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({a: (a)} = obj);",
+                        output => "({a} = obj);",
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "({a: (a) = aa} = obj);",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Destructuring assignment" }, type => PairPattern }]
+                    },
+                    {
+                        code => "import {a/* comment */ as a} from 'foo';",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Import" }, type => ImportSpecifier }]
+                    },
+                    {
+                        code => "import {a as /* comment */a} from 'foo';",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Import" }, type => ImportSpecifier }]
+                    },
+                    {
+                        code => "let a; export {a/* comment */ as a};",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Export" }, type => ExportSpecifier }]
+                    },
+                    {
+                        code => "let a; export {a as /* comment */a};",
+                        output => None,
+                        errors => [{ message_id => "unnecessarily_renamed", data => { name => "a", type => "Export" }, type => ExportSpecifier }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,333 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::Deserialize;
+use squalid::regex;
+use tree_sitter_lint::{rule, violation, Rule};
+
+use crate::{all_comments::AllComments, ast_helpers::get_comment_contents, utils::ast_utils};
+
+const CHAR_LIMIT: usize = 40;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Location {
+    #[default]
+    Start,
+    Anywhere,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct Options {
+    terms: Vec<String>,
+    location: Location,
+    decoration: Vec<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            terms: vec!["todo".to_owned(), "fixme".to_owned(), "xxx".to_owned()],
+            location: Default::default(),
+            decoration: Default::default(),
+        }
+    }
+}
+
+fn convert_to_reg_exp(term: &str, location: Location, decoration: &str) -> Regex {
+    let escaped = regex::escape(term);
+    let escaped_decoration = regex::escape(decoration);
+    let word_boundary = r#"\b"#;
+
+    let prefix = if location == Location::Start {
+        format!(r#"^[\s{escaped_decoration}]*"#)
+    } else if regex!(r#"^\w"#).is_match(term) {
+        word_boundary.to_owned()
+    } else {
+        "".to_owned()
+    };
+
+    let suffix = if regex!(r#"\w$"#).is_match(term) {
+        word_boundary
+    } else {
+        ""
+    };
+
+    Regex::new(&format!("(?i){prefix}{escaped}{suffix}")).unwrap()
+}
+
+fn get_comment_to_display(value: &str) -> String {
+    let mut comment_to_display = String::new();
+    let mut truncated = false;
+
+    for c in regex!(r#"\s+"#).split(value.trim()) {
+        let tmp = if comment_to_display.is_empty() {
+            c.to_owned()
+        } else {
+            format!("{comment_to_display} {c}")
+        };
+
+        if tmp.chars().count() <= CHAR_LIMIT {
+            comment_to_display = tmp;
+        } else {
+            truncated = true;
+            break;
+        }
+    }
+
+    if truncated {
+        comment_to_display.push_str("...");
+    }
+    comment_to_display
+}
+
+pub fn no_warning_comments_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-warning-comments",
+        languages => [Javascript],
+        messages => [
+            unexpected_comment => "Unexpected '{{matched_term}}' comment: '{{comment}}'.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            warning_reg_exps: Vec<(String, Regex)> = {
+                let decoration = options.decoration.join("");
+                options
+                    .terms
+                    .iter()
+                    .map(|term| {
+                        (
+                            term.clone(),
+                            convert_to_reg_exp(term, options.location, &decoration),
+                        )
+                    })
+                    .collect()
+            },
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                for &comment in context.retrieve::<AllComments<'a>>().iter() {
+                    let value = get_comment_contents(comment, context);
+
+                    if ast_utils::is_directive_comment(comment, context)
+                        && regex!(r#"\bno-warning-comments\b"#).is_match(&value)
+                    {
+                        continue;
+                    }
+
+                    for (matched_term, _) in self
+                        .warning_reg_exps
+                        .iter()
+                        .filter(|(_, reg_exp)| reg_exp.is_match(&value))
+                    {
+                        context.report(violation! {
+                            node => comment,
+                            message_id => "unexpected_comment",
+                            data => {
+                                matched_term => matched_term.clone(),
+                                comment => get_comment_to_display(&value),
+                            }
+                        });
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::Comment};
+
+    #[test]
+    fn test_no_warning_comments_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_warning_comments_rule(),
+            rule_tests! {
+                valid => [
+                    { code => "// any comment", options => { terms => ["fixme"] } },
+                    { code => "// any comment", options => { terms => ["fixme", "todo"] } },
+                    "// any comment",
+                    { code => "// any comment", options => { location => "anywhere" } },
+                    { code => "// any comment with TODO, FIXME or XXX", options => { location => "start" } },
+                    "// any comment with TODO, FIXME or XXX",
+                    { code => "/* any block comment */", options => { terms => ["fixme"] } },
+                    { code => "/* any block comment */", options => { terms => ["fixme", "todo"] } },
+                    "/* any block comment */",
+                    { code => "/* any block comment */", options => { location => "anywhere" } },
+                    { code => "/* any block comment with TODO, FIXME or XXX */", options => { location => "start" } },
+                    "/* any block comment with TODO, FIXME or XXX */",
+                    "/* any block comment with (TODO, FIXME's or XXX!) */",
+                    { code => "// comments containing terms as substrings like TodoMVC", options => { terms => ["todo"], location => "anywhere" } },
+                    { code => "// special regex characters don't cause problems", options => { terms => ["[aeiou]"], location => "anywhere" } },
+                    "/*eslint no-warning-comments: [2, { \"terms\": [\"todo\", \"fixme\", \"any other term\"], \"location\": \"anywhere\" }]*/\n\nvar x = 10;\n",
+                    { code => "/*eslint no-warning-comments: [2, { \"terms\": [\"todo\", \"fixme\", \"any other term\"], \"location\": \"anywhere\" }]*/\n\nvar x = 10;\n", options => { location => "anywhere" } },
+                    { code => "// foo", options => { terms => ["foo-bar"] } },
+                    "/** multi-line block comment with lines starting with\nTODO\nFIXME or\nXXX\n*/",
+                    { code => "//!TODO ", options => { decoration => ["*"] } }
+                ],
+                invalid => [
+                    {
+                        code => "// fixme",
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "fixme" }, type => Comment }]
+                    },
+                    {
+                        code => "// any fixme",
+                        options => { location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "any fixme" } }]
+                    },
+                    {
+                        code => "// any fixme",
+                        options => { terms => ["fixme"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "any fixme" } }]
+                    },
+                    {
+                        code => "// any FIXME",
+                        options => { terms => ["fixme"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "any FIXME" } }]
+                    },
+                    {
+                        code => "// any fIxMe",
+                        options => { terms => ["fixme"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "any fIxMe" } }]
+                    },
+                    {
+                        code => "/* any fixme */",
+                        options => { terms => ["FIXME"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "FIXME", comment => "any fixme" } }]
+                    },
+                    {
+                        code => "/* any FIXME */",
+                        options => { terms => ["FIXME"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "FIXME", comment => "any FIXME" } }]
+                    },
+                    {
+                        code => "// any fixme or todo",
+                        options => { terms => ["fixme", "todo"], location => "anywhere" },
+                        errors => [
+                            { message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "any fixme or todo" } },
+                            { message_id => "unexpected_comment", data => { matched_term => "todo", comment => "any fixme or todo" } }
+                        ]
+                    },
+                    {
+                        code => "/* any fixme or todo */",
+                        options => { location => "anywhere" },
+                        errors => [
+                            { message_id => "unexpected_comment", data => { matched_term => "todo", comment => "any fixme or todo" } },
+                            { message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "any fixme or todo" } }
+                        ]
+                    },
+                    {
+                        code => "/* fixme and todo */",
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "fixme and todo" } }]
+                    },
+                    {
+                        code => "/* fixme! */",
+                        options => { terms => ["fixme"] },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "fixme!" } }]
+                    },
+                    {
+                        code => "// regex [litera|$]",
+                        options => { terms => ["[litera|$]"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "[litera|$]", comment => "regex [litera|$]" } }]
+                    },
+                    {
+                        code => "/* eslint one-var: 2 */",
+                        options => { terms => ["eslint"] },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "eslint", comment => "eslint one-var: 2" } }]
+                    },
+                    {
+                        code => "/* eslint one-var: 2 */",
+                        options => { terms => ["one"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "one", comment => "eslint one-var: 2" } }]
+                    },
+                    {
+                        code => "/* any block comment with TODO, FIXME or XXX */",
+                        options => { location => "anywhere" },
+                        errors => [
+                            { message_id => "unexpected_comment", data => { matched_term => "todo", comment => "any block comment with TODO, FIXME or..." } },
+                            { message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "any block comment with TODO, FIXME or..." } },
+                            { message_id => "unexpected_comment", data => { matched_term => "xxx", comment => "any block comment with TODO, FIXME or..." } }
+                        ]
+                    },
+                    {
+                        code => "/** \n *any block comment \n*with (TODO, FIXME's or XXX!) **/",
+                        options => { location => "anywhere" },
+                        errors => [
+                            { message_id => "unexpected_comment", data => { matched_term => "todo", comment => "* *any block comment *with (TODO,..." } },
+                            { message_id => "unexpected_comment", data => { matched_term => "fixme", comment => "* *any block comment *with (TODO,..." } },
+                            { message_id => "unexpected_comment", data => { matched_term => "xxx", comment => "* *any block comment *with (TODO,..." } }
+                        ]
+                    },
+                    {
+                        code => "// TODO: something small",
+                        options => { location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "TODO: something small" } }]
+                    },
+                    {
+                        code => "// TODO: something really longer than 40 characters",
+                        options => { location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "TODO: something really longer than 40..." } }]
+                    },
+                    {
+                        code => "/* TODO: something \n really longer than 40 characters \n and also a new line */",
+                        options => { location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "TODO: something really longer than 40..." } }]
+                    },
+                    {
+                        code => "// https://github.com/eslint/eslint/pull/13522#discussion_r470293411 TODO",
+                        options => { location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "..." } }]
+                    },
+
+                    // Tests for terms containing punctuation
+                    {
+                        code => "// Comment ending with term followed by punctuation TODO!",
+                        options => { terms => ["todo"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "Comment ending with term followed by..." } }]
+                    },
+                    {
+                        code => "// !TODO comment starting with term preceded by punctuation",
+                        options => { terms => ["todo"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "!TODO comment starting with term..." } }]
+                    },
+                    {
+                        code => "// FIX!term ending with punctuation followed word character",
+                        options => { terms => ["FIX!"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "FIX!", comment => "FIX!term ending with punctuation..." } }]
+                    },
+                    {
+                        code => "//!XXX comment starting with no spaces (anywhere)",
+                        options => { terms => ["!xxx"], location => "anywhere" },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "!xxx", comment => "!XXX comment starting with no spaces..." } }]
+                    },
+
+                    // Decoration
+                    {
+                        code => "/*\n\t*\t  todo list item\n\t*/",
+                        options => { decoration => ["*"] },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "* todo list item" } }]
+                    },
+                    {
+                        code => "//**TODO term starts with a decoration",
+                        options => { decoration => ["*"] },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "**TODO term starts with a decoration" } }]
+                    },
+                    {
+                        code => "// -= TODO =- term surrounded by decoration",
+                        options => { decoration => ["-", "="] },
+                        errors => [{ message_id => "unexpected_comment", data => { matched_term => "todo", comment => "-= TODO =- term surrounded by decoration" } }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,1367 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::Deserialize;
+use squalid::{regex, OptionExt};
+use tree_sitter_lint::{
+    range_between_start_and_end, rule, tree_sitter::Node, violation, Fixer, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{
+        is_async_function, is_generator_method_definition, skip_parenthesized_expressions,
+    },
+    kind::{
+        self, ArrowFunction, ComputedPropertyName, Function, GeneratorFunction, Identifier,
+        MetaProperty, MethodDefinition, Pair, PropertyIdentifier, ShorthandPropertyIdentifier,
+        StatementBlock, Super,
+    },
+    scope::{Scope, ScopeManager, ScopeType},
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Apply {
+    #[default]
+    Always,
+    Methods,
+    Properties,
+    Never,
+    Consistent,
+    ConsistentAsNeeded,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    EmptyList(),
+    JustApply([Apply; 1]),
+    ApplyAndOptionsObject(Apply, OptionsObject),
+}
+
+impl Default for OptionsVariants {
+    fn default() -> Self {
+        Self::EmptyList()
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    ignore_constructors: bool,
+    #[serde(with = "serde_regex")]
+    methods_ignore_pattern: Option<Regex>,
+    avoid_quotes: bool,
+    avoid_explicit_return_arrows: bool,
+}
+
+struct Options {
+    apply: Apply,
+    ignore_constructors: bool,
+    methods_ignore_pattern: Option<Regex>,
+    avoid_quotes: bool,
+    avoid_explicit_return_arrows: bool,
+}
+
+impl Options {
+    pub fn from_apply_and_options_object(apply: Apply, options_object: OptionsObject) -> Self {
+        Self {
+            apply,
+            ignore_constructors: options_object.ignore_constructors,
+            methods_ignore_pattern: options_object.methods_ignore_pattern,
+            avoid_quotes: options_object.avoid_quotes,
+            avoid_explicit_return_arrows: options_object.avoid_explicit_return_arrows,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::default().into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::EmptyList() => {
+                Self::from_apply_and_options_object(Default::default(), Default::default())
+            }
+            OptionsVariants::JustApply(apply) => {
+                Self::from_apply_and_options_object(apply[0], Default::default())
+            }
+            OptionsVariants::ApplyAndOptionsObject(apply, options_object) => {
+                Self::from_apply_and_options_object(apply, options_object)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+fn is_constructor(name: &str) -> bool {
+    let Some(first_char) = regex!(r#"[^_$0-9]"#)
+        .find(name)
+        .map(|match_| match_.as_str().chars().next().unwrap())
+    else {
+        return false;
+    };
+    first_char.to_uppercase().eq([first_char])
+}
+
+fn is_getter_or_setter(node: Node) -> bool {
+    node.kind() == MethodDefinition && node.has_child_of_kinds(&["get", "set"])
+}
+
+fn can_have_shorthand(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        Pair | MethodDefinition | ShorthandPropertyIdentifier
+    ) && !is_getter_or_setter(node)
+}
+
+fn is_shorthand(node: Node) -> bool {
+    matches!(node.kind(), MethodDefinition | ShorthandPropertyIdentifier)
+}
+
+fn is_redundant(node: Node, context: &QueryMatchContext) -> bool {
+    if node.kind() != Pair {
+        return false;
+    }
+    let value = skip_parenthesized_expressions(node.field("value"));
+    match value.kind() {
+        Function | GeneratorFunction => value.child_by_field_name("name").is_none(),
+        Identifier => ast_utils::get_static_property_name(node, context)
+            .matches(|name| name == value.text(context)),
+        _ => false,
+    }
+}
+
+fn is_function_value(node: Node) -> bool {
+    matches!(node.kind(), Function | GeneratorFunction | ArrowFunction)
+}
+
+fn contains_super_or_new_target(node: Node) -> bool {
+    let mut cursor = node.walk();
+    let ret = node
+        .named_children(&mut cursor)
+        .any(|child| match child.kind() {
+            Super => true,
+            MetaProperty => child.has_child_of_kind("new"),
+            kind if ast_utils::is_function(child) && kind != ArrowFunction => false,
+            _ => contains_super_or_new_target(child),
+        });
+    ret
+}
+
+fn creates_own_this_binding(scope: &Scope) -> bool {
+    match scope.type_() {
+        ScopeType::Function => scope.block().kind() != ArrowFunction,
+        ScopeType::FunctionExpressionName
+        | ScopeType::ClassFieldInitializer
+        | ScopeType::ClassStaticBlock => true,
+        _ => false,
+    }
+}
+
+fn uses_lexical_this_or_arguments(scope: &Scope, context: &QueryMatchContext) -> bool {
+    scope.type_() == ScopeType::Function && scope.is_this_materialized()
+        || scope.references().any(|reference| {
+            reference.identifier().text(context) == "arguments"
+                && reference.resolved().map_or(true, |variable| {
+                    variable.name() == "arguments" && variable.defs().next().is_none()
+                })
+        })
+        || scope.child_scopes().any(|child_scope| {
+            !creates_own_this_binding(&child_scope)
+                && uses_lexical_this_or_arguments(&child_scope, context)
+        })
+}
+
+/// Determines whether converting the given arrow function into a (non-arrow)
+/// method would change the meaning of `this`, `arguments`, `super` or
+/// `new.target` inside of it.
+fn arrow_function_has_lexical_identifier<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if contains_super_or_new_target(node) {
+        return true;
+    }
+
+    let scope_manager = context.retrieve::<ScopeManager<'a>>();
+    scope_manager
+        .acquire(node, None)
+        .matches(|scope| uses_lexical_this_or_arguments(&scope, context))
+}
+
+fn make_function_shorthand<'a>(
+    fixer: &mut Fixer,
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let key = node.field("key");
+    let value = skip_parenthesized_expressions(node.field("value"));
+
+    // key: /* */ () => {}
+    if context.comments_exist_between(key, value) {
+        return;
+    }
+
+    let mut key_prefix = String::new();
+    if is_async_function(value) {
+        key_prefix.push_str("async ");
+    }
+    if value.kind() == GeneratorFunction {
+        key_prefix.push('*');
+    }
+
+    let fix_range = range_between_start_and_end(key.range(), node.range());
+    let method_prefix = format!("{key_prefix}{}", key.text(context));
+
+    if value.kind() != ArrowFunction {
+        let function_token = value.maybe_first_child_of_kind("function").unwrap();
+        let token_before_params = if value.kind() == GeneratorFunction {
+            context.get_token_after(function_token, Option::<fn(Node) -> bool>::None)
+        } else {
+            function_token
+        };
+        fixer.replace_text_range(
+            fix_range,
+            format!(
+                "{method_prefix}{}",
+                context.slice(token_before_params.end_byte()..value.end_byte())
+            ),
+        );
+        return;
+    }
+
+    let arrow_token = value.maybe_first_child_of_kind("=>").unwrap();
+    let fn_body = context.slice(arrow_token.end_byte()..value.end_byte());
+    let new_param_text = match value.child_by_field_name("parameter") {
+        Some(parameter) => format!("({})", parameter.text(context)),
+        None => value.field("parameters").text(context).into_owned(),
+    };
+
+    fixer.replace_text_range(
+        fix_range,
+        format!("{method_prefix}{new_param_text}{fn_body}"),
+    );
+}
+
+fn make_function_longform<'a>(
+    fixer: &mut Fixer,
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let key = node.field("name");
+    let key_text = key.text(context);
+
+    let mut function_header = "function".to_owned();
+    if node.has_child_of_kind("async") {
+        function_header = format!("async {function_header}");
+    }
+    if is_generator_method_definition(node, context) {
+        function_header = format!("{function_header}*");
+    }
+
+    fixer.replace_text_range(
+        range_between_start_and_end(node.range(), key.range()),
+        format!("{key_text}: {function_header}"),
+    );
+}
+
+pub fn object_shorthand_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "object-shorthand",
+        languages => [Javascript],
+        messages => [
+            expected_all_properties_shorthanded => "Expected shorthand for all properties.",
+            expected_literal_method_longform => "Expected longform method syntax for string literal keys.",
+            expected_property_shorthand => "Expected property shorthand.",
+            expected_property_longform => "Expected longform property syntax.",
+            expected_method_shorthand => "Expected method shorthand.",
+            expected_method_longform => "Expected longform method syntax.",
+            unexpected_mix => "Unexpected mix of shorthand and non-shorthand properties.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            apply_to_methods: bool = matches!(options.apply, Apply::Methods | Apply::Always),
+            apply_to_props: bool = matches!(options.apply, Apply::Properties | Apply::Always),
+            apply_never: bool = options.apply == Apply::Never,
+            apply_consistent: bool = options.apply == Apply::Consistent,
+            apply_consistent_as_needed: bool = options.apply == Apply::ConsistentAsNeeded,
+            ignore_constructors: bool = options.ignore_constructors,
+            methods_ignore_pattern: Option<Regex> = options.methods_ignore_pattern.clone(),
+            avoid_quotes: bool = options.avoid_quotes,
+            avoid_explicit_return_arrows: bool = options.avoid_explicit_return_arrows,
+        },
+        methods => {
+            fn check_consistency(&self, node: Node<'a>, check_redundancy: bool, context: &QueryMatchContext<'a, '_>) {
+                let properties = node
+                    .non_comment_named_children(context)
+                    .filter(|&property| can_have_shorthand(property))
+                    .collect::<Vec<_>>();
+
+                if properties.is_empty() {
+                    return;
+                }
+
+                let num_shorthand_properties = properties
+                    .iter()
+                    .filter(|&&property| is_shorthand(property))
+                    .count();
+
+                if num_shorthand_properties == properties.len() {
+                    return;
+                }
+
+                if num_shorthand_properties > 0 {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "unexpected_mix",
+                    });
+                } else if check_redundancy {
+                    let can_always_use_shorthand = properties
+                        .iter()
+                        .all(|&property| is_redundant(property, context));
+                    if can_always_use_shorthand {
+                        context.report(violation! {
+                            node => node,
+                            message_id => "expected_all_properties_shorthanded",
+                        });
+                    }
+                }
+            }
+
+            fn check_pair(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let key = node.field("key");
+                let value = skip_parenthesized_expressions(node.field("value"));
+
+                // only computed methods can fail the following checks
+                if key.kind() == ComputedPropertyName && !is_function_value(value) {
+                    return;
+                }
+
+                if self.apply_to_methods
+                    && is_function_value(value)
+                    && value.child_by_field_name("name").is_none()
+                {
+                    if self.ignore_constructors
+                        && key.kind() == PropertyIdentifier
+                        && is_constructor(&key.text(context))
+                    {
+                        return;
+                    }
+
+                    if let Some(methods_ignore_pattern) = self.methods_ignore_pattern.as_ref() {
+                        if ast_utils::get_static_property_name(node, context)
+                            .matches(|property_name| methods_ignore_pattern.is_match(&property_name))
+                        {
+                            return;
+                        }
+                    }
+
+                    if self.avoid_quotes && key.kind() == kind::String {
+                        return;
+                    }
+
+                    // {[x]: function(){}} should be written as {[x]() {}}
+                    if value.kind() != ArrowFunction
+                        || value.field("body").kind() == StatementBlock
+                            && self.avoid_explicit_return_arrows
+                            && !arrow_function_has_lexical_identifier(value, context)
+                    {
+                        context.report(violation! {
+                            node => node,
+                            message_id => "expected_method_shorthand",
+                            fix => |fixer| {
+                                make_function_shorthand(fixer, node, context);
+                            }
+                        });
+                    }
+                } else if value.kind() == Identifier
+                    && key.kind() == PropertyIdentifier
+                    && key.text(context) == value.text(context)
+                    && self.apply_to_props
+                {
+                    // {x: x} should be written as {x}
+                    context.report(violation! {
+                        node => node,
+                        message_id => "expected_property_shorthand",
+                        fix => |fixer| {
+                            // x: /* */ x
+                            // x: (/* */ x)
+                            if context.get_comments_inside(node).next().is_some() {
+                                return;
+                            }
+                            fixer.replace_text(node, value.text(context));
+                        }
+                    });
+                } else if value.kind() == Identifier
+                    && key.kind() == kind::String
+                    && ast_utils::get_static_string_value(key, context)
+                        .matches(|key_value| key_value == value.text(context))
+                    && self.apply_to_props
+                {
+                    if self.avoid_quotes {
+                        return;
+                    }
+
+                    // {"x": x} should be written as {x}
+                    context.report(violation! {
+                        node => node,
+                        message_id => "expected_property_shorthand",
+                        fix => |fixer| {
+                            if context.get_comments_inside(node).next().is_some() {
+                                return;
+                            }
+                            fixer.replace_text(node, value.text(context));
+                        }
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (object) @c
+            "# => |node, context| {
+                if self.apply_consistent {
+                    self.check_consistency(node, false, context);
+                } else if self.apply_consistent_as_needed {
+                    self.check_consistency(node, true, context);
+                }
+            },
+            r#"
+              (object
+                (method_definition) @c
+              )
+            "# => |node, context| {
+                // getters and setters are ignored
+                if is_getter_or_setter(node) {
+                    return;
+                }
+
+                if self.apply_never
+                    || self.avoid_quotes && node.field("name").kind() == kind::String
+                {
+                    // { x() {} } should be written as { x: function() {} }
+                    context.report(violation! {
+                        node => node,
+                        message_id => if self.apply_never {
+                            "expected_method_longform"
+                        } else {
+                            "expected_literal_method_longform"
+                        },
+                        fix => |fixer| {
+                            make_function_longform(fixer, node, context);
+                        }
+                    });
+                }
+            },
+            r#"
+              (object
+                (shorthand_property_identifier) @c
+              )
+            "# => |node, context| {
+                if self.apply_never {
+                    // { x } should be written as { x: x }
+                    context.report(violation! {
+                        node => node,
+                        message_id => "expected_property_longform",
+                        fix => |fixer| {
+                            fixer.insert_text_after(node, format!(": {}", node.text(context)));
+                        }
+                    });
+                }
+            },
+            r#"
+              (object
+                (pair) @c
+              )
+            "# => |node, context| {
+                self.check_pair(node, context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use squalid::json_object;
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::Object};
+
+    #[test]
+    fn test_object_shorthand_rule() {
+        RuleTester::run_with_instance_provider_and_environment(
+            object_shorthand_rule(),
+            rule_tests! {
+                valid => [
+                    "var x = {y() {}}",
+                    "var x = {y}",
+                    "var x = {a: b}",
+                    "var x = {a: 'a'}",
+                    "var x = {'a': 'a'}",
+                    "var x = {'a': b}",
+                    "var x = {y(x) {}}",
+                    "var {x,y,z} = x",
+                    "var {x: {y}} = z",
+                    "var x = {*x() {}}",
+                    "var x = {x: y}",
+                    "var x = {x: y, y: z}",
+                    "var x = {x: y, y: z, z: 'z'}",
+                    "var x = {x() {}, y: z, l(){}}",
+                    "var x = {x: y, y: z, a: b}",
+                    "var x = {x: y, y: z, 'a': b}",
+                    "var x = {x: y, y() {}, z: a}",
+                    "var x = {[y]: y}",
+                    "doSomething({x: y})",
+                    "doSomething({'x': y})",
+                    "doSomething({x: 'x'})",
+                    "doSomething({'x': 'x'})",
+                    "doSomething({y() {}})",
+                    "doSomething({x: y, y() {}})",
+                    "doSomething({y() {}, z: a})",
+                    "!{ a: function a(){} };",
+
+                    // arrow functions are still alright by default
+                    "var x = {y: (x)=>x}",
+                    "doSomething({y: (x)=>x})",
+                    "var x = {y: (x)=>x, y: a}",
+                    "doSomething({x, y: (x)=>x})",
+                    "({ foo: x => { return; }})",
+                    "({ foo: (x) => { return; }})",
+                    "({ foo: () => { return; }})",
+
+                    // getters and setters
+                    "var x = {get y() {}}",
+                    "var x = {set y(z) {}}",
+                    "var x = {get y() {}, set y(z) {}}",
+                    "doSomething({get y() {}})",
+                    "doSomething({set y(z) {}})",
+                    "doSomething({get y() {}, set y(z) {}})",
+
+                    // object literal destructuring
+                    "var {a: {b}} = c",
+                    "var {x: {y}} = z",
+                    "var {a: x} = b",
+                    "({a: x} = b)",
+
+                    // ignoreConstructors
+                    { code => "var x = {ConstructorFunction: function(){}, a: b}", options => ["always", { ignore_constructors => true }] },
+                    { code => "var x = {_ConstructorFunction: function(){}, a: b}", options => ["always", { ignore_constructors => true }] },
+                    { code => "var x = {$ConstructorFunction: function(){}, a: b}", options => ["always", { ignore_constructors => true }] },
+                    { code => "var x = {__ConstructorFunction: function(){}, a: b}", options => ["always", { ignore_constructors => true }] },
+                    { code => "var x = {_0ConstructorFunction: function(){}, a: b}", options => ["always", { ignore_constructors => true }] },
+                    { code => "var x = {notConstructorFunction(){}, b: c}", options => ["always", { ignore_constructors => true }] },
+                    { code => "var x = {ConstructorFunction: function(){}, a: b}", options => ["methods", { ignore_constructors => true }] },
+                    { code => "var x = {_ConstructorFunction: function(){}, a: b}", options => ["methods", { ignore_constructors => true }] },
+                    { code => "var x = {ConstructorFunction: function(){}, a: b}", options => ["never"] },
+                    { code => "var x = {notConstructorFunction: function(){}, b: c}", options => ["never"] },
+
+                    // methodsIgnorePattern
+                    { code => "var x = { foo: function() {}  }", options => ["always", { methods_ignore_pattern => "^foo$" }] },
+                    { code => "var x = { foo: function() {}  }", options => ["methods", { methods_ignore_pattern => "^foo$" }] },
+                    { code => "var x = { foo: function*() {}  }", options => ["always", { methods_ignore_pattern => "^foo$" }] },
+                    { code => "var x = { foo: async function() {}  }", options => ["always", { methods_ignore_pattern => "^foo$" }] },
+                    { code => "var x = { foo: () => { return 5; }  }", options => ["always", { methods_ignore_pattern => "^foo$", avoid_explicit_return_arrows => true }] },
+                    { code => "var x = { 'foo': function() {}  }", options => ["always", { methods_ignore_pattern => "^foo$" }] },
+                    { code => "var x = { ['foo']: function() {}  }", options => ["always", { methods_ignore_pattern => "^foo$" }] },
+                    { code => "var x = { 123: function() {}  }", options => ["always", { methods_ignore_pattern => "^123$" }] },
+                    { code => "var x = { afoob: function() {}  }", options => ["always", { methods_ignore_pattern => "foo" }] },
+                    { code => "var x = { afoob: function() {}  }", options => ["always", { methods_ignore_pattern => "^.foo.$" }] },
+
+                    // avoidQuotes
+                    { code => "var x = {'a': function(){}}", options => ["always", { avoid_quotes => true }] },
+                    { code => "var x = {['a']: function(){}}", options => ["methods", { avoid_quotes => true }] },
+                    { code => "var x = {'y': y}", options => ["properties", { avoid_quotes => true }] },
+
+                    // consistent
+                    { code => "var x = {a: a, b: b}", options => ["consistent"] },
+                    { code => "var x = {a: b, c: d, f: g}", options => ["consistent"] },
+                    { code => "var x = {a, b}", options => ["consistent"] },
+                    { code => "var x = {a, b, get test() { return 1; }}", options => ["consistent"] },
+                    { code => "var x = {...bar}", options => ["consistent-as-needed"], environment => { ecma_version => 2018 } },
+                    { code => "var x = {foo, bar, ...baz}", options => ["consistent"], environment => { ecma_version => 2018 } },
+                    { code => "var x = {bar: baz, ...qux}", options => ["consistent"], environment => { ecma_version => 2018 } },
+                    { code => "var x = {...foo, bar: baz}", options => ["consistent"], environment => { ecma_version => 2018 } },
+
+                    // consistent-as-needed
+                    { code => "var x = {a, b}", options => ["consistent-as-needed"] },
+                    { code => "var x = {a, b, get test(){return 1;}}", options => ["consistent-as-needed"] },
+                    { code => "var x = {0: 'foo'}", options => ["consistent-as-needed"] },
+                    { code => "var x = {'key': 'baz'}", options => ["consistent-as-needed"] },
+                    { code => "var x = {foo: 'foo'}", options => ["consistent-as-needed"] },
+                    { code => "var x = {[foo]: foo}", options => ["consistent-as-needed"] },
+                    { code => "var x = {foo: function foo() {}}", options => ["consistent-as-needed"] },
+                    { code => "var x = {[foo]: 'foo'}", options => ["consistent-as-needed"] },
+                    { code => "var x = {bar, ...baz}", options => ["consistent-as-needed"], environment => { ecma_version => 2018 } },
+                    { code => "var x = {bar: baz, ...qux}", options => ["consistent-as-needed"], environment => { ecma_version => 2018 } },
+                    { code => "var x = {...foo, bar}", options => ["consistent-as-needed"], environment => { ecma_version => 2018 } },
+
+                    // avoidExplicitReturnArrows
+                    { code => "({ x: () => foo })", options => ["always", { avoid_explicit_return_arrows => false }] },
+                    { code => "({ x: () => { return; } })", options => ["always", { avoid_explicit_return_arrows => false }] },
+                    { code => "({ x() { return; } })", options => ["always", { avoid_explicit_return_arrows => true }] },
+                    { code => "({ x() { return; }, y() { return; } })", options => ["always", { avoid_explicit_return_arrows => true }] },
+                    { code => "({ x() { return; }, y: () => foo })", options => ["always", { avoid_explicit_return_arrows => true }] },
+                    { code => "({ x: () => foo, y() { return; } })", options => ["always", { avoid_explicit_return_arrows => true }] },
+                    { code => "({ x: () => { this; } })", options => ["always", { avoid_explicit_return_arrows => true }] },
+                    { code => "function foo() { ({ x: () => { arguments; } }) }", options => ["always", { avoid_explicit_return_arrows => true }] },
+                    {
+                        code => "
+                            class Foo extends Bar {
+                                constructor() {
+                                    var foo = { x: () => { super(); } };
+                                }
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }]
+                    },
+                    {
+                        code => "
+                            class Foo extends Bar {
+                                baz() {
+                                    var foo = { x: () => { super.baz(); } };
+                                }
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }]
+                    },
+                    {
+                        code => "
+                            function foo() {
+                                var x = { x: () => { new.target; } };
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }]
+                    },
+                    {
+                        code => "
+                            function foo() {
+                                var x = {
+                                    x: () => {
+                                        var y = () => { this; };
+                                    }
+                                };
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }]
+                    },
+                    {
+                        code => "
+                            function foo() {
+                                var x = {
+                                    x: () => {
+                                        var y = () => { this; };
+                                        function foo() { this; }
+                                    }
+                                };
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }]
+                    },
+                    {
+                        code => "
+                            function foo() {
+                                var x = {
+                                    x: () => {
+                                        return { y: () => { this; } };
+                                    }
+                                };
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }]
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "var x = {x: x}",
+                        output => "var x = {x}",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {'x': x}",
+                        output => "var x = {x}",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {y: y, x: x}",
+                        output => "var x = {y, x}",
+                        errors => [
+                            { message_id => "expected_property_shorthand", type => Pair },
+                            { message_id => "expected_property_shorthand", type => Pair }
+                        ]
+                    },
+                    {
+                        code => "var x = {y: z, x: x, a: b}",
+                        output => "var x = {y: z, x, a: b}",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {y: z,\n x: x,\n a: b\n // comment \n}",
+                        output => "var x = {y: z,\n x,\n a: b\n // comment \n}",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {y: z,\n a: b,\n // comment \nf: function() {}}",
+                        output => "var x = {y: z,\n a: b,\n // comment \nf() {}}",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {a: b,\n/* comment */\ny: y\n }",
+                        output => "var x = {a: b,\n/* comment */\ny\n }",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {\n  a: b,\n  /* comment */\n  y: y\n}",
+                        output => "var x = {\n  a: b,\n  /* comment */\n  y\n}",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {\n  f: function() {\n    /* comment */\n    a(b);\n    }\n  }",
+                        output => "var x = {\n  f() {\n    /* comment */\n    a(b);\n    }\n  }",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {\n  [f]: function() {\n    /* comment */\n    a(b);\n    }\n  }",
+                        output => "var x = {\n  [f]() {\n    /* comment */\n    a(b);\n    }\n  }",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {\n  f: function*() {\n    /* comment */\n    a(b);\n    }\n  }",
+                        output => "var x = {\n  *f() {\n    /* comment */\n    a(b);\n    }\n  }",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {\n  f: /* comment */ function() {\n  }\n  }",
+                        output => None,
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {\n f /* comment */: function() {\n  }\n  }",
+                        output => None,
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {a: /* comment */ a}",
+                        output => None,
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {a /* comment */: a}",
+                        output => None,
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {a: (a /* comment */)}",
+                        output => None,
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {'a': /* comment */ a}",
+                        output => None,
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {'a': (a /* comment */)}",
+                        output => None,
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {'a' /* comment */: a}",
+                        output => None,
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {y: function() {}}",
+                        output => "var x = {y() {}}",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {y: function*() {}}",
+                        output => "var x = {*y() {}}",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {x: y, y: z, a: a}",
+                        output => "var x = {x: y, y: z, a}",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {ConstructorFunction: function(){}, a: b}",
+                        output => "var x = {ConstructorFunction(){}, a: b}",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {x: y, y: z, a: function(){}, b() {}}",
+                        output => "var x = {x: y, y: z, a(){}, b() {}}",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {x: x, y: function() {}}",
+                        output => "var x = {x, y() {}}",
+                        errors => [
+                            { message_id => "expected_property_shorthand", type => Pair },
+                            { message_id => "expected_method_shorthand", type => Pair }
+                        ]
+                    },
+                    {
+                        code => "doSomething({x: x})",
+                        output => "doSomething({x})",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "doSomething({'x': x})",
+                        output => "doSomething({x})",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "doSomething({a: 'a', 'x': x})",
+                        output => "doSomething({a: 'a', x})",
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "doSomething({y: function() {}})",
+                        output => "doSomething({y() {}})",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "doSomething({[y]: function() {}})",
+                        output => "doSomething({[y]() {}})",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "doSomething({['y']: function() {}})",
+                        output => "doSomething({['y']() {}})",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ foo: async function () {} })",
+                        output => "({ async foo () {} })",
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ 'foo': async function() {} })",
+                        output => "({ async 'foo'() {} })",
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ [foo]: async function() {} })",
+                        output => "({ async [foo]() {} })",
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ [foo.bar]: function*() {} })",
+                        output => "({ *[foo.bar]() {} })",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ [foo   ]: function() {} })",
+                        output => "({ [foo   ]() {} })",
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ [ foo ]: async function() {} })",
+                        output => "({ async [ foo ]() {} })",
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ foo: async function*() {} })",
+                        output => "({ async *foo() {} })",
+                        environment => { ecma_version => 2018 },
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+
+                    // options
+                    {
+                        code => "var x = {y: function() {}}",
+                        output => "var x = {y() {}}",
+                        options => ["methods"],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {x, y() {}, z: function() {}}",
+                        output => "var x = {x, y() {}, z() {}}",
+                        options => ["methods"],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {ConstructorFunction: function(){}, a: b}",
+                        output => "var x = {ConstructorFunction(){}, a: b}",
+                        options => ["methods"],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {[y]: y}",
+                        output => None,
+                        options => ["properties"],
+                        errors => []
+                    },
+                    {
+                        code => "var x = {x: x}",
+                        output => "var x = {x}",
+                        options => ["properties"],
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {a, b, c(){}, x: x}",
+                        output => "var x = {a, b, c(){}, x}",
+                        options => ["properties"],
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {y() {}}",
+                        output => "var x = {y: function() {}}",
+                        options => ["never"],
+                        errors => [{ message_id => "expected_method_longform", type => MethodDefinition }]
+                    },
+                    {
+                        code => "var x = {*y() {}}",
+                        output => "var x = {y: function*() {}}",
+                        options => ["never"],
+                        errors => [{ message_id => "expected_method_longform", type => MethodDefinition }]
+                    },
+                    {
+                        code => "var x = {y}",
+                        output => "var x = {y: y}",
+                        options => ["never"],
+                        errors => [{ message_id => "expected_property_longform", type => ShorthandPropertyIdentifier }]
+                    },
+                    {
+                        code => "var x = {y, a: b, *x(){}}",
+                        output => "var x = {y: y, a: b, x: function*(){}}",
+                        options => ["never"],
+                        errors => [
+                            { message_id => "expected_property_longform", type => ShorthandPropertyIdentifier },
+                            { message_id => "expected_method_longform", type => MethodDefinition }
+                        ]
+                    },
+                    {
+                        code => "var x = {y: {x}}",
+                        output => "var x = {y: {x: x}}",
+                        options => ["never"],
+                        errors => [{ message_id => "expected_property_longform", type => ShorthandPropertyIdentifier }]
+                    },
+                    {
+                        code => "var x = {ConstructorFunction(){}, a: b}",
+                        output => "var x = {ConstructorFunction: function(){}, a: b}",
+                        options => ["never"],
+                        errors => [{ message_id => "expected_method_longform", type => MethodDefinition }]
+                    },
+                    {
+                        code => "var x = {notConstructorFunction(){}, b: c}",
+                        output => "var x = {notConstructorFunction: function(){}, b: c}",
+                        options => ["never"],
+                        errors => [{ message_id => "expected_method_longform", type => MethodDefinition }]
+                    },
+                    {
+                        code => "var x = {foo, bar: baz, ...qux}",
+                        output => "var x = {foo: foo, bar: baz, ...qux}",
+                        options => ["never"],
+                        environment => { ecma_version => 2018 },
+                        errors => [{ message_id => "expected_property_longform", type => ShorthandPropertyIdentifier }]
+                    },
+                    {
+                        code => "({ async foo() {} })",
+                        output => "({ foo: async function() {} })",
+                        options => ["never"],
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "expected_method_longform", type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ async *foo() {} })",
+                        output => "({ foo: async function*() {} })",
+                        options => ["never"],
+                        environment => { ecma_version => 2018 },
+                        errors => [{ message_id => "expected_method_longform", type => MethodDefinition }]
+                    },
+                    {
+                        code => "({ [foo]() {} })",
+                        output => "({ [foo]: function() {} })",
+                        options => ["never"],
+                        errors => [{ message_id => "expected_method_longform", type => MethodDefinition }]
+                    },
+
+                    // ignoreConstructors
+                    {
+                        code => "var x = {y: function() {}}",
+                        output => "var x = {y() {}}",
+                        options => ["methods", { ignore_constructors => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {_y: function() {}}",
+                        output => "var x = {_y() {}}",
+                        options => ["methods", { ignore_constructors => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {$y: function() {}}",
+                        output => "var x = {$y() {}}",
+                        options => ["methods", { ignore_constructors => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {__y: function() {}}",
+                        output => "var x = {__y() {}}",
+                        options => ["methods", { ignore_constructors => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {_0y: function() {}}",
+                        output => "var x = {_0y() {}}",
+                        options => ["methods", { ignore_constructors => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+
+                    // methodsIgnorePattern
+                    {
+                        code => "var x = { afoob: function() {} }",
+                        output => "var x = { afoob() {} }",
+                        options => ["always", { methods_ignore_pattern => "^foo$" }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = { afoob: function() {} }",
+                        output => "var x = { afoob() {} }",
+                        options => ["methods", { methods_ignore_pattern => "^foo$" }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = { 'afoob': function() {} }",
+                        output => "var x = { 'afoob'() {} }",
+                        options => ["always", { methods_ignore_pattern => "^foo$" }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = { 1234: function() {} }",
+                        output => "var x = { 1234() {} }",
+                        options => ["always", { methods_ignore_pattern => "^123$" }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = { bar: function() {} }",
+                        output => "var x = { bar() {} }",
+                        options => ["always", { methods_ignore_pattern => "foo" }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = { [foo]: function() {} }",
+                        output => "var x = { [foo]() {} }",
+                        options => ["always", { methods_ignore_pattern => "foo" }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = { foo: foo }",
+                        output => "var x = { foo }",
+                        options => ["always", { methods_ignore_pattern => "^foo$" }],
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+
+                    // avoidQuotes
+                    {
+                        code => "var x = {a: a}",
+                        output => "var x = {a}",
+                        options => ["always", { avoid_quotes => true }],
+                        errors => [{ message_id => "expected_property_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {a: function(){}}",
+                        output => "var x = {a(){}}",
+                        options => ["methods", { avoid_quotes => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {[a]: function(){}}",
+                        output => "var x = {[a](){}}",
+                        options => ["methods", { avoid_quotes => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "var x = {'a'(){}}",
+                        output => "var x = {'a': function(){}}",
+                        options => ["always", { avoid_quotes => true }],
+                        errors => [{ message_id => "expected_literal_method_longform", type => MethodDefinition }]
+                    },
+                    {
+                        code => "var x = {['a'](){}}",
+                        output => "var x = {['a']: function(){}}",
+                        options => ["methods", { avoid_quotes => true }],
+                        errors => []
+                    },
+
+                    // consistent
+                    {
+                        code => "var x = {a: a, b}",
+                        output => None,
+                        options => ["consistent"],
+                        errors => [{ message_id => "unexpected_mix", type => Object }]
+                    },
+                    {
+                        code => "var x = {b, c: d, f: g}",
+                        output => None,
+                        options => ["consistent"],
+                        errors => [{ message_id => "unexpected_mix", type => Object }]
+                    },
+                    {
+                        code => "var x = {foo, bar: baz, ...qux}",
+                        output => None,
+                        options => ["consistent"],
+                        environment => { ecma_version => 2018 },
+                        errors => [{ message_id => "unexpected_mix", type => Object }]
+                    },
+
+                    // consistent-as-needed
+                    {
+                        code => "var x = {a: a, b: b}",
+                        output => None,
+                        options => ["consistent-as-needed"],
+                        errors => [{ message_id => "expected_all_properties_shorthanded", type => Object }]
+                    },
+                    {
+                        code => "var x = {a, z: function z(){}}",
+                        output => None,
+                        options => ["consistent-as-needed"],
+                        errors => [{ message_id => "unexpected_mix", type => Object }]
+                    },
+                    {
+                        code => "var x = {foo: function() {}}",
+                        output => None,
+                        options => ["consistent-as-needed"],
+                        errors => [{ message_id => "expected_all_properties_shorthanded", type => Object }]
+                    },
+                    {
+                        code => "var x = {a: a, b: b, ...baz}",
+                        output => None,
+                        options => ["consistent-as-needed"],
+                        environment => { ecma_version => 2018 },
+                        errors => [{ message_id => "expected_all_properties_shorthanded", type => Object }]
+                    },
+                    {
+                        code => "var x = {foo, bar: bar, ...qux}",
+                        output => None,
+                        options => ["consistent-as-needed"],
+                        environment => { ecma_version => 2018 },
+                        errors => [{ message_id => "unexpected_mix", type => Object }]
+                    },
+
+                    // avoidExplicitReturnArrows
+                    {
+                        code => "({ x: () => { return; } })",
+                        output => "({ x() { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x() { foo; }, y: () => { return; } })",
+                        output => "({ x() { foo; }, y() { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x: () => { return; }, y: () => foo })",
+                        output => "({ x() { return; }, y: () => foo })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x: () => { return; }, y: () => { return; } })",
+                        output => "({ x() { return; }, y() { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [
+                            { message_id => "expected_method_shorthand", type => Pair },
+                            { message_id => "expected_method_shorthand", type => Pair }
+                        ]
+                    },
+                    {
+                        code => "({ x: foo => { return; } })",
+                        output => "({ x(foo) { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x: (foo = 1) => { return; } })",
+                        output => "({ x(foo = 1) { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x: ({ foo: bar = 1 } = {}) => { return; } })",
+                        output => "({ x({ foo: bar = 1 } = {}) { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x: () => { function foo() { this; } } })",
+                        output => "({ x() { function foo() { this; } } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x: () => { var foo = function() { arguments; } } })",
+                        output => "({ x() { var foo = function() { arguments; } } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ x: () => { function foo() { arguments; } } })",
+                        output => "({ x() { function foo() { arguments; } } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "
+                            ({
+                                x: () => {
+                                    class Foo extends Bar {
+                                        constructor() {
+                                            super();
+                                        }
+                                    }
+                                }
+                            })
+                        ",
+                        output => "
+                            ({
+                                x() {
+                                    class Foo extends Bar {
+                                        constructor() {
+                                            super();
+                                        }
+                                    }
+                                }
+                            })
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "
+                            ({
+                                x: () => {
+                                    function foo() {
+                                        new.target;
+                                    }
+                                }
+                            })
+                        ",
+                        output => "
+                            ({
+                                x() {
+                                    function foo() {
+                                        new.target;
+                                    }
+                                }
+                            })
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ 'foo bar': () => { return; } })",
+                        output => "({ 'foo bar'() { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ [foo]: () => { return; } })",
+                        output => "({ [foo]() { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ a: 1, foo: async (bar = 1) => { return; } })",
+                        output => "({ a: 1, async foo(bar = 1) { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ [ foo ]: async bar => { return; } })",
+                        output => "({ async [ foo ](bar) { return; } })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "({ key: (arg = () => {}) => {} })",
+                        output => "({ key(arg = () => {}) {} })",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair }]
+                    },
+                    {
+                        code => "
+                            function foo() {
+                                var x = {
+                                    x: () => {
+                                        this;
+                                        return { y: () => { foo; } };
+                                    }
+                                };
+                            }
+                        ",
+                        output => "
+                            function foo() {
+                                var x = {
+                                    x: () => {
+                                        this;
+                                        return { y() { foo; } };
+                                    }
+                                };
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair, line => 6 }]
+                    },
+                    {
+                        code => "
+                            function foo() {
+                                var x = {
+                                    x: () => {
+                                        ({ y: () => { foo; } });
+                                        this;
+                                    }
+                                };
+                            }
+                        ",
+                        output => "
+                            function foo() {
+                                var x = {
+                                    x: () => {
+                                        ({ y() { foo; } });
+                                        this;
+                                    }
+                                };
+                            }
+                        ",
+                        options => ["always", { avoid_explicit_return_arrows => true }],
+                        errors => [{ message_id => "expected_method_shorthand", type => Pair, line => 5 }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+            json_object!({
+                "ecma_version": 6,
+            }),
+        )
+    }
+}
@@ -0,0 +1,1334 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    range_between_start_and_end, range_between_starts, rule,
+    tree_sitter::{Node, Range},
+    tree_sitter_grep::SupportedLanguage,
+    violation, NodeExt, QueryMatchContext, Rule, SkipOptionsBuilder,
+};
+
+use crate::{
+    ast_helpers::maybe_get_prev_non_comment_sibling,
+    kind::{
+        CallExpression, Comment, ExportStatement, ForInStatement, ForStatement, Identifier,
+        LexicalDeclaration, Program, StatementBlock, SwitchCase, VariableDeclaration,
+    },
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+    Always,
+    Never,
+    Consecutive,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsObject {
+    var: Option<Mode>,
+    r#let: Option<Mode>,
+    r#const: Option<Mode>,
+    separate_requires: bool,
+    initialized: Option<Mode>,
+    uninitialized: Option<Mode>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OptionsVariants {
+    Mode(Mode),
+    Object(OptionsObject),
+}
+
+#[derive(Copy, Clone, Default)]
+struct DeclarationOptions {
+    initialized: Option<Mode>,
+    uninitialized: Option<Mode>,
+}
+
+impl DeclarationOptions {
+    fn from_mode(mode: Option<Mode>, options_object: &OptionsObject) -> Self {
+        Self {
+            initialized: options_object.initialized.or(mode),
+            uninitialized: options_object.uninitialized.or(mode),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Options {
+    var: DeclarationOptions,
+    r#let: DeclarationOptions,
+    r#const: DeclarationOptions,
+    separate_requires: bool,
+}
+
+impl Options {
+    fn for_kind(&self, kind: &str) -> DeclarationOptions {
+        match kind {
+            "var" => self.var,
+            "let" => self.r#let,
+            "const" => self.r#const,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        OptionsVariants::Mode(Mode::Always).into()
+    }
+}
+
+impl From<OptionsVariants> for Options {
+    fn from(value: OptionsVariants) -> Self {
+        match value {
+            OptionsVariants::Mode(mode) => {
+                let declaration_options = DeclarationOptions {
+                    initialized: Some(mode),
+                    uninitialized: Some(mode),
+                };
+                Self {
+                    var: declaration_options,
+                    r#let: declaration_options,
+                    r#const: declaration_options,
+                    separate_requires: false,
+                }
+            }
+            OptionsVariants::Object(options_object) => Self {
+                var: DeclarationOptions::from_mode(options_object.var, &options_object),
+                r#let: DeclarationOptions::from_mode(options_object.r#let, &options_object),
+                r#const: DeclarationOptions::from_mode(options_object.r#const, &options_object),
+                separate_requires: options_object.separate_requires,
+            },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(OptionsVariants::deserialize(deserializer)?.into())
+    }
+}
+
+#[derive(Default)]
+struct ScopeState {
+    initialized: bool,
+    uninitialized: bool,
+    required: bool,
+}
+
+#[derive(Default)]
+struct BlockState {
+    r#let: ScopeState,
+    r#const: ScopeState,
+}
+
+#[derive(Default)]
+struct DeclarationCounts {
+    initialized: usize,
+    uninitialized: usize,
+}
+
+fn get_declaration_kind(node: Node) -> &'static str {
+    match node.kind() {
+        VariableDeclaration => "var",
+        LexicalDeclaration => node.field("kind").kind(),
+        _ => unreachable!(),
+    }
+}
+
+// `for (var x in y)` / `for (let x of y)` heads don't have a
+// `variable_declaration`/`lexical_declaration` node in tree-sitter, so the
+// `for_in_statement` itself stands in for both the declaration and its
+// (single) declarator
+fn is_for_in_or_of_head(node: Node) -> bool {
+    node.kind() == ForInStatement
+}
+
+fn get_declaration_range(node: Node) -> Range {
+    if is_for_in_or_of_head(node) {
+        range_between_start_and_end(node.field("kind").range(), node.field("left").range())
+    } else {
+        node.range()
+    }
+}
+
+fn is_require(declarator: Node, context: &QueryMatchContext) -> bool {
+    declarator.child_by_field_name("value").matches(|init| {
+        init.kind() == CallExpression && {
+            let callee = init.field("function");
+            callee.kind() == Identifier && callee.text(context) == "require"
+        }
+    })
+}
+
+fn count_declarations(declarations: &[Node]) -> DeclarationCounts {
+    let mut counts = DeclarationCounts::default();
+    for declaration in declarations {
+        if declaration.child_by_field_name("value").is_none() {
+            counts.uninitialized += 1;
+        } else {
+            counts.initialized += 1;
+        }
+    }
+    counts
+}
+
+fn get_declarations(node: Node) -> Vec<Node> {
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .collect()
+}
+
+fn is_mixed_requires(declarations: &[Node], context: &QueryMatchContext) -> bool {
+    declarations
+        .iter()
+        .any(|&declaration| is_require(declaration, context))
+        && !declarations
+            .iter()
+            .all(|&declaration| is_require(declaration, context))
+}
+
+fn join_declarations<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<(Node<'a>, Node<'a>)> {
+    if is_for_in_or_of_head(node) {
+        return None;
+    }
+    if !matches!(
+        node.parent().unwrap().kind(),
+        Program | StatementBlock | SwitchCase
+    ) {
+        return None;
+    }
+    let previous_node = maybe_get_prev_non_comment_sibling(node)?;
+    if !matches!(
+        previous_node.kind(),
+        VariableDeclaration | LexicalDeclaration
+    ) || get_declaration_kind(previous_node) != get_declaration_kind(node)
+    {
+        return None;
+    }
+
+    let type_ = context.get_first_token(node, Option::<fn(Node) -> bool>::None);
+    let prev_semi = context.get_token_before(type_, Option::<fn(Node) -> bool>::None);
+    Some((type_, prev_semi))
+}
+
+pub fn one_var_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "one-var",
+        languages => [Javascript],
+        messages => [
+            combine_uninitialized => "Combine this with the previous '{{type}}' statement with uninitialized variables.",
+            combine_initialized => "Combine this with the previous '{{type}}' statement with initialized variables.",
+            split_uninitialized => "Split uninitialized '{{type}}' declarations into multiple statements.",
+            split_initialized => "Split initialized '{{type}}' declarations into multiple statements.",
+            split_requires => "Split requires to be separated into a single block.",
+            combine => "Combine this with the previous '{{type}}' statement.",
+            split => "Split '{{type}}' declarations into multiple statements.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            options: Options = options,
+
+            [per-file-run]
+            function_stack: Vec<ScopeState>,
+            block_stack: Vec<BlockState>,
+        },
+        methods => {
+            fn get_current_scope(&mut self, statement_type: &str) -> &mut ScopeState {
+                match statement_type {
+                    "var" => self.function_stack.last_mut().unwrap(),
+                    "let" => &mut self.block_stack.last_mut().unwrap().r#let,
+                    "const" => &mut self.block_stack.last_mut().unwrap().r#const,
+                    _ => unreachable!(),
+                }
+            }
+
+            fn record_types(
+                &mut self,
+                statement_type: &str,
+                declarations: &[Node<'a>],
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let current_options = self.options.for_kind(statement_type);
+                let separate_requires = self.options.separate_requires;
+                let current_scope = self.get_current_scope(statement_type);
+                for &declaration in declarations {
+                    if declaration.child_by_field_name("value").is_none() {
+                        if current_options.uninitialized == Some(Mode::Always) {
+                            current_scope.uninitialized = true;
+                        }
+                    } else if current_options.initialized == Some(Mode::Always) {
+                        if separate_requires && is_require(declaration, context) {
+                            current_scope.required = true;
+                        } else {
+                            current_scope.initialized = true;
+                        }
+                    }
+                }
+            }
+
+            fn has_only_one_statement(
+                &mut self,
+                statement_type: &str,
+                declarations: &[Node<'a>],
+                context: &QueryMatchContext<'a, '_>,
+            ) -> bool {
+                let declaration_counts = count_declarations(declarations);
+                let current_options = self.options.for_kind(statement_type);
+                let has_requires = declarations
+                    .iter()
+                    .any(|&declaration| is_require(declaration, context));
+                let current_scope = self.get_current_scope(statement_type);
+
+                if current_options.uninitialized == Some(Mode::Always)
+                    && current_options.initialized == Some(Mode::Always)
+                    && (current_scope.uninitialized || current_scope.initialized)
+                    && !has_requires
+                {
+                    return false;
+                }
+
+                if declaration_counts.uninitialized > 0
+                    && current_options.uninitialized == Some(Mode::Always)
+                    && current_scope.uninitialized
+                {
+                    return false;
+                }
+
+                if declaration_counts.initialized > 0
+                    && current_options.initialized == Some(Mode::Always)
+                    && current_scope.initialized
+                    && !has_requires
+                {
+                    return false;
+                }
+
+                if current_scope.required && has_requires {
+                    return false;
+                }
+
+                self.record_types(statement_type, declarations, context);
+                true
+            }
+
+            fn check_always(
+                &mut self,
+                node: Node<'a>,
+                statement_type: &str,
+                declarations: &[Node<'a>],
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                if self.has_only_one_statement(statement_type, declarations, context) {
+                    return;
+                }
+
+                let options = self.options.for_kind(statement_type);
+                let declaration_counts = count_declarations(declarations);
+                if options.initialized == Some(Mode::Always)
+                    && options.uninitialized == Some(Mode::Always)
+                {
+                    self.report_combine(node, "combine", statement_type, context);
+                } else {
+                    if options.initialized == Some(Mode::Always)
+                        && declaration_counts.initialized > 0
+                    {
+                        self.report_combine(node, "combine_initialized", statement_type, context);
+                    }
+                    if options.uninitialized == Some(Mode::Always)
+                        && declaration_counts.uninitialized > 0
+                    {
+                        if is_for_in_or_of_head(node) {
+                            return;
+                        }
+                        self.report_combine(node, "combine_uninitialized", statement_type, context);
+                    }
+                }
+            }
+
+            fn report_combine(
+                &self,
+                node: Node<'a>,
+                message_id: &str,
+                statement_type: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                context.report(violation! {
+                    node => node,
+                    range => get_declaration_range(node),
+                    message_id => message_id,
+                    data => {
+                        type => statement_type,
+                    },
+                    fix => |fixer| {
+                        let Some((type_, prev_semi)) = join_declarations(node, context) else {
+                            return;
+                        };
+
+                        if prev_semi.kind() == ";" {
+                            fixer.replace_text(prev_semi, ",");
+                        } else {
+                            fixer.insert_text_after(prev_semi, ",");
+                        }
+                        fixer.remove(type_);
+                    }
+                });
+            }
+
+            fn report_split(
+                &self,
+                node: Node<'a>,
+                message_id: &str,
+                statement_type: &str,
+                declarations: &[Node<'a>],
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                context.report(violation! {
+                    node => node,
+                    message_id => message_id,
+                    data => {
+                        type => statement_type,
+                    },
+                    fix => |fixer| {
+                        let parent = node.parent().unwrap();
+                        // don't autofix code such as: if (foo) var x, y;
+                        if !matches!(
+                            parent.kind(),
+                            Program | StatementBlock | SwitchCase | ExportStatement
+                        ) {
+                            return;
+                        }
+
+                        let export_placement = if parent.kind() == ExportStatement {
+                            "export "
+                        } else {
+                            ""
+                        };
+                        for &declarator in declarations {
+                            let Some(token_after_declarator) = context
+                                .maybe_get_token_after(declarator, Option::<fn(Node) -> bool>::None)
+                                .filter(|token_after_declarator| token_after_declarator.kind() == ",")
+                            else {
+                                continue;
+                            };
+                            let after_comma = context.get_token_after(
+                                token_after_declarator,
+                                Some(
+                                    SkipOptionsBuilder::<fn(Node) -> bool>::default()
+                                        .include_comments(true)
+                                        .build()
+                                        .unwrap(),
+                                ),
+                            );
+
+                            // `var x,y`
+                            // tokenAfterDeclarator ^^ afterComma
+                            if after_comma.start_byte() == token_after_declarator.end_byte() {
+                                fixer.replace_text(
+                                    token_after_declarator,
+                                    format!("; {export_placement}{statement_type} "),
+                                );
+                                continue;
+                            }
+
+                            // `var x,
+                            // tokenAfterDeclarator ^
+                            //      y`
+                            //      ^ afterComma
+                            if after_comma.range().start_point.row > token_after_declarator.range().end_point.row
+                                || after_comma.kind() == Comment
+                            {
+                                let mut last_comment = after_comma;
+                                while last_comment.kind() == Comment {
+                                    last_comment = context.get_token_after(
+                                        last_comment,
+                                        Some(
+                                            SkipOptionsBuilder::<fn(Node) -> bool>::default()
+                                                .include_comments(true)
+                                                .build()
+                                                .unwrap(),
+                                        ),
+                                    );
+                                }
+                                fixer.replace_text_range(
+                                    range_between_starts(token_after_declarator.range(), last_comment.range()),
+                                    format!(
+                                        ";{}{export_placement}{statement_type} ",
+                                        context.get_text_slice(
+                                            token_after_declarator.end_byte()..last_comment.start_byte()
+                                        ),
+                                    ),
+                                );
+                                continue;
+                            }
+
+                            fixer.replace_text(
+                                token_after_declarator,
+                                format!("; {export_placement}{statement_type}"),
+                            );
+                        }
+                    }
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (program) @c
+              (function_declaration) @c
+              (function) @c
+              (arrow_function) @c
+              (generator_function_declaration) @c
+              (generator_function) @c
+              (method_definition) @c
+              (class_static_block) @c
+            "# => |node, context| {
+                self.function_stack.push(Default::default());
+                self.block_stack.push(Default::default());
+            },
+            r#"
+              (statement_block) @c
+              (for_statement) @c
+              (for_in_statement) @c
+              (switch_statement) @c
+            "# => |node, context| {
+                self.block_stack.push(Default::default());
+
+                if is_for_in_or_of_head(node) {
+                    if let Some(kind) = node.child_by_field_name("kind") {
+                        self.check_always(node, kind.kind(), &[node], context);
+                    }
+                }
+            },
+            r#"
+              statement_block:exit,
+              for_statement:exit,
+              for_in_statement:exit,
+              switch_statement:exit
+            "# => |node, context| {
+                self.block_stack.pop().unwrap();
+            },
+            r#"
+              program:exit,
+              function_declaration:exit,
+              function:exit,
+              arrow_function:exit,
+              generator_function_declaration:exit,
+              generator_function:exit,
+              method_definition:exit,
+              class_static_block:exit
+            "# => |node, context| {
+                self.function_stack.pop().unwrap();
+                self.block_stack.pop().unwrap();
+            },
+            r#"
+              (variable_declaration) @c
+              (lexical_declaration) @c
+            "# => |node, context| {
+                let parent = node.parent().unwrap();
+                let type_ = get_declaration_kind(node);
+                let options = self.options.for_kind(type_);
+                let declarations = get_declarations(node);
+                let declaration_counts = count_declarations(&declarations);
+                let mixed_requires = is_mixed_requires(&declarations, context);
+
+                if options.initialized == Some(Mode::Always)
+                    && self.options.separate_requires
+                    && mixed_requires
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "split_requires",
+                    });
+                }
+
+                // consecutive
+                if matches!(parent.kind(), Program | StatementBlock) {
+                    if let Some(previous_node) = maybe_get_prev_non_comment_sibling(node).filter(|previous_node| {
+                        matches!(
+                            previous_node.kind(),
+                            VariableDeclaration | LexicalDeclaration
+                        ) && get_declaration_kind(*previous_node) == type_
+                    }) {
+                        let previous_declarations = get_declarations(previous_node);
+                        let declarations_with_previous = declarations
+                            .iter()
+                            .chain(&previous_declarations)
+                            .copied()
+                            .collect_vec();
+
+                        if !is_mixed_requires(&declarations_with_previous, context) {
+                            let previous_decl_counts = count_declarations(&previous_declarations);
+
+                            if options.initialized == Some(Mode::Consecutive)
+                                && options.uninitialized == Some(Mode::Consecutive)
+                            {
+                                self.report_combine(node, "combine", type_, context);
+                            } else if options.initialized == Some(Mode::Consecutive)
+                                && declaration_counts.initialized > 0
+                                && previous_decl_counts.initialized > 0
+                            {
+                                self.report_combine(node, "combine_initialized", type_, context);
+                            } else if options.uninitialized == Some(Mode::Consecutive)
+                                && declaration_counts.uninitialized > 0
+                                && previous_decl_counts.uninitialized > 0
+                            {
+                                self.report_combine(node, "combine_uninitialized", type_, context);
+                            }
+                        }
+                    }
+                }
+
+                // always
+                self.check_always(node, type_, &declarations, context);
+
+                // never
+                if !(parent.kind() == ForStatement
+                    && parent.child_by_field_name("initializer") == Some(node))
+                    && declarations.len() > 1
+                {
+                    if options.initialized == Some(Mode::Never)
+                        && options.uninitialized == Some(Mode::Never)
+                    {
+                        self.report_split(node, "split", type_, &declarations, context);
+                    } else if options.initialized == Some(Mode::Never)
+                        && declaration_counts.initialized > 0
+                    {
+                        self.report_split(node, "split_initialized", type_, &declarations, context);
+                    } else if options.uninitialized == Some(Mode::Never)
+                        && declaration_counts.uninitialized > 0
+                    {
+                        self.report_split(node, "split_uninitialized", type_, &declarations, context);
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_one_var_rule() {
+        RuleTester::run(
+            one_var_rule(),
+            rule_tests! {
+                valid => [
+                    "function foo() { var bar = true; }",
+                    "function foo() { var bar = true, baz = 1; if (qux) { bar = false; } }",
+                    "var foo = function() { var bar = true; baz(); }",
+                    {
+                        code => "function foo() { var bar = true, baz = false; }",
+                        options => "always"
+                    },
+                    {
+                        code => "function foo() { var bar = true; var baz = false; }",
+                        options => "never"
+                    },
+                    {
+                        code => "for (var i = 0, len = arr.length; i < len; i++) {}",
+                        options => "never"
+                    },
+                    {
+                        code => "var bar = true; var baz = false;",
+                        options => { initialized => "never" }
+                    },
+                    {
+                        code => "var bar = true, baz = false;",
+                        options => { initialized => "always" }
+                    },
+                    {
+                        code => "var bar, baz;",
+                        options => { initialized => "never" }
+                    },
+                    {
+                        code => "var bar; var baz;",
+                        options => { uninitialized => "never" }
+                    },
+                    {
+                        code => "var bar, baz;",
+                        options => { uninitialized => "always" }
+                    },
+                    {
+                        code => "var bar = true, baz = false;",
+                        options => { uninitialized => "never" }
+                    },
+                    {
+                        code => "var bar = true, baz = false, a, b;",
+                        options => { uninitialized => "always", initialized => "always" }
+                    },
+                    {
+                        code => "var bar = true; var baz = false; var a; var b;",
+                        options => { uninitialized => "never", initialized => "never" }
+                    },
+                    {
+                        code => "var bar, baz; var a = true; var b = false;",
+                        options => { uninitialized => "always", initialized => "never" }
+                    },
+                    {
+                        code => "var bar = true, baz = false; var a; var b;",
+                        options => { uninitialized => "never", initialized => "always" }
+                    },
+                    {
+                        code => "var bar; var baz; var a = true, b = false;",
+                        options => { uninitialized => "never", initialized => "always" }
+                    },
+                    {
+                        code => "function foo() { var a = [1, 2, 3]; var [b, c, d] = a; }",
+                        options => "never"
+                    },
+                    {
+                        code => "function foo() { let a = 1; var c = true; if (a) {let c = true; } }",
+                        options => "always"
+                    },
+                    {
+                        code => "function foo() { const a = 1; var c = true; if (a) {const c = true; } }",
+                        options => "always"
+                    },
+                    {
+                        code => "function foo() { if (true) { const a = 1; }; if (true) {const a = true; } }",
+                        options => "always"
+                    },
+                    {
+                        code => "function foo() { let a = 1; let b = true; }",
+                        options => "never"
+                    },
+                    {
+                        code => "function foo() { const a = 1; const b = true; }",
+                        options => "never"
+                    },
+                    {
+                        code => "function foo() { let a = 1; const b = false; var c = true; }",
+                        options => "always"
+                    },
+                    {
+                        code => "function foo() { let a = 1, b = false; var c = true; }",
+                        options => "always"
+                    },
+                    {
+                        code => "function foo() { let a = 1; let b = 2; const c = false; const d = true; var e = true, f = false; }",
+                        options => { var => "always", let => "never", const => "never" }
+                    },
+                    {
+                        code => "let foo = true; for (let i = 0; i < 1; i++) { let foo = false; }",
+                        options => { var => "always", let => "always", const => "never" }
+                    },
+                    {
+                        code => "let foo = true; for (let i = 0; i < 1; i++) { let foo = false; }",
+                        options => { var => "always" }
+                    },
+                    {
+                        code => "let foo = true, bar = false;",
+                        options => { var => "never" }
+                    },
+                    {
+                        code => "let foo = true; let bar = false;",
+                        options => { const => "never" }
+                    },
+                    {
+                        code => "var a = 0, b, c;",
+                        options => "always"
+                    },
+                    {
+                        code => "var a = 0, b = 1, c;",
+                        options => "always"
+                    },
+                    {
+                        code => "let a = 0, b, c;",
+                        options => "always"
+                    },
+                    {
+                        code => "var a = 0; var b; var c;",
+                        options => { initialized => "never" }
+                    },
+                    {
+                        code => "var bar = 'bar'; var foo = require('foo');",
+                        options => { separate_requires => true, var => "always" }
+                    },
+                    {
+                        code => "var foo = require('foo'); var bar = 'bar';",
+                        options => { separate_requires => true, var => "always" }
+                    },
+                    {
+                        code => "var a = 0, b = 1; var c, d;",
+                        options => { initialized => "consecutive", uninitialized => "always" }
+                    },
+                    {
+                        code => "var a = 0, b = 1; foo(); var c = 2, d = 3;",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "var a, b; foo(); var c, d;",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "var a = 0, b; foo(); var c = 2;",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "let a, b; var c, d;",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "const a = 0, b = 1; foo(); const c = 2, d = 3;",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "var a = 0; var b; foo(); var c;",
+                        options => { initialized => "consecutive", uninitialized => "never" }
+                    },
+                    {
+                        code => "var bar, baz;",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "function foo() { var bar, baz; }",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "switch (a) { case 1: var bar; break; case 2: var baz; }",
+                        options => "consecutive"
+                    },
+                    {
+                        code => "class C { static { var a; let b; const c = 0; } }",
+                        options => "always"
+                    },
+                    {
+                        code => "class C { static { var a; } } var b;",
+                        options => "always"
+                    },
+                    {
+                        code => "var a; class C { static { var b; } }",
+                        options => "always"
+                    },
+                    {
+                        code => "class C { static { let a; } static { let b; } }",
+                        options => "always"
+                    },
+                    {
+                        code => "class C { static { var a, b; } }",
+                        options => "always"
+                    },
+                    {
+                        code => "class C { static { var a; var b; } }",
+                        options => "never"
+                    },
+                    {
+                        code => "class C { static { var a; foo(); var b; } }",
+                        options => "consecutive"
+                    },
+
+                    // for-in/for-of heads
+                    {
+                        code => "var x; for (var y in foo) {}",
+                        options => { initialized => "never", uninitialized => "always" }
+                    },
+                    {
+                        code => "var x, y; for (y in foo) {}",
+                        options => { initialized => "never", uninitialized => "always" }
+                    },
+                    {
+                        code => "var x, y; for (var z in foo) {}",
+                        options => { initialized => "never", uninitialized => "always" }
+                    },
+                    {
+                        code => "var x; for (var y of foo) {}",
+                        options => { initialized => "never", uninitialized => "always" }
+                    },
+                    {
+                        code => "var x, y; for (y of foo) {}",
+                        options => { initialized => "never", uninitialized => "always" }
+                    },
+                    {
+                        code => "var x, y; for (var z of foo) {}",
+                        options => { initialized => "never", uninitialized => "always" }
+                    },
+                    {
+                        code => "let x; for (let y of foo) {}",
+                        options => "always"
+                    },
+                    {
+                        code => "for (let a in b) { let c; }",
+                        options => "always"
+                    },
+                    {
+                        code => "for (var x in foo) {} for (var y in bar) {}",
+                        options => "never"
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "var bar = true, baz = false;",
+                        output => "var bar = true; var baz = false;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration,
+                            line => 1,
+                            column => 1
+                        }]
+                    },
+                    {
+                        code => "let bar = true, baz = false;",
+                        output => "let bar = true; let baz = false;",
+                        options => { let => "never" },
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "let" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "const bar = true, baz = false;",
+                        output => "const bar = true; const baz = false;",
+                        options => { const => "never" },
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "const" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "var one = 1, two = 2;\nvar three;",
+                        output => "var one = 1, two = 2,\n three;",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration,
+                            line => 2,
+                            column => 1
+                        }]
+                    },
+                    {
+                        code => "var i = [0], j;",
+                        output => "var i = [0]; var j;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var i = [0], j;",
+                        output => "var i = [0]; var j;",
+                        options => { initialized => "never" },
+                        errors => [{
+                            message_id => "split_initialized",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var foo = 1;\nvar bar = 2;",
+                        output => "var foo = 1,\n bar = 2;",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var foo = 1\nvar bar = 2",
+                        output => "var foo = 1,\n bar = 2",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var foo, bar;",
+                        output => "var foo; var bar;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var foo, bar;",
+                        output => "var foo; var bar;",
+                        options => { uninitialized => "never" },
+                        errors => [{
+                            message_id => "split_uninitialized",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var bar = 'bar', foo = require('foo');",
+                        output => None,
+                        options => { separate_requires => true, var => "always" },
+                        errors => [{
+                            message_id => "split_requires",
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "function foo() { var bar = true; var baz = false; }",
+                        output => "function foo() { var bar = true,  baz = false; }",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration,
+                            line => 1,
+                            column => 34
+                        }]
+                    },
+                    {
+                        code => "function foo() { var a = 1; if (a) { var b = 2; } }",
+                        output => None,
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "function foo() { let a = 1; let b = 2; }",
+                        output => "function foo() { let a = 1,  b = 2; }",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "let" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "function foo() { const a = 1; const b = 2; }",
+                        output => "function foo() { const a = 1,  b = 2; }",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "const" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "function foo() { let a = 1, b = 2; }",
+                        output => "function foo() { let a = 1; let b = 2; }",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "let" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "function foo() { var a = 1, b = 2; let c = 3, d = 4; }",
+                        output => "function foo() { var a = 1; var b = 2; let c = 3; let d = 4; }",
+                        options => "never",
+                        errors => [
+                            {
+                                message_id => "split",
+                                data => { type => "var" },
+                                type => VariableDeclaration
+                            },
+                            {
+                                message_id => "split",
+                                data => { type => "let" },
+                                type => LexicalDeclaration
+                            }
+                        ]
+                    },
+                    {
+                        code => "var bar, baz; var a = true; var b = false;",
+                        output => "var bar, baz; var a = true,  b = false;",
+                        options => { uninitialized => "always", initialized => "consecutive" },
+                        errors => [{
+                            message_id => "combine_initialized",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var a = 1; var b = 2; foo(); var c = 3; var d = 4;",
+                        output => "var a = 1,  b = 2; foo(); var c = 3,  d = 4;",
+                        options => "consecutive",
+                        errors => [
+                            {
+                                message_id => "combine",
+                                data => { type => "var" },
+                                type => VariableDeclaration
+                            },
+                            {
+                                message_id => "combine",
+                                data => { type => "var" },
+                                type => VariableDeclaration
+                            }
+                        ]
+                    },
+                    {
+                        code => "var a = 1\nvar b = 2",
+                        output => "var a = 1,\n b = 2",
+                        options => "consecutive",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var a, b; var c; var d;",
+                        output => "var a, b,  c,  d;",
+                        options => "consecutive",
+                        errors => [
+                            {
+                                message_id => "combine",
+                                data => { type => "var" },
+                                type => VariableDeclaration
+                            },
+                            {
+                                message_id => "combine",
+                                data => { type => "var" },
+                                type => VariableDeclaration
+                            }
+                        ]
+                    },
+                    {
+                        code => "var a = 0, b; var c;",
+                        output => "var a = 0, b,  c;",
+                        options => { var => "consecutive" },
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "const a = 1; const b = 2;",
+                        output => "const a = 1,  b = 2;",
+                        options => { const => "consecutive" },
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "const" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "var a = 1, b = 2; var c; var d;",
+                        output => "var a = 1, b = 2; var c,  d;",
+                        options => { initialized => "always", uninitialized => "consecutive" },
+                        errors => [{
+                            message_id => "combine_uninitialized",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var a = 1; var b = 2; foo(); var c = 3;",
+                        output => "var a = 1,  b = 2; foo(); var c = 3;",
+                        options => { initialized => "consecutive" },
+                        errors => [{
+                            message_id => "combine_initialized",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var foo = require('foo'); var bar = require('bar');",
+                        output => "var foo = require('foo'),  bar = require('bar');",
+                        options => { separate_requires => true, var => "always" },
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "let a = 1,\n    b = 2;",
+                        output => "let a = 1;\n    let b = 2;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "let" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "var foo = 1, /* comment */ bar = 2;",
+                        output => "var foo = 1; /* comment */ var bar = 2;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var foo = 1,\n    // comment\n    bar = 2;",
+                        output => "var foo = 1;\n    // comment\n    var bar = 2;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var a = 1,b = 2;",
+                        output => "var a = 1; var b = 2;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "if (foo) var x, y;",
+                        output => None,
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "export const foo = 1, bar = 2;",
+                        output => "export const foo = 1; export const bar = 2;",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "const" },
+                            type => LexicalDeclaration
+                        }]
+                    },
+                    {
+                        code => "switch (a) { case 1: var bar = 1, baz = 2; }",
+                        output => "switch (a) { case 1: var bar = 1; var baz = 2; }",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "switch (a) { case 1: var bar; var baz; }",
+                        output => "switch (a) { case 1: var bar,  baz; }",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var a; foo(); var b;",
+                        output => None,
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "class C { static { var a; var b; } }",
+                        output => "class C { static { var a,  b; } }",
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "class C { static { var a, b; } }",
+                        output => "class C { static { var a; var b; } }",
+                        options => "never",
+                        errors => [{
+                            message_id => "split",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "class C { static { var a; var b; } }",
+                        output => "class C { static { var a,  b; } }",
+                        options => "consecutive",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => VariableDeclaration
+                        }]
+                    },
+                    {
+                        code => "var a; for (var b in c) {}",
+                        output => None,
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => ForInStatement,
+                            line => 1,
+                            column => 13,
+                            end_column => 18
+                        }]
+                    },
+                    {
+                        code => "var a = 1; for (var b of c) {}",
+                        output => None,
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => ForInStatement,
+                            line => 1,
+                            column => 17
+                        }]
+                    },
+                    {
+                        code => "for (var a in b) {} var c;",
+                        output => None,
+                        options => { uninitialized => "always" },
+                        errors => [{
+                            message_id => "combine_uninitialized",
+                            data => { type => "var" },
+                            type => VariableDeclaration,
+                            line => 1,
+                            column => 21
+                        }]
+                    },
+                    {
+                        code => "for (var a of b) {} for (var c of d) {}",
+                        output => None,
+                        options => "always",
+                        errors => [{
+                            message_id => "combine",
+                            data => { type => "var" },
+                            type => ForInStatement,
+                            line => 1,
+                            column => 26
+                        }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,632 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, Rule, SkipOptionsBuilder};
+
+use crate::{
+    ast_helpers::NodeExtJs,
+    kind::{
+        is_literal_kind, BinaryExpression, Identifier, MemberExpression, SubscriptExpression, This,
+    },
+    utils::ast_utils,
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+    #[default]
+    Always,
+    Never,
+}
+
+fn is_commutative_operator_with_shorthand(operator: &str) -> bool {
+    ["*", "&", "^", "|"].contains(&operator)
+}
+
+fn is_non_commutative_operator_with_shorthand(operator: &str) -> bool {
+    ["+", "-", "/", "%", "<<", ">>", ">>>", "**"].contains(&operator)
+}
+
+fn can_be_fixed(node: Node) -> bool {
+    let node = node.skip_parentheses();
+    match node.kind() {
+        Identifier => true,
+        MemberExpression => matches!(
+            node.field("object").skip_parentheses().kind(),
+            Identifier | This
+        ),
+        SubscriptExpression => {
+            matches!(
+                node.field("object").skip_parentheses().kind(),
+                Identifier | This
+            ) && is_literal_kind(node.field("index").skip_parentheses().kind())
+        }
+        _ => false,
+    }
+}
+
+pub fn operator_assignment_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "operator-assignment",
+        languages => [Javascript],
+        messages => [
+            replaced => "Assignment (=) can be replaced with operator assignment ({{operator}}).",
+            unexpected => "Unexpected operator assignment ({{operator}}) statement.",
+        ],
+        fixable => true,
+        options_type => Mode,
+        state => {
+            [per-config]
+            mode: Mode = options,
+        },
+        listeners => [
+            r#"
+              (assignment_expression) @c
+            "# => |node, context| {
+                if self.mode != Mode::Always {
+                    return;
+                }
+
+                let left = node.field("left");
+                let expr = node.field("right").skip_parentheses();
+                if expr.kind() != BinaryExpression {
+                    return;
+                }
+                let operator_token = expr.field("operator");
+                let operator = operator_token.kind();
+
+                if !is_commutative_operator_with_shorthand(operator)
+                    && !is_non_commutative_operator_with_shorthand(operator)
+                {
+                    return;
+                }
+
+                let replacement_operator = format!("{operator}=");
+                if ast_utils::is_same_reference(left, expr.field("left"), Some(true), context) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "replaced",
+                        data => {
+                            operator => replacement_operator,
+                        },
+                        fix => |fixer| {
+                            if !(can_be_fixed(left) && can_be_fixed(expr.field("left"))) {
+                                return;
+                            }
+
+                            let equals_token = context.get_token_after(left, Option::<fn(Node) -> bool>::None);
+
+                            // Check for comments that would be removed.
+                            if context.comments_exist_between(equals_token, operator_token) {
+                                return;
+                            }
+
+                            fixer.replace_text(
+                                node,
+                                format!(
+                                    "{}{}{}",
+                                    context.get_text_slice(node.start_byte()..equals_token.start_byte()),
+                                    replacement_operator,
+                                    context.get_text_slice(operator_token.end_byte()..expr.end_byte()),
+                                ),
+                            );
+                        }
+                    });
+                } else if ast_utils::is_same_reference(left, expr.field("right"), Some(true), context)
+                    && is_commutative_operator_with_shorthand(operator)
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "replaced",
+                        data => {
+                            operator => replacement_operator,
+                        },
+                    });
+                }
+            },
+            r#"
+              (augmented_assignment_expression) @c
+            "# => |node, context| {
+                if self.mode != Mode::Never {
+                    return;
+                }
+
+                let operator_token = node.field("operator");
+                let operator = operator_token.kind();
+                if ast_utils::is_logical_assignment_operator(operator) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "unexpected",
+                    data => {
+                        operator => operator,
+                    },
+                    fix => |fixer| {
+                        let left = node.field("left");
+                        if !can_be_fixed(left) {
+                            return;
+                        }
+
+                        let first_token = context.get_first_token(node, Option::<fn(Node) -> bool>::None);
+
+                        // Check for comments that would be duplicated.
+                        if context.comments_exist_between(first_token, operator_token) {
+                            return;
+                        }
+
+                        let right = node.field("right");
+                        let left_text = context.get_text_slice(node.start_byte()..operator_token.start_byte());
+                        let new_operator = &operator[..operator.len() - 1];
+
+                        // If this change would modify precedence (e.g. `foo *= bar + 1` => `foo = foo * (bar + 1)`), parenthesize the right side.
+                        let right_text = if ast_utils::get_precedence(right)
+                            <= ast_utils::get_binary_expression_operator_precedence(new_operator)
+                            && !ast_utils::is_parenthesised(right)
+                        {
+                            format!(
+                                "{}({})",
+                                context.get_text_slice(operator_token.end_byte()..right.start_byte()),
+                                right.text(context),
+                            )
+                        } else {
+                            let token_after_operator = context.get_token_after(
+                                operator_token,
+                                Some(SkipOptionsBuilder::<fn(Node) -> bool>::default()
+                                    .include_comments(true)
+                                    .build().unwrap())
+                            );
+                            let right_text_prefix = if operator_token.end_byte() == token_after_operator.start_byte()
+                                && !ast_utils::can_tokens_be_adjacent(new_operator, token_after_operator, context)
+                            {
+                                // foo+=+bar -> foo= foo+ +bar
+                                " "
+                            } else {
+                                ""
+                            };
+                            format!(
+                                "{}{}",
+                                right_text_prefix,
+                                context.get_text_slice(operator_token.end_byte()..node.end_byte()),
+                            )
+                        };
+
+                        fixer.replace_text(
+                            node,
+                            format!("{left_text}= {left_text}{new_operator}{right_text}"),
+                        );
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::{AssignmentExpression, AugmentedAssignmentExpression};
+
+    #[test]
+    fn test_operator_assignment_rule() {
+        RuleTester::run(
+            operator_assignment_rule(),
+            rule_tests! {
+                valid => [
+                    "x = y",
+                    "x = y + x",
+                    "x += x + y",
+                    "x = (x + y) - z",
+                    "x -= y",
+                    "x = y - x",
+                    "x *= x",
+                    "x = y * z",
+                    "x = (x * y) * z",
+                    "x = y / x",
+                    "x /= y",
+                    "x %= y",
+                    "x <<= y",
+                    "x >>= x >> y",
+                    "x >>>= y",
+                    "x &= y",
+                    "x **= y",
+                    "x ^= y ^ z",
+                    "x |= x | y",
+                    "x = x && y",
+                    "x = x || y",
+                    "x = x < y",
+                    "x = x > y",
+                    "x = x <= y",
+                    "x = x >= y",
+                    "x = x instanceof y",
+                    "x = x in y",
+                    "x = x == y",
+                    "x = x != y",
+                    "x = x === y",
+                    "x = x !== y",
+                    "x[y] = x['y'] + z",
+                    "x.y = x['y'] / z",
+                    "x.y = z + x.y",
+                    "x[fn()] = x[fn()] + y",
+                    { code => "x += x + y", options => "always" },
+                    { code => "x = x + y", options => "never" },
+                    { code => "x = x ** y", options => "never" },
+                    { code => "x = y ** x", options => "always" },
+                    { code => "x = x * y + z", options => "always" },
+                    { code => "this.x = this.y + z", options => "always" },
+                    { code => "this.x = foo.x + y", options => "always" },
+                    { code => "this.x = foo.this.x + y", options => "always" },
+
+                    // does not check logical operators
+                    { code => "x = x && y", options => "always" },
+                    { code => "x = x || y", options => "always" },
+                    { code => "x = x ?? y", options => "always" },
+                    { code => "x &&= y", options => "never" },
+                    { code => "x ||= y", options => "never" },
+                    { code => "x ??= y", options => "never" }
+                ],
+                invalid => [
+                    {
+                        code => "x = x + y",
+                        output => "x += y",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" }, type => AssignmentExpression, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "x = x - y",
+                        output => "x -= y",
+                        errors => [{ message_id => "replaced", data => { operator => "-=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x * y",
+                        output => "x *= y",
+                        errors => [{ message_id => "replaced", data => { operator => "*=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = y * x",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "*=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = (y * z) * x",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "*=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x / y",
+                        output => "x /= y",
+                        errors => [{ message_id => "replaced", data => { operator => "/=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x % y",
+                        output => "x %= y",
+                        errors => [{ message_id => "replaced", data => { operator => "%=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x << y",
+                        output => "x <<= y",
+                        errors => [{ message_id => "replaced", data => { operator => "<<=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x >> y",
+                        output => "x >>= y",
+                        errors => [{ message_id => "replaced", data => { operator => ">>=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x >>> y",
+                        output => "x >>>= y",
+                        errors => [{ message_id => "replaced", data => { operator => ">>>=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x & y",
+                        output => "x &= y",
+                        errors => [{ message_id => "replaced", data => { operator => "&=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x ^ y",
+                        output => "x ^= y",
+                        errors => [{ message_id => "replaced", data => { operator => "^=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x | y",
+                        output => "x |= y",
+                        errors => [{ message_id => "replaced", data => { operator => "|=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x[0] = x[0] - y",
+                        output => "x[0] -= y",
+                        errors => [{ message_id => "replaced", data => { operator => "-=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x.y[z['a']][0].b = x.y[z['a']][0].b * 2",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "*=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x + y",
+                        output => "x += y",
+                        options => "always",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = (x + y)",
+                        output => "x += y",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x = x + (y)",
+                        output => "x += (y)",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" }, type => AssignmentExpression }]
+                    },
+                    {
+                        code => "x += (y)",
+                        output => "x = x + (y)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" }, type => AugmentedAssignmentExpression }]
+                    },
+                    {
+                        code => "x += y",
+                        output => "x = x + y",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" }, type => AugmentedAssignmentExpression }]
+                    },
+                    {
+                        code => "foo.bar = foo.bar + baz",
+                        output => "foo.bar += baz",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo.bar += baz",
+                        output => "foo.bar = foo.bar + baz",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "this.foo = this.foo + bar",
+                        output => "this.foo += bar",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "this.foo += bar",
+                        output => "this.foo = this.foo + bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo.bar.baz = foo.bar.baz + qux",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo.bar.baz += qux",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "this.foo.bar = this.foo.bar + baz",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "this.foo.bar += baz",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo[bar] = foo[bar] + baz",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "this[foo] = this[foo] + bar",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo[bar] >>>= baz",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => ">>>=" } }]
+                    },
+                    {
+                        code => "this[foo] >>>= bar",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => ">>>=" } }]
+                    },
+                    {
+                        code => "foo[5] = foo[5] / baz",
+                        output => "foo[5] /= baz",
+                        errors => [{ message_id => "replaced", data => { operator => "/=" } }]
+                    },
+                    {
+                        code => "this[5] = this[5] / foo",
+                        output => "this[5] /= foo",
+                        errors => [{ message_id => "replaced", data => { operator => "/=" } }]
+                    },
+                    {
+                        code => "/*1*/x/*2*/./*3*/y/*4*/= x.y +/*5*/z/*6*/./*7*/w/*8*/;",
+                        output => "/*1*/x/*2*/./*3*/y/*4*/+=/*5*/z/*6*/./*7*/w/*8*/;",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x // 1\n . // 2\n y // 3\n = x.y + //4\n z //5\n . // 6\n w;",
+                        output => "x // 1\n . // 2\n y // 3\n += //4\n z //5\n . // 6\n w;",
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x = /*1*/ x + y",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x = //1\n x + y",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x.y = x/*1*/.y + z",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x.y = x. //1\n y + z",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x = x /*1*/ + y",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x = x //1\n + y",
+                        output => None,
+                        errors => [{ message_id => "replaced", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "/*1*/x +=/*2*/y/*3*/;",
+                        output => "/*1*/x = x +/*2*/y/*3*/;",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x +=//1\n y",
+                        output => "x = x +//1\n y",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "(/*1*/x += y)",
+                        output => "(/*1*/x = x + y)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x/*1*/+=  y",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x //1\n += y",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "(/*1*/x) += y",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x/*1*/.y += z",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "x.//1\n y += z",
+                        output => None,
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "(foo.bar) ^= ((((((((((((((((baz))))))))))))))))",
+                        output => "(foo.bar) = (foo.bar) ^ ((((((((((((((((baz))))))))))))))))",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "^=" } }]
+                    },
+                    {
+                        code => "foo = foo ** bar",
+                        output => "foo **= bar",
+                        errors => [{ message_id => "replaced", data => { operator => "**=" } }]
+                    },
+                    {
+                        code => "foo **= bar",
+                        output => "foo = foo ** bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "**=" } }]
+                    },
+                    {
+                        code => "foo *= bar + 1",
+                        output => "foo = foo * (bar + 1)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "*=" } }]
+                    },
+                    {
+                        code => "foo -= bar - baz",
+                        output => "foo = foo - (bar - baz)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "-=" } }]
+                    },
+                    {
+                        code => "foo += bar + baz",
+                        output => "foo = foo + (bar + baz)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo += bar = 1",
+                        output => "foo = foo + (bar = 1)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo *= (bar + 1)",
+                        output => "foo = foo * (bar + 1)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "*=" } }]
+                    },
+                    {
+                        code => "foo+=-bar",
+                        output => "foo= foo+-bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo/=bar",
+                        output => "foo= foo/bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "/=" } }]
+                    },
+                    {
+                        code => "foo/=/**/bar",
+                        output => "foo= foo/ /**/bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "/=" } }]
+                    },
+                    {
+                        code => "foo+=+bar",
+                        output => "foo= foo+ +bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo+= +bar",
+                        output => "foo= foo+ +bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo+=/**/+bar",
+                        output => "foo= foo+/**/+bar",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    },
+                    {
+                        code => "foo+=+bar===baz",
+                        output => "foo= foo+(+bar===baz)",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", data => { operator => "+=" } }]
+                    }
+                ]
+            },
+        )
+    }
+}
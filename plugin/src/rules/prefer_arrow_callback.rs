@@ -0,0 +1,555 @@
+use std::{collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    range_between_start_and_end, range_between_starts, rule, tree_sitter::Node, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{call_expression_has_single_matching_argument, is_logical_expression, NodeExtJs},
+    kind::{
+        Arguments, CallExpression, Identifier, MemberExpression, NewExpression,
+        ParenthesizedExpression, TernaryExpression, This,
+    },
+    scope::{ScopeManager, VariableType},
+    utils::ast_utils,
+};
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_named_functions: bool,
+    allow_unbound_this: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            allow_named_functions: false,
+            allow_unbound_this: true,
+        }
+    }
+}
+
+#[derive(Default)]
+struct CallbackInfo {
+    is_callback: bool,
+    is_lexical_this: bool,
+}
+
+fn get_callback_info<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> CallbackInfo {
+    let mut ret = CallbackInfo::default();
+    let mut current_node = node;
+    let mut parent = node.next_non_parentheses_ancestor(context);
+    let mut bound = false;
+
+    loop {
+        match parent.kind() {
+            TernaryExpression => (),
+            _ if is_logical_expression(parent) => (),
+            MemberExpression => {
+                if !(parent.field("object").skip_parentheses() == current_node
+                    && parent.field("property").text(context) == "bind")
+                {
+                    return ret;
+                }
+                if !ast_utils::is_callee(parent, context) {
+                    return ret;
+                }
+                let call_node = parent.next_non_parentheses_ancestor(context);
+                if !bound {
+                    bound = true;
+                    ret.is_lexical_this =
+                        call_expression_has_single_matching_argument(call_node, |arg| {
+                            arg.kind() == This
+                        });
+                }
+                parent = call_node;
+            }
+            Arguments => {
+                if matches!(
+                    parent.parent().map(|grandparent| grandparent.kind()),
+                    Some(CallExpression | NewExpression)
+                ) {
+                    ret.is_callback = true;
+                }
+                return ret;
+            }
+            _ => return ret,
+        }
+        current_node = parent;
+        parent = parent.next_non_parentheses_ancestor(context);
+    }
+}
+
+fn has_duplicate_params(node: Node, context: &QueryMatchContext) -> bool {
+    let params = node
+        .field("parameters")
+        .non_comment_named_children(context)
+        .collect::<Vec<_>>();
+    params.iter().all(|param| param.kind() == Identifier)
+        && params.len()
+            != params
+                .iter()
+                .map(|param| param.text(context))
+                .collect::<HashSet<_>>()
+                .len()
+}
+
+fn is_self_referencing<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let scope_manager = context.retrieve::<ScopeManager<'a>>();
+    scope_manager
+        .get_declared_variables(node)
+        .find(|variable| {
+            variable
+                .defs()
+                .next()
+                .matches(|def| def.type_() == VariableType::FunctionName)
+        })
+        .matches(|variable| variable.references().next().is_some())
+}
+
+fn uses_arguments<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let scope_manager = context.retrieve::<ScopeManager<'a>>();
+    scope_manager
+        .get_scope(node)
+        .variables()
+        .find(|variable| variable.name() == "arguments")
+        .filter(|variable| variable.identifiers().next().is_none())
+        .matches(|variable| variable.references().next().is_some())
+}
+
+#[derive(Default)]
+struct ScopeInfo {
+    this: bool,
+    super_: bool,
+    meta: bool,
+}
+
+pub fn prefer_arrow_callback_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-arrow-callback",
+        languages => [Javascript],
+        messages => [
+            prefer_arrow_callback => "Unexpected function expression.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_unbound_this: bool = options.allow_unbound_this,
+            allow_named_functions: bool = options.allow_named_functions,
+            [per-file-run]
+            stack: Vec<ScopeInfo>,
+        },
+        listeners => [
+            r#"
+              (this) @c
+            "# => |node, context| {
+                if let Some(info) = self.stack.last_mut() {
+                    info.this = true;
+                }
+            },
+            r#"
+              (super) @c
+            "# => |node, context| {
+                if let Some(info) = self.stack.last_mut() {
+                    info.super_ = true;
+                }
+            },
+            r#"
+              (meta_property) @c
+            "# => |node, context| {
+                if node.has_child_of_kind("new") {
+                    if let Some(info) = self.stack.last_mut() {
+                        info.meta = true;
+                    }
+                }
+            },
+            r#"
+              (function) @c
+              (function_declaration) @c
+              (generator_function) @c
+              (generator_function_declaration) @c
+              (method_definition) @c
+            "# => |node, context| {
+                self.stack.push(Default::default());
+            },
+            r#"
+              function_declaration:exit,
+              generator_function:exit,
+              generator_function_declaration:exit,
+              method_definition:exit
+            "# => |node, context| {
+                self.stack.pop().unwrap();
+            },
+            r#"
+              function:exit
+            "# => |node, context| {
+                let scope_info = self.stack.pop().unwrap();
+
+                // Skip named function expressions
+                if self.allow_named_functions && node.child_by_field_name("name").is_some() {
+                    return;
+                }
+
+                // Skip recursive functions.
+                if is_self_referencing(node, context) {
+                    return;
+                }
+
+                // Skip if it's using arguments.
+                if uses_arguments(node, context) {
+                    return;
+                }
+
+                // Reports if it's a callback which can replace with arrows.
+                let callback_info = get_callback_info(node, context);
+
+                if callback_info.is_callback
+                    && (!self.allow_unbound_this
+                        || !scope_info.this
+                        || callback_info.is_lexical_this)
+                    && !scope_info.super_
+                    && !scope_info.meta
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "prefer_arrow_callback",
+                        fix => |fixer| {
+                            if !callback_info.is_lexical_this && scope_info.this
+                                || has_duplicate_params(node, context)
+                            {
+                                // If the callback function does not have .bind(this) and contains a reference to `this`, there
+                                // is no way to determine what `this` should be, so don't perform any fixes.
+                                // If the callback function has duplicates in its list of parameters (possible in sloppy mode),
+                                // don't replace it with an arrow function, because this is a SyntaxError with arrow functions.
+                                return;
+                            }
+
+                            let mut replaced_node = node;
+
+                            // Remove `.bind(this)` if exists.
+                            if callback_info.is_lexical_this {
+                                let member_node = node.next_non_parentheses_ancestor(context);
+
+                                // If `.bind(this)` exists but the parent is not `.bind(this)`, don't remove it automatically.
+                                // E.g. `(foo || function(){}).bind(this)`
+                                if member_node.kind() != MemberExpression {
+                                    return;
+                                }
+
+                                let call_node = member_node.next_non_parentheses_ancestor(context);
+                                let first_token_to_remove = context.get_token_after(
+                                    member_node.field("object"),
+                                    Some(|node: Node| ast_utils::is_not_closing_paren_token(node, context)),
+                                );
+                                let last_token_to_remove = context.get_last_token(
+                                    call_node,
+                                    Option::<fn(Node) -> bool>::None,
+                                );
+
+                                // If the member expression is parenthesized, don't remove the right paren.
+                                // E.g. `(function(){}.bind)(this)`
+                                //                    ^^^^^^^^^^^^
+                                if ast_utils::is_parenthesised(member_node) {
+                                    return;
+                                }
+
+                                // If comments exist in the `.bind(this)`, don't remove those.
+                                if context.comments_exist_between(first_token_to_remove, last_token_to_remove) {
+                                    return;
+                                }
+
+                                fixer.remove_range(
+                                    range_between_start_and_end(
+                                        first_token_to_remove.range(),
+                                        last_token_to_remove.range(),
+                                    ),
+                                );
+
+                                replaced_node = call_node;
+                            }
+
+                            let function_token = node.maybe_first_child_of_kind("function").unwrap();
+                            let parameters = node.field("parameters");
+
+                            if context.comments_exist_between(function_token, parameters) {
+                                // Remove only extra tokens to keep comments.
+                                fixer.remove(function_token);
+                                if let Some(name) = node.child_by_field_name("name") {
+                                    fixer.remove(name);
+                                }
+                            } else {
+                                // Remove extra tokens and spaces.
+                                fixer.remove_range(range_between_starts(function_token.range(), parameters.range()));
+                            }
+                            fixer.insert_text_after(parameters, " =>");
+
+                            if !matches!(
+                                replaced_node.parent().unwrap().kind(),
+                                Arguments | TernaryExpression | ParenthesizedExpression
+                            ) && !ast_utils::is_parenthesised(node)
+                            {
+                                fixer.insert_text_before(replaced_node, "(");
+                                fixer.insert_text_after(replaced_node, ")");
+                            }
+                        }
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::Function};
+
+    #[test]
+    fn test_prefer_arrow_callback_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            prefer_arrow_callback_rule(),
+            rule_tests! {
+                valid => [
+                    "foo(a => a);",
+                    "foo(function*() {});",
+                    "foo(function() { this; });",
+                    { code => "foo(function bar() {});", options => { allow_named_functions => true } },
+                    "foo(function() { (() => this); });",
+                    "foo(function() { this; }.bind(obj));",
+                    "foo(function() { this; }.call(this));",
+                    "foo(a => { (function() {}); });",
+                    "var foo = function foo() {};",
+                    "(function foo() {})();",
+                    "foo(function bar() { bar; });",
+                    "foo(function bar() { arguments; });",
+                    "foo(function bar() { arguments; }.bind(this));",
+                    "foo(function bar() { new.target; });",
+                    "foo(function bar() { new.target; }.bind(this));",
+                    "foo(function bar() { this; }.bind(this, somethingElse));",
+                    "foo((function() {}).bind.bar)",
+                    "foo((function() { this.bar(); }).bind(obj).bind(this))"
+                ],
+                invalid => [
+                    {
+                        code => "foo(function bar() {});",
+                        output => "foo(() => {});",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() {});",
+                        output => "foo(() => {});",
+                        options => { allow_named_functions => true },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function bar() {});",
+                        output => "foo(() => {});",
+                        options => { allow_named_functions => false },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() {});",
+                        output => "foo(() => {});",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(nativeCb || function() {});",
+                        output => "foo(nativeCb || (() => {}));",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(bar ? function() {} : function() {});",
+                        output => "foo(bar ? () => {} : () => {});",
+                        errors => [
+                            { message_id => "prefer_arrow_callback", type => Function },
+                            { message_id => "prefer_arrow_callback", type => Function }
+                        ]
+                    },
+                    {
+                        code => "foo(function() { (function() { this; }); });",
+                        output => "foo(() => { (function() { this; }); });",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() { this; }.bind(this));",
+                        output => "foo(() => { this; });",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(bar || function() { this; }.bind(this));",
+                        output => "foo(bar || (() => { this; }));",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() { (() => this); }.bind(this));",
+                        output => "foo(() => { (() => this); });",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function bar(a) { a; });",
+                        output => "foo((a) => { a; });",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function(a) { a; });",
+                        output => "foo((a) => { a; });",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function(arguments) { arguments; });",
+                        output => "foo((arguments) => { arguments; });",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() { this; });",
+                        output => None, // No fix applied
+                        options => { allow_unbound_this => false },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() { (() => this); });",
+                        output => None, // No fix applied
+                        options => { allow_unbound_this => false },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(function(foo, bar, baz) { return foo * 2; })",
+                        output => "qux((foo, bar, baz) => { return foo * 2; })",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(function(foo, bar, baz) { return foo * bar; }.bind(this))",
+                        output => "qux((foo, bar, baz) => { return foo * bar; })",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(function(foo, bar, baz) { return foo * this.qux; }.bind(this))",
+                        output => "qux((foo, bar, baz) => { return foo * this.qux; })",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() {}.bind(this, somethingElse))",
+                        output => "foo((() => {}).bind(this, somethingElse))",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(function(foo = 1, [bar = 2] = [], {qux: baz = 3} = {foo: 'bar'}) { return foo + bar; });",
+                        output => "qux((foo = 1, [bar = 2] = [], {qux: baz = 3} = {foo: 'bar'}) => { return foo + bar; });",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(function(baz, baz) { })",
+                        output => None, // Duplicate parameter names are a SyntaxError in arrow functions
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(function( /* no params */ ) { })",
+                        output => "qux(( /* no params */ ) => { })",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(function( /* a */ foo /* b */ , /* c */ bar /* d */ , /* e */ baz /* f */ ) { return foo; })",
+                        output => "qux(( /* a */ foo /* b */ , /* c */ bar /* d */ , /* e */ baz /* f */ ) => { return foo; })",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(async function (foo = 1, bar = 2, baz = 3) { return baz; })",
+                        output => "qux(async (foo = 1, bar = 2, baz = 3) => { return baz; })",
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "qux(async function (foo = 1, bar = 2, baz = 3) { return this; }.bind(this))",
+                        output => "qux(async (foo = 1, bar = 2, baz = 3) => { return this; })",
+                        environment => { ecma_version => 8 },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo((bar || function() {}).bind(this))",
+                        output => None,
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() {}.bind(this).bind(obj))",
+                        output => "foo((() => {}).bind(obj))",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+
+                    // Optional chaining
+                    {
+                        code => "foo?.(function() {});",
+                        output => "foo?.(() => {});",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo?.(function() { return this; }.bind(this));",
+                        output => "foo?.(() => { return this; });",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo(function() { return this; }?.bind(this));",
+                        output => "foo(() => { return this; });",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "foo((function() { return this; }?.bind)(this));",
+                        output => None,
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+
+                    // https://github.com/eslint/eslint/issues/16718
+                    {
+                        code => "
+                            test(
+                                function ()
+                                { }
+                            );
+                        ",
+                        output => "
+                            test(
+                                () =>
+                                { }
+                            );
+                        ",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    },
+                    {
+                        code => "
+                            test(
+                                function (
+                                    ...args
+                                ) /* Lorem ipsum
+                                dolor sit amet. */ {
+                                    return args;
+                                }
+                            );
+                        ",
+                        output => "
+                            test(
+                                (
+                                    ...args
+                                ) => /* Lorem ipsum
+                                dolor sit amet. */ {
+                                    return args;
+                                }
+                            );
+                        ",
+                        errors => [{ message_id => "prefer_arrow_callback", type => Function }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
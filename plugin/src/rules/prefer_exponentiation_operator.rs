@@ -0,0 +1,914 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::{get_call_expression_arguments, NodeExtJs},
+    kind::{
+        AwaitExpression, BinaryExpression, ClassHeritage, SpreadElement, SubscriptExpression,
+        UnaryExpression,
+    },
+    scope::ScopeManager,
+    utils::{
+        ast_utils,
+        eslint_utils::{ReferenceTracker, TraceMap},
+    },
+};
+
+static PRECEDENCE_OF_EXPONENTIATION_EXPR: Lazy<u32> =
+    Lazy::new(|| ast_utils::get_binary_expression_operator_precedence("**"));
+
+fn does_base_need_parens(base: Node) -> bool {
+    // '**' is right-associative, parens are needed when Math.pow(a ** b, c) is converted to (a ** b) ** c
+    ast_utils::get_precedence(base) <= *PRECEDENCE_OF_EXPONENTIATION_EXPR
+        // An unary operator cannot be used immediately before an exponentiation expression
+        || matches!(base.kind(), AwaitExpression | UnaryExpression)
+}
+
+fn does_exponent_need_parens(exponent: Node) -> bool {
+    // '**' is right-associative, there is no need for parens when Math.pow(a, b ** c) is converted to a ** b ** c
+    ast_utils::get_precedence(exponent) < *PRECEDENCE_OF_EXPONENTIATION_EXPR
+}
+
+fn does_exponentiation_expression_need_parens(node: Node) -> bool {
+    if ast_utils::is_parenthesised(node) {
+        return false;
+    }
+
+    let parent = node.parent().unwrap();
+    match parent.kind() {
+        ClassHeritage => true,
+        BinaryExpression if parent.field("operator").kind() == "**" => {
+            parent.field("right") != node
+        }
+        SubscriptExpression => parent.field("object") == node,
+        kind if kind.ends_with("_expression") => {
+            ast_utils::get_precedence(parent) >= *PRECEDENCE_OF_EXPONENTIATION_EXPR
+        }
+        _ => false,
+    }
+}
+
+fn parenthesize_if_should(text: &str, should_parenthesize: bool) -> String {
+    if should_parenthesize {
+        format!("({text})")
+    } else {
+        text.to_owned()
+    }
+}
+
+pub fn prefer_exponentiation_operator_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-exponentiation-operator",
+        languages => [Javascript],
+        messages => [
+            use_exponentiation => "Use the '**' operator instead of 'Math.pow'.",
+        ],
+        fixable => true,
+        methods => {
+            fn report(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                context.report(violation! {
+                    node => node,
+                    message_id => "use_exponentiation",
+                    fix => |fixer| {
+                        let args = get_call_expression_arguments(node).unwrap().collect_vec();
+                        if args.len() != 2
+                            || args.iter().any(|arg| arg.kind() == SpreadElement)
+                            || context.get_comments_inside(node).next().is_some()
+                        {
+                            return;
+                        }
+
+                        let base = args[0].skip_parentheses();
+                        let exponent = args[1].skip_parentheses();
+                        let should_parenthesize_base = does_base_need_parens(base);
+                        let should_parenthesize_exponent = does_exponent_need_parens(exponent);
+                        let should_parenthesize_all = does_exponentiation_expression_need_parens(node);
+
+                        let mut prefix = "";
+                        let mut suffix = "";
+
+                        if !should_parenthesize_all {
+                            if !should_parenthesize_base {
+                                let first_replacement_token = context.get_first_token(base, Option::<fn(Node) -> bool>::None);
+                                let token_before = context.maybe_get_token_before(node, Option::<fn(Node) -> bool>::None);
+
+                                // a+Math.pow(++b, c) -> a+ ++b**c
+                                if token_before.matches(|token_before| {
+                                    token_before.end_byte() == node.start_byte()
+                                        && !ast_utils::can_tokens_be_adjacent(token_before, first_replacement_token, context)
+                                }) {
+                                    prefix = " ";
+                                }
+                            }
+                            if !should_parenthesize_exponent {
+                                let last_replacement_token = context.get_last_token(exponent, Option::<fn(Node) -> bool>::None);
+                                let token_after = context.maybe_get_token_after(node, Option::<fn(Node) -> bool>::None);
+
+                                // Math.pow(a, b)in c -> a**b in c
+                                if token_after.matches(|token_after| {
+                                    node.end_byte() == token_after.start_byte()
+                                        && !ast_utils::can_tokens_be_adjacent(last_replacement_token, token_after, context)
+                                }) {
+                                    suffix = " ";
+                                }
+                            }
+                        }
+
+                        let base_replacement = parenthesize_if_should(&base.text(context), should_parenthesize_base);
+                        let exponent_replacement = parenthesize_if_should(&exponent.text(context), should_parenthesize_exponent);
+                        let replacement = parenthesize_if_should(
+                            &format!("{base_replacement}**{exponent_replacement}"),
+                            should_parenthesize_all,
+                        );
+
+                        fixer.replace_text(node, format!("{prefix}{replacement}{suffix}"));
+                    }
+                });
+            }
+        },
+        listeners => [
+            "program:exit" => |node, context| {
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let scope = scope_manager.get_scope(node);
+                let mut tracker = ReferenceTracker::new(scope);
+                let trace_map = TraceMap {
+                    children: [(
+                        "Math".to_owned(),
+                        TraceMap {
+                            children: [(
+                                "pow".to_owned(),
+                                TraceMap {
+                                    call: Some(()),
+                                    ..Default::default()
+                                },
+                            )]
+                            .into(),
+                            ..Default::default()
+                        },
+                    )]
+                    .into(),
+                    ..Default::default()
+                };
+
+                for reference in tracker.iterate_global_references(&trace_map, context) {
+                    self.report(reference.node, context);
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{get_instance_provider_factory, kind::CallExpression};
+
+    #[test]
+    fn test_prefer_exponentiation_operator_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            prefer_exponentiation_operator_rule(),
+            rule_tests! {
+                valid => [
+                    // not Math.pow()
+                    "Object.pow(a, b)",
+                    "Math.max(a, b)",
+                    "Math",
+                    "Math(a, b)",
+                    "pow",
+                    "pow(a, b)",
+                    "Math.pow",
+                    "Math.Pow(a, b)",
+                    "math.pow(a, b)",
+                    "foo.Math.pow(a, b)",
+                    "new Math.pow(a, b)",
+                    "Math[pow](a, b)",
+                    "Math.pow`a`",
+
+                    // not the global Math
+                    "let Math; Math.pow(a, b);",
+                    "if (foo) { const Math = 1; Math.pow(a, b); }",
+                    "var x = function Math() { Math.pow(a, b); }",
+                    "function foo(Math) { Math.pow(a, b); }",
+                    "function foo() { Math.pow(a, b); var Math; }",
+
+                    // the global Math is modified
+                    "Math = foo; Math.pow(a, b);",
+
+                    "a ** b",
+                    "2 ** 2"
+                ],
+                invalid => [
+                    {
+                        code => "Math.pow(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "globalThis.Math.pow(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(Math || foo).pow(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(foo ?? Math).pow(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "const { pow } = Math; pow(a, b)",
+                        output => "const { pow } = Math; a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(Math).pow(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math['pow'](a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(Math)['pow'](a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "var x=Math\n.  pow( a, \n b )",
+                        output => "var x=a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math[`pow`](a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // non-expression parents that don't require parens
+                    {
+                        code => "var x = Math.pow(a, b);",
+                        output => "var x = a**b;",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "if(Math.pow(a, b)){}",
+                        output => "if(a**b){}",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "for(;Math.pow(a, b);){}",
+                        output => "for(;a**b;){}",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "switch(foo){ case Math.pow(a, b): break; }",
+                        output => "switch(foo){ case a**b: break; }",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "{ foo: Math.pow(a, b) }",
+                        output => "{ foo: a**b }",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "function foo(bar, baz = Math.pow(a, b), quux){}",
+                        output => "function foo(bar, baz = a**b, quux){}",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "`${Math.pow(a, b)}`",
+                        output => "`${a**b}`",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // non-expression parents that do require parens
+                    {
+                        code => "class C extends Math.pow(a, b) {}",
+                        output => "class C extends (a**b) {}",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // parents with a higher precedence
+                    {
+                        code => "+ Math.pow(a, b)",
+                        output => "+ (a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "- Math.pow(a, b)",
+                        output => "- (a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "! Math.pow(a, b)",
+                        output => "! (a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "typeof Math.pow(a, b)",
+                        output => "typeof (a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "void Math.pow(a, b)",
+                        output => "void (a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b) .toString()",
+                        output => "(a**b) .toString()",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b) ()",
+                        output => "(a**b) ()",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b) ``",
+                        output => "(a**b) ``",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(class extends Math.pow(a, b) {})",
+                        output => "(class extends (a**b) {})",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // already parenthesised, shouldn't insert extra parens
+                    {
+                        code => "+(Math.pow(a, b))",
+                        output => "+(a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(Math.pow(a, b)).toString()",
+                        output => "(a**b).toString()",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(class extends (Math.pow(a, b)) {})",
+                        output => "(class extends (a**b) {})",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // parents with a higher precedence, but the expression's role doesn't require parens
+                    {
+                        code => "f(Math.pow(a, b))",
+                        output => "f(a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "f(foo, Math.pow(a, b))",
+                        output => "f(foo, a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "f(Math.pow(a, b), foo)",
+                        output => "f(a**b, foo)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "f(foo, Math.pow(a, b), bar)",
+                        output => "f(foo, a**b, bar)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "new F(Math.pow(a, b))",
+                        output => "new F(a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "foo[Math.pow(a, b)]",
+                        output => "foo[a**b]",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "foo.bar.baz[Math.pow(a, b)]",
+                        output => "foo.bar.baz[a**b]",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // parents with a lower precedence
+                    {
+                        code => "a * Math.pow(b, c)",
+                        output => "a * b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b) * c",
+                        output => "a**b * c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a + Math.pow(b, c)",
+                        output => "a + b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b)/c",
+                        output => "a**b/c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a < Math.pow(b, c)",
+                        output => "a < b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b) > c",
+                        output => "a**b > c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a === Math.pow(b, c)",
+                        output => "a === b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a ? Math.pow(b, c) : d",
+                        output => "a ? b**c : d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a = Math.pow(b, c)",
+                        output => "a = b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a += Math.pow(b, c)",
+                        output => "a += b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "function *f() { yield Math.pow(a, b) }",
+                        output => "function *f() { yield a**b }",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a, Math.pow(b, c), d",
+                        output => "a, b**c, d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // '**' is right-associative, that applies to both parent and child nodes
+                    {
+                        code => "a ** Math.pow(b, c)",
+                        output => "a ** b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b) ** c",
+                        output => "(a**b) ** c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b ** c)",
+                        output => "a**b ** c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a ** b, c)",
+                        output => "(a ** b)**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a ** b, c ** d)",
+                        output => "(a ** b)**c ** d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // doesn't remove already existing unnecessary parens around the whole expression
+                    {
+                        code => "(Math.pow(a, b))",
+                        output => "(a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "foo + (Math.pow(a, b))",
+                        output => "foo + (a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(Math.pow(a, b)) + foo",
+                        output => "(a**b) + foo",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "`${(Math.pow(a, b))}`",
+                        output => "`${(a**b)}`",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // base and exponent with a higher precedence
+                    {
+                        code => "Math.pow(2, 3)",
+                        output => "2**3",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a.foo, b)",
+                        output => "a.foo**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b.foo)",
+                        output => "a**b.foo",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a(), b)",
+                        output => "a()**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b())",
+                        output => "a**b()",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(++a, ++b)",
+                        output => "++a**++b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a++, ++b)",
+                        output => "a++**++b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a--, b--)",
+                        output => "a--**b--",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(--a, b--)",
+                        output => "--a**b--",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // doesn't preserve unnecessary parens around base and exponent
+                    {
+                        code => "Math.pow((a), (b))",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(((a)), ((b)))",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow((a.foo), b)",
+                        output => "a.foo**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, (b.foo))",
+                        output => "a**b.foo",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow((a()), b)",
+                        output => "a()**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, (b()))",
+                        output => "a**b()",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // unary expressions are exception: base cannot be unary (parens required), exponent can
+                    {
+                        code => "Math.pow(+a, b)",
+                        output => "(+a)**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, +b)",
+                        output => "a**+b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(-a, b)",
+                        output => "(-a)**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, -b)",
+                        output => "a**-b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(-2, 3)",
+                        output => "(-2)**3",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(2, -3)",
+                        output => "2**-3",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "async () => Math.pow(await a, b)",
+                        output => "async () => (await a)**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "async () => Math.pow(a, await b)",
+                        output => "async () => a**await b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // base and exponent with a lower precedence
+                    {
+                        code => "Math.pow(a * b, c)",
+                        output => "(a * b)**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b * c)",
+                        output => "a**(b * c)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a / b, c)",
+                        output => "(a / b)**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b / c)",
+                        output => "a**(b / c)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a + b, 3)",
+                        output => "(a + b)**3",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(2, a - b)",
+                        output => "2**(a - b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a + b, c + d)",
+                        output => "(a + b)**(c + d)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a = b, c = d)",
+                        output => "(a = b)**(c = d)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a += b, c -= d)",
+                        output => "(a += b)**(c -= d)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow((a, b), (c, d))",
+                        output => "(a, b)**(c, d)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "function *f() { Math.pow(yield, yield) }",
+                        output => "function *f() { (yield)**(yield) }",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // combination of lower and higher precedence
+                    {
+                        code => "Math.pow(a * b, c) + d",
+                        output => "(a * b)**c + d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // tokens that can be adjacent
+                    {
+                        code => "a+Math.pow(b, c)+d",
+                        output => "a+b**c+d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // tokens that cannot be adjacent
+                    {
+                        code => "a+Math.pow(++b, c)",
+                        output => "a+ ++b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(a)+(Math).pow((++b), c)",
+                        output => "(a)+ ++b**c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b)in c",
+                        output => "a**b in c",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, (b))in (c)",
+                        output => "a**b in (c)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a+Math.pow(++b, c)in d",
+                        output => "a+ ++b**c in d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a+Math.pow( ++b, c )in d",
+                        output => "a+ ++b**c in d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // tokens that cannot be adjacent, but there is already space or something else between
+                    {
+                        code => "a+ Math.pow(++b, c) in d",
+                        output => "a+ ++b**c in d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a+/**/Math.pow(++b, c)/**/in d",
+                        output => "a+/**/++b**c/**/in d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "a+(Math.pow(++b, c))in d",
+                        output => "a+(++b**c)in d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // tokens that cannot be adjacent, but the fix inserts parens
+                    {
+                        code => "+Math.pow(++a, b)",
+                        output => "+(++a**b)",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b + c)in d",
+                        output => "a**(b + c)in d",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // fix only if the call has exactly 2 arguments
+                    {
+                        code => "Math.pow()",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b, c)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b, c, d)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // don't fix if there are spread arguments
+                    {
+                        code => "Math.pow(...a)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(...a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, ...b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b, ...c)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // don't fix if there are comments
+                    {
+                        code => "/* comment */Math.pow(a, b)",
+                        output => "/* comment */a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math/**/.pow(a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math//\n.pow(a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math[//\n'pow'](a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math['pow'/**/](a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math./**/pow(a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow/**/(a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow//\n(a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(/**/a, b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a,//\n b)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b/**/)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b//\n)",
+                        output => None,
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b)/* comment */;",
+                        output => "a**b/* comment */;",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math.pow(a, b)// comment\n;",
+                        output => "a**b// comment\n;",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+
+                    // Optional chaining
+                    {
+                        code => "Math.pow?.(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math?.pow(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "Math?.pow?.(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(Math?.pow)(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    },
+                    {
+                        code => "(Math?.pow)?.(a, b)",
+                        output => "a**b",
+                        errors => [{ message_id => "use_exponentiation", type => CallExpression }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,263 @@
+use std::{cell::RefCell, sync::Arc};
+
+use regexpp_js::{
+    id_arena::Id, visit_reg_exp_ast, visitor, AllArenas, NodeInterface, RegExpParser,
+    ValidatePatternFlags, Wtf16,
+};
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::get_call_expression_arguments,
+    kind::{self, TemplateString},
+    scope::ScopeManager,
+    utils::{ast_utils, ast_utils::get_static_string_value},
+};
+
+fn check_regex<'a>(
+    pattern: &str,
+    node: Node<'a>,
+    flags: Option<&str>,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let arena: AllArenas = Default::default();
+    let mut parser = RegExpParser::new(&arena, None);
+    let pattern_as_wtf16: Wtf16 = pattern.into();
+    let Ok(ast) = parser.parse_pattern(
+        &pattern_as_wtf16,
+        Some(0),
+        Some(pattern_as_wtf16.len()),
+        Some(ValidatePatternFlags {
+            unicode: Some(flags.matches(|flags| flags.contains('u'))),
+            unicode_sets: Some(flags.matches(|flags| flags.contains('v'))),
+        }),
+    ) else {
+        // ignore regex syntax errors
+        return;
+    };
+
+    #[derive(Default)]
+    struct Handlers {
+        unnamed_groups: RefCell<Vec<Id<regexpp_js::Node>>>,
+    }
+
+    impl visitor::Handlers for Handlers {
+        fn on_capturing_group_enter(&self, group: Id<regexpp_js::Node /* CapturingGroup */>) {
+            self.unnamed_groups.borrow_mut().push(group);
+        }
+    }
+
+    let handlers = Handlers::default();
+
+    visit_reg_exp_ast(ast, &handlers, &arena);
+
+    for &group in handlers.unnamed_groups.borrow().iter() {
+        let group_ref = arena.node(group);
+        if group_ref.as_capturing_group().name.is_some() {
+            continue;
+        }
+
+        context.report(violation! {
+            node => node,
+            message_id => "required",
+            data => {
+                group => String::from_utf16_lossy(&group_ref.raw()),
+            },
+            // TODO: suggestions?
+        });
+    }
+}
+
+pub fn prefer_named_capture_group_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-named-capture-group",
+        languages => [Javascript],
+        messages => [
+            add_group_name => "Add name to capture group.",
+            add_non_capture => "Convert group to non-capturing.",
+            required => "Capture group '{{group}}' should be converted to a named or non-capturing group.",
+        ],
+        listeners => [
+            r#"
+              (regex) @c
+            "# => |node, context| {
+                check_regex(
+                    &node.field("pattern").text(context),
+                    node,
+                    node.child_by_field_name("flags").map(|flags| flags.text(context)).as_deref(),
+                    context,
+                );
+            },
+            r#"
+              (call_expression
+                function: (identifier) @regexp (#eq? @regexp "RegExp")
+              ) @call_expression
+              (new_expression
+                constructor: (identifier) @regexp (#eq? @regexp "RegExp")
+              ) @call_expression
+            "# => |captures, context| {
+                let node = captures["call_expression"];
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let scope = scope_manager.get_scope(node);
+                let reg_exp_var = ast_utils::get_variable_by_name(scope, "RegExp");
+                if reg_exp_var.matches(|reg_exp_var| reg_exp_var.defs().next().is_some()) {
+                    return;
+                }
+
+                let Some(mut args) = get_call_expression_arguments(node) else {
+                    return;
+                };
+                let Some(pattern) = args
+                    .next()
+                    .filter(|arg| matches!(arg.kind(), kind::String | TemplateString))
+                    .and_then(|arg| get_static_string_value(arg, context))
+                else {
+                    return;
+                };
+                let flags = args
+                    .next()
+                    .filter(|arg| matches!(arg.kind(), kind::String | TemplateString))
+                    .and_then(|arg| get_static_string_value(arg, context));
+
+                check_regex(&pattern, node, flags.as_deref(), context);
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{CallExpression, NewExpression, Regex},
+    };
+
+    #[test]
+    fn test_prefer_named_capture_group_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            prefer_named_capture_group_rule(),
+            rule_tests! {
+                valid => [
+                    "/normal_regex/",
+                    "/(?:[0-9]{4})/",
+                    "/(?<year>[0-9]{4})/",
+                    "/\\u{1F680}/u",
+                    "new RegExp()",
+                    "new RegExp(foo)",
+                    "new RegExp('')",
+                    "new RegExp('(?<year>[0-9]{4})')",
+                    "RegExp()",
+                    "RegExp(foo)",
+                    "RegExp('')",
+                    "RegExp('(?<year>[0-9]{4})')",
+                    "RegExp('(')", // invalid regexp should be ignored
+                    "RegExp('\\\\u{1F680}', 'u')",
+                    "new RegExp('\\\\u{1F680}', 'u')",
+                    "function foo() { var RegExp; RegExp('([0-9]{4})'); }",
+                    "function foo(RegExp) { new RegExp('([0-9]{4})'); }"
+                ],
+                invalid => [
+                    {
+                        code => "/([0-9]{4})/",
+                        errors => [{
+                            message_id => "required",
+                            type => Regex,
+                            data => { group => "([0-9]{4})" },
+                            line => 1,
+                            column => 1,
+                            end_column => 13,
+                            // suggestions: [
+                            //     {
+                            //         messageId: "addGroupName",
+                            //         output: "/(?<temp1>[0-9]{4})/"
+                            //     },
+                            //     {
+                            //         messageId: "addNonCapture",
+                            //         output: "/(?:[0-9]{4})/"
+                            //     }
+                            // ]
+                        }]
+                    },
+                    {
+                        code => "new RegExp('([0-9]{4})')",
+                        errors => [{
+                            message_id => "required",
+                            type => NewExpression,
+                            data => { group => "([0-9]{4})" },
+                            line => 1,
+                            column => 1,
+                            end_column => 25,
+                        }]
+                    },
+                    {
+                        code => "RegExp('([0-9]{4})')",
+                        errors => [{
+                            message_id => "required",
+                            type => CallExpression,
+                            data => { group => "([0-9]{4})" },
+                            line => 1,
+                            column => 1,
+                            end_column => 21,
+                        }]
+                    },
+                    {
+                        code => "new RegExp(`a(bc)d`)",
+                        errors => [{
+                            message_id => "required",
+                            type => NewExpression,
+                            data => { group => "(bc)" },
+                        }]
+                    },
+                    {
+                        code => "/([0-9]{4})-(\\w{5})/",
+                        errors => [
+                            {
+                                message_id => "required",
+                                type => Regex,
+                                data => { group => "([0-9]{4})" },
+                                line => 1,
+                                column => 1,
+                                end_column => 21,
+                            },
+                            {
+                                message_id => "required",
+                                type => Regex,
+                                data => { group => "(\\w{5})" },
+                                line => 1,
+                                column => 1,
+                                end_column => 21,
+                            }
+                        ]
+                    },
+                    {
+                        code => "/([0-9]{4})-(5)/",
+                        errors => [
+                            {
+                                message_id => "required",
+                                type => Regex,
+                                data => { group => "([0-9]{4})" },
+                            },
+                            {
+                                message_id => "required",
+                                type => Regex,
+                                data => { group => "(5)" },
+                            }
+                        ]
+                    },
+                    {
+                        code => "RegExp('(a)', 'u')",
+                        errors => [{
+                            message_id => "required",
+                            type => CallExpression,
+                            data => { group => "(a)" },
+                        }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,775 @@
+use std::{borrow::Cow, cell::RefCell, collections::HashSet, sync::Arc};
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regexpp_js::{
+    id_arena::Id, visit_reg_exp_ast, visitor, AllArenas, NodeInterface, RegExpParser,
+    RegExpValidator, ValidatePatternFlags, Wtf16,
+};
+use serde::Deserialize;
+use squalid::{regex, CowStrExt, OptionExt};
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+
+use crate::{
+    ast_helpers::{get_call_expression_arguments, is_tagged_template_expression, NodeExtJs},
+    kind::{self, CallExpression, TemplateString},
+    scope::ScopeManager,
+    utils::{
+        ast_utils,
+        ast_utils::get_static_string_value,
+        eslint_utils::{ReferenceTracker, TraceMap},
+    },
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    disallow_redundant_wrapping: bool,
+}
+
+static VALID_PRECEDING_TOKENS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    [
+        "(",
+        ";",
+        "[",
+        ",",
+        "=",
+        "+",
+        "*",
+        "-",
+        "?",
+        "~",
+        "%",
+        "**",
+        "!",
+        "typeof",
+        "instanceof",
+        "&&",
+        "||",
+        "??",
+        "return",
+        "...",
+        "delete",
+        "void",
+        "in",
+        "<",
+        ">",
+        "<=",
+        ">=",
+        "==",
+        "===",
+        "!=",
+        "!==",
+        "<<",
+        ">>",
+        ">>>",
+        "&",
+        "|",
+        "^",
+        ":",
+        "{",
+        "=>",
+        "*=",
+        "<<=",
+        ">>=",
+        ">>>=",
+        "^=",
+        "|=",
+        "&=",
+        "??=",
+        "||=",
+        "&&=",
+        "**=",
+        "+=",
+        "-=",
+        "/=",
+        "%=",
+        "/",
+        "do",
+        "break",
+        "continue",
+        "debugger",
+        "case",
+        "throw",
+    ]
+    .into()
+});
+
+fn is_string_raw_tagged_static_template_literal<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if !is_tagged_template_expression(node) {
+        return false;
+    }
+
+    let tag = node.field("function").skip_parentheses();
+    ast_utils::is_specific_member_access(tag, Some("String"), Some("raw"), context)
+        && ast_utils::is_global_reference(tag.field("object").skip_parentheses(), context)
+        && ast_utils::is_static_template_literal(node.field("arguments"))
+}
+
+fn is_static_string<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    node.kind() == kind::String
+        || ast_utils::is_static_template_literal(node)
+        || is_string_raw_tagged_static_template_literal(node, context)
+}
+
+fn get_string_value<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    match node.kind() {
+        kind::String | TemplateString => get_static_string_value(node, context).unwrap(),
+        CallExpression => node
+            .field("arguments")
+            .text(context)
+            .sliced(|len| 1..len - 1),
+        _ => unreachable!(),
+    }
+}
+
+fn is_unnecessarily_wrapped_regex_literal<'a>(
+    args: &[Node<'a>],
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    match args {
+        [pattern] => pattern.kind() == kind::Regex,
+        [pattern, flags] => pattern.kind() == kind::Regex && is_static_string(*flags, context),
+        _ => false,
+    }
+}
+
+fn is_valid_regex(pattern: &str, flags: Option<&str>) -> bool {
+    let mut validator = RegExpValidator::new(None);
+    validator
+        .validate_pattern(
+            &Wtf16::from(pattern),
+            None,
+            None,
+            Some(ValidatePatternFlags {
+                unicode: Some(flags.matches(|flags| flags.contains('u'))),
+                unicode_sets: Some(flags.matches(|flags| flags.contains('v'))),
+            }),
+        )
+        .is_ok()
+        && flags.is_none_or_matches(|flags| {
+            validator
+                .validate_flags(&Wtf16::from(flags), None, None)
+                .is_ok()
+        })
+}
+
+fn can_fix_to<'a>(
+    node: Node<'a>,
+    pattern: &str,
+    flags: Option<&str>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    context.get_comments_inside(node).next().is_none()
+        && context
+            .maybe_get_token_before(node, Option::<fn(Node) -> bool>::None)
+            .is_none_or_matches(|token_before| {
+                VALID_PRECEDING_TOKENS.contains(&*token_before.text(context))
+            })
+        && is_valid_regex(pattern, flags)
+}
+
+fn get_safe_output<'a>(
+    node: Node<'a>,
+    regex_literal: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> String {
+    let token_before = context.maybe_get_token_before(node, Option::<fn(Node) -> bool>::None);
+    let token_after = context.maybe_get_token_after(node, Option::<fn(Node) -> bool>::None);
+    let mut prefix = "";
+    let mut suffix = "";
+
+    if token_before.matches(|token_before| {
+        token_before.end_byte() == node.start_byte()
+            && !ast_utils::can_tokens_be_adjacent(token_before, regex_literal, context)
+    }) {
+        prefix = " ";
+    }
+
+    if token_after.matches(|token_after| {
+        node.end_byte() == token_after.start_byte()
+            && !ast_utils::can_tokens_be_adjacent(regex_literal, token_after, context)
+    }) {
+        suffix = " ";
+    }
+
+    format!("{prefix}{regex_literal}{suffix}")
+}
+
+fn resolve_escapes(character: &str) -> Option<&'static str> {
+    match character {
+        "\n" | "\\\n" => Some("\\n"),
+        "\r" | "\\\r" => Some("\\r"),
+        "\t" | "\\\t" => Some("\\t"),
+        "\x0B" | "\\\x0B" => Some("\\v"),
+        "\x0C" | "\\\x0C" => Some("\\f"),
+        "/" => Some("\\/"),
+        _ => None,
+    }
+}
+
+fn escape_regex_content(regex_content: &str, flags: Option<&str>) -> String {
+    let arena: AllArenas = Default::default();
+    let mut parser = RegExpParser::new(&arena, None);
+    let regex_content_as_wtf16: Wtf16 = regex_content.into();
+    let Ok(ast) = parser.parse_pattern(
+        &regex_content_as_wtf16,
+        Some(0),
+        Some(regex_content_as_wtf16.len()),
+        Some(ValidatePatternFlags {
+            unicode: Some(flags.matches(|flags| flags.contains('u'))),
+            unicode_sets: Some(flags.matches(|flags| flags.contains('v'))),
+        }),
+    ) else {
+        return regex_content.to_owned();
+    };
+
+    #[derive(Default)]
+    struct Handlers {
+        character_nodes: RefCell<Vec<Id<regexpp_js::Node>>>,
+    }
+
+    impl visitor::Handlers for Handlers {
+        fn on_character_enter(&self, node: Id<regexpp_js::Node /* Character */>) {
+            self.character_nodes.borrow_mut().push(node);
+        }
+    }
+
+    let handlers = Handlers::default();
+
+    visit_reg_exp_ast(ast, &handlers, &arena);
+
+    // the regex content has already been checked to only contain ASCII
+    // characters so regexpp's UTF-16 offsets line up with byte offsets
+    let mut escaped_regex_content = regex_content.to_owned();
+    let mut char_increase = 0;
+    for &character_node in handlers.character_nodes.borrow().iter() {
+        let character_node_ref = arena.node(character_node);
+        let raw = String::from_utf16_lossy(&character_node_ref.raw());
+        let Some(escaped) = resolve_escapes(&raw) else {
+            continue;
+        };
+
+        escaped_regex_content.replace_range(
+            character_node_ref.start() + char_increase..character_node_ref.end() + char_increase,
+            escaped,
+        );
+        if raw.len() == 1 {
+            char_increase += 1;
+        }
+    }
+    escaped_regex_content
+}
+
+pub fn prefer_regex_literals_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-regex-literals",
+        languages => [Javascript],
+        messages => [
+            unexpected_reg_exp => "Use a regular expression literal instead of the 'RegExp' constructor.",
+            replace_with_literal => "Replace with an equivalent regular expression literal.",
+            replace_with_literal_and_flags => "Replace with an equivalent regular expression literal with flags '{{ flags }}'.",
+            replace_with_intended_literal_and_flags => "Replace with a regular expression literal with flags '{{ flags }}'.",
+            unexpected_redundant_reg_exp => "Regular expression literal is unnecessarily wrapped within a 'RegExp' constructor.",
+            unexpected_redundant_reg_exp_with_flags => "Use regular expression literal with flags instead of the 'RegExp' constructor.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            disallow_redundant_wrapping: bool = options.disallow_redundant_wrapping,
+        },
+        methods => {
+            fn check(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let Some(args) = get_call_expression_arguments(node) else {
+                    return;
+                };
+                let args = args.collect_vec();
+
+                if self.disallow_redundant_wrapping && is_unnecessarily_wrapped_regex_literal(&args, context) {
+                    let regex_node = args[0];
+                    let pattern = regex_node.field("pattern").text(context);
+
+                    if args.len() == 2 {
+                        let arg_flags = get_string_value(args[1], context);
+
+                        context.report(violation! {
+                            node => node,
+                            message_id => "unexpected_redundant_reg_exp_with_flags",
+                            fix => |fixer| {
+                                // The flags passed to the constructor are the ones in
+                                // effect, so they (rather than the "intended" merge of
+                                // both sets of flags) are what the fix preserves
+                                if !can_fix_to(node, &pattern, Some(&arg_flags), context) {
+                                    return;
+                                }
+
+                                fixer.replace_text(
+                                    node,
+                                    get_safe_output(node, &format!("/{pattern}/{arg_flags}"), context),
+                                );
+                            }
+                        });
+                    } else {
+                        let flags = regex_node.child_by_field_name("flags").map(|flags| flags.text(context));
+
+                        context.report(violation! {
+                            node => node,
+                            message_id => "unexpected_redundant_reg_exp",
+                            fix => |fixer| {
+                                if !can_fix_to(node, &pattern, flags.as_deref(), context) {
+                                    return;
+                                }
+
+                                fixer.replace_text(
+                                    node,
+                                    get_safe_output(node, &regex_node.text(context), context),
+                                );
+                            }
+                        });
+                    }
+                } else if matches!(args.len(), 1 | 2) && args.iter().all(|&arg| is_static_string(arg, context)) {
+                    let regex_content = get_string_value(args[0], context);
+                    let flags = args.get(1).map(|&arg| get_string_value(arg, context));
+
+                    let no_fix = !can_fix_to(node, &regex_content, flags.as_deref(), context)
+                        || !regex!(r##"^[-a-zA-Z0-9\\\[\](){} \t\r\n\v\f!@#$%^&*+_=/~`.><?,'"|:;]*$"##)
+                            .is_match(&regex_content);
+
+                    let regex_content = if !regex_content.is_empty() && !no_fix {
+                        escape_regex_content(&regex_content, flags.as_deref()).into()
+                    } else {
+                        regex_content
+                    };
+
+                    let new_reg_exp_value = format!(
+                        "/{}/{}",
+                        if regex_content.is_empty() { "(?:)" } else { &*regex_content },
+                        flags.as_deref().unwrap_or_default(),
+                    );
+
+                    context.report(violation! {
+                        node => node,
+                        message_id => "unexpected_reg_exp",
+                        fix => |fixer| {
+                            if no_fix {
+                                return;
+                            }
+
+                            fixer.replace_text(
+                                node,
+                                get_safe_output(node, &new_reg_exp_value, context),
+                            );
+                        }
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let scope = scope_manager.get_scope(node);
+                let mut tracker = ReferenceTracker::new(scope);
+                let trace_map = TraceMap {
+                    children: [(
+                        "RegExp".to_owned(),
+                        TraceMap {
+                            call: Some(()),
+                            construct: Some(()),
+                            ..Default::default()
+                        },
+                    )]
+                    .into(),
+                    ..Default::default()
+                };
+
+                for reference in tracker.iterate_global_references(&trace_map, context) {
+                    self.check(reference.node, context);
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{CallExpression, NewExpression},
+    };
+
+    #[test]
+    fn test_prefer_regex_literals_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            prefer_regex_literals_rule(),
+            rule_tests! {
+                valid => [
+                    "/abc/",
+                    "/abc/g",
+
+                    // considered as dynamic
+                    "new RegExp(pattern)",
+                    "RegExp(pattern, 'g')",
+                    "new RegExp(f('a'))",
+                    "RegExp(prefix + 'a')",
+                    "new RegExp('a' + suffix)",
+                    "RegExp(`a` + suffix);",
+                    "new RegExp(String.raw`a` + suffix);",
+                    "RegExp('a', flags)",
+                    "const flags = 'gu';RegExp('a', flags)",
+                    "RegExp('a', 'g' + flags)",
+                    "new RegExp(String.raw`a`, flags);",
+                    "RegExp(`${prefix}abc`)",
+                    "new RegExp(`a${b}c`);",
+                    "new RegExp(`a${''}c`);",
+                    "new RegExp(String.raw`a${b}c`);",
+                    "new RegExp(String.raw`a${''}c`);",
+                    "new RegExp('a' + 'b')",
+                    "RegExp(1)",
+                    "new RegExp(/a/, 'u');",
+                    "new RegExp(/a/);",
+                    {
+                        code => "new RegExp(/a/, flags);",
+                        options => { disallow_redundant_wrapping => true }
+                    },
+                    {
+                        code => "new RegExp(/a/, `u${flags}`);",
+                        options => { disallow_redundant_wrapping => true }
+                    },
+
+                    // invalid number of arguments
+                    "new RegExp;",
+                    "new RegExp();",
+                    "RegExp();",
+                    "new RegExp('a', 'g', 'b');",
+                    "RegExp('a', 'g', 'b');",
+                    "new RegExp(`a`, `g`, `b`);",
+                    "RegExp(`a`, `g`, `b`);",
+                    "new RegExp(String.raw`a`, String.raw`g`, String.raw`b`);",
+                    "RegExp(String.raw`a`, String.raw`g`, String.raw`b`);",
+                    {
+                        code => "new RegExp(/a/, 'u', 'foo');",
+                        options => { disallow_redundant_wrapping => true }
+                    },
+
+                    // not String.raw``
+                    "new RegExp(String`a`);",
+                    "RegExp(raw`a`);",
+                    "new RegExp(f(String.raw)`a`);",
+                    "RegExp(string.raw`a`);",
+                    "new RegExp(String.Raw`a`);",
+                    "new RegExp(String[raw]`a`);",
+                    "RegExp(String.raw.foo`a`);",
+                    "new RegExp(String.foo.raw`a`);",
+                    "RegExp(foo.String.raw`a`);",
+                    "new RegExp(String.raw);",
+
+                    // not the global String in String.raw``
+                    "let String; new RegExp(String.raw`a`);",
+                    "function foo() { var String; new RegExp(String.raw`a`); }",
+                    "function foo(String) { RegExp(String.raw`a`); }",
+                    "if (foo) { const String = bar; RegExp(String.raw`a`); }",
+
+                    // not RegExp
+                    "new Regexp('abc');",
+                    "Regexp(`a`);",
+                    "new Regexp(String.raw`a`);",
+                    "RegExp`abc`;",
+
+                    // not the global RegExp
+                    "let RegExp; new RegExp('a');",
+                    "function foo() { var RegExp; RegExp('a', 'g'); }",
+                    "function foo(RegExp) { new RegExp(String.raw`a`); }",
+                    "if (foo) { const RegExp = bar; RegExp('a'); }",
+                    "RegExp = foo; new RegExp('a');",
+
+                    // globalThis
+                    {
+                        code => "new globalThis.RegExp('a');",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "globalThis.RegExp('a');",
+                        environment => { ecma_version => 6 }
+                    },
+                    "let globalThis; new globalThis.RegExp('a');",
+                    "new globalThis.Regexp('a');",
+                    "new globalThis[RegExp]('a');"
+                ],
+                invalid => [
+                    {
+                        code => "new globalThis.RegExp('a');",
+                        output => "/a/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "globalThis.RegExp('a');",
+                        output => "/a/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "new globalThis.RegExp('\\\\d', 'g');",
+                        output => "/\\d/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "new window.RegExp('a');",
+                        output => "/a/;",
+                        environment => { env => { browser => true } },
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "new RegExp('abc');",
+                        output => "/abc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "RegExp('abc');",
+                        output => "/abc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression, line => 1, column => 1 }]
+                    },
+                    {
+                        code => "new RegExp('abc', 'g');",
+                        output => "/abc/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp('abc', 'g');",
+                        output => "/abc/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(`abc`);",
+                        output => "/abc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp(`abc`);",
+                        output => "/abc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(`abc`, `g`);",
+                        output => "/abc/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp(`abc`, `g`);",
+                        output => "/abc/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(String.raw`abc`);",
+                        output => "/abc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(String.raw`abc\nabc`);",
+                        output => "/abc\\nabc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(String.raw`\tabc\nabc`);",
+                        output => "/\\tabc\\nabc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp(String.raw`abc`);",
+                        output => "/abc/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(String.raw`abc`, String.raw`g`);",
+                        output => "/abc/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp(String.raw`abc`, String.raw`g`);",
+                        output => "/abc/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(String['raw']`a`);",
+                        output => "/a/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('');",
+                        output => "/(?:)/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp('', '');",
+                        output => "/(?:)/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(String.raw``);",
+                        output => "/(?:)/;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('a', `g`);",
+                        output => "/a/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp(`a`, 'g');",
+                        output => "/a/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "RegExp(String.raw`a`, 'g');",
+                        output => "/a/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(String.raw`\\d`, `g`);",
+                        output => "/\\d/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(String.raw`\\\\d`, `g`);",
+                        output => "/\\\\d/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(String[\"raw\"]`\\\\d`, `g`);",
+                        output => "/\\\\d/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp('a', String.raw`g`);",
+                        output => "/a/g;",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "new RegExp(/a/);",
+                        output => "/a/;",
+                        options => { disallow_redundant_wrapping => true },
+                        errors => [{ message_id => "unexpected_redundant_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(/a/, 'u');",
+                        output => "/a/u;",
+                        options => { disallow_redundant_wrapping => true },
+                        errors => [{ message_id => "unexpected_redundant_reg_exp_with_flags", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(/a/, `u`);",
+                        output => "/a/u;",
+                        options => { disallow_redundant_wrapping => true },
+                        errors => [{ message_id => "unexpected_redundant_reg_exp_with_flags", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(/a/g, 'u');",
+                        output => "/a/u;",
+                        options => { disallow_redundant_wrapping => true },
+                        errors => [{ message_id => "unexpected_redundant_reg_exp_with_flags", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('a');",
+                        output => "/a/;",
+                        options => { disallow_redundant_wrapping => true },
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+
+                    // ASI hazards
+                    {
+                        code => "a/RegExp(\"foo\")in b",
+                        output => "a/ /foo/ in b",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "x = y\n            RegExp(\"foo\").test(x) ? bar() : baz()",
+                        output => None,
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "typeof RegExp(\"foo\")",
+                        output => "typeof /foo/",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    },
+                    {
+                        code => "[   new RegExp(`someregular`)]",
+                        output => "[   /someregular/]",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(/* comment */ 'a');",
+                        output => None,
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+
+                    // escaping
+                    {
+                        code => "new RegExp('\\n', '')",
+                        output => "/\\n/",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('\\\\n', '')",
+                        output => "/\\n/",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('\\t', '')",
+                        output => "/\\t/",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('/', '')",
+                        output => "/\\//",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('[/]', '')",
+                        output => "/[\\/]/",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('\\\\/', '')",
+                        output => "/\\//",
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+
+                    // no fix for invalid regexes or flags
+                    {
+                        code => "new RegExp('[', '')",
+                        output => None,
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('a', 'z')",
+                        output => None,
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('a', 'uu')",
+                        output => None,
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('\\u0001')",
+                        output => None,
+                        errors => [{ message_id => "unexpected_reg_exp", type => NewExpression }]
+                    },
+                    {
+                        code => "RegExp('a', 'gu')",
+                        output => "/a/gu",
+                        errors => [{ message_id => "unexpected_reg_exp", type => CallExpression }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
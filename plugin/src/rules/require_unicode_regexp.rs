@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+use crate::{
+    ast_helpers::get_call_expression_arguments,
+    kind::SpreadElement,
+    scope::ScopeManager,
+    utils::ast_utils::{self, get_static_string_value},
+};
+
+pub fn require_unicode_regexp_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "require-unicode-regexp",
+        languages => [Javascript],
+        messages => [
+            add_u_flag => "Add the 'u' flag.",
+            require_u_flag => "Use the 'u' flag.",
+        ],
+        listeners => [
+            r#"
+              (regex) @c
+            "# => |node, context| {
+                let flags = node
+                    .child_by_field_name("flags")
+                    .map(|flags| flags.text(context))
+                    .unwrap_or_default();
+
+                if !flags.contains('u') && !flags.contains('v') {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "require_u_flag",
+                        // TODO: suggestions?
+                        // suggest: [
+                        //     {
+                        //         fix(fixer) {
+                        //             return fixer.insertTextAfter(node, "u");
+                        //         },
+                        //         messageId: "addUFlag"
+                        //     }
+                        // ]
+                    });
+                }
+            },
+            r#"
+              (call_expression
+                function: (identifier) @regexp (#eq? @regexp "RegExp")
+              ) @call_expression
+              (new_expression
+                constructor: (identifier) @regexp (#eq? @regexp "RegExp")
+              ) @call_expression
+            "# => |captures, context| {
+                let node = captures["call_expression"];
+                let scope_manager = context.retrieve::<ScopeManager<'a>>();
+                let scope = scope_manager.get_scope(node);
+                let reg_exp_var = ast_utils::get_variable_by_name(scope, "RegExp");
+                if reg_exp_var.matches(|reg_exp_var| reg_exp_var.defs().next().is_some()) {
+                    return;
+                }
+
+                let Some(mut args) = get_call_expression_arguments(node) else {
+                    return;
+                };
+                if args.next().matches(|pattern_node| pattern_node.kind() == SpreadElement) {
+                    return;
+                }
+                let flags_node = args.next();
+                let flags = flags_node.and_then(|flags_node| get_static_string_value(flags_node, context));
+
+                if flags_node.is_none()
+                    || flags.matches(|flags| !flags.contains('u') && !flags.contains('v'))
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "require_u_flag",
+                        // TODO: suggestions?
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{CallExpression, NewExpression, Regex},
+    };
+
+    #[test]
+    fn test_require_unicode_regexp_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            require_unicode_regexp_rule(),
+            rule_tests! {
+                valid => [
+                    "/foo/u",
+                    "/foo/gimuy",
+                    "RegExp('', 'u')",
+                    "new RegExp('', 'u')",
+                    "RegExp('', 'gimuy')",
+                    "new RegExp('', 'gimuy')",
+                    "const flags = 'u'; new RegExp('', flags)",
+                    "const flags = 'g'; new RegExp('', flags + 'u')",
+                    "const flags = 'gimu'; new RegExp('foo', flags[3])",
+                    "new RegExp('', flags)",
+                    "function f(flags) { return new RegExp('', flags) }",
+                    "function f(RegExp) { return new RegExp('foo') }",
+                    "new RegExp(...args)",
+                    "RegExp`foo`",
+                    "/foo/v",
+                    "/foo/dgimsvy",
+                    "RegExp('', 'v')",
+                    "new RegExp('', 'v')",
+                    "new RegExp('', 'dgimsvy')"
+                ],
+                invalid => [
+                    {
+                        code => "/foo/",
+                        errors => [{
+                            message_id => "require_u_flag",
+                            type => Regex,
+                            // suggestions: [{ messageId: "addUFlag", output: "/foo/u" }]
+                        }]
+                    },
+                    {
+                        code => "/foo/gimy",
+                        errors => [{
+                            message_id => "require_u_flag",
+                            type => Regex,
+                            // suggestions: [{ messageId: "addUFlag", output: "/foo/gimyu" }]
+                        }]
+                    },
+                    {
+                        code => "RegExp('foo')",
+                        errors => [{
+                            message_id => "require_u_flag",
+                            type => CallExpression,
+                            // suggestions: [{ messageId: "addUFlag", output: "RegExp('foo', \"u\")" }]
+                        }]
+                    },
+                    {
+                        code => "RegExp('foo', '')",
+                        errors => [{
+                            message_id => "require_u_flag",
+                            type => CallExpression,
+                            // suggestions: [{ messageId: "addUFlag", output: "RegExp('foo', 'u')" }]
+                        }]
+                    },
+                    {
+                        code => "RegExp('foo', 'gimy')",
+                        errors => [{
+                            message_id => "require_u_flag",
+                            type => CallExpression,
+                            // suggestions: [{ messageId: "addUFlag", output: "RegExp('foo', 'gimyu')" }]
+                        }]
+                    },
+                    {
+                        code => "new RegExp('foo')",
+                        errors => [{
+                            message_id => "require_u_flag",
+                            type => NewExpression,
+                            // suggestions: [{ messageId: "addUFlag", output: "new RegExp('foo', \"u\")" }]
+                        }]
+                    },
+                    {
+                        code => "new RegExp('foo', '')",
+                        errors => [{ message_id => "require_u_flag", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp('foo', 'gimy')",
+                        errors => [{ message_id => "require_u_flag", type => NewExpression }]
+                    },
+                    {
+                        code => "new RegExp(`foo`, `gimy`)",
+                        errors => [{ message_id => "require_u_flag", type => NewExpression }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Point, Range},
+    violation, Rule,
+};
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+enum AlwaysNever {
+    Always,
+    #[default]
+    Never,
+}
+
+const BOM: char = '\u{feff}';
+
+fn bom_range(len: usize) -> Range {
+    Range {
+        start_byte: 0,
+        end_byte: len,
+        start_point: Point { row: 0, column: 0 },
+        end_point: Point {
+            row: 0,
+            column: len,
+        },
+    }
+}
+
+pub fn unicode_bom_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "unicode-bom",
+        languages => [Javascript],
+        messages => [
+            expected => "Expected Unicode BOM (Byte Order Mark).",
+            unexpected => "Unexpected Unicode BOM (Byte Order Mark).",
+        ],
+        fixable => true,
+        options_type => AlwaysNever,
+        state => {
+            [per-config]
+            require_bom: bool = options == AlwaysNever::Always,
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                let has_bom = context.slice(0..node.end_byte()).starts_with(BOM);
+
+                if self.require_bom && !has_bom {
+                    context.report(violation! {
+                        node => node,
+                        range => bom_range(0),
+                        message_id => "expected",
+                        fix => |fixer| {
+                            fixer.replace_text_range(bom_range(0), BOM.to_string());
+                        },
+                    });
+                } else if !self.require_bom && has_bom {
+                    context.report(violation! {
+                        node => node,
+                        range => bom_range(BOM.len_utf8()),
+                        message_id => "unexpected",
+                        fix => |fixer| {
+                            fixer.remove_range(bom_range(BOM.len_utf8()));
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+
+    #[test]
+    fn test_unicode_bom_rule() {
+        RuleTester::run(
+            unicode_bom_rule(),
+            rule_tests! {
+                valid => [
+                    {
+                        code => "\u{feff} var a = 123;",
+                        options => "always"
+                    },
+                    {
+                        code => "var a = 123;",
+                        options => "never"
+                    },
+                    {
+                        code => "var a = 123; \u{feff}",
+                        options => "never"
+                    },
+                    "var a = 123;"
+                ],
+                invalid => [
+                    {
+                        code => "var a = 123;",
+                        output => "\u{feff}var a = 123;",
+                        options => "always",
+                        errors => [{ message_id => "expected", line => 1, column => 1 }]
+                    },
+                    {
+                        code => " // here's a comment \nvar a = 123;",
+                        output => "\u{feff} // here's a comment \nvar a = 123;",
+                        options => "always",
+                        errors => [{ message_id => "expected", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "\u{feff} var a = 123;",
+                        output => " var a = 123;",
+                        errors => [{ message_id => "unexpected", line => 1, column => 1 }]
+                    },
+                    {
+                        code => "\u{feff} var a = 123;",
+                        output => " var a = 123;",
+                        options => "never",
+                        errors => [{ message_id => "unexpected", line => 1, column => 1 }]
+                    }
+                ]
+            },
+        )
+    }
+}
@@ -0,0 +1,651 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{
+        get_call_expression_arguments, get_last_expression_of_sequence_expression, NodeExtJs,
+    },
+    kind::{MemberExpression, SequenceExpression, SubscriptExpression, SwitchCase},
+    utils::ast_utils,
+};
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct Options {
+    enforce_for_switch_case: bool,
+    enforce_for_index_of: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            enforce_for_switch_case: true,
+            enforce_for_index_of: Default::default(),
+        }
+    }
+}
+
+fn is_nan_identifier(node: Node, context: &QueryMatchContext) -> bool {
+    let node = node.skip_parentheses();
+    let node_to_check = if node.kind() == SequenceExpression {
+        get_last_expression_of_sequence_expression(node).skip_parentheses()
+    } else {
+        node
+    };
+
+    ast_utils::is_specific_id(node_to_check, "NaN", context)
+        || ast_utils::is_specific_member_access(node_to_check, Some("Number"), Some("NaN"), context)
+}
+
+pub fn use_isnan_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "use-isnan",
+        languages => [Javascript],
+        messages => [
+            comparison_with_nan => "Use the isNaN function to compare with NaN.",
+            switch_nan => "'switch(NaN)' can never match a case clause. Use Number.isNaN instead of the switch.",
+            case_nan => "'case NaN' can never match. Use Number.isNaN before the switch.",
+            index_of_nan => "Array prototype method '{{method_name}}' cannot find NaN.",
+            replace_with_is_nan => "Replace with Number.isNaN.",
+            replace_with_casting_and_is_nan => "Replace with Number.isNaN and cast to a Number.",
+            replace_with_find_index => "Replace with Array.prototype.{{method_name}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            enforce_for_switch_case: bool = options.enforce_for_switch_case,
+            enforce_for_index_of: bool = options.enforce_for_index_of,
+        },
+        listeners => [
+            r#"
+              (binary_expression
+                operator: [
+                  "<"
+                  ">"
+                  "<="
+                  ">="
+                  "=="
+                  "==="
+                  "!="
+                  "!=="
+                ]
+              ) @c
+            "# => |node, context| {
+                if is_nan_identifier(node.field("left"), context)
+                    || is_nan_identifier(node.field("right"), context)
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "comparison_with_nan",
+                        // TODO: suggestions?
+                        // const suggestedFixes = [];
+                        // const NaNNode = isNaNIdentifier(node.left) ? node.left : node.right;
+                        // const isSequenceExpression = NaNNode.type === "SequenceExpression";
+                        // const isSuggestable = fixableOperators.has(node.operator) && !isSequenceExpression;
+                        // const isCastable = castableOperators.has(node.operator);
+                        //
+                        // if (isSuggestable) {
+                        //     suggestedFixes.push({
+                        //         messageId: "replaceWithIsNaN",
+                        //         fix: getBinaryExpressionFixer(node, value => `Number.isNaN(${value})`)
+                        //     });
+                        //
+                        //     if (isCastable) {
+                        //         suggestedFixes.push({
+                        //             messageId: "replaceWithCastingAndIsNaN",
+                        //             fix: getBinaryExpressionFixer(node, value => `Number.isNaN(Number(${value}))`)
+                        //         });
+                        //     }
+                        // }
+                    });
+                }
+            },
+            r#"
+              (switch_statement) @c
+            "# => |node, context| {
+                if !self.enforce_for_switch_case {
+                    return;
+                }
+
+                if is_nan_identifier(node.field("value"), context) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "switch_nan",
+                        // TODO: suggestions?
+                        // suggest: [{
+                        //     messageId: "replaceWithIsNaN",
+                        //     fix: getSwitchDiscriminantFix(node)
+                        // }]
+                    });
+                }
+
+                for switch_case in node
+                    .field("body")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .filter(|child| child.kind() == SwitchCase)
+                {
+                    if is_nan_identifier(switch_case.field("value"), context) {
+                        context.report(violation! {
+                            node => switch_case,
+                            message_id => "case_nan",
+                        });
+                    }
+                }
+            },
+            r#"
+              (call_expression) @c
+            "# => |node, context| {
+                if !self.enforce_for_index_of {
+                    return;
+                }
+
+                let callee = node.field("function").skip_parentheses();
+                if !matches!(callee.kind(), MemberExpression | SubscriptExpression) {
+                    return;
+                }
+
+                let Some(method_name) = ast_utils::get_static_property_name(callee, context) else {
+                    return;
+                };
+                if !matches!(&*method_name, "indexOf" | "lastIndexOf") {
+                    return;
+                }
+
+                let Some(arguments) = get_call_expression_arguments(node) else {
+                    return;
+                };
+                let arguments = arguments.collect::<Vec<_>>();
+                if arguments.len() <= 2
+                    && arguments
+                        .first()
+                        .matches(|&argument| is_nan_identifier(argument, context))
+                {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "index_of_nan",
+                        data => {
+                            method_name => method_name,
+                        },
+                        // TODO: suggestions?
+                        // suggest: [{
+                        //     messageId: "replaceWithFindIndex",
+                        //     data: { methodName: methodName === "indexOf" ? "findIndex" : "findLastIndex" },
+                        //     fix: ...
+                        // }]
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::kind::{BinaryExpression, CallExpression, SwitchStatement};
+
+    #[test]
+    fn test_use_isnan_rule() {
+        RuleTester::run(
+            use_isnan_rule(),
+            rule_tests! {
+                valid => [
+                    "var x = NaN;",
+                    "isNaN(NaN) === true;",
+                    "isNaN(123) !== true;",
+                    "Number.isNaN(NaN) === true;",
+                    "Number.isNaN(123) !== true;",
+                    "foo(NaN + 1);",
+                    "foo(1 + NaN);",
+                    "foo(NaN - 1)",
+                    "foo(1 - NaN)",
+                    "foo(NaN * 2)",
+                    "foo(2 * NaN)",
+                    "foo(NaN / 2)",
+                    "foo(2 / NaN)",
+                    "var x; if (x = NaN) { }",
+                    "var x = Number.NaN;",
+                    "isNaN(Number.NaN) === true;",
+                    "Number.isNaN(Number.NaN) === true;",
+                    "foo(Number.NaN + 1);",
+                    "foo(1 + Number.NaN);",
+                    "foo(Number.NaN - 1)",
+                    "foo(1 - Number.NaN)",
+                    "foo(Number.NaN * 2)",
+                    "foo(2 * Number.NaN)",
+                    "foo(Number.NaN / 2)",
+                    "foo(2 / Number.NaN)",
+                    "var x; if (x = Number.NaN) { }",
+                    "x === Number[NaN];",
+                    "x === (NaN, 1)",
+                    "x === (doStuff(), NaN, 1)",
+                    "x === (doStuff(), Number.NaN, 1)",
+
+                    // enforceForSwitchCase
+                    "switch(NaN) { case foo: break; }",
+                    "switch(foo) { case NaN: break; }",
+                    {
+                        code => "switch(NaN) { case foo: break; }",
+                        options => { enforce_for_switch_case => false }
+                    },
+                    {
+                        code => "switch(foo) { case NaN: break; }",
+                        options => { enforce_for_switch_case => false }
+                    },
+                    {
+                        code => "switch(NaN) { case NaN: break; }",
+                        options => { enforce_for_switch_case => false }
+                    },
+                    {
+                        code => "switch(foo) { case bar: break; case NaN: break; default: break; }",
+                        options => { enforce_for_switch_case => false }
+                    },
+                    {
+                        code => "switch(foo) {}",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { case bar: NaN; }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { default: NaN; }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(Nan) {}",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch('NaN') { default: break; }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo(NaN)) {}",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo.NaN) {}",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { case Nan: break }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { case 'NaN': break }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { case foo(NaN): break }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { case foo.NaN: break }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { case bar: break; case 1: break; default: break; }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(Number.NaN) { case foo: break; }",
+                        options => { enforce_for_switch_case => false }
+                    },
+                    {
+                        code => "switch(foo) { case Number.NaN: break; }",
+                        options => { enforce_for_switch_case => false }
+                    },
+                    {
+                        code => "switch(foo) { case Number.Nan: break }",
+                        options => { enforce_for_switch_case => true }
+                    },
+                    {
+                        code => "switch(foo) { case Number[NaN]: break }",
+                        options => { enforce_for_switch_case => true }
+                    },
+
+                    // enforceForIndexOf
+                    "foo.indexOf(NaN)",
+                    "foo.lastIndexOf(NaN)",
+                    "foo.indexOf(Number.NaN)",
+                    "foo.lastIndexOf(Number.NaN)",
+                    {
+                        code => "foo.indexOf(NaN)",
+                        options => { enforce_for_index_of => false }
+                    },
+                    {
+                        code => "indexOf(NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "lastIndexOf(NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "new foo.indexOf(NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.bar(NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.IndexOf(NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo[indexOf](NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo[lastIndexOf](NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "indexOf.foo(NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf()",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf(a)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf(Nan)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf(a, NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.lastIndexOf(NaN, b, c)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf(a, b)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.lastIndexOf(NaN, NaN, b)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf(...NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.lastIndexOf(NaN())",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf(Number.NaN)",
+                        options => { enforce_for_index_of => false }
+                    },
+                    {
+                        code => "foo.indexOf(Number.Nan)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf(a, Number.NaN)",
+                        options => { enforce_for_index_of => true }
+                    },
+                    {
+                        code => "foo.indexOf((NaN, 1))",
+                        options => { enforce_for_index_of => true }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "123 == NaN;",
+                        // suggestions: [{ message_id => "replace_with_is_nan", output => "Number.isNaN(123);" }]
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "123 === NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN === \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN == \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "123 != NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "123 !== NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN !== \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN != \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN < \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "\"abc\" < NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN > \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "\"abc\" > NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN <= \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "\"abc\" <= NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "NaN >= \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "\"abc\" >= NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "123 == Number.NaN;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "Number.NaN === \"abc\";",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "x === Number?.NaN;",
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "x === Number['NaN'];",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "x == (foo, NaN);",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "x === (doStuff(), Number.NaN);",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+                    {
+                        code => "(NaN) === x;",
+                        errors => [{ message_id => "comparison_with_nan", type => BinaryExpression }]
+                    },
+
+                    // enforceForSwitchCase
+                    {
+                        code => "switch(NaN) { case foo: break; }",
+                        errors => [{ message_id => "switch_nan", type => SwitchStatement, column => 1 }]
+                    },
+                    {
+                        code => "switch(foo) { case NaN: break; }",
+                        errors => [{ message_id => "case_nan", type => SwitchCase, column => 15 }]
+                    },
+                    {
+                        code => "switch(NaN) { case foo: break; }",
+                        options => { enforce_for_switch_case => true },
+                        errors => [{ message_id => "switch_nan", type => SwitchStatement, column => 1 }]
+                    },
+                    {
+                        code => "switch(foo) { case NaN: break; }",
+                        options => { enforce_for_switch_case => true },
+                        errors => [{ message_id => "case_nan", type => SwitchCase, column => 15 }]
+                    },
+                    {
+                        code => "switch(NaN) {}",
+                        options => { enforce_for_switch_case => true },
+                        errors => [{ message_id => "switch_nan", type => SwitchStatement, column => 1 }]
+                    },
+                    {
+                        code => "switch(NaN) { case foo: break; default: break; }",
+                        options => { enforce_for_switch_case => true },
+                        errors => [{ message_id => "switch_nan", type => SwitchStatement, column => 1 }]
+                    },
+                    {
+                        code => "switch(foo) { case bar: break; case NaN: break; default: break; }",
+                        options => { enforce_for_switch_case => true },
+                        errors => [{ message_id => "case_nan", type => SwitchCase, column => 32 }]
+                    },
+                    {
+                        code => "switch(foo) { case bar: foo(); case baz: break; case NaN: break; }",
+                        options => { enforce_for_switch_case => true },
+                        errors => [{ message_id => "case_nan", type => SwitchCase, column => 49 }]
+                    },
+                    {
+                        code => "switch(NaN) { case NaN: break; }",
+                        options => { enforce_for_switch_case => true },
+                        errors => [
+                            { message_id => "switch_nan", type => SwitchStatement, column => 1 },
+                            { message_id => "case_nan", type => SwitchCase, column => 15 }
+                        ]
+                    },
+                    {
+                        code => "switch(foo) { case NaN: case NaN: break; }",
+                        options => { enforce_for_switch_case => true },
+                        errors => [
+                            { message_id => "case_nan", type => SwitchCase, column => 15 },
+                            { message_id => "case_nan", type => SwitchCase, column => 25 }
+                        ]
+                    },
+                    {
+                        code => "switch(Number.NaN) { case foo: break; }",
+                        errors => [{ message_id => "switch_nan", type => SwitchStatement, column => 1 }]
+                    },
+                    {
+                        code => "switch(foo) { case Number.NaN: break; }",
+                        errors => [{ message_id => "case_nan", type => SwitchCase, column => 15 }]
+                    },
+                    {
+                        code => "switch((NaN)) { case foo: break; }",
+                        errors => [{ message_id => "switch_nan", type => SwitchStatement, column => 1 }]
+                    },
+                    {
+                        code => "switch(foo) { case (NaN): break; }",
+                        errors => [{ message_id => "case_nan", type => SwitchCase, column => 15 }]
+                    },
+
+                    // enforceForIndexOf
+                    {
+                        code => "foo.indexOf(NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    },
+                    {
+                        code => "foo.lastIndexOf(NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "lastIndexOf" } }]
+                    },
+                    {
+                        code => "foo['indexOf'](NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    },
+                    {
+                        code => "foo['lastIndexOf'](NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "lastIndexOf" } }]
+                    },
+                    {
+                        code => "foo().indexOf(NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    },
+                    {
+                        code => "foo.bar.lastIndexOf(NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "lastIndexOf" } }]
+                    },
+                    {
+                        code => "foo.indexOf?.(NaN)",
+                        options => { enforce_for_index_of => true },
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    },
+                    {
+                        code => "foo?.indexOf(NaN)",
+                        options => { enforce_for_index_of => true },
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    },
+                    {
+                        code => "(foo?.indexOf)(NaN)",
+                        options => { enforce_for_index_of => true },
+                        environment => { ecma_version => 2020 },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    },
+                    {
+                        code => "foo.indexOf(Number.NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    },
+                    {
+                        code => "foo.lastIndexOf(Number.NaN)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "lastIndexOf" } }]
+                    },
+                    {
+                        code => "foo.lastIndexOf(NaN, 0)",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "lastIndexOf" } }]
+                    },
+                    {
+                        code => "foo.indexOf((1, NaN))",
+                        options => { enforce_for_index_of => true },
+                        errors => [{ message_id => "index_of_nan", type => CallExpression, data => { method_name => "indexOf" } }]
+                    }
+                ]
+            },
+        )
+    }
+}
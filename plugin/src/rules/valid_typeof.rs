@@ -0,0 +1,353 @@
+use std::{collections::HashSet, sync::Arc};
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, Rule};
+
+use crate::{
+    ast_helpers::NodeExtJs,
+    kind::{self, is_literal_kind, BinaryExpression, UnaryExpression, Undefined},
+    scope::ScopeManager,
+    utils::ast_utils,
+};
+
+static VALID_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    [
+        "symbol",
+        "undefined",
+        "object",
+        "boolean",
+        "number",
+        "string",
+        "function",
+        "bigint",
+    ]
+    .into()
+});
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    require_string_literals: bool,
+}
+
+fn is_typeof_expression(node: Node) -> bool {
+    node.kind() == UnaryExpression && node.field("operator").kind() == "typeof"
+}
+
+pub fn valid_typeof_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "valid-typeof",
+        languages => [Javascript],
+        messages => [
+            invalid_value => "Invalid typeof comparison value.",
+            not_string => "Typeof comparisons should be to string literals.",
+            suggest_string => "Use `\"{{type}}\"` instead of `{{type}}`.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            require_string_literals: bool = options.require_string_literals,
+        },
+        listeners => [
+            r#"
+              (unary_expression
+                operator: "typeof"
+              ) @c
+            "# => |node, context| {
+                let parent = node.next_non_parentheses_ancestor(context);
+                if parent.kind() != BinaryExpression
+                    || !matches!(parent.field("operator").kind(), "==" | "===" | "!=" | "!==")
+                {
+                    return;
+                }
+
+                let sibling = if parent.field("left").skip_parentheses() == node {
+                    parent.field("right")
+                } else {
+                    parent.field("left")
+                }
+                .skip_parentheses();
+
+                if sibling.kind() == kind::String || ast_utils::is_static_template_literal(sibling) {
+                    let value = ast_utils::get_static_string_value(sibling, context).unwrap();
+                    if !VALID_TYPES.contains(&*value) {
+                        context.report(violation! {
+                            node => sibling,
+                            message_id => "invalid_value",
+                        });
+                    }
+                } else if is_literal_kind(sibling.kind()) {
+                    context.report(violation! {
+                        node => sibling,
+                        message_id => "invalid_value",
+                    });
+                } else if sibling.kind() == Undefined
+                    && ast_utils::is_reference_to_global_variable(
+                        &context.retrieve::<ScopeManager<'a>>().get_scope(sibling),
+                        sibling,
+                    )
+                {
+                    context.report(violation! {
+                        node => sibling,
+                        message_id => if self.require_string_literals {
+                            "not_string"
+                        } else {
+                            "invalid_value"
+                        },
+                        // TODO: suggestions?
+                        // suggest: [{
+                        //     messageId: "suggestString",
+                        //     data: { type: "undefined" },
+                        //     fix(fixer) {
+                        //         return fixer.replaceText(sibling, '"undefined"');
+                        //     }
+                        // }]
+                    });
+                } else if self.require_string_literals && !is_typeof_expression(sibling) {
+                    context.report(violation! {
+                        node => sibling,
+                        message_id => "not_string",
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, RuleTester};
+
+    use super::*;
+    use crate::{
+        get_instance_provider_factory,
+        kind::{CallExpression, Identifier, Number, TemplateString},
+    };
+
+    #[test]
+    fn test_valid_typeof_rule() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            valid_typeof_rule(),
+            rule_tests! {
+                valid => [
+                    "typeof foo === 'string'",
+                    "typeof foo === 'object'",
+                    "typeof foo === 'function'",
+                    "typeof foo === 'undefined'",
+                    "typeof foo === 'boolean'",
+                    "typeof foo === 'number'",
+                    "typeof foo === 'bigint'",
+                    "'string' === typeof foo",
+                    "'object' === typeof foo",
+                    "'function' === typeof foo",
+                    "'undefined' === typeof foo",
+                    "'boolean' === typeof foo",
+                    "'number' === typeof foo",
+                    "typeof foo === typeof bar",
+                    "typeof foo === baz",
+                    "typeof foo !== someType",
+                    "typeof bar != someType",
+                    "someType === typeof bar",
+                    "someType == typeof bar",
+                    "typeof foo == 'string'",
+                    "typeof(foo) === 'string'",
+                    "typeof(foo) !== 'string'",
+                    "typeof(foo) == 'string'",
+                    "typeof(foo) != 'string'",
+                    "var oddUse = typeof foo + 'thing'",
+                    "function f(undefined) { typeof x === undefined }",
+                    "var undefined; typeof x === undefined",
+                    {
+                        code => "typeof foo === 'number'",
+                        options => { require_string_literals => true }
+                    },
+                    {
+                        code => "typeof foo === \"number\"",
+                        options => { require_string_literals => true }
+                    },
+                    {
+                        code => "var baz = typeof foo + 'thing'",
+                        options => { require_string_literals => true }
+                    },
+                    {
+                        code => "typeof foo === typeof bar",
+                        options => { require_string_literals => true }
+                    },
+                    {
+                        code => "typeof foo === `string`",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "`object` === typeof foo",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "typeof foo === `str${somethingElse}`",
+                        environment => { ecma_version => 6 }
+                    },
+                    {
+                        code => "typeof foo === (`number`)",
+                        environment => { ecma_version => 6 }
+                    }
+                ],
+                invalid => [
+                    {
+                        code => "typeof foo === 'strnig'",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "'strnig' === typeof foo",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "if (typeof bar === 'umdefined') {}",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "typeof foo !== 'strnig'",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "'strnig' !== typeof foo",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "if (typeof bar !== 'umdefined') {}",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "typeof foo != 'strnig'",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "'strnig' != typeof foo",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "if (typeof bar != 'umdefined') {}",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "typeof foo == 'strnig'",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "'strnig' == typeof foo",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "if (typeof bar == 'umdefined') {}",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "if (typeof bar === `umdefined`) {}",
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "invalid_value", type => TemplateString }]
+                    },
+                    {
+                        code => "typeof foo == 'invalid string'",
+                        options => { require_string_literals => true },
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    },
+                    {
+                        code => "if (typeof bar !== undefined) {}",
+                        errors => [{
+                            message_id => "invalid_value",
+                            type => Undefined,
+                            // suggestions: [{
+                            //     message_id => "suggest_string",
+                            //     data => { type => "undefined" },
+                            //     output => "if (typeof bar !== \"undefined\") {}"
+                            // }]
+                        }]
+                    },
+                    {
+                        code => "typeof foo == Object",
+                        options => { require_string_literals => true },
+                        errors => [{ message_id => "not_string", type => Identifier }]
+                    },
+                    {
+                        code => "typeof foo === undefined",
+                        options => { require_string_literals => true },
+                        errors => [{
+                            message_id => "not_string",
+                            type => Undefined,
+                            // suggestions: [{
+                            //     message_id => "suggest_string",
+                            //     data => { type => "undefined" },
+                            //     output => "typeof foo === \"undefined\""
+                            // }]
+                        }]
+                    },
+                    {
+                        code => "undefined === typeof foo",
+                        options => { require_string_literals => true },
+                        errors => [{
+                            message_id => "not_string",
+                            type => Undefined,
+                            // suggestions: [{
+                            //     message_id => "suggest_string",
+                            //     data => { type => "undefined" },
+                            //     output => "\"undefined\" === typeof foo"
+                            // }]
+                        }]
+                    },
+                    {
+                        code => "undefined == typeof foo",
+                        options => { require_string_literals => true },
+                        errors => [{
+                            message_id => "not_string",
+                            type => Undefined,
+                            // suggestions: [{
+                            //     message_id => "suggest_string",
+                            //     data => { type => "undefined" },
+                            //     output => "\"undefined\" == typeof foo"
+                            // }]
+                        }]
+                    },
+                    {
+                        code => "typeof foo === `undefined${foo}`",
+                        options => { require_string_literals => true },
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "not_string", type => TemplateString }]
+                    },
+                    {
+                        code => "typeof foo === `${string}`",
+                        options => { require_string_literals => true },
+                        environment => { ecma_version => 6 },
+                        errors => [{ message_id => "not_string", type => TemplateString }]
+                    },
+                    {
+                        code => "typeof foo === Object",
+                        options => { require_string_literals => true },
+                        errors => [{ message_id => "not_string", type => Identifier }]
+                    },
+                    {
+                        code => "typeof foo === foo()",
+                        options => { require_string_literals => true },
+                        errors => [{ message_id => "not_string", type => CallExpression }]
+                    },
+                    {
+                        code => "typeof foo === 1 + 1",
+                        options => { require_string_literals => true },
+                        errors => [{ message_id => "not_string", type => BinaryExpression }]
+                    },
+                    {
+                        code => "typeof foo === null",
+                        errors => [{ message_id => "invalid_value", type => kind::Null }]
+                    },
+                    {
+                        code => "typeof foo === 123",
+                        errors => [{ message_id => "invalid_value", type => Number }]
+                    },
+                    {
+                        code => "typeof foo === (('strnig'))",
+                        errors => [{ message_id => "invalid_value", type => kind::String }]
+                    }
+                ]
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
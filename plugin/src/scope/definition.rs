@@ -147,4 +147,8 @@ impl<'a, 'b> Definition<'a, 'b> {
     pub fn node(&self) -> Node<'a> {
         self.definition.node()
     }
+
+    pub fn parent(&self) -> Option<Node<'a>> {
+        self.definition.parent()
+    }
 }
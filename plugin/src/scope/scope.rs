@@ -987,6 +987,10 @@ impl<'a, 'b> Scope<'a, 'b> {
             .is_arguments_materialized(&self.scope_manager.arena.variables.borrow())
     }
 
+    pub fn is_this_materialized(&self) -> bool {
+        self.scope.is_this_materialized()
+    }
+
     pub fn child_scopes(&self) -> impl Iterator<Item = Scope<'a, 'b>> + '_ {
         self.scope
             .base()
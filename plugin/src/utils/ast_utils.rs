@@ -13,12 +13,13 @@ use tree_sitter_lint::{
 use crate::{
     assert_kind,
     ast_helpers::{
-        get_call_expression_arguments, get_cooked_value, get_first_non_comment_child,
-        get_last_expression_of_sequence_expression, get_method_definition_kind,
-        get_number_literal_string_value, get_number_literal_value, get_prev_non_comment_sibling,
-        is_block_comment, is_chain_expression, is_logical_expression, is_punctuation_kind, parse,
-        skip_nodes_of_type, template_string_has_any_cooked_literal_characters,
-        MethodDefinitionKind, NodeExtJs, Number, NumberOrBigInt,
+        get_call_expression_arguments, get_comment_contents, get_comment_type, get_cooked_value,
+        get_first_non_comment_child, get_last_expression_of_sequence_expression,
+        get_method_definition_kind, get_number_literal_string_value, get_number_literal_value,
+        get_prev_non_comment_sibling, is_block_comment, is_chain_expression, is_logical_expression,
+        is_punctuation_kind, parse, skip_nodes_of_type,
+        template_string_has_any_cooked_literal_characters, CommentType, MethodDefinitionKind,
+        NodeExtJs, Number, NumberOrBigInt,
     },
     kind::{
         self, is_literal_kind, Array, ArrowFunction, AssignmentExpression,
@@ -32,7 +33,7 @@ use crate::{
         TemplateString, TemplateSubstitution, TernaryExpression, This, True, UnaryExpression,
         Undefined, UpdateExpression, YieldExpression,
     },
-    scope::{Reference, Scope, ScopeType, Variable},
+    scope::{Reference, Scope, ScopeManager, ScopeType, Variable},
 };
 
 static ARRAY_OR_TYPED_ARRAY_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r#"Array$"#).unwrap());
@@ -297,7 +298,9 @@ pub fn is_same_reference(
 
     match left.kind() {
         Super | This => true,
-        Identifier | PrivatePropertyIdentifier => left.text(context) == right.text(context),
+        Identifier | PropertyIdentifier | PrivatePropertyIdentifier => {
+            left.text(context) == right.text(context)
+        }
         kind if is_literal_kind(kind) => equal_literal_value(left, right, context),
         MemberExpression | SubscriptExpression => {
             if !disable_static_computed_key {
@@ -405,6 +408,15 @@ pub fn is_coalesce_expression(node: Node) -> bool {
     node.kind() == BinaryExpression && node.field("operator").kind() == "??"
 }
 
+fn is_and_or_expression(node: Node) -> bool {
+    node.kind() == BinaryExpression && matches!(node.field("operator").kind(), "&&" | "||")
+}
+
+pub fn is_mixed_logical_and_coalesce_expressions(left: Node, right: Node) -> bool {
+    is_and_or_expression(left) && is_coalesce_expression(right)
+        || is_coalesce_expression(left) && is_and_or_expression(right)
+}
+
 static LOGICAL_ASSIGNMENT_OPERATORS: Lazy<HashSet<&'static str>> =
     Lazy::new(|| ["&&=", "||=", "??="].into_iter().collect());
 
@@ -623,6 +635,13 @@ pub fn get_variable_by_name<'a, 'b>(
     }
 }
 
+pub fn is_global_reference<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let scope_manager = context.retrieve::<ScopeManager<'a>>();
+    get_variable_by_name(scope_manager.get_scope(node), &node.text(context)).matches(|variable| {
+        variable.scope().type_() == ScopeType::Global && variable.defs().next().is_none()
+    })
+}
+
 pub fn get_precedence(node: Node) -> u32 {
     _get_precedence(
         node.kind(),
@@ -1085,3 +1104,27 @@ pub fn is_static_template_literal(node: Node) -> bool {
 pub fn has_octal_or_non_octal_decimal_escape_sequence(raw_string: &str) -> bool {
     OCTAL_OR_NON_OCTAL_DECIMAL_ESCAPE_PATTERN.is_match(raw_string)
 }
+
+pub fn is_directive_comment(node: Node, context: &QueryMatchContext) -> bool {
+    let comment = get_comment_contents(node, context);
+    let comment = comment.trim();
+
+    match get_comment_type(node, context) {
+        CommentType::Line => comment.starts_with("eslint-"),
+        CommentType::Block => {
+            comment.starts_with("global ")
+                || comment.starts_with("eslint ")
+                || comment.starts_with("eslint-")
+        }
+    }
+}
+
+pub fn get_module_export_name<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Cow<'a, str> {
+    match node.kind() {
+        kind::String => get_static_string_value(node, context).unwrap(),
+        _ => node.text(context),
+    }
+}
@@ -1,5 +1,7 @@
 mod find_variable;
 mod get_innermost_scope;
+mod reference_tracker;
 
 pub use find_variable::find_variable;
 pub use get_innermost_scope::get_innermost_scope;
+pub use reference_tracker::{ReferenceTracker, ReferenceType, TraceMap, TrackedReference};
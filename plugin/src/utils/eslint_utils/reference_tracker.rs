@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+
+use super::find_variable;
+use crate::{
+    ast_helpers::is_tagged_template_expression,
+    kind::{
+        AssignmentExpression, AssignmentPattern, BinaryExpression, CallExpression, Identifier,
+        MemberExpression, NewExpression, ObjectAssignmentPattern, ObjectPattern, PairPattern,
+        ParenthesizedExpression, SequenceExpression, ShorthandPropertyIdentifierPattern,
+        SubscriptExpression, TernaryExpression, VariableDeclarator,
+    },
+    scope::{Scope, Variable},
+    utils::ast_utils,
+};
+
+const DEFAULT_GLOBAL_OBJECT_NAMES: [&str; 4] = ["global", "globalThis", "self", "window"];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReferenceType {
+    Read,
+    Call,
+    Construct,
+}
+
+#[derive(Clone, Debug)]
+pub struct TraceMap<TInfo> {
+    pub read: Option<TInfo>,
+    pub call: Option<TInfo>,
+    pub construct: Option<TInfo>,
+    pub children: HashMap<String, TraceMap<TInfo>>,
+}
+
+impl<TInfo> Default for TraceMap<TInfo> {
+    fn default() -> Self {
+        Self {
+            read: Default::default(),
+            call: Default::default(),
+            construct: Default::default(),
+            children: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TrackedReference<'a, TInfo> {
+    pub node: Node<'a>,
+    pub path: Vec<String>,
+    pub type_: ReferenceType,
+    pub info: TInfo,
+}
+
+fn is_modified_global(variable: &Variable) -> bool {
+    variable.defs().next().is_some() || variable.references().any(|reference| reference.is_write())
+}
+
+fn is_pass_through(node: Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+
+    match parent.kind() {
+        TernaryExpression => parent.field("condition") != node,
+        BinaryExpression => matches!(parent.field("operator").kind(), "&&" | "||" | "??"),
+        SequenceExpression => parent.field("right") == node,
+        ParenthesizedExpression => true,
+        _ => false,
+    }
+}
+
+pub struct ReferenceTracker<'a, 'b> {
+    global_scope: Scope<'a, 'b>,
+    global_object_names: Vec<String>,
+    variable_stack: Vec<Variable<'a, 'b>>,
+}
+
+impl<'a, 'b> ReferenceTracker<'a, 'b> {
+    pub fn new(global_scope: Scope<'a, 'b>) -> Self {
+        Self {
+            global_scope,
+            global_object_names: DEFAULT_GLOBAL_OBJECT_NAMES
+                .into_iter()
+                .map(ToOwned::to_owned)
+                .collect(),
+            variable_stack: Default::default(),
+        }
+    }
+
+    pub fn iterate_global_references<TInfo: Clone>(
+        &mut self,
+        trace_map: &TraceMap<TInfo>,
+        context: &QueryMatchContext<'a, '_>,
+    ) -> Vec<TrackedReference<'a, TInfo>> {
+        let mut references: Vec<TrackedReference<'a, TInfo>> = Default::default();
+
+        for (key, next_trace_map) in &trace_map.children {
+            let Some(variable) = self
+                .global_scope
+                .set()
+                .get(&**key)
+                .cloned()
+                .filter(|variable| !is_modified_global(variable))
+            else {
+                continue;
+            };
+
+            self.iterate_variable_references(
+                variable,
+                &[key.clone()],
+                next_trace_map,
+                true,
+                &mut references,
+                context,
+            );
+        }
+
+        for key in self.global_object_names.clone() {
+            let Some(variable) = self
+                .global_scope
+                .set()
+                .get(&*key)
+                .cloned()
+                .filter(|variable| !is_modified_global(variable))
+            else {
+                continue;
+            };
+
+            self.iterate_variable_references(
+                variable,
+                &[],
+                trace_map,
+                false,
+                &mut references,
+                context,
+            );
+        }
+
+        references
+    }
+
+    fn iterate_variable_references<TInfo: Clone>(
+        &mut self,
+        variable: Variable<'a, 'b>,
+        path: &[String],
+        trace_map: &TraceMap<TInfo>,
+        should_report: bool,
+        references: &mut Vec<TrackedReference<'a, TInfo>>,
+        context: &QueryMatchContext<'a, '_>,
+    ) {
+        if self.variable_stack.contains(&variable) {
+            return;
+        }
+
+        self.variable_stack.push(variable.clone());
+        for reference in variable.references() {
+            if !reference.is_read() {
+                continue;
+            }
+            let node = reference.identifier();
+
+            if should_report {
+                if let Some(info) = trace_map.read.as_ref() {
+                    references.push(TrackedReference {
+                        node,
+                        path: path.to_owned(),
+                        type_: ReferenceType::Read,
+                        info: info.clone(),
+                    });
+                }
+            }
+            self.iterate_property_references(node, path, trace_map, references, context);
+        }
+        self.variable_stack.pop();
+    }
+
+    fn iterate_property_references<TInfo: Clone>(
+        &mut self,
+        root_node: Node<'a>,
+        path: &[String],
+        trace_map: &TraceMap<TInfo>,
+        references: &mut Vec<TrackedReference<'a, TInfo>>,
+        context: &QueryMatchContext<'a, '_>,
+    ) {
+        let mut node = root_node;
+        while is_pass_through(node) {
+            node = node.parent().unwrap();
+        }
+
+        let Some(parent) = node.parent() else {
+            return;
+        };
+        match parent.kind() {
+            MemberExpression | SubscriptExpression => {
+                if parent.field("object") != node {
+                    return;
+                }
+                let Some(key) = ast_utils::get_static_property_name(parent, context) else {
+                    return;
+                };
+                let Some(next_trace_map) = trace_map.children.get(&*key) else {
+                    return;
+                };
+
+                let mut path = path.to_owned();
+                path.push(key.into_owned());
+                if let Some(info) = next_trace_map.read.as_ref() {
+                    references.push(TrackedReference {
+                        node: parent,
+                        path: path.clone(),
+                        type_: ReferenceType::Read,
+                        info: info.clone(),
+                    });
+                }
+                self.iterate_property_references(
+                    parent,
+                    &path,
+                    next_trace_map,
+                    references,
+                    context,
+                );
+            }
+            CallExpression => {
+                if parent.field("function") != node || is_tagged_template_expression(parent) {
+                    return;
+                }
+                if let Some(info) = trace_map.call.as_ref() {
+                    references.push(TrackedReference {
+                        node: parent,
+                        path: path.to_owned(),
+                        type_: ReferenceType::Call,
+                        info: info.clone(),
+                    });
+                }
+            }
+            NewExpression => {
+                if parent.field("constructor") != node {
+                    return;
+                }
+                if let Some(info) = trace_map.construct.as_ref() {
+                    references.push(TrackedReference {
+                        node: parent,
+                        path: path.to_owned(),
+                        type_: ReferenceType::Construct,
+                        info: info.clone(),
+                    });
+                }
+            }
+            AssignmentExpression => {
+                if parent.field("right") != node {
+                    return;
+                }
+                self.iterate_lhs_references(
+                    parent.field("left"),
+                    path,
+                    trace_map,
+                    references,
+                    context,
+                );
+                self.iterate_property_references(parent, path, trace_map, references, context);
+            }
+            AssignmentPattern | ObjectAssignmentPattern => {
+                if parent.field("right") != node {
+                    return;
+                }
+                self.iterate_lhs_references(
+                    parent.field("left"),
+                    path,
+                    trace_map,
+                    references,
+                    context,
+                );
+            }
+            VariableDeclarator => {
+                if parent.child_by_field_name("value") != Some(node) {
+                    return;
+                }
+                self.iterate_lhs_references(
+                    parent.field("name"),
+                    path,
+                    trace_map,
+                    references,
+                    context,
+                );
+            }
+            _ => (),
+        }
+    }
+
+    fn iterate_lhs_references<TInfo: Clone>(
+        &mut self,
+        pattern_node: Node<'a>,
+        path: &[String],
+        trace_map: &TraceMap<TInfo>,
+        references: &mut Vec<TrackedReference<'a, TInfo>>,
+        context: &QueryMatchContext<'a, '_>,
+    ) {
+        match pattern_node.kind() {
+            Identifier | ShorthandPropertyIdentifierPattern => {
+                if let Some(variable) = find_variable(&self.global_scope, pattern_node, context) {
+                    self.iterate_variable_references(
+                        variable, path, trace_map, false, references, context,
+                    );
+                }
+            }
+            ObjectPattern => {
+                for property in
+                    pattern_node.non_comment_named_children(SupportedLanguage::Javascript)
+                {
+                    let value = match property.kind() {
+                        PairPattern => property.field("value"),
+                        ShorthandPropertyIdentifierPattern => property,
+                        ObjectAssignmentPattern => property.field("left"),
+                        _ => continue,
+                    };
+                    let key_node = match property.kind() {
+                        ObjectAssignmentPattern => value,
+                        _ => property,
+                    };
+                    let Some(key) = ast_utils::get_static_property_name(key_node, context) else {
+                        continue;
+                    };
+                    let Some(next_trace_map) = trace_map.children.get(&*key) else {
+                        continue;
+                    };
+
+                    let mut next_path = path.to_owned();
+                    next_path.push(key.into_owned());
+                    if let Some(info) = next_trace_map.read.as_ref() {
+                        references.push(TrackedReference {
+                            node: property,
+                            path: next_path.clone(),
+                            type_: ReferenceType::Read,
+                            info: info.clone(),
+                        });
+                    }
+                    self.iterate_lhs_references(
+                        value,
+                        &next_path,
+                        next_trace_map,
+                        references,
+                        context,
+                    );
+                }
+            }
+            AssignmentPattern | ObjectAssignmentPattern => {
+                self.iterate_lhs_references(
+                    pattern_node.field("left"),
+                    path,
+                    trace_map,
+                    references,
+                    context,
+                );
+            }
+            _ => (),
+        }
+    }
+}